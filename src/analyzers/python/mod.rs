@@ -3,9 +3,11 @@ use crate::core::{
     ImplementationStatus, UserStory, Task, TaskType, Priority, Complexity, FeatureDescription,
     ProductRequirementDocument, AnalysisMetadata, ProjectType, AnalyzerConfig
 };
+use crate::core::analyzers::feature_flag_analyzer::inventory_feature_flags;
+use crate::core::analyzers::file_index::ProjectFileIndex;
 use regex::Regex;
 use std::fs;
-use walkdir::WalkDir;
+use std::path::Path;
 use anyhow::Result;
 
 pub struct PythonAnalyzer {
@@ -262,6 +264,7 @@ impl PythonAnalyzer {
                 "Template rendering system".to_string(),
             ],
             business_context: "Python web application designed to provide comprehensive functionality with both backend services and user-facing interfaces.".to_string(),
+            feature_flags: Vec::new(),
         }
     }
 
@@ -356,22 +359,17 @@ impl CodebaseAnalyzer for PythonAnalyzer {
         let mut files_analyzed = 0;
         let mut lines_of_code = 0;
 
-        for entry in WalkDir::new(project_path).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
-            
-            if let Some(ext) = path.extension() {
-                if ext == "py" {
-                    if let Ok(content) = fs::read_to_string(path) {
-                        files_analyzed += 1;
-                        lines_of_code += content.lines().count() as u32;
-                        
-                        if let Some(component) = self.extract_component_info(
-                            path.to_str().unwrap(),
-                            &content
-                        ) {
-                            components.push(component);
-                        }
-                    }
+        let index = ProjectFileIndex::build(Path::new(project_path));
+        for file in index.with_extensions(&["py"]) {
+            if let Ok(content) = fs::read_to_string(&file.path) {
+                files_analyzed += 1;
+                lines_of_code += content.lines().count() as u32;
+
+                if let Some(component) = self.extract_component_info(
+                    file.path.to_str().unwrap(),
+                    &content
+                ) {
+                    components.push(component);
                 }
             }
         }
@@ -384,7 +382,9 @@ impl CodebaseAnalyzer for PythonAnalyzer {
             .to_string();
 
         let user_stories = self.generate_user_stories(&components);
-        let prd = self.generate_prd(&components);
+        let mut prd = self.generate_prd(&components);
+        let mut warnings = Vec::new();
+        prd.feature_flags = inventory_feature_flags(&index, &mut warnings);
         let tasks = self.generate_tasks(&components, &user_stories);
 
         Ok(CodebaseAnalysis {
@@ -400,7 +400,7 @@ impl CodebaseAnalyzer for PythonAnalyzer {
                 files_analyzed,
                 lines_of_code,
                 confidence_score: 0.75,
-                warnings: Vec::new(),
+                warnings,
             },
             // Default implementations for enhanced fields
             framework_analysis: crate::core::FrameworkAnalysis {
@@ -435,6 +435,7 @@ impl CodebaseAnalyzer for PythonAnalyzer {
                 configuration_files: Vec::new(),
                 environment_variables: Vec::new(),
             },
+            workspace: None,
         })
     }
 
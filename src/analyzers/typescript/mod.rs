@@ -6,9 +6,14 @@ use crate::core::{
     DetectedFramework, UsageExtent, EndpointAnalysis, EntityAnalysis, ComponentRelationship, DataFlowAnalysis,
     FeatureStatus, TechnicalDebt, ExternalService, InternalDependency, ConfigFile
 };
+use crate::core::types::Framework;
+use crate::core::lockfile_resolver::LockfileResolver;
+use crate::core::analyzers::feature_flag_analyzer::inventory_feature_flags;
+use crate::core::analyzers::file_index::ProjectFileIndex;
 use regex::Regex;
 use std::fs;
-use walkdir::WalkDir;
+use std::path::Path;
+use crate::core::project_walker::ProjectWalker;
 use anyhow::Result;
 
 pub struct TypeScriptAnalyzer {
@@ -258,23 +263,17 @@ impl CodebaseAnalyzer for TypeScriptAnalyzer {
         let mut files_analyzed = 0;
         let mut lines_of_code = 0;
 
-        // Walk through the project directory
-        for entry in WalkDir::new(project_path).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
-            
-            if let Some(ext) = path.extension() {
-                if ["ts", "tsx", "js", "jsx"].contains(&ext.to_str().unwrap_or("")) {
-                    if let Ok(content) = fs::read_to_string(path) {
-                        files_analyzed += 1;
-                        lines_of_code += content.lines().count() as u32;
-                        
-                        if let Some(component) = self.extract_component_info(
-                            path.to_str().unwrap(),
-                            &content
-                        ) {
-                            components.push(component);
-                        }
-                    }
+        let index = ProjectFileIndex::build(Path::new(project_path));
+        for file in index.with_extensions(&["ts", "tsx", "js", "jsx"]) {
+            if let Ok(content) = fs::read_to_string(&file.path) {
+                files_analyzed += 1;
+                lines_of_code += content.lines().count() as u32;
+
+                if let Some(component) = self.extract_component_info(
+                    file.path.to_str().unwrap(),
+                    &content
+                ) {
+                    components.push(component);
                 }
             }
         }
@@ -287,7 +286,9 @@ impl CodebaseAnalyzer for TypeScriptAnalyzer {
             .to_string();
 
         let user_stories = self.generate_user_stories(&components);
-        let prd = self.generate_prd(&components);
+        let mut prd = self.generate_prd(&components);
+        let mut warnings = Vec::new();
+        prd.feature_flags = inventory_feature_flags(&index, &mut warnings);
         let tasks = self.generate_tasks(&components, &user_stories);
 
         // Enhanced analysis
@@ -321,13 +322,14 @@ impl CodebaseAnalyzer for TypeScriptAnalyzer {
                 files_analyzed,
                 lines_of_code,
                 confidence_score: 0.85,
-                warnings: Vec::new(),
+                warnings,
             },
             framework_analysis,
             business_context,
             implementation_analysis,
             status_intelligence,
             integration_points,
+            workspace: None,
         })
     }
 
@@ -624,6 +626,7 @@ impl TypeScriptAnalyzer {
                 "Responsive design implementation".to_string(),
             ],
             business_context: "Web application designed to provide efficient user experience through modern React components and interactions.".to_string(),
+            feature_flags: Vec::new(),
         }
     }
 
@@ -735,6 +738,7 @@ impl TypeScriptAnalyzer {
                 detected_frameworks.push(DetectedFramework {
                     name: "React".to_string(),
                     version,
+                    installed_version: None,
                     confidence,
                     evidence: vec!["package.json".to_string(), "*.tsx files".to_string()],
                     usage_extent: UsageExtent::Core,
@@ -748,6 +752,7 @@ impl TypeScriptAnalyzer {
                 detected_frameworks.push(DetectedFramework {
                     name: "Next.js".to_string(),
                     version,
+                    installed_version: None,
                     confidence: 0.9,
                     evidence: vec!["package.json".to_string(), "next.config.js".to_string()],
                     usage_extent: UsageExtent::Core,
@@ -778,6 +783,7 @@ impl TypeScriptAnalyzer {
                 detected_frameworks.push(DetectedFramework {
                     name: "NestJS".to_string(),
                     version,
+                    installed_version: None,
                     confidence,
                     evidence,
                     usage_extent: UsageExtent::Core,
@@ -791,6 +797,7 @@ impl TypeScriptAnalyzer {
                 detected_frameworks.push(DetectedFramework {
                     name: "TypeScript".to_string(),
                     version,
+                    installed_version: None,
                     confidence: 0.95,
                     evidence: vec!["package.json".to_string(), "*.ts files".to_string()],
                     usage_extent: UsageExtent::Core,
@@ -809,6 +816,7 @@ impl TypeScriptAnalyzer {
             detected_frameworks.push(DetectedFramework {
                 name: "NestJS".to_string(),
                 version: None,
+                installed_version: None,
                 confidence: 0.85, // Lower confidence without package.json evidence
                 evidence: nestjs_patterns,
                 usage_extent: UsageExtent::Core,
@@ -816,6 +824,18 @@ impl TypeScriptAnalyzer {
             confidence_scores.insert("NestJS".to_string(), 0.85);
         }
 
+        // Report what the lockfiles pin next to the declared ranges
+        let lockfiles = LockfileResolver::new(Path::new(project_path));
+        for framework in &mut detected_frameworks {
+            let installed = match Framework::from_display_name(&framework.name) {
+                Some(builtin) => lockfiles.framework_version(builtin),
+                // TypeScript and the Deno-only frameworks publish under their own name, or a JSR scope of it such as `@oak/oak`
+                None => lockfiles.installed_version(&framework.name)
+                    .or_else(|| lockfiles.installed_version(&format!("@{0}/{0}", framework.name))),
+            };
+            framework.installed_version = installed.map(|installed| installed.version.clone());
+        }
+
         let architecture_pattern = if detected_frameworks.iter().any(|f| f.name == "Next.js") {
             "SSR/SSG with React".to_string()
         } else if detected_frameworks.iter().any(|f| f.name == "NestJS") {
@@ -1256,37 +1276,34 @@ impl TypeScriptAnalyzer {
         ];
 
         // Scan TypeScript files for NestJS patterns
-        if let Ok(entries) = std::fs::read_dir(project_path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if let Some(extension) = path.extension() {
-                    if extension == "ts" && !path.to_string_lossy().contains("node_modules") {
-                        if let Ok(content) = std::fs::read_to_string(&path) {
-                            // Check for NestJS decorators
-                            for decorator in &nestjs_decorators {
-                                if content.contains(decorator) {
-                                    evidence.push(format!("{} found in {}", decorator, path.file_name().unwrap().to_string_lossy()));
-                                    break; // Only add one evidence per file
-                                }
+        for path in ProjectWalker::new(project_path).max_depth(1).files() {
+            if let Some(extension) = path.extension() {
+                if extension == "ts" {
+                    if let Ok(content) = std::fs::read_to_string(&path) {
+                        // Check for NestJS decorators
+                        for decorator in &nestjs_decorators {
+                            if content.contains(decorator) {
+                                evidence.push(format!("{} found in {}", decorator, path.file_name().unwrap().to_string_lossy()));
+                                break; // Only add one evidence per file
                             }
+                        }
 
-                            // Check for NestJS imports
-                            for import in &nestjs_imports {
-                                if content.contains(import) {
-                                    evidence.push(format!("NestJS import found in {}", path.file_name().unwrap().to_string_lossy()));
-                                    break; // Only add one evidence per file
-                                }
+                        // Check for NestJS imports
+                        for import in &nestjs_imports {
+                            if content.contains(import) {
+                                evidence.push(format!("NestJS import found in {}", path.file_name().unwrap().to_string_lossy()));
+                                break; // Only add one evidence per file
                             }
+                        }
 
-                            // Check for NestJS main.ts pattern
-                            if path.file_name().unwrap() == "main.ts" && content.contains("NestFactory.create") {
-                                evidence.push("NestJS application bootstrap in main.ts".to_string());
-                            }
+                        // Check for NestJS main.ts pattern
+                        if path.file_name().unwrap() == "main.ts" && content.contains("NestFactory.create") {
+                            evidence.push("NestJS application bootstrap in main.ts".to_string());
+                        }
 
-                            // Check for app.module.ts pattern
-                            if path.file_name().unwrap() == "app.module.ts" && content.contains("@Module") {
-                                evidence.push("NestJS root module in app.module.ts".to_string());
-                            }
+                        // Check for app.module.ts pattern
+                        if path.file_name().unwrap() == "app.module.ts" && content.contains("@Module") {
+                            evidence.push("NestJS root module in app.module.ts".to_string());
                         }
                     }
                 }
@@ -1296,28 +1313,25 @@ impl TypeScriptAnalyzer {
         // Also check subdirectories (src/, etc.)
         for subdir in &["src", "apps", "libs"] {
             let subdir_path = format!("{}/{}", project_path, subdir);
-            if let Ok(entries) = walkdir::WalkDir::new(&subdir_path).max_depth(3).into_iter().collect::<Result<Vec<_>, _>>() {
-                for entry in entries {
-                    let path = entry.path();
-                    if let Some(extension) = path.extension() {
-                        if extension == "ts" && !path.to_string_lossy().contains("node_modules") {
-                            if let Ok(content) = std::fs::read_to_string(&path) {
-                                // Check for NestJS decorators
-                                for decorator in &nestjs_decorators {
-                                    if content.contains(decorator) {
-                                        evidence.push(format!("{} found in {}", decorator, path.file_name().unwrap().to_string_lossy()));
-                                        break;
-                                    }
+            for path in ProjectWalker::new(&subdir_path).max_depth(3).files() {
+                if let Some(extension) = path.extension() {
+                    if extension == "ts" {
+                        if let Ok(content) = std::fs::read_to_string(&path) {
+                            // Check for NestJS decorators
+                            for decorator in &nestjs_decorators {
+                                if content.contains(decorator) {
+                                    evidence.push(format!("{} found in {}", decorator, path.file_name().unwrap().to_string_lossy()));
+                                    break;
                                 }
+                            }
 
-                                // Check for NestJS-specific class patterns
-                                if content.contains("@Controller") && content.contains("@Get") {
-                                    evidence.push(format!("NestJS controller pattern in {}", path.file_name().unwrap().to_string_lossy()));
-                                }
+                            // Check for NestJS-specific class patterns
+                            if content.contains("@Controller") && content.contains("@Get") {
+                                evidence.push(format!("NestJS controller pattern in {}", path.file_name().unwrap().to_string_lossy()));
+                            }
 
-                                if content.contains("@Injectable") && (content.contains("@InjectRepository") || content.contains("Repository")) {
-                                    evidence.push(format!("NestJS service pattern in {}", path.file_name().unwrap().to_string_lossy()));
-                                }
+                            if content.contains("@Injectable") && (content.contains("@InjectRepository") || content.contains("Repository")) {
+                                evidence.push(format!("NestJS service pattern in {}", path.file_name().unwrap().to_string_lossy()));
                             }
                         }
                     }
@@ -1353,6 +1367,7 @@ impl TypeScriptAnalyzer {
                     detected_frameworks.push(DetectedFramework {
                         name: "Danet".to_string(),
                         version: None,
+                        installed_version: None,
                         confidence: 0.95,
                         evidence: vec!["deno.json with Danet dependency".to_string()],
                         usage_extent: UsageExtent::Core,
@@ -1366,6 +1381,7 @@ impl TypeScriptAnalyzer {
                     detected_frameworks.push(DetectedFramework {
                         name: "Fresh".to_string(),
                         version: None,
+                        installed_version: None,
                         confidence: 0.95,
                         evidence: vec!["deno.json with Fresh dependency".to_string()],
                         usage_extent: UsageExtent::Core,
@@ -1379,6 +1395,7 @@ impl TypeScriptAnalyzer {
                     detected_frameworks.push(DetectedFramework {
                         name: "Oak".to_string(),
                         version: None,
+                        installed_version: None,
                         confidence: 0.90,
                         evidence: vec!["deno.json with Oak dependency".to_string()],
                         usage_extent: UsageExtent::Core,
@@ -1400,6 +1417,7 @@ impl TypeScriptAnalyzer {
                         detected_frameworks.push(DetectedFramework {
                             name: "Danet".to_string(),
                             version: None,
+                            installed_version: None,
                             confidence: 0.90,
                             evidence: vec!["Danet import in import_map.json".to_string()],
                             usage_extent: UsageExtent::Core,
@@ -1414,6 +1432,7 @@ impl TypeScriptAnalyzer {
                         detected_frameworks.push(DetectedFramework {
                             name: "Oak".to_string(),
                             version: None,
+                            installed_version: None,
                             confidence: 0.85,
                             evidence: vec!["Oak import in import_map.json".to_string()],
                             usage_extent: UsageExtent::Core,
@@ -1439,6 +1458,7 @@ impl TypeScriptAnalyzer {
                 detected_frameworks.push(DetectedFramework {
                     name: framework_name.clone(),
                     version: None,
+                    installed_version: None,
                     confidence,
                     evidence: evidence.clone(),
                     usage_extent: UsageExtent::Core,
@@ -1453,6 +1473,7 @@ impl TypeScriptAnalyzer {
             detected_frameworks.push(DetectedFramework {
                 name: "Deno Runtime".to_string(),
                 version: None,
+                installed_version: None,
                 confidence: 0.90,
                 evidence: deno_evidence,
                 usage_extent: UsageExtent::Core,
@@ -1502,41 +1523,38 @@ impl TypeScriptAnalyzer {
                 format!("{}/{}", project_path, subdir)
             };
 
-            if let Ok(entries) = walkdir::WalkDir::new(&scan_path).max_depth(3).into_iter().collect::<Result<Vec<_>, _>>() {
-                for entry in entries {
-                    let path = entry.path();
-                    if let Some(extension) = path.extension() {
-                        if (extension == "ts" || extension == "tsx") && !path.to_string_lossy().contains("node_modules") {
-                            if let Ok(content) = std::fs::read_to_string(&path) {
-                                // Check for Danet patterns
-                                for decorator in &danet_decorators {
-                                    if content.contains(decorator) && content.contains("deno.land/x/danet") {
-                                        danet_evidence.push(format!("{} with Danet import in {}", decorator, path.file_name().unwrap().to_string_lossy()));
-                                        break;
-                                    }
+            for path in ProjectWalker::new(&scan_path).max_depth(3).files() {
+                if let Some(extension) = path.extension() {
+                    if extension == "ts" || extension == "tsx" {
+                        if let Ok(content) = std::fs::read_to_string(&path) {
+                            // Check for Danet patterns
+                            for decorator in &danet_decorators {
+                                if content.contains(decorator) && content.contains("deno.land/x/danet") {
+                                    danet_evidence.push(format!("{} with Danet import in {}", decorator, path.file_name().unwrap().to_string_lossy()));
+                                    break;
                                 }
-                                
-                                for import in &danet_imports {
-                                    if content.contains(import) {
-                                        danet_evidence.push(format!("Danet import in {}", path.file_name().unwrap().to_string_lossy()));
-                                        break;
-                                    }
+                            }
+                            
+                            for import in &danet_imports {
+                                if content.contains(import) {
+                                    danet_evidence.push(format!("Danet import in {}", path.file_name().unwrap().to_string_lossy()));
+                                    break;
                                 }
+                            }
 
-                                // Check for Fresh patterns
-                                for pattern in &fresh_patterns {
-                                    if content.contains(pattern) {
-                                        fresh_evidence.push(format!("Fresh pattern '{}' in {}", pattern, path.file_name().unwrap().to_string_lossy()));
-                                        break;
-                                    }
+                            // Check for Fresh patterns
+                            for pattern in &fresh_patterns {
+                                if content.contains(pattern) {
+                                    fresh_evidence.push(format!("Fresh pattern '{}' in {}", pattern, path.file_name().unwrap().to_string_lossy()));
+                                    break;
                                 }
+                            }
 
-                                // Check for Oak patterns
-                                for pattern in &oak_patterns {
-                                    if content.contains(pattern) {
-                                        oak_evidence.push(format!("Oak pattern in {}", path.file_name().unwrap().to_string_lossy()));
-                                        break;
-                                    }
+                            // Check for Oak patterns
+                            for pattern in &oak_patterns {
+                                if content.contains(pattern) {
+                                    oak_evidence.push(format!("Oak pattern in {}", path.file_name().unwrap().to_string_lossy()));
+                                    break;
                                 }
                             }
                         }
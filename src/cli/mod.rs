@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Subcommand)]
 pub enum CacheAction {
@@ -11,6 +11,64 @@ pub enum CacheAction {
 }
 use anyhow::Result;
 
+use crate::core::findings_baseline::{FindingKind, FindingSeverity, FindingThresholds};
+
+/// Machine-readable findings output and baseline gating of the `Analyze` command, for CI
+#[derive(Args, Debug, Clone)]
+pub struct FindingsArgs {
+    /// Write TODOs, documentation conflicts and status findings as a SARIF 2.1.0 log to this file
+    #[arg(long)]
+    pub sarif: Option<String>,
+    
+    /// Compare findings against this baseline and fail when new findings exceed the thresholds
+    #[arg(long)]
+    pub baseline: Option<String>,
+    
+    /// Save the current findings as a baseline to this file (after comparing, when both are given)
+    #[arg(long)]
+    pub save_baseline: Option<String>,
+    
+    /// Number of new findings allowed (0 unless only --max-new limits are given)
+    #[arg(long)]
+    pub max_new_findings: Option<usize>,
+    
+    /// Number of new findings allowed for one kind, e.g. `todo=5` (todo, conflict, missing_implementation, anti_pattern)
+    #[arg(long, value_parser = parse_kind_limit)]
+    pub max_new: Vec<(FindingKind, usize)>,
+    
+    /// Only new findings at or above this severity count against the thresholds
+    #[arg(long, default_value = "low")]
+    pub fail_on_severity: FindingSeverity,
+}
+
+impl FindingsArgs {
+    fn thresholds(&self) -> FindingThresholds {
+        let default_limit = if self.max_new.is_empty() { Some(0) } else { None };
+        FindingThresholds {
+            max_new_findings: self.max_new_findings.or(default_limit),
+            max_new_by_kind: self.max_new.iter().copied().collect(),
+            min_severity: self.fail_on_severity,
+        }
+    }
+}
+
+fn parse_kind_limit(value: &str) -> Result<(FindingKind, usize)> {
+    let (kind, limit) = value.split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("expected KIND=N, got '{}'", value))?;
+    Ok((kind.trim().parse()?, limit.trim().parse()?))
+}
+
+/// What the analysis run already computed, so the findings export doesn't compute it again
+#[derive(Default)]
+struct AnalysisArtifacts {
+    /// TODO scan with ticket statuses resolved, when integrations ran
+    todo_results: Option<crate::core::todo_scanner::TodoScanResult>,
+    /// Project documentation, when it was extracted for external sources or document generation
+    documentation: Option<crate::core::documentation_extractor::ExtractedDocumentationInfo>,
+    /// Language analyzer result, when the legacy mode or a workspace analysis produced one
+    analysis: Option<crate::core::CodebaseAnalysis>,
+}
+
 #[derive(Parser)]
 #[command(name = "codebase-analyzer")]
 #[command(about = "Reverse engineer codebases into systematic development workflows")]
@@ -32,7 +90,7 @@ pub enum Commands {
         #[arg(long)]
         analyzer: Option<String>,
         
-        /// Enable LLM analysis for enhanced business intelligence (requires Ollama)
+        /// Enable LLM analysis for enhanced business intelligence (requires an LLM server, see LLM_PROVIDER)
         #[arg(long)]
         enable_llm: bool,
         
@@ -49,6 +107,9 @@ pub enum Commands {
         #[arg(long, default_value = "true")]
         enable_fusion: bool,
         
+        #[command(flatten)]
+        findings: FindingsArgs,
+        
         /// Enable integrations (requires --features integrations)
         #[cfg(feature = "integrations")]
         #[arg(long)]
@@ -82,9 +143,17 @@ pub enum Commands {
         #[arg(short, long)]
         path: Option<String>,
         
-        /// Enable LLM analysis (requires Ollama running)
+        /// Enable LLM analysis (requires an LLM server running, see LLM_PROVIDER)
         #[arg(long)]
         enable_llm: bool,
+
+        /// Replay LLM responses from this cassette directory instead of calling the server
+        #[arg(long)]
+        cassette: Option<String>,
+
+        /// Call the server and record its responses into --cassette
+        #[arg(long, requires = "cassette")]
+        record: bool,
     },
     
     /// Setup and test Ollama integration
@@ -198,17 +267,15 @@ impl CliRunner {
                 generate_docs,
                 ext_docs_path,
                 enable_fusion,
+                findings,
                 #[cfg(feature = "integrations")]
                 enable_integrations
             } => {
                 #[cfg(feature = "integrations")]
-                {
-                    self.run_analysis_with_integrations(path, analyzer, enable_llm, generate_docs, ext_docs_path, enable_fusion, enable_integrations).await
-                }
+                let artifacts = self.run_analysis_with_integrations(path.clone(), analyzer.clone(), enable_llm, generate_docs, ext_docs_path, enable_fusion, enable_integrations).await?;
                 #[cfg(not(feature = "integrations"))]
-                {
-                    self.run_analysis(path, analyzer, enable_llm, generate_docs, ext_docs_path, enable_fusion).await
-                }
+                let artifacts = self.run_analysis(path.clone(), analyzer.clone(), enable_llm, generate_docs, ext_docs_path, enable_fusion).await?;
+                self.export_findings(&path, analyzer.as_deref(), &findings, artifacts)
             }
             Commands::List => {
                 self.list_supported_types();
@@ -220,8 +287,8 @@ impl CliRunner {
             Commands::TestAst { path } => {
                 self.run_ast_test(path).await
             }
-            Commands::TestLlm { path, enable_llm } => {
-                self.run_llm_test(path, enable_llm).await
+            Commands::TestLlm { path, enable_llm, cassette, record } => {
+                self.run_llm_test(path, enable_llm, cassette, record).await
             }
             Commands::SetupOllama { path, non_interactive } => {
                 self.setup_ollama_integration(path, non_interactive).await
@@ -257,7 +324,7 @@ impl CliRunner {
         }
     }
     
-    async fn run_analysis(&self, path: String, analyzer: Option<String>, enable_llm: bool, generate_docs: Option<String>, ext_docs_path: Option<String>, enable_fusion: bool) -> Result<()> {
+    async fn run_analysis(&self, path: String, analyzer: Option<String>, enable_llm: bool, generate_docs: Option<String>, ext_docs_path: Option<String>, enable_fusion: bool) -> Result<AnalysisArtifacts> {
         use crate::core::performance_monitor::PerformanceMonitor;
         use crate::core::cache_manager::CacheManager;
         
@@ -291,9 +358,19 @@ impl CliRunner {
             }
         }
         
+        // Detect the workspace once; the cache key, the per-package analysis and the export all use it
+        use crate::core::workspace_detector::WorkspaceDetector;
+        let workspace_layout = WorkspaceDetector::new(&path_buf).detect().filter(|layout| layout.is_monorepo());
+        if let Some(layout) = &workspace_layout {
+            println!("Workspace detected ({}): {} packages, {} internal dependencies",
+                     layout.tools.iter().map(|tool| format!("{:?}", tool)).collect::<Vec<_>>().join(", "),
+                     layout.packages.len(),
+                     layout.dependency_edges.len());
+        }
+        
         // Check cache first
         perf_monitor.start_phase("Cache Check");
-        let project_hash = cache_manager.calculate_project_hash(&path)?;
+        let project_hash = cache_manager.calculate_project_hash(&path, workspace_layout.as_ref())?;
         let cache_key = cache_manager.get_cache_key(&path, "hierarchical_analysis");
         
         if let Ok(Some(_cached_result)) = cache_manager.get::<serde_json::Value>(&cache_key) {
@@ -309,17 +386,24 @@ impl CliRunner {
             
             perf_monitor.end_phase("Total Analysis");
             println!("Analysis complete (cached results)");
-            return Ok(());
+            return Ok(AnalysisArtifacts::default());
         } else {
             perf_monitor.record_cache_miss();
         }
         perf_monitor.end_phase("Cache Check");
         
+        // Monorepos are analyzed per package instead of as one blended project
+        let workspace_analysis = match &workspace_layout {
+            Some(layout) => Some(self.run_workspace_analysis(layout)?),
+            None => None,
+        };
+        
         // Store analysis results for summary
         let mut primary_framework = "Unknown".to_string();
         let mut business_domain = "Unknown".to_string();
         let mut business_confidence = 0.0;
         let mut analysis_result: Option<crate::core::context_aware_framework_detector::ContextAwareFrameworkAnalysisResult> = None;
+        let mut codebase_analysis: Option<crate::core::CodebaseAnalysis> = None;
         
         if enable_fusion {
             // Use SOTA Hierarchical Result Fusion System
@@ -347,40 +431,15 @@ impl CliRunner {
         } else {
             // Fallback to basic analysis (for comparison)
             println!("Running legacy analysis mode");
-            use crate::core::{CodebaseAnalyzer, AnalyzerConfig};
-            use crate::analyzers::{TypeScriptAnalyzer, JavaAnalyzer, PythonAnalyzer};
-            
-            let config = AnalyzerConfig::default();
-            let ts_analyzer = TypeScriptAnalyzer::new(config.clone());
-            let java_analyzer = JavaAnalyzer::new(config.clone());
-            let python_analyzer = PythonAnalyzer::new(config);
-            
-            let (selected_analyzer, analyzer_name): (Box<dyn CodebaseAnalyzer>, &str) = 
-                if let Some(analyzer_name) = &analyzer {
-                    match analyzer_name.to_lowercase().as_str() {
-                        "typescript" | "ts" => (Box::new(ts_analyzer), "TypeScript"),
-                        "java" => (Box::new(java_analyzer), "Java"),
-                        "python" | "py" => (Box::new(python_analyzer), "Python"),
-                        _ => anyhow::bail!("Unsupported analyzer: {}. Use 'typescript', 'java', or 'python'", analyzer_name),
-                    }
-                } else {
-                    // Auto-detect
-                    if ts_analyzer.can_analyze(&path) {
-                        (Box::new(ts_analyzer), "TypeScript")
-                    } else if java_analyzer.can_analyze(&path) {
-                        (Box::new(java_analyzer), "Java")
-                    } else if python_analyzer.can_analyze(&path) {
-                        (Box::new(python_analyzer), "Python")
-                    } else {
-                        (Box::new(TypeScriptAnalyzer::new(AnalyzerConfig::default())), "TypeScript")
-                    }
-                };
-            
-            let analysis = selected_analyzer.analyze(&path)?;
+            let analysis = match &workspace_analysis {
+                Some(analysis) if analyzer.is_none() => analysis.clone(),
+                _ => self.select_analyzer(&path, analyzer.as_deref())?.analyze(&path)?,
+            };
             
             primary_framework = analysis.framework_analysis.architecture_pattern.clone();
             business_domain = analysis.business_context.inferred_product_type.clone();
             business_confidence = analysis.business_context.confidence;
+            codebase_analysis = Some(analysis);
         }
         
         // Extract documentation (including external sources and cross-repository analysis)
@@ -472,6 +531,15 @@ impl CliRunner {
             perf_monitor.end_phase("Analysis Results Export");
         }
 
+        // Save the per-package breakdown of a monorepo next to the other results
+        if let Some(analysis) = &workspace_analysis {
+            let analysis_dir = format!("{}/analysis-results", path);
+            std::fs::create_dir_all(&analysis_dir)?;
+            std::fs::write(format!("{}/workspace-analysis.yaml", analysis_dir), serde_yaml::to_string(analysis)?)?;
+            std::fs::write(format!("{}/workspace-analysis.json", analysis_dir), serde_json::to_string_pretty(analysis)?)?;
+            println!("Workspace analysis saved to: {}/workspace-analysis.{{yaml,json}}", analysis_dir);
+        }
+
         // Generate documentation if requested
         let docs_location = if let Some(docs_dir) = generate_docs {
             perf_monitor.start_phase("Document Generation");
@@ -520,8 +588,173 @@ impl CliRunner {
         
         let analysis_duration = perf_monitor.get_total_duration();
         println!("Analysis completed in {:.2}s for {} files", analysis_duration.as_secs_f64(), total_files);
+        Ok(AnalysisArtifacts {
+            todo_results: None,
+            documentation: documentation_info.map(|(documentation, _)| documentation),
+            analysis: codebase_analysis.or(workspace_analysis.filter(|_| analyzer.is_none())),
+        })
+    }
+    
+    /// The language analyzers `--analyzer` can name, in auto-detection order
+    fn codebase_analyzers(&self) -> Vec<(&'static [&'static str], Box<dyn crate::core::CodebaseAnalyzer>)> {
+        use crate::core::AnalyzerConfig;
+        use crate::analyzers::{TypeScriptAnalyzer, JavaAnalyzer, PythonAnalyzer};
+        
+        let config = AnalyzerConfig::default();
+        vec![
+            (&["typescript", "ts"], Box::new(TypeScriptAnalyzer::new(config.clone()))),
+            (&["java"], Box::new(JavaAnalyzer::new(config.clone()))),
+            (&["python", "py"], Box::new(PythonAnalyzer::new(config))),
+        ]
+    }
+    
+    /// The analyzer named with `--analyzer`, else the first one that recognizes the project
+    fn select_analyzer(&self, path: &str, analyzer: Option<&str>) -> Result<Box<dyn crate::core::CodebaseAnalyzer>> {
+        let mut analyzers = self.codebase_analyzers();
+        
+        let position = match analyzer {
+            Some(analyzer_name) => {
+                let name = analyzer_name.to_lowercase();
+                match analyzers.iter().position(|(names, _)| names.contains(&name.as_str())) {
+                    Some(position) => position,
+                    None => anyhow::bail!("Unsupported analyzer: {}. Use 'typescript', 'java', or 'python'", analyzer_name),
+                }
+            }
+            // Auto-detect, falling back to TypeScript
+            None => analyzers.iter().position(|(_, candidate)| candidate.can_analyze(path)).unwrap_or(0),
+        };
+        Ok(analyzers.swap_remove(position).1)
+    }
+    
+    /// Write the SARIF log and compare against or save the findings baseline requested on the `Analyze` command line
+    ///
+    /// TODOs, documentation and the codebase analysis come from `artifacts` when the analysis run
+    /// produced them. The status analyzers and the SARIF locations share one project index and
+    /// its code segments.
+    fn export_findings(&self, path: &str, analyzer: Option<&str>, findings: &FindingsArgs, artifacts: AnalysisArtifacts) -> Result<()> {
+        use crate::core::analyzers::file_index::ProjectFileIndex;
+        use crate::core::code_reality_analyzer::CodeRealityAnalyzer;
+        use crate::core::conflict_resolution_engine::ConflictResolutionEngine;
+        use crate::core::documentation_claims_extractor::DocumentationClaimsExtractor;
+        use crate::core::documentation_extractor::DocumentationExtractor;
+        use crate::core::findings_baseline::{FindingsBaseline, FindingsCollector};
+        use crate::core::status_inference_engine::StatusInferenceEngine;
+        use crate::core::todo_scanner::TodoScanner;
+        use crate::generators::{DocumentGenerator, SarifGenerator};
+        use crate::intelligence::IntelligenceEngine;
+
+        let gate_findings = findings.baseline.is_some() || findings.save_baseline.is_some();
+        if findings.sarif.is_none() && !gate_findings {
+            return Ok(());
+        }
+
+        println!("Collecting findings...");
+        let project_path = std::path::Path::new(path);
+        let todo_results = match artifacts.todo_results {
+            Some(todo_results) => todo_results,
+            None => TodoScanner::new()?.scan_project(project_path)?,
+        };
+        let documentation = match artifacts.documentation {
+            Some(documentation) => documentation,
+            None => DocumentationExtractor::new()?.extract_documentation(project_path)?,
+        };
+        let index = std::sync::Arc::new(ProjectFileIndex::build(project_path));
+        let status = StatusInferenceEngine::from_global_config()?
+            .analyze_indexed_project_status(&index, &documentation, &todo_results)?;
+        let claims = DocumentationClaimsExtractor::new()?.extract_claims(project_path)?;
+        let reality = CodeRealityAnalyzer::new()?.analyze_reality(project_path)?;
+        let conflicts = ConflictResolutionEngine::new().resolve_conflicts(&claims.claims, &reality.implementations)?;
+        let analysis = match artifacts.analysis {
+            Some(analysis) => analysis,
+            None => self.select_analyzer(path, analyzer)?.analyze(path)?,
+        };
+
+        if let Some(output) = &findings.sarif {
+            let sarif = SarifGenerator::new()
+                .with_source_root(project_path)
+                .with_todos(&todo_results)
+                .with_conflicts(&conflicts.conflicts)
+                .with_status(&status)
+                .with_segments(index.segments()?)
+                .generate(&analysis, None)?;
+            if let Some(parent) = std::path::Path::new(output).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(output, sarif)?;
+            println!("SARIF log saved to: {}", output);
+        }
+
+        if !gate_findings {
+            return Ok(());
+        }
+
+        let intelligence = IntelligenceEngine::new().enhance_analysis(&analysis);
+        let current = FindingsCollector::new(project_path)
+            .with_todos(&todo_results)
+            .with_conflicts(&conflicts.conflicts)
+            .with_missing_implementations(&status.inferred_status.missing_implementations)
+            .with_anti_patterns(&intelligence, &analysis.components)
+            .collect();
+
+        // Compare before saving, so --baseline and --save-baseline may name the same file
+        let violations = match &findings.baseline {
+            Some(baseline_path) => {
+                let diff = FindingsBaseline::load(std::path::Path::new(baseline_path))?.compare(&current);
+                println!("\n=== Findings vs. Baseline ===");
+                println!("{} added, {} resolved, {} unchanged", diff.added.len(), diff.resolved.len(), diff.unchanged.len());
+                for (marker, finding) in diff.added.iter().map(|finding| ("+", finding))
+                    .chain(diff.resolved.iter().map(|finding| ("-", finding)))
+                {
+                    println!("  {} [{}] {} {}: {}", marker, finding.severity, finding.rule_id,
+                             finding.location().unwrap_or_else(|| "-".to_string()), finding.message);
+                }
+                diff.threshold_violations(&findings.thresholds())
+            }
+            None => Vec::new(),
+        };
+
+        if let Some(output) = &findings.save_baseline {
+            FindingsBaseline::new(current).save(std::path::Path::new(output))?;
+            println!("Findings baseline saved to: {}", output);
+        }
+
+        if !violations.is_empty() {
+            anyhow::bail!("New findings exceed the baseline thresholds: {}", violations.join("; "));
+        }
         Ok(())
     }
+
+    /// Analyze every workspace package with the analyzer that fits it and print the breakdown
+    fn run_workspace_analysis(&self, layout: &crate::core::workspace_detector::WorkspaceLayout) -> Result<crate::core::CodebaseAnalysis> {
+        use crate::core::workspace_analyzer::WorkspaceAnalyzer;
+        
+        let workspace_analyzer = WorkspaceAnalyzer::new(
+            self.codebase_analyzers().into_iter().map(|(_, analyzer)| analyzer).collect()
+        );
+        let analysis = workspace_analyzer.analyze(layout)?;
+        
+        if let Some(workspace) = &analysis.workspace {
+            for package in &workspace.packages {
+                let frameworks: Vec<String> = package.frameworks.iter().map(|f| f.framework.to_string()).collect();
+                match &package.analysis {
+                    Some(package_analysis) => println!("  {} ({}): {} - {} ({:.1}% complete)",
+                        package.package.name,
+                        if frameworks.is_empty() { "no framework".to_string() } else { frameworks.join(", ") },
+                        package_analysis.business_context.business_domain,
+                        package_analysis.business_context.inferred_product_type,
+                        package_analysis.status_intelligence.overall_completion_percentage),
+                    None => println!("  {} ({}): not analyzed",
+                        package.package.name,
+                        if frameworks.is_empty() { "no framework".to_string() } else { frameworks.join(", ") }),
+                }
+            }
+            for edge in &workspace.dependency_edges {
+                println!("  {} -> {} ({:?})", edge.from, edge.to, edge.kind);
+            }
+        }
+        
+        Ok(analysis)
+    }
     
     async fn handle_cache_command(&self, action: &CacheAction) -> Result<()> {
         use crate::core::cache_manager::CacheManager;
@@ -733,33 +966,50 @@ impl CliRunner {
     }
     
     #[cfg(feature = "integrations")]
-    async fn run_analysis_with_integrations(&self, path: String, analyzer: Option<String>, enable_llm: bool, generate_docs: Option<String>, ext_docs_path: Option<String>, enable_fusion: bool, enable_integrations: bool) -> Result<()> {
+    /// Run the analysis, then resolve the tickets TODOs reference; the TODO scan with ticket
+    /// statuses filled in goes into the artifacts so the findings export reports them
+    async fn run_analysis_with_integrations(&self, path: String, analyzer: Option<String>, enable_llm: bool, generate_docs: Option<String>, ext_docs_path: Option<String>, enable_fusion: bool, enable_integrations: bool) -> Result<AnalysisArtifacts> {
         // Run SOTA analysis first
-        self.run_analysis(path.clone(), analyzer, enable_llm, generate_docs, ext_docs_path, enable_fusion).await?;
+        let artifacts = self.run_analysis(path.clone(), analyzer, enable_llm, generate_docs, ext_docs_path, enable_fusion).await?;
         
-        // If integrations are enabled, run them
-        if enable_integrations {
-            println!("\nRunning integrations...");
-            
-            let config = crate::integrations::IntegrationConfig::default();
-            let integration_engine = crate::integrations::IntegrationEngine::new(config);
-            
-            // Note: For a real integration, we would need to properly analyze the codebase
-            // and create a CodebaseAnalysis struct. For now, we'll show basic integration status.
-            
-            println!("Integration Status:");
-            println!("  Git: {}", if std::path::Path::new(&format!("{}/.git", path)).exists() { "Available" } else { "Not a git repository" });
-            println!("  GitHub: {}", if std::env::var("GITHUB_TOKEN").is_ok() { "Token configured" } else { "No token (set GITHUB_TOKEN)" });
-            println!("  Jira: {}", if std::env::var("JIRA_URL").is_ok() && std::env::var("JIRA_TOKEN").is_ok() { "Configured" } else { "Not configured" });
-            
-            println!("\nTo use integrations, ensure environment variables are set:");
-            println!("  export GITHUB_TOKEN=your_token");
-            println!("  export JIRA_URL=https://your-instance.atlassian.net");
-            println!("  export JIRA_TOKEN=your_token");
-            println!("  export JIRA_PROJECT_KEY=YOUR_PROJECT");
+        if !enable_integrations {
+            return Ok(artifacts);
         }
+
+        println!("\nRunning integrations...");
         
-        Ok(())
+        let config = crate::integrations::IntegrationConfig::default();
+        let integration_engine = crate::integrations::IntegrationEngine::new(config);
+        
+        println!("Integration Status:");
+        println!("  Git: {}", if std::path::Path::new(&format!("{}/.git", path)).exists() { "Available" } else { "Not a git repository" });
+        println!("  GitHub: {}", if std::env::var("GITHUB_TOKEN").is_ok() { "Token configured" } else { "No token (set GITHUB_TOKEN)" });
+        println!("  Jira: {}", if std::env::var("JIRA_URL").is_ok() && std::env::var("JIRA_TOKEN").is_ok() { "Configured" } else { "Not configured" });
+        
+        // Cross-reference TODO comments with their tickets
+        let todo_scanner = crate::core::todo_scanner::TodoScanner::new()?;
+        let mut todo_scan = todo_scanner.scan_project(std::path::Path::new(&path))?;
+        integration_engine.resolve_todo_tickets(&mut todo_scan, &todo_scanner.config().closed_ticket_statuses).await?;
+        let tickets = &todo_scan.summary.tickets;
+        println!("\nTODO tickets: {} with a ticket, {} without", tickets.with_ticket, tickets.without_ticket);
+        for todo in &tickets.closed_ticket_items {
+            println!("  Ticket closed: {}:{} {}", todo.file_path.display(), todo.line_number, todo.description);
+        }
+
+        // Keep the resolved TODOs with the other analysis results
+        let analysis_dir = format!("{}/analysis-results", path);
+        std::fs::create_dir_all(&analysis_dir)?;
+        std::fs::write(format!("{}/todo-analysis.yaml", analysis_dir), serde_yaml::to_string(&todo_scan)?)?;
+        std::fs::write(format!("{}/todo-analysis.json", analysis_dir), serde_json::to_string_pretty(&todo_scan)?)?;
+        println!("TODO analysis saved to: {}/todo-analysis.{{yaml,json}}", analysis_dir);
+
+        println!("\nTo use integrations, ensure environment variables are set:");
+        println!("  export GITHUB_TOKEN=your_token");
+        println!("  export JIRA_URL=https://your-instance.atlassian.net");
+        println!("  export JIRA_TOKEN=your_token");
+        println!("  export JIRA_PROJECT_KEY=YOUR_PROJECT");
+        
+        Ok(AnalysisArtifacts { todo_results: Some(todo_scan), ..artifacts })
     }
     
     
@@ -996,8 +1246,9 @@ impl CliRunner {
         Ok(())
     }
 
-    async fn run_llm_test(&self, path: Option<String>, enable_llm: bool) -> Result<()> {
+    async fn run_llm_test(&self, path: Option<String>, enable_llm: bool, cassette: Option<String>, record: bool) -> Result<()> {
         use crate::core::enhanced_framework_detector::EnhancedFrameworkDetector;
+        use crate::intelligence::llm_cassette::{CassetteConfig, CassetteMode};
         use crate::intelligence::llm_client::ModelConfig;
         
         println!("Starting LLM Integration Test (Phase 2B)");
@@ -1019,7 +1270,13 @@ impl CliRunner {
         // Add LLM analysis if requested
         if enable_llm {
             println!("Initializing LLM integration...");
-            detector = detector.with_llm_analysis(Some(ModelConfig::default())).await?;
+            let mut config = ModelConfig::from_env()?;
+            if let Some(dir) = cassette {
+                let mode = if record { CassetteMode::Record } else { CassetteMode::Replay };
+                println!("Using LLM cassette {} ({})", dir, mode);
+                config.cassette = Some(CassetteConfig { dir: dir.into(), mode });
+            }
+            detector = detector.with_llm_analysis(Some(config)).await?;
         } else {
             println!("LLM analysis disabled, running without LLM integration");
         }
@@ -1045,6 +1302,9 @@ impl CliRunner {
             println!("      - Available: {}", llm_analysis.llm_available);
             println!("      - Processing Time: {}ms", llm_analysis.processing_time_ms);
             println!("      - Segments Analyzed: {}", llm_analysis.business_domain_analysis.segments.len());
+            if llm_analysis.business_domain_analysis.incomplete {
+                println!("      - Incomplete: {} batches have no results", llm_analysis.business_domain_analysis.failed_batches.len());
+            }
             
             // Display new business analysis if available
             if let Some(project_analysis) = &llm_analysis.business_domain_analysis.project_analysis {
@@ -0,0 +1,696 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::auth_analyzer::{class_route_prefix, SOURCE_EXTENSIONS};
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::ast_analyzer::{CodeSegment, SegmentType};
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, ApiAnalysisConfig,
+    MissingImplementation, PartialImplementation
+};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+use crate::core::types::Framework;
+
+/// Lines read after a decorator when a handler body has to be recovered from source
+const HANDLER_WINDOW_LINES: usize = 25;
+
+/// Placeholder bodies across supported languages
+static PLACEHOLDER_PATTERNS: Lazy<Vec<(&'static str, Regex)>> = Lazy::new(|| {
+    vec![
+        ("NotImplementedError", Regex::new(r"\bNotImplemented(?:Error|Exception)\b").unwrap()),
+        ("UnsupportedOperationException", Regex::new(r"\bUnsupportedOperationException\b").unwrap()),
+        ("todo!()", Regex::new(r"\b(?:todo|unimplemented)!\s*\(").unwrap()),
+        ("throw new Error(\"not implemented\")", Regex::new(r#"(?i)throw\s+new\s+\w*Error\s*\(\s*['"`][^'"`]*not\s+(?:yet\s+)?implemented"#).unwrap()),
+        ("panic(\"not implemented\")", Regex::new(r#"(?i)panic!?\s*\(\s*"[^"]*not\s+(?:yet\s+)?implemented"#).unwrap()),
+        ("501 Not Implemented", Regex::new(r"HttpStatus\.NOT_IMPLEMENTED|StatusNotImplemented|\.status\(\s*501\s*\)|status_code\s*=\s*501|StatusCode::NOT_IMPLEMENTED").unwrap()),
+    ]
+});
+
+/// Error handling inside a handler body
+static ERROR_HANDLING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?x)
+        \btry\b | \bcatch\b | \bexcept\b | \.catch\s*\( | if\s+err\s*!=\s*nil | \)\?
+        | \bthrow\s | \braise\s | \babort\s*\( | orElseThrow | HTTPException | next\(\s*err
+        | \.status\(\s*[45]\d\d | status_code\s*=\s*[45]\d\d | ResponseEntity\.(?:badRequest|notFound|status)
+        | c\.(?:AbortWithStatus\w*|AbortWithError)\s*\( | http\.Status(?:BadRequest|NotFound|InternalServerError)
+        | \.map_err\s*\( | \.ok_or | Result<
+    ").unwrap()
+});
+
+/// Operations that can fail at runtime and therefore deserve error handling
+static FALLIBLE_OPERATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bawait\b|\.save\s*\(|\.find\w*\s*\(|\.query\s*\(|\.execute\s*\(|\bfetch\s*\(|\baxios\b|\brequests\.|\bdb\.|\brepo\w*\.|\.create\s*\(|\.update\w*\s*\(|\.delete\w*\s*\(|json\.loads|JSON\.parse|\bopen\s*\(|\bhttp\.\w+\s*\(").unwrap()
+});
+
+/// Project-wide error handlers that make per-handler try/catch unnecessary
+static GLOBAL_ERROR_HANDLER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"@ControllerAdvice|@RestControllerAdvice|@ExceptionHandler|@Catch\s*\(|useGlobalFilters|exception_handler\s*\(|\.errorhandler\s*\(|app\.use\(\s*(?:async\s*)?(?:function\s*\w*\s*)?\(\s*err\b|express-async-errors|gin\.Recovery\s*\(|middleware\.Recover\w*\s*\(|recover\.New\s*\(|HandleErrorLayer|CatchPanicLayer").unwrap()
+});
+
+/// `METHOD /path` references in API documentation
+static DOCUMENTED_ENDPOINT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(GET|POST|PUT|PATCH|DELETE)\s+`?(/[^\s`'\x22)\]]*)").unwrap()
+});
+
+static HANDLER_IDENTIFIER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z_][\w.]*$").unwrap());
+
+/// Trailing identifier argument of an inline route registration (`router.get('/x', listThings)`)
+static TRAILING_HANDLER_ARG: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r",\s*([A-Za-z_][\w.]*)\s*\)\s*;?\s*$").unwrap()
+});
+
+/// A single endpoint of the inventory
+#[derive(Debug, Clone)]
+struct Endpoint {
+    method: String,
+    path: String,
+    file: String,
+    line: usize,
+    handler_body: Option<String>,
+    /// Framework layer that turns errors thrown by the handler into error responses
+    exception_layer: Option<&'static str>,
+}
+
+impl Endpoint {
+    fn signature(&self) -> String {
+        format!("{} {}", self.method, self.path)
+    }
+
+    fn location(&self) -> String {
+        format!("{}:{}", self.file, self.line)
+    }
+}
+
+/// API completeness analyzer
+/// Builds an endpoint inventory from AST route segments and flags placeholder handlers,
+/// handlers without error handling, resources missing expected verbs and documented
+/// endpoints without a handler
+pub struct ApiAnalyzer {
+    config: ApiAnalysisConfig,
+    weight: f32,
+}
+
+impl ApiAnalyzer {
+    pub fn new(config: ApiAnalysisConfig, weight: f32) -> Self {
+        Self { config, weight }
+    }
+
+    /// Build the endpoint inventory from extracted code segments
+    fn build_inventory(&self, project_path: &Path, segments: &[CodeSegment]) -> Vec<Endpoint> {
+        let mut sources: HashMap<PathBuf, String> = HashMap::new();
+
+        // A route registered twice in one file is still one endpoint
+        let mut routes: BTreeMap<(PathBuf, String, String), &CodeSegment> = BTreeMap::new();
+        for segment in segments {
+            let SegmentType::Route(route) = &segment.segment_type else {
+                continue;
+            };
+            // Client-side router entries render views, they are not API endpoints
+            if route.method == "VIEW" {
+                continue;
+            }
+            let key = (segment.metadata.file_path.clone(), route.method.clone(), route.path.clone());
+            routes.entry(key).or_insert(segment);
+        }
+
+        let mut endpoints = Vec::new();
+        for ((file_path, methods, raw_path), segment) in routes {
+            let SegmentType::Route(route) = &segment.segment_type else {
+                continue;
+            };
+            let source = sources.entry(file_path.clone())
+                .or_insert_with(|| fs::read_to_string(&file_path).unwrap_or_default());
+            let lines: Vec<&str> = source.lines().collect();
+            let line_index = segment.metadata.line_start.saturating_sub(1);
+
+            // Controller-level prefixes (NestJS/Spring) declared before this route
+            let mut prefix = None;
+            let mut is_class_mapping = false;
+            for index in 0..=line_index.min(lines.len().saturating_sub(1)) {
+                if let Some(class_prefix) = class_route_prefix(&lines, index) {
+                    is_class_mapping = (class_prefix.block_start..=class_prefix.block_end).contains(&line_index);
+                    prefix = Some(class_prefix.prefix);
+                }
+            }
+            if is_class_mapping {
+                continue;
+            }
+
+            let path = normalize_route_path(prefix.as_deref(), &raw_path);
+            let file = file_path.strip_prefix(project_path)
+                .unwrap_or(&file_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            // Multi-verb routes ("GET,POST") may list one handler per verb
+            let handlers: Vec<&str> = route.handler.split(',').map(str::trim).collect();
+            for (position, method) in methods.split(',').map(str::trim).filter(|m| !m.is_empty()).enumerate() {
+                let handler = handlers.get(position).or(handlers.first()).copied().unwrap_or("");
+                endpoints.push(Endpoint {
+                    method: method.to_uppercase(),
+                    path: path.clone(),
+                    file: file.clone(),
+                    line: segment.metadata.line_start,
+                    handler_body: resolve_handler_body(segment, handler, segments, &lines),
+                    exception_layer: framework_exception_layer(segment.framework_context),
+                });
+            }
+        }
+
+        endpoints
+    }
+
+    /// Handlers whose bodies are placeholders
+    fn find_placeholder_handlers(&self, endpoints: &[Endpoint]) -> Vec<MissingImplementation> {
+        endpoints.iter()
+            .filter_map(|endpoint| {
+                let body = endpoint.handler_body.as_deref()?;
+                let marker = placeholder_marker(body)?;
+                Some(MissingImplementation {
+                    implementation_type: "API Endpoint Handler".to_string(),
+                    expected_location: endpoint.location(),
+                    description: format!("{} is declared but its handler is a placeholder ({})", endpoint.signature(), marker),
+                    confidence: 0.9,
+                    evidence: vec![format!("{}: {} handler body contains {}", endpoint.location(), endpoint.signature(), marker)],
+                    suggested_implementation: Some(format!("Implement the handler for {}", endpoint.signature())),
+                })
+            })
+            .collect()
+    }
+
+    /// Handlers performing fallible work without any error handling
+    fn find_missing_error_handling(&self, endpoints: &[Endpoint]) -> Vec<MissingImplementation> {
+        endpoints.iter()
+            .filter_map(|endpoint| {
+                let body = endpoint.handler_body.as_deref()?;
+                if endpoint.exception_layer.is_some() || placeholder_marker(body).is_some() || ERROR_HANDLING.is_match(body) {
+                    return None;
+                }
+                let operation = FALLIBLE_OPERATION.find(body)?;
+                Some(MissingImplementation {
+                    implementation_type: "API Error Handling".to_string(),
+                    expected_location: endpoint.location(),
+                    description: format!("{} performs fallible work without handling errors", endpoint.signature()),
+                    confidence: 0.6,
+                    evidence: vec![format!("{}: {} calls `{}` with no try/catch, error return or error response",
+                                           endpoint.location(), endpoint.signature(), operation.as_str().trim())],
+                    suggested_implementation: Some("Handle failures and map them to 4xx/5xx responses, or register a global error handler".to_string()),
+                })
+            })
+            .collect()
+    }
+
+    /// REST resources that do not implement all expected verbs
+    fn find_incomplete_resources(&self, endpoints: &[Endpoint]) -> Vec<PartialImplementation> {
+        let expected_groups: BTreeSet<&'static str> = self.config.standard_http_methods.iter()
+            .filter_map(|method| verb_group(method))
+            .collect();
+
+        let mut resources: BTreeMap<String, Vec<&Endpoint>> = BTreeMap::new();
+        for endpoint in endpoints {
+            if self.config.graphql_conventions && endpoint.path.to_lowercase().contains("graphql") {
+                continue;
+            }
+            resources.entry(resource_key(&endpoint.path)).or_default().push(endpoint);
+        }
+
+        let mut findings = Vec::new();
+        for (resource, resource_endpoints) in resources {
+            let implemented: BTreeSet<&'static str> = resource_endpoints.iter()
+                .flat_map(|endpoint| match endpoint.method.as_str() {
+                    "ANY" | "ALL" => expected_groups.iter().copied().collect::<Vec<_>>(),
+                    method => verb_group(method).into_iter().collect(),
+                })
+                .collect();
+
+            // Single-verb paths (health checks, webhooks, RPC-style actions) are not resources
+            if implemented.len() < 2 {
+                continue;
+            }
+
+            let missing: Vec<&str> = expected_groups.difference(&implemented).copied().collect();
+            if missing.is_empty() {
+                continue;
+            }
+
+            let implemented_parts: Vec<String> = resource_endpoints.iter()
+                .map(|endpoint| endpoint.method.clone())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            findings.push(PartialImplementation {
+                implementation_name: format!("{} endpoints", resource),
+                implemented_parts,
+                missing_parts: missing.iter().map(|group| group.to_string()).collect(),
+                completeness_percentage: implemented.intersection(&expected_groups).count() as f32
+                    / expected_groups.len().max(1) as f32 * 100.0,
+                category: "API".to_string(),
+                evidence: resource_endpoints.iter()
+                    .map(|endpoint| format!("{}: {}", endpoint.location(), endpoint.signature()))
+                    .collect(),
+                next_steps: vec![
+                    format!("Add {} handlers for {} or document why they are intentionally absent", missing.join(", "), resource),
+                ],
+            });
+        }
+
+        findings
+    }
+
+    /// Endpoints mentioned in the API documentation that have no handler
+    fn find_undocumented_handlers(
+        &self,
+        endpoints: &[Endpoint],
+        documentation: &ExtractedDocumentationInfo,
+    ) -> Vec<MissingImplementation> {
+        let mut seen = BTreeSet::new();
+        let mut findings = Vec::new();
+
+        for text in documentation.api_documentation.iter().chain(&documentation.usage_examples) {
+            for captures in DOCUMENTED_ENDPOINT.captures_iter(text) {
+                let method = captures[1].to_string();
+                let path = normalize_route_path(None, captures[2].trim_end_matches(['.', ',', ':']));
+                if !seen.insert((method.clone(), path.clone())) {
+                    continue;
+                }
+
+                let implemented = endpoints.iter().any(|endpoint| {
+                    (endpoint.method == method || endpoint.method == "ANY" || endpoint.method == "ALL")
+                        && paths_match(&endpoint.path, &path)
+                });
+                if implemented {
+                    continue;
+                }
+
+                let line = text.lines()
+                    .find(|line| line.contains(&captures[0]))
+                    .unwrap_or(&captures[0])
+                    .trim();
+                findings.push(MissingImplementation {
+                    implementation_type: "Documented API Endpoint".to_string(),
+                    expected_location: format!("{} {}", method, path),
+                    description: format!("{} {} is documented but no route handler was found", method, path),
+                    confidence: 0.7,
+                    evidence: vec![format!("API documentation: {}", line)],
+                    suggested_implementation: Some(format!("Implement {} {} or remove it from the documentation", method, path)),
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Turn the endpoint inventory into status findings
+    fn generate_analysis_results(
+        &self,
+        endpoints: Vec<Endpoint>,
+        documentation: &ExtractedDocumentationInfo,
+        global_error_handler: Option<String>,
+    ) -> StatusAnalysisResult {
+        let mut missing_implementations = Vec::new();
+        let mut partial_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+
+        let resolved = endpoints.iter().filter(|endpoint| endpoint.handler_body.is_some()).count();
+        analysis_notes.push(format!("Built inventory of {} endpoints ({} handler bodies resolved)", endpoints.len(), resolved));
+
+        missing_implementations.extend(self.find_placeholder_handlers(&endpoints));
+
+        if self.config.detect_incomplete_error_handling {
+            match &global_error_handler {
+                Some(location) => evidence.push(format!("Global error handler: {}", location)),
+                None => missing_implementations.extend(self.find_missing_error_handling(&endpoints)),
+            }
+            let layers: BTreeSet<&str> = endpoints.iter().filter_map(|endpoint| endpoint.exception_layer).collect();
+            for layer in layers {
+                analysis_notes.push(format!("Handlers relying on {} are not checked for error handling", layer));
+            }
+        }
+
+        if self.config.detect_missing_endpoints {
+            if self.config.rest_conventions {
+                partial_implementations.extend(self.find_incomplete_resources(&endpoints));
+            }
+            missing_implementations.extend(self.find_undocumented_handlers(&endpoints, documentation));
+        }
+
+        for endpoint in &endpoints {
+            evidence.push(format!("{}: {}", endpoint.location(), endpoint.signature()));
+        }
+
+        let confidence = if endpoints.is_empty() {
+            analysis_notes.push("No API endpoints detected".to_string());
+            0.3
+        } else {
+            0.6 + 0.3 * resolved as f32 / endpoints.len() as f32
+        };
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            missing_implementations,
+            partial_implementations,
+            confidence,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatusAnalyzer for ApiAnalyzer {
+    fn name(&self) -> &str {
+        "API Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Analyzing API completeness for project: {}", index.root().display());
+
+        let segments = index.segments()?;
+        let endpoints = self.build_inventory(index.root(), segments);
+        let global_error_handler = find_global_error_handler(index);
+        let result = self.generate_analysis_results(endpoints, documentation, global_error_handler);
+
+        println!("API analysis completed: {} items found, {:.1}% confidence",
+                 result.missing_implementations.len() + result.partial_implementations.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Locate the body of the function handling a route segment
+fn resolve_handler_body(
+    segment: &CodeSegment,
+    handler: &str,
+    segments: &[CodeSegment],
+    lines: &[&str],
+) -> Option<String> {
+    let file_path = &segment.metadata.file_path;
+    let functions = || segments.iter().filter_map(|candidate| match &candidate.segment_type {
+        SegmentType::Function(function) => Some((function, candidate)),
+        _ => None,
+    });
+
+    // 1. Named handler (`router.get('/x', listThings)`, `r.GET("/x", ping)`)
+    let trailing_argument = TRAILING_HANDLER_ARG.captures(segment.content.trim())
+        .map(|captures| captures[1].to_string());
+    let names = [Some(handler.to_string()), trailing_argument].into_iter()
+        .flatten()
+        .filter(|name| HANDLER_IDENTIFIER.is_match(name))
+        .map(|name| name.rsplit('.').next().unwrap_or_default().to_string());
+    for name in names {
+        let mut matching = functions().filter(|(function, _)| function.name == name);
+        let same_file = functions().find(|(function, candidate)| function.name == name && &candidate.metadata.file_path == file_path);
+        if let Some((_, candidate)) = same_file.or_else(|| matching.next()) {
+            return Some(candidate.content.clone());
+        }
+    }
+
+    // 2. Function declared right below a route decorator
+    let decorator_end = segment.metadata.line_end;
+    if let Some((_, candidate)) = functions().find(|(_, candidate)| {
+        &candidate.metadata.file_path == file_path
+            && candidate.metadata.line_start >= segment.metadata.line_start
+            && candidate.metadata.line_start <= decorator_end + 3
+    }) {
+        return Some(candidate.content.clone());
+    }
+
+    // 3. Inline handler included in the route expression, or the declaration a route decorator spans
+    let content = segment.content.trim_start();
+    let is_decorator_only = content.starts_with('@') || content.starts_with("#[");
+    if content.contains("=>") || content.contains("function") || content.contains("fn ") || content.contains("def ") || content.contains(") {") {
+        return Some(segment.content.clone());
+    }
+
+    // 4. Decorated method the extractor did not emit as a function: read it from source
+    if is_decorator_only && segment.metadata.line_start <= lines.len() {
+        let start = segment.metadata.line_end;
+        let end = lines[start.min(lines.len())..].iter()
+            .position(|line| {
+                let trimmed = line.trim_start();
+                trimmed.starts_with('@') || trimmed.starts_with("#[")
+            })
+            .map_or(lines.len(), |offset| start + offset)
+            .min(start + HANDLER_WINDOW_LINES);
+        if end > start {
+            return Some(lines[start..end].join("\n"));
+        }
+    }
+
+    None
+}
+
+fn placeholder_marker(body: &str) -> Option<&'static str> {
+    if let Some((name, _)) = PLACEHOLDER_PATTERNS.iter().find(|(_, pattern)| pattern.is_match(body)) {
+        return Some(name);
+    }
+
+    // Python handlers whose body is only `pass` / `...`
+    let statements: Vec<&str> = body.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('@') && !line.starts_with('#')
+            && !line.starts_with("def ") && !line.starts_with("async def "))
+        .collect();
+    let is_python = body.lines().any(|line| line.trim_start().starts_with("def ") || line.trim_start().starts_with("async def "));
+    if is_python && !statements.is_empty() && statements.iter().all(|line| *line == "pass" || *line == "...") {
+        return Some("pass");
+    }
+
+    None
+}
+
+/// First project-wide error handler, as `file:line`
+fn find_global_error_handler(index: &ProjectFileIndex) -> Option<String> {
+    index.with_extensions(SOURCE_EXTENSIONS).find_map(|file| {
+        let source = fs::read_to_string(&file.path).ok()?;
+        let line = source.lines().position(|line| GLOBAL_ERROR_HANDLER.is_match(line))?;
+        Some(format!("{}:{}", file.relative, line + 1))
+    })
+}
+
+/// Frameworks whose handlers are written to throw: their exception layer maps uncaught errors to
+/// error responses, so a handler without try/catch is idiomatic rather than incomplete
+fn framework_exception_layer(framework: Option<Framework>) -> Option<&'static str> {
+    match framework? {
+        Framework::NestJS => Some("NestJS exception filters"),
+        Framework::SpringBoot => Some("Spring exception resolvers"),
+        _ => None,
+    }
+}
+
+/// Join an optional controller prefix with a route path into `/a/b` form
+fn normalize_route_path(prefix: Option<&str>, path: &str) -> String {
+    let joined = match prefix {
+        Some(prefix) if !prefix.is_empty() => format!("{}/{}", prefix, path),
+        _ => path.to_string(),
+    };
+    let segments: Vec<&str> = joined.split('/').filter(|segment| !segment.is_empty()).collect();
+    format!("/{}", segments.join("/"))
+}
+
+fn is_path_parameter(segment: &str) -> bool {
+    segment.starts_with(':') || segment.starts_with('{') || segment.starts_with('<')
+        || segment.starts_with('[') || segment.starts_with('*') || segment.starts_with('$')
+}
+
+/// Collection path a route belongs to: `/users/:id` and `/users` share `/users`
+fn resource_key(path: &str) -> String {
+    let mut segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    while segments.last().is_some_and(|segment| is_path_parameter(segment)) {
+        segments.pop();
+    }
+    let normalized: Vec<&str> = segments.into_iter()
+        .map(|segment| if is_path_parameter(segment) { "{}" } else { segment })
+        .collect();
+    format!("/{}", normalized.join("/"))
+}
+
+/// Whether two paths refer to the same endpoint, treating parameters as wildcards and
+/// tolerating a mount prefix such as `/api` or `/v1` on either side
+fn paths_match(left: &str, right: &str) -> bool {
+    let split = |path: &str| -> Vec<String> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| if is_path_parameter(segment) { "{}".to_string() } else { segment.to_lowercase() })
+            .collect()
+    };
+    let (left, right) = (split(left), split(right));
+    let (longer, shorter) = if left.len() >= right.len() { (&left, &right) } else { (&right, &left) };
+    let offset = longer.len() - shorter.len();
+
+    let prefix_is_mount = longer[..offset].iter().all(|segment| {
+        segment == "api" || (segment.starts_with('v') && segment[1..].chars().all(|c| c.is_ascii_digit()))
+    });
+
+    prefix_is_mount && longer[offset..].iter().zip(shorter.iter())
+        .all(|(a, b)| a == b || a == "{}" || b == "{}")
+}
+
+/// Verb group used for completeness: PUT and PATCH both count as "update"
+fn verb_group(method: &str) -> Option<&'static str> {
+    match method.to_uppercase().as_str() {
+        "GET" => Some("GET"),
+        "POST" => Some("POST"),
+        "PUT" | "PATCH" => Some("PUT/PATCH"),
+        "DELETE" => Some("DELETE"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::{analyze, analyze_documented, documentation, write};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_placeholder_handlers_and_error_handling() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "app/main.py", r#"from fastapi import FastAPI
+app = FastAPI()
+
+@app.get('/items/{item_id}')
+async def read_item(item_id: int):
+    raise NotImplementedError
+
+@app.post('/items')
+async def create_item(item: Item):
+    return await db.save(item)
+"#);
+        write(temp_dir.path(), "src/orders.controller.ts", r#"import { Controller, Get, Post } from '@nestjs/common';
+@Controller('orders')
+export class OrdersController {
+  @Get(':id')
+  findOne(@Param('id') id: string) { return this.svc.findOne(id); }
+  @Post()
+  create(@Body() dto: any) {
+    throw new Error('Not implemented');
+  }
+}
+"#);
+
+        let result = analyze(&ApiAnalyzer::new(ApiAnalysisConfig::default(), 0.2), temp_dir.path());
+
+        let placeholders: Vec<&str> = result.missing_implementations.iter()
+            .filter(|m| m.implementation_type == "API Endpoint Handler")
+            .map(|m| m.expected_location.as_str())
+            .collect();
+        assert_eq!(placeholders.len(), 2, "{:?}", result.missing_implementations);
+        assert!(placeholders.contains(&"app/main.py:4"));
+        assert!(placeholders.contains(&"src/orders.controller.ts:6"));
+
+        let unhandled: Vec<&str> = result.missing_implementations.iter()
+            .filter(|m| m.implementation_type == "API Error Handling")
+            .map(|m| m.description.as_str())
+            .collect();
+        assert_eq!(unhandled.len(), 1, "{:?}", unhandled);
+        assert!(unhandled[0].starts_with("POST /items "));
+        // NestJS handlers throw into the framework's exception filters
+        assert!(result.analysis_notes.iter().any(|note| note.contains("NestJS exception filters")));
+    }
+
+    #[test]
+    fn test_routes_are_reported_once_and_global_handlers_found_anywhere() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/routes.js", r#"router.get('/users', async (req, res) => {
+  res.json(await db.users.findAll());
+});
+router.delete('/users/:id', async (req, res) => {
+  await db.users.delete(req.params.id);
+  res.sendStatus(204);
+});
+"#);
+
+        let index = ProjectFileIndex::build(temp_dir.path());
+        let analyzer = ApiAnalyzer::new(ApiAnalysisConfig::default(), 0.2);
+        let route_segments = index.segments().unwrap().iter()
+            .filter(|segment| matches!(segment.segment_type, SegmentType::Route(_)))
+            .count();
+        assert_eq!(route_segments, 2);
+        let endpoints = analyzer.build_inventory(temp_dir.path(), index.segments().unwrap());
+        let mut signatures: Vec<String> = endpoints.iter().map(Endpoint::signature).collect();
+        signatures.sort();
+        assert_eq!(signatures, vec!["DELETE /users/:id".to_string(), "GET /users".to_string()]);
+        assert!(find_global_error_handler(&index).is_none());
+
+        // An error middleware registered in a file without routes still covers every handler
+        write(temp_dir.path(), "src/errors.js", "app.use((err, req, res, next) => res.status(500).end());\n");
+        let index = ProjectFileIndex::build(temp_dir.path());
+        assert_eq!(find_global_error_handler(&index).as_deref(), Some("src/errors.js:1"));
+    }
+
+    #[test]
+    fn test_missing_verbs_and_documented_endpoints() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/main/java/com/x/UserController.java", r#"package com.x;
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+  @GetMapping("/{id}")
+  public User get(@PathVariable Long id) { return repo.findById(id).orElseThrow(); }
+  @PostMapping
+  public User create(@RequestBody User u) { return repo.save(u); }
+}
+"#);
+        write(temp_dir.path(), "src/App.tsx", "const routes = [{ path: '/users', component: Users }];\n");
+
+        let documentation = documentation(vec![
+            "GET /users/:id - fetch a user\nDELETE /users/:id - remove a user".to_string(),
+        ]);
+
+        let result = analyze_documented(&ApiAnalyzer::new(ApiAnalysisConfig::default(), 0.2), temp_dir.path(), &documentation);
+
+        assert_eq!(result.partial_implementations.len(), 1, "{:?}", result.partial_implementations);
+        let users = &result.partial_implementations[0];
+        assert_eq!(users.implementation_name, "/api/users endpoints");
+        assert_eq!(users.implemented_parts, vec!["GET".to_string(), "POST".to_string()]);
+        assert_eq!(users.missing_parts, vec!["DELETE".to_string(), "PUT/PATCH".to_string()]);
+
+        let documented: Vec<&str> = result.missing_implementations.iter()
+            .filter(|m| m.implementation_type == "Documented API Endpoint")
+            .map(|m| m.expected_location.as_str())
+            .collect();
+        assert_eq!(documented, vec!["DELETE /users/:id"]);
+    }
+
+    #[test]
+    fn test_path_helpers() {
+        assert_eq!(normalize_route_path(Some("orders"), ":id/"), "/orders/:id");
+        assert_eq!(resource_key("/users/{id}/orders/:orderId"), "/users/{}/orders");
+        assert!(paths_match("/api/v1/users/:id", "/users/{id}"));
+        assert!(!paths_match("/admin/users/:id", "/users/{id}"));
+    }
+}
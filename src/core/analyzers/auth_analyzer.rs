@@ -0,0 +1,885 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, AuthAnalysisConfig,
+    MissingImplementation, PartialImplementation
+};
+use crate::core::analyzers::common::is_test_path;
+use crate::core::analyzers::file_index::{IndexedFile, ProjectFileIndex};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Maximum number of evidence lines attached to a single finding
+const MAX_EVIDENCE_PER_FINDING: usize = 5;
+
+/// Source extensions scanned for auth flows and route definitions
+pub(super) const SOURCE_EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "java", "kt", "go", "rs", "rb", "php", "cs",
+];
+
+/// Authentication flow steps recognised by the analyzer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum AuthFlow {
+    Login,
+    Logout,
+    Registration,
+    PasswordReset,
+    TokenIssue,
+    TokenVerification,
+    TokenRefresh,
+    Mfa,
+}
+
+impl AuthFlow {
+    fn label(&self) -> &'static str {
+        match self {
+            AuthFlow::Login => "login",
+            AuthFlow::Logout => "logout",
+            AuthFlow::Registration => "registration",
+            AuthFlow::PasswordReset => "password reset",
+            AuthFlow::TokenIssue => "token issuing",
+            AuthFlow::TokenVerification => "token verification",
+            AuthFlow::TokenRefresh => "token refresh",
+            AuthFlow::Mfa => "multi-factor authentication",
+        }
+    }
+}
+
+/// A flow that, once present, implies another flow should exist too
+struct FlowRequirement {
+    present: AuthFlow,
+    expected: AuthFlow,
+    description: &'static str,
+    suggestion: &'static str,
+    confidence: f32,
+}
+
+const FLOW_REQUIREMENTS: &[FlowRequirement] = &[
+    FlowRequirement {
+        present: AuthFlow::TokenIssue,
+        expected: AuthFlow::TokenVerification,
+        description: "JWTs are issued but no verification middleware or guard checks them on incoming requests",
+        suggestion: "Add middleware/guard that verifies the token signature and expiry before protected handlers run",
+        confidence: 0.85,
+    },
+    FlowRequirement {
+        present: AuthFlow::Registration,
+        expected: AuthFlow::PasswordReset,
+        description: "Users can register but there is no password reset / forgot password flow",
+        suggestion: "Add forgot-password and reset-password endpoints with single-use, expiring reset tokens",
+        confidence: 0.75,
+    },
+    FlowRequirement {
+        present: AuthFlow::Registration,
+        expected: AuthFlow::Login,
+        description: "Users can register but no login flow was found",
+        suggestion: "Add a login endpoint that validates credentials and establishes a session or token",
+        confidence: 0.7,
+    },
+    FlowRequirement {
+        present: AuthFlow::Login,
+        expected: AuthFlow::Logout,
+        description: "Users can log in but there is no logout flow to end the session or revoke the token",
+        suggestion: "Add a logout endpoint that destroys the session or revokes/blacklists the token",
+        confidence: 0.7,
+    },
+    FlowRequirement {
+        present: AuthFlow::TokenIssue,
+        expected: AuthFlow::TokenRefresh,
+        description: "Access tokens are issued but there is no token refresh flow",
+        suggestion: "Add a refresh-token endpoint or shorten-lived access tokens paired with refresh tokens",
+        confidence: 0.55,
+    },
+];
+
+/// Builds a pattern matching function/method definitions, variable bindings and
+/// route path literals whose name ends with one of `keywords`.
+fn flow_definition_pattern(keywords: &str, paths: &str) -> Regex {
+    Regex::new(&format!(
+        r#"(?i)(?:\b(?:def|function|fn|func|async|public|private|protected)\s+[\w<>\[\], ]*?\b\w*(?:{k})\s*[(<]|\b\w*(?:{k})\s*(?::\s*[\w<>]+\s*)?=\s*(?:async\s*)?(?:\([^)]*\)|\w+)\s*=>|\b\w*(?:{k})\s*\([^)]*\)\s*\{{|['"`][\w/:{{}}.-]*/(?:{p})[\w/-]*['"`])"#,
+        k = keywords,
+        p = paths,
+    ))
+    .expect("invalid auth flow pattern")
+}
+
+/// Detection patterns per flow: definition-style matches plus framework/library idioms
+static FLOW_PATTERNS: Lazy<Vec<(AuthFlow, Vec<Regex>)>> = Lazy::new(|| {
+    let lib = |pattern: &str| Regex::new(pattern).expect("invalid auth library pattern");
+    vec![
+        (AuthFlow::Login, vec![
+            flow_definition_pattern("log_?in|sign_?in|authenticate_?user", "login|signin|sign-in"),
+            lib(r"(?i)passport\.authenticate\(\s*['\x22]local|LoginView|formLogin\s*\(|signInWith(?:EmailAndPassword|Password|OAuth)|auth\.signIn\w*\(|django\.contrib\.auth\.urls|:database_authenticatable"),
+        ]),
+        (AuthFlow::Logout, vec![
+            flow_definition_pattern("log_?out|sign_?out", "logout|signout|sign-out"),
+            lib(r"(?i)req\.logout\s*\(|session\.destroy\s*\(|LogoutView|\.logout\s*\(\s*\)|auth\.signOut\s*\(|django\.contrib\.auth\.urls|:database_authenticatable"),
+        ]),
+        (AuthFlow::Registration, vec![
+            flow_definition_pattern("register|register_?user|sign_?up|create_?account", "register|signup|sign-up"),
+            lib(r"(?i)createUserWithEmailAndPassword|auth\.signUp\s*\(|UserCreationForm|:registerable"),
+        ]),
+        (AuthFlow::PasswordReset, vec![
+            flow_definition_pattern("reset_?password|password_?reset|forgot_?password|request_?password_?reset", "reset-password|forgot-password|password/reset|password-reset|reset_password|forgot_password"),
+            lib(r"(?i)sendPasswordResetEmail|resetPasswordForEmail|PasswordResetView|django\.contrib\.auth\.urls|:recoverable"),
+        ]),
+        (AuthFlow::TokenIssue, vec![
+            lib(r"(?i)\bjwt\.(?:sign|encode)\s*\(|create_access_token\s*\(|Jwts\.builder\s*\(|jwt\.NewWithClaims\s*\(|JWT\.create\s*\(|new\s+SignJWT\s*\(|jwtService\.sign(?:Async)?\s*\(|jsonwebtoken::encode|\bencode\s*\(\s*&Header"),
+        ]),
+        (AuthFlow::TokenVerification, vec![
+            lib(r"(?i)\bjwt\.(?:verify|decode)\s*\(|express-?jwt|passport-jwt|JwtStrategy|AuthGuard\(\s*['\x22]jwt|jwt_required|verify_jwt_in_request|decode_access_token|get_current_user|Jwts\.parser|parseClaimsJws|parseSignedClaims|jwt\.Parse(?:WithClaims)?\s*\(|jsonwebtoken::decode|\bdecode\s*::<|JWT\.require\s*\(|jwtVerify\s*\(|jwtService\.verify(?:Async)?\s*\(|oauth2ResourceServer|JwtAuthenticationFilter|JwtDecoder"),
+        ]),
+        (AuthFlow::TokenRefresh, vec![
+            flow_definition_pattern("refresh_?token|token_?refresh|refresh_?access_?token|refresh_?session", "refresh|refresh-token|refresh_token|token/refresh"),
+            lib(r"(?i)create_refresh_token\s*\(|jwt_refresh_token_required|TokenRefreshView|refreshSession\s*\("),
+        ]),
+        (AuthFlow::Mfa, vec![
+            flow_definition_pattern("mfa|two_?factor|2fa|totp|verify_?otp", "mfa|2fa|two-factor|otp|totp"),
+            lib(r"(?i)\b(?:speakeasy|otplib|pyotp|django_otp|pquerna/otp|totp-rs|GoogleAuthenticator)\b|:two_factor_authenticatable"),
+        ]),
+    ]
+});
+
+/// Route definitions across supported web frameworks.
+/// Each pattern captures `method` and `path`; `rest` (when present) holds inline middleware.
+static ROUTE_PATTERNS: Lazy<Vec<(RouteStyle, Regex)>> = Lazy::new(|| {
+    let re = |pattern: &str| Regex::new(pattern).expect("invalid route pattern");
+    vec![
+        // NestJS controllers
+        (RouteStyle::Decorated, re(r#"@(?P<method>Get|Post|Put|Patch|Delete|All)\s*\(\s*(?:['"`](?P<path>[^'"`]*)['"`])?\s*\)"#)),
+        // Spring MVC
+        (RouteStyle::Decorated, re(r#"@(?P<method>Get|Post|Put|Patch|Delete|Request)Mapping\s*(?:\(\s*(?:(?:value|path)\s*=\s*)?\{?\s*(?:"(?P<path>[^"]*)")?[^)]*\))?"#)),
+        // FastAPI, Flask 2.x shortcuts, Sanic
+        (RouteStyle::Decorated, re(r#"@\w+\.(?P<method>get|post|put|patch|delete)\s*\(\s*['"](?P<path>[^'"]*)['"]"#)),
+        // Flask / Quart classic
+        (RouteStyle::Decorated, re(r#"@\w+\.(?P<method>route)\s*\(\s*['"](?P<path>[^'"]*)['"]"#)),
+        // Actix-web, Rocket
+        (RouteStyle::Decorated, re(r#"#\[(?P<method>get|post|put|patch|delete)\s*\(\s*"(?P<path>[^"]*)""#)),
+        // Express, Koa, Fastify, Hono, chi-style routers
+        (RouteStyle::Inline, re(r#"\b(?:app|router|fastify|routes|\w+Router)\.(?P<method>get|post|put|patch|delete|all)\s*\(\s*['"`](?P<path>[^'"`]*)['"`]\s*(?P<rest>.*)"#)),
+        // Gin, Echo, Fiber (Go)
+        (RouteStyle::Inline, re(r#"\.(?P<method>GET|POST|PUT|PATCH|DELETE|Any)\s*\(\s*"(?P<path>[^"]*)"\s*(?P<rest>.*)"#)),
+        // Axum
+        (RouteStyle::Inline, re(r#"\.route\(\s*"(?P<path>[^"]*)"\s*,\s*(?P<method>get|post|put|patch|delete)\s*\((?P<rest>.*)"#)),
+        // Laravel
+        (RouteStyle::Inline, re(r#"Route::(?P<method>get|post|put|patch|delete|any)\s*\(\s*['"](?P<path>[^'"]*)['"](?P<rest>.*)"#)),
+    ]
+});
+
+/// Controller-level path prefixes (NestJS, Spring)
+static CONTROLLER_PREFIX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"@(?:Controller|RequestMapping)\s*\(\s*(?:(?:value|path)\s*=\s*)?['"`]([^'"`]*)['"`]"#).unwrap()
+});
+
+/// Statements that apply an auth guard to every route registered after them in the same file
+static FILE_GUARD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\.use\s*\(|\.Use\s*\(|\.layer\s*\(|\.route_layer\s*\(|before_request|before_action|dependencies\s*=|->middleware\s*\()").unwrap()
+});
+
+/// Statements that guard every route in the project
+static GLOBAL_GUARD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)useGlobalGuards\s*\(|provide:\s*APP_GUARD|anyRequest\(\)\s*\.\s*authenticated\(\)|DEFAULT_PERMISSION_CLASSES|\bapp\.use\s*\(").unwrap()
+});
+
+/// Markers that intentionally expose a route without authentication
+static PUBLIC_MARKER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"@Public\s*\(|@AllowAnonymous|@PermitAll|\bAllowAny\b|permitAll\s*\(|@anonymous|skip_before_action\s+:authenticate").unwrap()
+});
+
+/// String literals are stripped before looking for guard identifiers, so that
+/// paths such as `/auth/profile` are not mistaken for middleware
+static STRING_LITERAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"'[^']*'|"[^"]*"|`[^`]*`"#).unwrap()
+});
+
+static IDENTIFIER: Lazy<Regex> = Lazy::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap());
+
+/// Class declarations; each one starts a new controller scope
+static CLASS_DECLARATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?:export\s+)?(?:default\s+)?(?:(?:public|abstract|final)\s+)*class\s+\w+").unwrap()
+});
+
+/// Identifier fragments that indicate an auth guard/middleware
+const GUARD_FRAGMENTS: &[&str] = &[
+    "auth", "guard", "loggedin", "logged_in", "login_required", "jwt", "currentuser",
+    "current_user", "oauth2_scheme", "verifytoken", "verify_token", "checktoken", "preauthorize",
+    "secured", "rolesallowed", "permission", "roles", "protectroute", "protectedroute",
+];
+
+/// Names that carry a guard fragment but mount routes or hold handlers, e.g. `authRouter`, `rolesController`
+const ROUTE_MODULE_SUFFIXES: &[&str] = &["controller", "router", "routes", "service", "module"];
+
+/// Route path segments that are expected to be reachable without authentication
+const PUBLIC_PATH_SEGMENTS: &[&str] = &[
+    "login", "logout", "signin", "sign-in", "signup", "sign-up", "register", "forgot-password",
+    "reset-password", "forgot", "reset", "refresh", "token", "callback", "oauth", "verify",
+    "health", "healthz", "ready", "readyz", "livez", "ping", "status", "version", "metrics",
+    "public", "docs", "swagger", "openapi", "webhook", "webhooks", "static", "assets", "favicon.ico",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RouteStyle {
+    /// Middleware is passed inline with the route registration
+    Inline,
+    /// Route is declared with a decorator/annotation/attribute above a handler
+    Decorated,
+}
+
+/// Location of a match inside the project
+#[derive(Debug, Clone)]
+struct SourceLocation {
+    file: String,
+    line: usize,
+    snippet: String,
+}
+
+impl SourceLocation {
+    fn describe(&self) -> String {
+        format!("{}:{}: {}", self.file, self.line, self.snippet)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RouteDefinition {
+    method: String,
+    path: String,
+    location: SourceLocation,
+    guarded: bool,
+    public: bool,
+}
+
+impl RouteDefinition {
+    fn signature(&self) -> String {
+        format!("{} {}", self.method, self.path)
+    }
+}
+
+/// Everything the analyzer learned about the project's authentication
+#[derive(Debug, Default)]
+struct AuthInventory {
+    flows: BTreeMap<AuthFlow, Vec<SourceLocation>>,
+    routes: Vec<RouteDefinition>,
+    global_guards: Vec<SourceLocation>,
+    auth_libraries: BTreeSet<String>,
+    oauth_providers: BTreeSet<String>,
+    permission_evidence: Vec<SourceLocation>,
+    session_evidence: Vec<SourceLocation>,
+    files_scanned: usize,
+}
+
+impl AuthInventory {
+    fn has_auth(&self) -> bool {
+        !self.flows.is_empty()
+            || !self.global_guards.is_empty()
+            || !self.auth_libraries.is_empty()
+            || self.routes.iter().any(|r| r.guarded)
+    }
+}
+
+/// Authentication completeness analyzer
+/// Detects login/logout/registration/password-reset/token/MFA flows, flags missing
+/// pieces of those flows and reports routes that are not covered by any auth guard
+pub struct AuthAnalyzer {
+    config: AuthAnalysisConfig,
+    weight: f32,
+    library_patterns: Vec<(String, Regex)>,
+}
+
+impl AuthAnalyzer {
+    pub fn new(config: AuthAnalysisConfig, weight: f32) -> Self {
+        let library_patterns = config.auth_patterns.iter()
+            .filter_map(|name| {
+                Regex::new(&format!(
+                    r#"(?i)(?:import|require|from|use|using|@Grab)\b.*\b{}"#,
+                    regex::escape(name)
+                ))
+                .ok()
+                .map(|regex| (name.clone(), regex))
+            })
+            .collect();
+
+        Self { config, weight, library_patterns }
+    }
+
+    /// Indexed source files, excluding tests
+    fn collect_source_files<'a>(&self, index: &'a ProjectFileIndex) -> impl Iterator<Item = &'a IndexedFile> + 'a {
+        index.with_extensions(SOURCE_EXTENSIONS)
+            .filter(|file| !is_test_path(&file.relative))
+    }
+
+    /// Scan the project and build the auth inventory
+    fn build_inventory(&self, index: &ProjectFileIndex) -> Result<AuthInventory> {
+        let mut inventory = AuthInventory::default();
+
+        for file in self.collect_source_files(index) {
+            let Ok(content) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+
+            inventory.files_scanned += 1;
+            self.scan_file(&file.relative, &content, &mut inventory);
+        }
+
+        // Routes are only considered unguarded once project-wide guards are known
+        if !inventory.global_guards.is_empty() {
+            for route in &mut inventory.routes {
+                route.guarded = true;
+            }
+        }
+
+        Ok(inventory)
+    }
+
+    fn scan_file(&self, file: &str, content: &str, inventory: &mut AuthInventory) {
+        let lines: Vec<&str> = content.lines().collect();
+
+        for (index, line) in lines.iter().enumerate() {
+            let location = || SourceLocation {
+                file: file.to_string(),
+                line: index + 1,
+                snippet: line.trim().chars().take(120).collect(),
+            };
+
+            for (flow, patterns) in FLOW_PATTERNS.iter() {
+                if patterns.iter().any(|pattern| pattern.is_match(line)) {
+                    inventory.flows.entry(*flow).or_default().push(location());
+                }
+            }
+
+            for (name, pattern) in &self.library_patterns {
+                if pattern.is_match(line) {
+                    inventory.auth_libraries.insert(name.clone());
+                }
+            }
+
+            let lower = line.to_lowercase();
+            if self.config.detect_oauth_providers && (lower.contains("oauth") || lower.contains("strategy") || lower.contains("provider")) {
+                for provider in &self.config.oauth_providers {
+                    if lower.contains(&provider.to_lowercase()) {
+                        inventory.oauth_providers.insert(provider.clone());
+                    }
+                }
+            }
+
+            if self.config.detect_permission_systems
+                && (lower.contains("hasrole") || lower.contains("haspermission") || lower.contains("@roles")
+                    || lower.contains("permission_required") || lower.contains("rolesguard") || lower.contains("@preauthorize"))
+            {
+                inventory.permission_evidence.push(location());
+            }
+
+            if self.config.detect_session_management
+                && (lower.contains("express-session") || lower.contains("cookie-session") || lower.contains("sessionmiddleware")
+                    || lower.contains("httpsession") || lower.contains("session_start(") || lower.contains("sessionmanagement("))
+            {
+                inventory.session_evidence.push(location());
+            }
+
+            if GLOBAL_GUARD.is_match(line) && (contains_guard_identifier(line) || !line.contains("app.use")) {
+                inventory.global_guards.push(location());
+            }
+        }
+
+        inventory.routes.extend(extract_routes(file, &lines));
+    }
+
+    /// Turn the inventory into status findings
+    fn generate_analysis_results(&self, inventory: AuthInventory) -> StatusAnalysisResult {
+        let mut missing_implementations = Vec::new();
+        let mut partial_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = vec![format!("Scanned {} source files for authentication flows", inventory.files_scanned)];
+
+        if !inventory.has_auth() {
+            analysis_notes.push("No authentication flows, guards or auth libraries detected".to_string());
+            return StatusAnalysisResult {
+                analyzer_name: self.name().to_string(),
+                missing_implementations,
+                partial_implementations,
+                confidence: 0.3,
+                evidence,
+                analysis_notes,
+                ..Default::default()
+            };
+        }
+
+        for (flow, locations) in &inventory.flows {
+            evidence.push(format!("Detected {} flow ({} occurrences), e.g. {}",
+                                  flow.label(), locations.len(), locations[0].describe()));
+        }
+        if !inventory.auth_libraries.is_empty() {
+            evidence.push(format!("Auth libraries in use: {}",
+                                  inventory.auth_libraries.iter().cloned().collect::<Vec<_>>().join(", ")));
+        }
+        if !inventory.oauth_providers.is_empty() {
+            evidence.push(format!("OAuth providers configured: {}",
+                                  inventory.oauth_providers.iter().cloned().collect::<Vec<_>>().join(", ")));
+        }
+        if let Some(location) = inventory.permission_evidence.first() {
+            evidence.push(format!("Permission/role checks found, e.g. {}", location.describe()));
+        }
+        if let Some(location) = inventory.session_evidence.first() {
+            evidence.push(format!("Session management found, e.g. {}", location.describe()));
+        }
+        for location in &inventory.global_guards {
+            evidence.push(format!("Global auth guard: {}", location.describe()));
+        }
+
+        // Missing pieces of detected flows
+        for requirement in FLOW_REQUIREMENTS {
+            let Some(present) = inventory.flows.get(&requirement.present) else {
+                continue;
+            };
+            if inventory.flows.contains_key(&requirement.expected) {
+                continue;
+            }
+            // Logout is part of session handling; respect the session toggle
+            if requirement.expected == AuthFlow::Logout && !self.config.detect_session_management {
+                continue;
+            }
+
+            missing_implementations.push(MissingImplementation {
+                implementation_type: format!("Authentication: {}", requirement.expected.label()),
+                expected_location: expected_location(&present[0].file),
+                description: requirement.description.to_string(),
+                confidence: requirement.confidence,
+                evidence: present.iter()
+                    .take(MAX_EVIDENCE_PER_FINDING)
+                    .map(|location| format!("{} flow at {}", requirement.present.label(), location.describe()))
+                    .collect(),
+                suggested_implementation: Some(requirement.suggestion.to_string()),
+            });
+        }
+
+        // Routes lacking any auth guard, grouped per file
+        let mut routes_by_file: BTreeMap<&str, Vec<&RouteDefinition>> = BTreeMap::new();
+        for route in &inventory.routes {
+            routes_by_file.entry(route.location.file.as_str()).or_default().push(route);
+        }
+
+        let mut unguarded_total = 0;
+        for (file, routes) in routes_by_file {
+            let unguarded: Vec<&&RouteDefinition> = routes.iter()
+                .filter(|route| !route.guarded && !route.public)
+                .collect();
+            if unguarded.is_empty() {
+                continue;
+            }
+            unguarded_total += unguarded.len();
+
+            let guarded: Vec<String> = routes.iter()
+                .filter(|route| route.guarded || route.public)
+                .map(|route| route.signature())
+                .collect();
+
+            partial_implementations.push(PartialImplementation {
+                implementation_name: format!("Route auth guards in {}", file),
+                completeness_percentage: guarded.len() as f32 / routes.len() as f32 * 100.0,
+                implemented_parts: guarded,
+                missing_parts: unguarded.iter().map(|route| route.signature()).collect(),
+                category: "Authentication".to_string(),
+                evidence: unguarded.iter()
+                    .map(|route| format!("{} has no auth guard ({})", route.signature(), route.location.describe()))
+                    .collect(),
+                next_steps: vec![
+                    format!("Protect the listed routes in {} with the project's auth middleware/guard", file),
+                    "Mark routes that are intentionally public explicitly (e.g. @Public(), permitAll, AllowAny)".to_string(),
+                ],
+            });
+        }
+
+        analysis_notes.push(format!("Detected {} auth flows and {} routes ({} without an auth guard)",
+                                    inventory.flows.len(), inventory.routes.len(), unguarded_total));
+        analysis_notes.push("Auth analysis covers login, logout, registration, password reset, token issue/verification/refresh and MFA".to_string());
+
+        let confidence = (0.5 + inventory.flows.len() as f32 * 0.05
+            + if inventory.routes.is_empty() { 0.0 } else { 0.1 }).min(0.9);
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            missing_implementations,
+            partial_implementations,
+            confidence,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatusAnalyzer for AuthAnalyzer {
+    fn name(&self) -> &str {
+        "Auth Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Analyzing authentication flows for project: {}", index.root().display());
+
+        let inventory = self.build_inventory(index)
+            .context("Failed to build authentication inventory")?;
+
+        let result = self.generate_analysis_results(inventory);
+
+        println!("Auth analysis completed: {} items found, {:.1}% confidence",
+                 result.missing_implementations.len() + result.partial_implementations.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Extract route definitions from a single file and decide whether each is guarded
+fn extract_routes(file: &str, lines: &[&str]) -> Vec<RouteDefinition> {
+    let mut routes = Vec::new();
+    let mut file_guarded = false;
+    let mut class_guarded = false;
+    let mut controller_prefix = String::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if FILE_GUARD.is_match(line) && contains_guard_identifier(line) {
+            file_guarded = true;
+        }
+
+        if CLASS_DECLARATION.is_match(line) {
+            // Guards and prefixes declared on a controller class cover only that class's handlers
+            (controller_prefix, class_guarded) = class_scope(lines, index);
+            continue;
+        }
+        if class_route_prefix(lines, index).is_some() {
+            continue;
+        }
+
+        for (style, pattern) in ROUTE_PATTERNS.iter() {
+            let Some(captures) = pattern.captures(line) else {
+                continue;
+            };
+
+            let method = captures.name("method").map(|m| m.as_str()).unwrap_or("GET");
+            let method = match method {
+                "route" | "Request" | "Any" | "any" | "all" | "All" => "ANY".to_string(),
+                other => other.to_uppercase(),
+            };
+            let raw_path = captures.name("path").map(|m| m.as_str()).unwrap_or("");
+
+            let (context, path) = match style {
+                RouteStyle::Inline => (
+                    captures.name("rest").map(|m| m.as_str().to_string()).unwrap_or_default(),
+                    raw_path.to_string(),
+                ),
+                RouteStyle::Decorated => {
+                    let (start, end) = decorator_block(lines, index);
+                    // Include the handler signature (FastAPI Depends(), typed guards)
+                    let signature_end = handler_signature_end(lines, end);
+                    let path = if controller_prefix.is_empty() {
+                        raw_path.to_string()
+                    } else {
+                        format!("/{}/{}", controller_prefix, raw_path.trim_start_matches('/'))
+                    };
+                    (lines[start..=signature_end].join("\n"), path)
+                }
+            };
+            let path = format!("/{}", path.trim_matches('/'));
+
+            routes.push(RouteDefinition {
+                method,
+                public: PUBLIC_MARKER.is_match(&context) || is_public_path(&path),
+                guarded: file_guarded || class_guarded || contains_guard_identifier(&context),
+                path,
+                location: SourceLocation {
+                    file: file.to_string(),
+                    line: index + 1,
+                    snippet: line.trim().chars().take(120).collect(),
+                },
+            });
+            break;
+        }
+    }
+
+    routes
+}
+
+/// Path prefix declared on a controller class (NestJS `@Controller`, Spring `@RequestMapping`)
+pub(super) struct ClassRoutePrefix {
+    /// Zero-based line range of the decorator block on the class
+    pub block_start: usize,
+    pub block_end: usize,
+    /// Prefix without leading/trailing slashes
+    pub prefix: String,
+}
+
+/// Class-level route prefix declared on line `index`, if any
+pub(super) fn class_route_prefix(lines: &[&str], index: usize) -> Option<ClassRoutePrefix> {
+    let captures = CONTROLLER_PREFIX.captures(lines[index])?;
+    let (block_start, block_end) = decorator_block(lines, index);
+    let class_level = lines.get(block_end + 1).is_some_and(|next| next.contains("class "));
+
+    class_level.then(|| ClassRoutePrefix {
+        block_start,
+        block_end,
+        prefix: captures[1].trim_matches('/').to_string(),
+    })
+}
+
+/// Route prefix and guard state of the class declared on line `index`, from the decorators above it
+fn class_scope(lines: &[&str], index: usize) -> (String, bool) {
+    let decorated = index > 0 && {
+        let previous = lines[index - 1].trim_start();
+        previous.starts_with('@') || previous.starts_with("#[")
+    };
+    if !decorated {
+        return (String::new(), false);
+    }
+
+    let (start, end) = decorator_block(lines, index - 1);
+    let prefix = lines[start..=end].iter()
+        .find_map(|line| CONTROLLER_PREFIX.captures(line).map(|captures| captures[1].trim_matches('/').to_string()))
+        .unwrap_or_default();
+    (prefix, contains_guard_identifier(&lines[start..=end].join("\n")))
+}
+
+/// The contiguous block of decorator/annotation/attribute lines around `index`
+fn decorator_block(lines: &[&str], index: usize) -> (usize, usize) {
+    let is_decorator = |line: &str| {
+        let trimmed = line.trim_start();
+        trimmed.starts_with('@') || trimmed.starts_with("#[")
+    };
+
+    let mut start = index;
+    while start > 0 && is_decorator(lines[start - 1]) {
+        start -= 1;
+    }
+    let mut end = index;
+    while end + 1 < lines.len() && is_decorator(lines[end + 1]) {
+        end += 1;
+    }
+    (start, end)
+}
+
+/// Last line of the handler signature following a decorator block, stopping
+/// before the next handler's decorators
+fn handler_signature_end(lines: &[&str], block_end: usize) -> usize {
+    let last = (block_end + 4).min(lines.len() - 1);
+    for (index, line) in lines.iter().enumerate().take(last + 1).skip(block_end + 1) {
+        let trimmed = line.trim();
+        if trimmed.starts_with('@') || trimmed.starts_with("#[") {
+            return index - 1;
+        }
+        if trimmed.contains('{') || trimmed.ends_with(':') || trimmed.ends_with(';') {
+            return index;
+        }
+    }
+    last
+}
+
+/// Whether the code (with string literals removed) applies an auth guard: as a decorator, a call,
+/// a bare middleware argument or a Ruby symbol. Receivers such as `authController.login` and route
+/// modules such as `authRouter` only share the name
+fn contains_guard_identifier(code: &str) -> bool {
+    let stripped = STRING_LITERAL.replace_all(code, "");
+    IDENTIFIER.find_iter(&stripped).any(|ident| {
+        let name = ident.as_str().to_lowercase();
+        // "author", "authors" etc. are domain words, not guards
+        if name.contains("author") && !name.contains("authoriz") {
+            return false;
+        }
+        let guard_name = name == "protect" || GUARD_FRAGMENTS.iter().any(|fragment| name.contains(fragment));
+        if !guard_name || ROUTE_MODULE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+            return false;
+        }
+
+        let preceding = &stripped[..ident.start()];
+        // `@UseGuards`, `@auth.login_required`
+        let decorator = preceding.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_' || c == '.').ends_with('@');
+        let symbol = preceding.ends_with(':') && !preceding.ends_with("::");
+        let before = preceding.trim_end().chars().next_back();
+        let after = stripped[ident.end()..].trim_start().chars().next();
+        match (before, after) {
+            _ if decorator || symbol => true,
+            (_, Some('.')) => false,
+            (_, Some('(')) => true,
+            (Some('(' | ',' | '['), Some(')' | ',' | ']')) => true,
+            _ => false,
+        }
+    })
+}
+
+fn is_public_path(path: &str) -> bool {
+    if path == "/" {
+        return false;
+    }
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .any(|segment| {
+            let segment = segment.to_lowercase();
+            PUBLIC_PATH_SEGMENTS.contains(&segment.as_str()) || segment == "auth"
+        })
+}
+
+/// Directory of the file where the missing piece would naturally live
+fn expected_location(file: &str) -> String {
+    match file.rsplit_once('/') {
+        Some((dir, _)) => dir.to_string(),
+        None => ".".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::{analyze, write};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_express_missing_flow_pieces_and_unguarded_routes() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/routes/auth.js", r#"
+const jwt = require('jsonwebtoken');
+router.post('/register', async (req, res) => { res.json(await createUser(req.body)); });
+router.post('/login', async (req, res) => {
+  res.json({ token: jwt.sign({ id: user.id }, SECRET) });
+});
+"#);
+        write(temp_dir.path(), "src/routes/users.js", r#"
+router.get('/users', listUsers);
+router.delete('/users/:id', requireAuth, deleteUser);
+router.get('/health', (req, res) => res.send('ok'));
+"#);
+
+        let result = analyze(&AuthAnalyzer::new(AuthAnalysisConfig::default(), 0.2), temp_dir.path());
+
+        let missing: Vec<&str> = result.missing_implementations.iter()
+            .map(|m| m.implementation_type.as_str())
+            .collect();
+        assert!(missing.contains(&"Authentication: token verification"));
+        assert!(missing.contains(&"Authentication: password reset"));
+        assert!(missing.contains(&"Authentication: logout"));
+        assert!(!missing.contains(&"Authentication: login"));
+
+        let verification = result.missing_implementations.iter()
+            .find(|m| m.implementation_type == "Authentication: token verification")
+            .unwrap();
+        assert_eq!(verification.expected_location, "src/routes");
+        assert!(verification.evidence[0].contains("src/routes/auth.js:5"));
+
+        assert_eq!(result.partial_implementations.len(), 1);
+        let guards = &result.partial_implementations[0];
+        assert_eq!(guards.implementation_name, "Route auth guards in src/routes/users.js");
+        assert_eq!(guards.missing_parts, vec!["GET /users".to_string()]);
+        assert!(guards.evidence[0].contains("src/routes/users.js:2"));
+    }
+
+    #[test]
+    fn test_decorated_routes_respect_class_and_method_guards() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/orders/orders.controller.ts", r#"
+@Controller('orders')
+export class OrdersController {
+  @Get()
+  @UseGuards(JwtAuthGuard)
+  findAll() {}
+
+  @Post(':id/refund')
+  refund() {}
+}
+"#);
+        write(temp_dir.path(), "src/admin/admin.controller.ts", r#"
+@UseGuards(AuthGuard('jwt'))
+@Controller('admin')
+export class AdminController {
+  @Delete('cache')
+  clear() {}
+}
+"#);
+        write(temp_dir.path(), "app/api.py", r#"
+@app.get("/profile")
+def profile(user = Depends(get_current_user)):
+    return user
+
+@app.get("/authors")
+def authors():
+    return []
+"#);
+
+        let result = analyze(&AuthAnalyzer::new(AuthAnalysisConfig::default(), 0.2), temp_dir.path());
+        let mut unguarded: Vec<String> = result.partial_implementations.iter()
+            .flat_map(|p| p.missing_parts.clone())
+            .collect();
+        unguarded.sort();
+
+        assert_eq!(unguarded, vec!["GET /authors".to_string(), "POST /orders/:id/refund".to_string()]);
+    }
+
+    #[test]
+    fn test_guard_names_count_only_in_guard_positions() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/app.js", r#"
+app.use('/auth', authRouter);
+router.post('/login', authController.login);
+router.get('/roles', rolesController.list);
+router.get('/me', requireAuth, showProfile);
+"#);
+        write(temp_dir.path(), "src/controllers.ts", r#"
+@UseGuards(JwtAuthGuard)
+@Controller('admin')
+export class AdminController {
+  @Get('stats')
+  stats() {}
+}
+
+@Controller('reports')
+export class ReportsController {
+  @Get()
+  list() {}
+}
+"#);
+
+        let result = analyze(&AuthAnalyzer::new(AuthAnalysisConfig::default(), 0.2), temp_dir.path());
+        let mut unguarded: Vec<String> = result.partial_implementations.iter()
+            .flat_map(|p| p.missing_parts.clone())
+            .collect();
+        unguarded.sort();
+
+        assert_eq!(unguarded, vec!["GET /reports".to_string(), "GET /roles".to_string()]);
+    }
+
+    #[test]
+    fn test_no_auth_produces_no_findings() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/server.js", "app.get('/items', listItems);\n");
+
+        let result = analyze(&AuthAnalyzer::new(AuthAnalysisConfig::default(), 0.2), temp_dir.path());
+        assert!(result.missing_implementations.is_empty());
+        assert!(result.partial_implementations.is_empty());
+        assert!(result.analysis_notes.iter().any(|note| note.contains("No authentication")));
+    }
+}
@@ -0,0 +1,35 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static TEST_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)((^|/)(__tests__|tests?|specs?|e2e)/|[._](test|spec)\.\w+$|(^|/)test_\w+\.py$|(^|/)conftest\.py$|(?-i:Tests?)\.(java|kt)$)").unwrap()
+});
+
+/// Whether a project-relative, `/`-separated path is a test file or lives in a test directory
+pub(crate) fn is_test_path(relative: &str) -> bool {
+    TEST_PATH.is_match(relative)
+}
+
+/// 1-based line number of a byte offset within `content`
+pub(crate) fn line_of(content: &str, byte_offset: usize) -> usize {
+    content[..byte_offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_paths_across_languages() {
+        for path in [
+            "src/__tests__/user.ts", "tests/api.rs", "spec/models/user_spec.rb", "web/e2e/login.ts",
+            "src/user.test.tsx", "src/user.spec.js", "pkg/user_test.go", "app/user_test.py",
+            "app/test_user.py", "conftest.py", "src/main/java/UserServiceTest.java", "UserTests.kt",
+        ] {
+            assert!(is_test_path(path), "{} should be a test path", path);
+        }
+        for path in ["src/latest.java", "src/contest.py", "src/testing/helpers.ts", "src/user.ts", "attest/main.go"] {
+            assert!(!is_test_path(path), "{} should not be a test path", path);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::core::project_walker::ProjectWalker;
 use crate::core::documentation_extractor::{DocumentationExtractor, ExtractedDocumentationInfo};
 
 /// Cross-repository project relationship detection and analysis
@@ -596,28 +597,14 @@ impl CrossRepositoryAnalyzer {
 
     /// Count documentation files in a directory
     fn count_documentation_files(&self, path: &Path) -> Result<usize> {
-        let mut count = 0;
-        
-        if path.is_dir() {
-            for entry in std::fs::read_dir(path)? {
-                let entry = entry?;
-                let path = entry.path();
-                
-                if path.is_file() {
-                    if let Some(extension) = path.extension() {
-                        if extension == "md" || extension == "rst" || extension == "txt" {
-                            count += 1;
-                        }
-                    }
-                } else if path.is_dir() {
-                    count += self.count_documentation_files(&path)?;
-                }
-            }
-        } else if path.is_file() {
-            count = 1;
+        if path.is_file() {
+            return Ok(1);
         }
-        
-        Ok(count)
+
+        Ok(ProjectWalker::new(path)
+            .files()
+            .filter(|file| file.extension().is_some_and(|extension| extension == "md" || extension == "rst" || extension == "txt"))
+            .count())
     }
 
     /// Convert string to camelCase
@@ -9,6 +9,7 @@ use crate::core::status_inference_engine::{
     StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, CrudAnalysisConfig,
     MissingImplementation, PartialImplementation
 };
+use crate::core::analyzers::file_index::{IndexedFile, ProjectFileIndex};
 use crate::core::documentation_extractor::ExtractedDocumentationInfo;
 use crate::core::todo_scanner::TodoScanResult;
 
@@ -185,87 +186,38 @@ impl CrudAnalyzer {
     }
 
     /// Analyze project for CRUD operation completeness
-    fn analyze_crud_completeness(&self, project_path: &Path) -> Result<Vec<EntityDefinition>> {
+    fn analyze_crud_completeness(&self, index: &ProjectFileIndex) -> Result<Vec<EntityDefinition>> {
         let mut entities = Vec::new();
 
         // Find entity definitions
-        let entity_files = self.find_entity_files(project_path)?;
-        
-        for file_path in entity_files {
-            if let Ok(content) = fs::read_to_string(&file_path) {
-                let mut file_entities = self.extract_entities_from_file(&file_path, &content)?;
-                entities.extend(file_entities.drain(..));
+        for file in self.find_entity_files(index) {
+            if let Ok(content) = fs::read_to_string(&file.path) {
+                entities.extend(self.extract_entities_from_file(&file.path, &content)?);
             }
         }
 
         // Analyze CRUD operations for each entity
+        let source_files: Vec<&IndexedFile> = self.find_source_files(index).collect();
         for entity in &mut entities {
-            self.analyze_entity_crud_operations(project_path, entity)?;
+            self.analyze_entity_crud_operations(&source_files, entity)?;
         }
 
         Ok(entities)
     }
 
     /// Find files that likely contain entity definitions
-    fn find_entity_files(&self, project_path: &Path) -> Result<Vec<std::path::PathBuf>> {
-        let mut entity_files = Vec::new();
-
-        // Common patterns for entity/model files
-        let patterns = vec![
-            "**/models/**/*.{ts,js,java,py,rs}",
-            "**/entities/**/*.{ts,js,java,py,rs}",
-            "**/schema/**/*.{ts,js,java,py,rs}",
-            "**/domain/**/*.{ts,js,java,py,rs}",
-            "**/*Model.{ts,js,java,py}",
-            "**/*Entity.{ts,js,java,py}",
-            "**/*Schema.{ts,js,java,py}",
-        ];
-
-        // Simple recursive directory traversal (would use glob in production)
-        self.traverse_directory(project_path, &mut entity_files, &patterns)?;
-
-        Ok(entity_files)
-    }
-
-    /// Recursively traverse directory looking for entity files
-    fn traverse_directory(
-        &self, 
-        dir: &Path, 
-        files: &mut Vec<std::path::PathBuf>,
-        _patterns: &[&str]
-    ) -> Result<()> {
-        if let Ok(entries) = fs::read_dir(dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_dir() {
-                    // Skip common ignore patterns
-                    if let Some(name) = path.file_name() {
-                        if name == "node_modules" || name == "target" || name == ".git" {
-                            continue;
-                        }
-                    }
-                    self.traverse_directory(&path, files, _patterns)?;
-                } else if path.is_file() {
-                    // Check file extensions
-                    if let Some(extension) = path.extension() {
-                        if matches!(extension.to_str(), Some("ts") | Some("js") | Some("java") | Some("py") | Some("rs")) {
-                            // Check if filename suggests entity/model
-                            if let Some(filename) = path.file_name() {
-                                let filename_str = filename.to_string_lossy().to_lowercase();
-                                if filename_str.contains("model") || 
-                                   filename_str.contains("entity") || 
-                                   filename_str.contains("schema") ||
-                                   path.to_string_lossy().contains("/models/") ||
-                                   path.to_string_lossy().contains("/entities/") {
-                                    files.push(path);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+    fn find_entity_files<'a>(&self, index: &'a ProjectFileIndex) -> impl Iterator<Item = &'a IndexedFile> + 'a {
+        self.find_source_files(index).filter(|file| {
+            // Check if filename suggests entity/model
+            let filename = file.path.file_name()
+                .map(|name| name.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            filename.contains("model") ||
+                filename.contains("entity") ||
+                filename.contains("schema") ||
+                file.path.to_string_lossy().contains("/models/") ||
+                file.path.to_string_lossy().contains("/entities/")
+        })
     }
 
     /// Extract entity definitions from a file
@@ -331,18 +283,16 @@ impl CrudAnalyzer {
     /// Analyze CRUD operations for a specific entity
     fn analyze_entity_crud_operations(
         &self,
-        project_path: &Path,
+        source_files: &[&IndexedFile],
         entity: &mut EntityDefinition,
     ) -> Result<()> {
         // Scan project files for CRUD operations related to this entity
-        let mut source_files = Vec::new();
-        self.find_source_files(project_path, &mut source_files)?;
-
         for operation in &self.config.required_operations {
             let mut operation_status = CrudOperationStatus::default();
             
             if let Some(patterns) = self.crud_patterns.get(operation) {
-                for file_path in &source_files {
+                for file in source_files {
+                    let file_path = &file.path;
                     if let Ok(content) = fs::read_to_string(file_path) {
                         for pattern in patterns {
                             if let Some(matches) = pattern.find(&content) {
@@ -415,28 +365,8 @@ impl CrudAnalyzer {
     }
 
     /// Find source code files to analyze
-    fn find_source_files(&self, project_path: &Path, files: &mut Vec<std::path::PathBuf>) -> Result<()> {
-        if let Ok(entries) = fs::read_dir(project_path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_dir() {
-                    // Skip common ignore patterns
-                    if let Some(name) = path.file_name() {
-                        if name == "node_modules" || name == "target" || name == ".git" {
-                            continue;
-                        }
-                    }
-                    self.find_source_files(&path, files)?;
-                } else if path.is_file() {
-                    if let Some(extension) = path.extension() {
-                        if matches!(extension.to_str(), Some("ts") | Some("js") | Some("java") | Some("py") | Some("rs")) {
-                            files.push(path);
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+    fn find_source_files<'a>(&self, index: &'a ProjectFileIndex) -> impl Iterator<Item = &'a IndexedFile> + 'a {
+        index.with_extensions(&["ts", "js", "java", "py", "rs"])
     }
 
     /// Generate analysis results from entity definitions
@@ -531,6 +461,7 @@ impl CrudAnalyzer {
                 format!("Used {} CRUD operation patterns", self.crud_patterns.values().map(|v| v.len()).sum::<usize>()),
                 "CRUD analysis focuses on Create, Read, Update, Delete operation completeness".to_string(),
             ],
+            ..Default::default()
         }
     }
 }
@@ -556,29 +487,43 @@ impl StatusAnalyzer for CrudAnalyzer {
     }
     
     fn can_analyze(&self, project_path: &Path) -> bool {
+        self.can_analyze_indexed(&ProjectFileIndex::build(project_path))
+    }
+
+    fn can_analyze_indexed(&self, index: &ProjectFileIndex) -> bool {
         // Check if project has entity/model files
-        let model_patterns = vec![
-            project_path.join("models"),
-            project_path.join("entities"),
-            project_path.join("src/models"),
-            project_path.join("src/entities"),
+        let model_patterns = [
+            index.root().join("models"),
+            index.root().join("entities"),
+            index.root().join("src/models"),
+            index.root().join("src/entities"),
         ];
 
         model_patterns.iter().any(|path| path.exists()) ||
         // Or has files that suggest entities
-        self.find_entity_files(project_path).unwrap_or_default().len() > 0
+        self.find_entity_files(index).next().is_some()
     }
     
     fn analyze(
         &self,
         project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
         _documentation: &ExtractedDocumentationInfo,
         _todo_results: &TodoScanResult,
         _config: &StatusInferenceConfig,
     ) -> Result<StatusAnalysisResult> {
-        println!("Analyzing CRUD operations for project: {}", project_path.display());
+        println!("Analyzing CRUD operations for project: {}", index.root().display());
         
-        let entities = self.analyze_crud_completeness(project_path)
+        let entities = self.analyze_crud_completeness(index)
             .context("Failed to analyze CRUD completeness")?;
         
         let result = self.generate_analysis_results(entities);
@@ -0,0 +1,1233 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, DatabaseAnalysisConfig,
+    IncompleteFeature, InferencePriority, MissingImplementation, PartialImplementation
+};
+use crate::core::analyzers::common::line_of;
+use crate::core::analyzers::file_index::{IndexedFile, ProjectFileIndex};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Maximum number of evidence lines attached to a single finding
+const MAX_EVIDENCE_PER_FINDING: usize = 5;
+
+/// Tables maintained by migration tools and frameworks rather than application models
+const FRAMEWORK_TABLE_PREFIXES: &[&str] = &[
+    "alembic_version", "flyway_schema_history", "schema_version", "databasechangelog",
+    "_prisma_migrations", "django_", "auth_", "schema_migrations", "ar_internal_metadata",
+    "typeorm_metadata", "knex_migrations", "sequelizemeta", "migrations",
+];
+
+/// ORM a model definition was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SchemaSource {
+    SqlAlchemy,
+    Django,
+    Jpa,
+    TypeOrm,
+    Prisma,
+    Mongoose,
+}
+
+impl SchemaSource {
+    fn label(&self) -> &'static str {
+        match self {
+            SchemaSource::SqlAlchemy => "SQLAlchemy",
+            SchemaSource::Django => "Django",
+            SchemaSource::Jpa => "JPA",
+            SchemaSource::TypeOrm => "TypeORM",
+            SchemaSource::Prisma => "Prisma",
+            SchemaSource::Mongoose => "Mongoose",
+        }
+    }
+
+    /// Schemaless document stores have no migrations to compare against
+    fn uses_migrations(&self) -> bool {
+        !matches!(self, SchemaSource::Mongoose)
+    }
+
+    fn default_migration_location(&self) -> &'static str {
+        match self {
+            SchemaSource::SqlAlchemy => "alembic/versions",
+            SchemaSource::Django => "<app>/migrations",
+            SchemaSource::Jpa => "src/main/resources/db/migration",
+            SchemaSource::TypeOrm => "src/migrations",
+            SchemaSource::Prisma => "prisma/migrations",
+            SchemaSource::Mongoose => "",
+        }
+    }
+
+    fn index_suggestion(&self, column: &str) -> String {
+        match self {
+            SchemaSource::SqlAlchemy => format!("Declare `{}` with index=True or add an Index() in __table_args__", column),
+            SchemaSource::Jpa => format!("Add @Index(columnList = \"{}\") to the entity's @Table(indexes = ...)", column),
+            SchemaSource::TypeOrm => format!("Add @Index() to the `{}` column or @Index([\"{}\"]) on the entity", column, column),
+            SchemaSource::Prisma => format!("Add @@index([{}]) to the model", column),
+            SchemaSource::Django | SchemaSource::Mongoose => format!("Add an index on `{}`", column),
+        }
+    }
+}
+
+/// Relationship from one model to another
+#[derive(Debug, Clone)]
+struct Relation {
+    field: String,
+    /// Target model name or table name, as written in the source
+    target: String,
+    /// Column holding the foreign key on this model's table, if this side owns it
+    foreign_key: Option<String>,
+    indexed: bool,
+    /// Field on the target model that maps this relation back (`mappedBy`, `back_populates`)
+    inverse: Option<String>,
+    line: usize,
+}
+
+#[derive(Debug, Clone)]
+struct ModelDefinition {
+    name: String,
+    table: Option<String>,
+    orm: SchemaSource,
+    file: String,
+    line: usize,
+    fields: BTreeSet<String>,
+    relations: Vec<Relation>,
+    indexed_columns: BTreeSet<String>,
+}
+
+impl ModelDefinition {
+    fn new(name: &str, orm: SchemaSource, file: &str, line: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            table: None,
+            orm,
+            file: file.to_string(),
+            line,
+            fields: BTreeSet::new(),
+            relations: Vec::new(),
+            indexed_columns: BTreeSet::new(),
+        }
+    }
+
+    fn location(&self) -> String {
+        format!("{}:{}", self.file, self.line)
+    }
+
+    /// Normalized names the model's table may appear under in migrations
+    fn table_candidates(&self) -> BTreeSet<String> {
+        let mut candidates = BTreeSet::new();
+        for name in self.table.iter().chain(std::iter::once(&self.name)) {
+            let normalized = normalize_identifier(name);
+            candidates.insert(format!("{}s", normalized));
+            candidates.insert(format!("{}es", normalized));
+            if let Some(stem) = normalized.strip_suffix('y') {
+                candidates.insert(format!("{}ies", stem));
+            }
+            candidates.insert(normalized);
+        }
+        candidates
+    }
+
+    fn has_field(&self, field: &str) -> bool {
+        self.fields.contains(&normalize_identifier(field))
+    }
+}
+
+/// Table touched by a migration
+#[derive(Debug, Clone)]
+struct MigrationTable {
+    table: String,
+    tool: &'static str,
+    file: String,
+    line: usize,
+}
+
+#[derive(Debug, Default)]
+struct MigrationInventory {
+    created: Vec<MigrationTable>,
+    dropped: BTreeSet<String>,
+    /// (normalized table, normalized column)
+    indexes: BTreeSet<(String, String)>,
+    tools: BTreeSet<&'static str>,
+    files: Vec<String>,
+}
+
+impl MigrationInventory {
+    fn creates_table_for(&self, model: &ModelDefinition) -> bool {
+        let candidates = model.table_candidates();
+        self.created.iter().any(|created| candidates.contains(&normalize_identifier(&created.table)))
+    }
+
+    fn has_index(&self, model: &ModelDefinition, column: &str) -> bool {
+        let column = normalize_identifier(column);
+        let candidates = model.table_candidates();
+        self.indexes.iter().any(|(table, indexed)| *indexed == column && candidates.contains(table))
+    }
+}
+
+// SQL DDL (Flyway, Prisma migrations, TypeORM/Knex raw queries, Liquibase SQL changelogs)
+static SQL_CREATE_TABLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\bcreate\s+table\s+(?:if\s+not\s+exists\s+)?([\w."`\[\]]+)"#).unwrap()
+});
+static SQL_DROP_TABLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\bdrop\s+table\s+(?:if\s+exists\s+)?([\w."`\[\]]+)"#).unwrap()
+});
+static SQL_CREATE_INDEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\bcreate\s+(?:unique\s+)?index\s+(?:concurrently\s+)?(?:if\s+not\s+exists\s+)?[\w."`\[\]]*\s*on\s+([\w."`\[\]]+)\s*(?:using\s+\w+\s*)?\(([^)]*)\)"#).unwrap()
+});
+static SQL_INLINE_INDEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)^\s*(?:unique\s+)?(?:key|index)\s+[\w"`]*\s*\(([^)]*)\)"#).unwrap()
+});
+
+// Alembic
+static ALEMBIC_CREATE_TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"op\.create_table\(\s*['"](\w+)"#).unwrap());
+static ALEMBIC_DROP_TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"op\.drop_table\(\s*['"](\w+)"#).unwrap());
+static ALEMBIC_CREATE_INDEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"op\.create_index\(\s*[^,]+,\s*['"](\w+)['"]\s*,\s*\[([^\]]*)\]"#).unwrap()
+});
+static ALEMBIC_INDEXED_COLUMN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"sa\.Column\(\s*['"](\w+)['"].*(?:index\s*=\s*True|primary_key\s*=\s*True|unique\s*=\s*True)"#).unwrap()
+});
+
+// Django migrations
+static DJANGO_CREATE_MODEL: Lazy<Regex> = Lazy::new(|| Regex::new(r#"CreateModel\(\s*name\s*=\s*['"](\w+)"#).unwrap());
+static DJANGO_DELETE_MODEL: Lazy<Regex> = Lazy::new(|| Regex::new(r#"DeleteModel\(\s*name\s*=\s*['"](\w+)"#).unwrap());
+
+// Liquibase XML/YAML changelogs
+static LIQUIBASE_CHANGE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(createTable|dropTable|createIndex)\b").unwrap());
+static LIQUIBASE_TABLE_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r#"tableName\s*[=:]\s*['"]?(\w+)"#).unwrap());
+static LIQUIBASE_COLUMN_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?:^|[\s<-])(?:column\s+)?name\s*[=:]\s*['"]?(\w+)"#).unwrap());
+
+// TypeORM / Knex migration builders
+static JS_CREATE_TABLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:createTable\(\s*new\s+Table\(\s*\{\s*name:\s*|createTable\(\s*)['"](\w+)"#).unwrap()
+});
+static JS_DROP_TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"dropTable(?:IfExists)?\(\s*['"](\w+)"#).unwrap());
+
+// Model definitions
+static PYTHON_CLASS: Lazy<Regex> = Lazy::new(|| Regex::new(r"^class\s+(\w+)\s*(?:\(([^)]*)\))?\s*:").unwrap());
+static SQLALCHEMY_TABLENAME: Lazy<Regex> = Lazy::new(|| Regex::new(r#"__tablename__\s*=\s*['"](\w+)"#).unwrap());
+static SQLALCHEMY_COLUMN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s+(\w+)\s*(?::[^=]+)?=\s*(?:db\.|sa\.|sqlalchemy\.)?(?:Column|mapped_column)\(").unwrap()
+});
+static SQLALCHEMY_FOREIGN_KEY: Lazy<Regex> = Lazy::new(|| Regex::new(r#"ForeignKey\(\s*['"]([\w.]+)['"]"#).unwrap());
+static SQLALCHEMY_RELATIONSHIP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"^\s+(\w+)\s*(?::\s*([^=]+))?=\s*(?:db\.|sa\.|orm\.)?relationship\(\s*(?:['"](\w+)['"])?"#).unwrap()
+});
+static SQLALCHEMY_MAPPED_TARGET: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"Mapped\[\s*(?:(?:List|list|Set|set|Optional)\[\s*)?['"]?(\w+)"#).unwrap()
+});
+static SQLALCHEMY_TABLE_INDEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"Index\(\s*['"][^'"]*['"]\s*,\s*['"]?(\w+)"#).unwrap());
+static BACK_POPULATES: Lazy<Regex> = Lazy::new(|| Regex::new(r#"back_populates\s*=\s*['"](\w+)"#).unwrap());
+static DJANGO_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s+(\w+)\s*=\s*models\.(\w+)\(([^)]*)").unwrap());
+static DJANGO_DB_TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"db_table\s*=\s*['"](\w+)"#).unwrap());
+
+static JAVA_CLASS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bclass\s+(\w+)").unwrap());
+static JPA_TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"@Table\s*\(\s*(?:.*?\bname\s*=\s*)"(\w+)""#).unwrap());
+static JPA_INDEX_COLUMNS: Lazy<Regex> = Lazy::new(|| Regex::new(r#"columnList\s*=\s*"([^"]+)""#).unwrap());
+static JPA_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?:(?:private|protected|public|final|transient)\s+)+([\w.<>, ?]+?)\s+(\w+)\s*(?:=[^;]*)?;").unwrap()
+});
+static KOTLIN_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(?:(?:private|protected|public|lateinit|open)\s+)*va[lr]\s+(\w+)\s*:\s*([\w.<>, ?]+)").unwrap());
+static RELATION_ANNOTATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"@(ManyToOne|OneToOne|OneToMany|ManyToMany)\b").unwrap());
+static MAPPED_BY: Lazy<Regex> = Lazy::new(|| Regex::new(r#"mappedBy\s*=\s*"(\w+)""#).unwrap());
+static JOIN_COLUMN: Lazy<Regex> = Lazy::new(|| Regex::new(r#"@JoinColumn\s*\(\s*(?:\{\s*)?name\s*[=:]\s*['"](\w+)"#).unwrap());
+static GENERIC_ELEMENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"<\s*(\w+)\s*>").unwrap());
+
+static TYPEORM_ENTITY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"@Entity\(\s*(?:['"](\w+)['"]|\{\s*name:\s*['"](\w+)['"])?"#).unwrap()
+});
+static TYPEORM_RELATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"@(ManyToOne|OneToOne|OneToMany|ManyToMany)\(\s*\(\)\s*=>\s*(\w+)(?:\s*,\s*\(?\s*\w*\s*\)?\s*=>\s*\w+\.(\w+))?").unwrap()
+});
+static TYPEORM_PROPERTY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(?:(?:public|private|protected|readonly)\s+)*(\w+)[!?]?\s*:\s*([\w<>\[\]]+)").unwrap());
+static TYPEORM_CLASS_INDEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"@Index\(\s*(?:['\x22]\w+['\x22]\s*,\s*)?\[([^\]]*)\]").unwrap());
+
+static PRISMA_MODEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*model\s+(\w+)\s*\{").unwrap());
+static PRISMA_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(\w+)\s+(\w+)(\[\])?\??(.*)$").unwrap());
+static PRISMA_RELATION_FIELDS: Lazy<Regex> = Lazy::new(|| Regex::new(r"@relation\([^)]*fields:\s*\[\s*(\w+)").unwrap());
+static PRISMA_BLOCK_INDEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"@@(?:index|unique|id)\(\s*(?:fields:\s*)?\[([^\]]*)\]").unwrap());
+static PRISMA_MAP: Lazy<Regex> = Lazy::new(|| Regex::new(r#"@@map\(\s*(?:name:\s*)?"(\w+)""#).unwrap());
+
+static MONGOOSE_MODEL: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?:mongoose\.)?model\(\s*['"](\w+)['"]\s*,"#).unwrap());
+static MONGOOSE_REF: Lazy<Regex> = Lazy::new(|| Regex::new(r#"ref:\s*['"](\w+)['"]"#).unwrap());
+static MONGOOSE_KEY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(\w+)\s*:\s*[\{\[]").unwrap());
+
+/// Database schema and migration completeness analyzer
+/// Compares ORM models (SQLAlchemy, Django, JPA, TypeORM, Prisma, Mongoose) with migrations
+/// (Alembic, Flyway, Liquibase, Prisma, Django, TypeORM/Knex) and checks relationships and
+/// foreign key indexes
+pub struct DatabaseAnalyzer {
+    config: DatabaseAnalysisConfig,
+    weight: f32,
+}
+
+impl DatabaseAnalyzer {
+    pub fn new(config: DatabaseAnalysisConfig, weight: f32) -> Self {
+        Self { config, weight }
+    }
+
+    /// Indexed files that can hold schema or migration definitions
+    fn collect_files<'a>(&self, index: &'a ProjectFileIndex) -> impl Iterator<Item = &'a IndexedFile> + 'a {
+        index.with_extensions(&["py", "java", "kt", "ts", "js", "mjs", "cjs", "prisma", "sql", "xml", "yaml", "yml"])
+    }
+
+    /// Scan the project for model definitions and migrations
+    fn build_inventory(&self, index: &ProjectFileIndex) -> (Vec<ModelDefinition>, MigrationInventory) {
+        let mut models = Vec::new();
+        let mut migrations = MigrationInventory::default();
+
+        for file in self.collect_files(index) {
+            let Ok(content) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+            let relative = file.relative.clone();
+            let extension = file.extension.as_str();
+
+            if let Some(tool) = migration_tool(&relative, extension, &content) {
+                let before = migrations.created.len() + migrations.dropped.len() + migrations.indexes.len();
+                parse_migration(tool, &relative, &content, &mut migrations);
+                if migrations.created.len() + migrations.dropped.len() + migrations.indexes.len() > before {
+                    migrations.tools.insert(tool);
+                    migrations.files.push(relative);
+                }
+                continue;
+            }
+
+            match extension {
+                "py" => models.extend(parse_python_models(&relative, &content)),
+                "java" | "kt" if content.contains("@Entity") => models.extend(parse_jpa_entities(&relative, &content)),
+                "ts" | "js" | "mjs" | "cjs" => {
+                    if content.contains("@Entity(") {
+                        models.extend(parse_typeorm_entities(&relative, &content));
+                    }
+                    if content.contains("mongoose") {
+                        models.extend(parse_mongoose_models(&relative, &content));
+                    }
+                }
+                "prisma" => models.extend(parse_prisma_models(&relative, &content)),
+                _ => {}
+            }
+        }
+
+        (models, migrations)
+    }
+
+    /// Turn models and migrations into status findings
+    fn generate_analysis_results(&self, models: Vec<ModelDefinition>, migrations: MigrationInventory) -> StatusAnalysisResult {
+        let mut missing_implementations = Vec::new();
+        let mut partial_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+        let mut models_with_gaps: BTreeSet<String> = BTreeSet::new();
+
+        let orms: BTreeSet<&str> = models.iter().map(|model| model.orm.label()).collect();
+        analysis_notes.push(format!(
+            "Found {} models ({}) and {} migration files ({})",
+            models.len(),
+            orms.into_iter().collect::<Vec<_>>().join(", "),
+            migrations.files.len(),
+            migrations.tools.iter().copied().collect::<Vec<_>>().join(", "),
+        ));
+
+        if models.is_empty() && migrations.created.is_empty() {
+            analysis_notes.push("No ORM models or migrations detected".to_string());
+            return StatusAnalysisResult {
+                analyzer_name: self.name().to_string(),
+                missing_implementations,
+                partial_implementations,
+                confidence: 0.3,
+                evidence,
+                analysis_notes,
+                ..Default::default()
+            };
+        }
+
+        for model in &models {
+            evidence.push(format!("{} model {} at {}{}", model.orm.label(), model.name, model.location(),
+                                  model.table.as_ref().map(|table| format!(" (table {})", table)).unwrap_or_default()));
+        }
+
+        let migrated_models: Vec<&ModelDefinition> = models.iter().filter(|model| model.orm.uses_migrations()).collect();
+
+        // Models without migrations / migrations without models
+        if self.config.detect_incomplete_migrations {
+            if migrations.tools.is_empty() {
+                if let Some(first) = migrated_models.first() {
+                    missing_implementations.push(MissingImplementation {
+                        implementation_type: "Database Migrations".to_string(),
+                        expected_location: first.orm.default_migration_location().to_string(),
+                        description: format!("{} models are defined but the project has no schema migrations", migrated_models.len()),
+                        confidence: 0.6,
+                        evidence: migrated_models.iter()
+                            .take(MAX_EVIDENCE_PER_FINDING)
+                            .map(|model| format!("{} model {} at {}", model.orm.label(), model.name, model.location()))
+                            .collect(),
+                        suggested_implementation: Some(format!(
+                            "Generate an initial migration for the {} models instead of relying on automatic schema creation",
+                            first.orm.label()
+                        )),
+                    });
+                    models_with_gaps.extend(migrated_models.iter().map(|model| model.name.clone()));
+                }
+            } else {
+                let migration_dir = migrations.files.first().map(|file| parent_dir(file)).unwrap_or_default();
+                for model in &migrated_models {
+                    if migrations.creates_table_for(model) {
+                        continue;
+                    }
+                    models_with_gaps.insert(model.name.clone());
+                    missing_implementations.push(MissingImplementation {
+                        implementation_type: "Database Migration".to_string(),
+                        expected_location: migration_dir.clone(),
+                        description: format!("Model {} has no migration creating its table", model.name),
+                        confidence: 0.75,
+                        evidence: vec![format!("{} model {} defined at {}", model.orm.label(), model.name, model.location())],
+                        suggested_implementation: Some(format!("Add a migration that creates the table for {}", model.name)),
+                    });
+                }
+            }
+
+            if !models.is_empty() {
+                let known: BTreeSet<String> = models.iter().flat_map(|model| model.table_candidates()).collect();
+                let mut reported = BTreeSet::new();
+                for created in &migrations.created {
+                    let table = normalize_identifier(&created.table);
+                    if known.contains(&table)
+                        || migrations.dropped.contains(&table)
+                        || is_framework_table(&created.table)
+                        || is_join_table(&created.table, &known)
+                        || !reported.insert(table)
+                    {
+                        continue;
+                    }
+                    missing_implementations.push(MissingImplementation {
+                        implementation_type: "Database Model".to_string(),
+                        expected_location: format!("{}:{}", created.file, created.line),
+                        description: format!("Table {} is created by a {} migration but no model defines it", created.table, created.tool),
+                        confidence: 0.6,
+                        evidence: vec![format!("{}:{}: creates table {}", created.file, created.line, created.table)],
+                        suggested_implementation: Some(format!("Add a model for {} or drop the table in a new migration", created.table)),
+                    });
+                }
+            }
+        }
+
+        // Relationships pointing at models that do not exist or are only mapped one way
+        if self.config.detect_missing_relations {
+            for model in &models {
+                for relation in &model.relations {
+                    let Some(target) = resolve_model(&models, &relation.target, model) else {
+                        models_with_gaps.insert(model.name.clone());
+                        missing_implementations.push(MissingImplementation {
+                            implementation_type: "Database Relation".to_string(),
+                            expected_location: format!("{}:{}", model.file, relation.line),
+                            description: format!("{}.{} references {}, which no model defines", model.name, relation.field, relation.target),
+                            confidence: 0.7,
+                            evidence: vec![format!("{}:{}: {}.{} -> {}", model.file, relation.line, model.name, relation.field, relation.target)],
+                            suggested_implementation: Some(format!("Define the {} model or remove the orphaned relationship", relation.target)),
+                        });
+                        continue;
+                    };
+
+                    let Some(inverse) = &relation.inverse else {
+                        continue;
+                    };
+                    if target.has_field(inverse) {
+                        continue;
+                    }
+                    models_with_gaps.insert(model.name.clone());
+                    partial_implementations.push(PartialImplementation {
+                        implementation_name: format!("{}.{} <-> {}.{} relationship", model.name, relation.field, target.name, inverse),
+                        implemented_parts: vec![format!("{}.{}", model.name, relation.field)],
+                        missing_parts: vec![format!("{}.{}", target.name, inverse)],
+                        completeness_percentage: 50.0,
+                        category: "Database".to_string(),
+                        evidence: vec![format!("{}:{}: {}.{} is mapped by {}.{}, but {} has no {} field",
+                                               model.file, relation.line, model.name, relation.field,
+                                               target.name, inverse, target.name, inverse)],
+                        next_steps: vec![format!("Add the {} field to {} or fix the inverse mapping on {}.{}",
+                                                 inverse, target.name, model.name, relation.field)],
+                    });
+                }
+            }
+        }
+
+        // Foreign keys without an index
+        if self.config.detect_missing_indexes && self.config.analyze_foreign_keys {
+            for model in &models {
+                for relation in &model.relations {
+                    let Some(column) = &relation.foreign_key else {
+                        continue;
+                    };
+                    if relation.indexed
+                        || model.indexed_columns.contains(&normalize_identifier(column))
+                        || migrations.has_index(model, column)
+                    {
+                        continue;
+                    }
+                    models_with_gaps.insert(model.name.clone());
+                    missing_implementations.push(MissingImplementation {
+                        implementation_type: "Database Index".to_string(),
+                        expected_location: format!("{}:{}", model.file, relation.line),
+                        description: format!("Foreign key {}.{} has no index", model.name, column),
+                        confidence: 0.65,
+                        evidence: vec![format!("{}:{}: {}.{} references {} without an index",
+                                               model.file, relation.line, model.name, column, relation.target)],
+                        suggested_implementation: Some(model.orm.index_suggestion(column)),
+                    });
+                }
+            }
+        }
+
+        // Roll data-layer gaps up into a single feature so the status report shows them together
+        let mut incomplete_features = Vec::new();
+        if !models_with_gaps.is_empty() {
+            let mut missing_components: Vec<String> = missing_implementations.iter()
+                .map(|missing| missing.implementation_type.clone())
+                .chain(partial_implementations.iter().map(|_| "Database Relation Mapping".to_string()))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            missing_components.sort();
+
+            let structural_gap = missing_implementations.iter()
+                .any(|missing| missing.implementation_type.starts_with("Database Migration") || missing.implementation_type == "Database Model");
+            let total = models.len().max(1);
+
+            incomplete_features.push(IncompleteFeature {
+                feature_name: "Data layer (schema, migrations and relationships)".to_string(),
+                feature_category: "Database".to_string(),
+                completeness_score: 1.0 - models_with_gaps.len().min(total) as f32 / total as f32,
+                missing_components,
+                evidence: models_with_gaps.iter().map(|name| format!("Model {} has data-layer gaps", name)).collect(),
+                recommendations: vec![
+                    "Keep models and migrations in sync; generate migrations for every model change".to_string(),
+                    "Index foreign key columns used in joins and lookups".to_string(),
+                ],
+                priority: if structural_gap { InferencePriority::High } else { InferencePriority::Medium },
+            });
+        }
+
+        analysis_notes.push(format!("{} of {} models have data-layer gaps", models_with_gaps.len(), models.len()));
+
+        let confidence = (0.6_f32
+            + if migrations.tools.is_empty() { 0.0 } else { 0.15 }
+            + if models.is_empty() { 0.0 } else { 0.1 }).min(0.85);
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            incomplete_features,
+            missing_implementations,
+            partial_implementations,
+            confidence,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatusAnalyzer for DatabaseAnalyzer {
+    fn name(&self) -> &str {
+        "Database Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Analyzing database schema and migrations for project: {}", index.root().display());
+
+        let (models, migrations) = self.build_inventory(index);
+        let result = self.generate_analysis_results(models, migrations);
+
+        println!("Database analysis completed: {} items found, {:.1}% confidence",
+                 result.missing_implementations.len() + result.partial_implementations.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Which migration tool (if any) a file belongs to
+fn migration_tool(relative: &str, extension: &str, content: &str) -> Option<&'static str> {
+    let path = relative.to_lowercase();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let in_migrations_dir = path.contains("/migrations/") || path.starts_with("migrations/")
+        || path.contains("/migration/") || path.starts_with("migration/");
+
+    match extension {
+        "py" if (path.contains("/versions/") || path.starts_with("versions/")) && content.contains("op.") => Some("Alembic"),
+        "py" if in_migrations_dir && content.contains("migrations.Migration") => Some("Django"),
+        "sql" if path.contains("prisma/migrations/") => Some("Prisma"),
+        "sql" if path.contains("db/migration") || (file_name.starts_with('v') && file_name.contains("__")) => Some("Flyway"),
+        "sql" if path.contains("changelog") => Some("Liquibase"),
+        "sql" if in_migrations_dir => Some("SQL"),
+        "xml" | "yaml" | "yml" if path.contains("changelog") && content.contains("databaseChangeLog") => Some("Liquibase"),
+        "ts" | "js" | "mjs" | "cjs" if in_migrations_dir => Some("TypeORM/Knex"),
+        _ => None,
+    }
+}
+
+fn parse_migration(tool: &'static str, file: &str, content: &str, migrations: &mut MigrationInventory) {
+    let record_created = |table: &str, line: usize, migrations: &mut MigrationInventory| {
+        migrations.created.push(MigrationTable {
+            table: strip_quotes(table),
+            tool,
+            file: file.to_string(),
+            line,
+        });
+    };
+
+    match tool {
+        "Django" => {
+            for captures in DJANGO_CREATE_MODEL.captures_iter(content) {
+                let line = line_of(content, captures.get(0).map_or(0, |m| m.start()));
+                record_created(&captures[1], line, migrations);
+            }
+            for captures in DJANGO_DELETE_MODEL.captures_iter(content) {
+                migrations.dropped.insert(normalize_identifier(&captures[1]));
+            }
+        }
+        "Liquibase" if !file.ends_with(".sql") => {
+            let mut change = "";
+            let mut table = String::new();
+            for (index, line) in content.lines().enumerate() {
+                if let Some(captures) = LIQUIBASE_CHANGE.captures(line) {
+                    change = captures.get(1).map_or("", |m| m.as_str());
+                    table.clear();
+                }
+                if let Some(captures) = LIQUIBASE_TABLE_NAME.captures(line) {
+                    table = captures[1].to_string();
+                    match change {
+                        "createTable" => record_created(&table, index + 1, migrations),
+                        "dropTable" => { migrations.dropped.insert(normalize_identifier(&table)); }
+                        _ => {}
+                    }
+                    continue;
+                }
+                if change == "createIndex" && !table.is_empty()
+                    && let Some(captures) = LIQUIBASE_COLUMN_NAME.captures(line) {
+                    migrations.indexes.insert((normalize_identifier(&table), normalize_identifier(&captures[1])));
+                }
+            }
+        }
+        _ => {
+            let mut current_table: Option<String> = None;
+            for (index, line) in content.lines().enumerate() {
+                let created = SQL_CREATE_TABLE.captures(line)
+                    .or_else(|| ALEMBIC_CREATE_TABLE.captures(line))
+                    .or_else(|| JS_CREATE_TABLE.captures(line));
+                if let Some(captures) = created {
+                    record_created(&captures[1], index + 1, migrations);
+                    current_table = Some(strip_quotes(&captures[1]));
+                }
+
+                let dropped = SQL_DROP_TABLE.captures(line)
+                    .or_else(|| ALEMBIC_DROP_TABLE.captures(line))
+                    .or_else(|| JS_DROP_TABLE.captures(line));
+                if let Some(captures) = dropped {
+                    migrations.dropped.insert(normalize_identifier(&strip_quotes(&captures[1])));
+                }
+
+                let index_definition = SQL_CREATE_INDEX.captures(line)
+                    .or_else(|| ALEMBIC_CREATE_INDEX.captures(line));
+                if let Some(captures) = index_definition {
+                    let table = normalize_identifier(&strip_quotes(&captures[1]));
+                    // Only the leading column of a composite index serves foreign key lookups
+                    if let Some(column) = captures[2].split(',').next() {
+                        migrations.indexes.insert((table, normalize_identifier(&strip_quotes(column))));
+                    }
+                }
+
+                if let Some(table) = &current_table {
+                    let inline = SQL_INLINE_INDEX.captures(line)
+                        .map(|captures| captures[1].split(',').next().unwrap_or_default().to_string())
+                        .or_else(|| ALEMBIC_INDEXED_COLUMN.captures(line).map(|captures| captures[1].to_string()));
+                    if let Some(column) = inline {
+                        migrations.indexes.insert((normalize_identifier(table), normalize_identifier(&strip_quotes(&column))));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// SQLAlchemy declarative models and Django models
+fn parse_python_models(file: &str, content: &str) -> Vec<ModelDefinition> {
+    let is_django = content.contains("django.db") || content.contains("models.Model");
+    let is_sqlalchemy = content.contains("sqlalchemy") || content.contains("db.Model") || content.contains("sqlmodel");
+    if !is_django && !is_sqlalchemy {
+        return Vec::new();
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut models = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let Some(captures) = PYTHON_CLASS.captures(line) else {
+            continue;
+        };
+        let bases = captures.get(2).map_or("", |m| m.as_str());
+        let body_end = lines[index + 1..].iter()
+            .position(|body_line| !body_line.trim().is_empty() && !body_line.starts_with([' ', '\t', '#', ')']))
+            .map_or(lines.len(), |offset| index + 1 + offset);
+        let body = &lines[index + 1..body_end];
+
+        if is_django && bases.contains("models.Model") {
+            let mut model = ModelDefinition::new(&captures[1], SchemaSource::Django, file, index + 1);
+            for (offset, body_line) in body.iter().enumerate() {
+                if let Some(table) = DJANGO_DB_TABLE.captures(body_line) {
+                    model.table = Some(table[1].to_string());
+                }
+                let Some(field) = DJANGO_FIELD.captures(body_line) else {
+                    continue;
+                };
+                model.fields.insert(normalize_identifier(&field[1]));
+                let arguments = &field[3];
+                let target = arguments.split(',').next().unwrap_or_default().trim();
+                // settings.AUTH_USER_MODEL and friends live outside the project's models
+                if target.is_empty() || target.starts_with("settings.") || target.contains("get_user_model") {
+                    continue;
+                }
+                let target = strip_quotes(target);
+                let target = if target == "self" { model.name.clone() } else { target.rsplit('.').next().unwrap_or_default().to_string() };
+                match &field[2] {
+                    "ForeignKey" | "OneToOneField" => model.relations.push(Relation {
+                        field: field[1].to_string(),
+                        target,
+                        foreign_key: Some(format!("{}_id", &field[1])),
+                        // Django creates an index for every ForeignKey unless told otherwise
+                        indexed: !arguments.contains("db_index=False"),
+                        inverse: None,
+                        line: index + offset + 2,
+                    }),
+                    "ManyToManyField" => model.relations.push(Relation {
+                        field: field[1].to_string(),
+                        target,
+                        foreign_key: None,
+                        indexed: true,
+                        inverse: None,
+                        line: index + offset + 2,
+                    }),
+                    _ => {}
+                }
+            }
+            models.push(model);
+            continue;
+        }
+
+        let declares_table = body.iter().any(|body_line| {
+            SQLALCHEMY_TABLENAME.is_match(body_line) || SQLALCHEMY_COLUMN.is_match(body_line)
+        });
+        if !is_sqlalchemy || !declares_table {
+            continue;
+        }
+
+        let mut model = ModelDefinition::new(&captures[1], SchemaSource::SqlAlchemy, file, index + 1);
+        for (offset, body_line) in body.iter().enumerate() {
+            let line_number = index + offset + 2;
+            if let Some(table) = SQLALCHEMY_TABLENAME.captures(body_line) {
+                model.table = Some(table[1].to_string());
+            }
+            for table_index in SQLALCHEMY_TABLE_INDEX.captures_iter(body_line) {
+                model.indexed_columns.insert(normalize_identifier(&table_index[1]));
+            }
+            if let Some(column) = SQLALCHEMY_COLUMN.captures(body_line) {
+                model.fields.insert(normalize_identifier(&column[1]));
+                if let Some(foreign_key) = SQLALCHEMY_FOREIGN_KEY.captures(body_line) {
+                    let target_table = foreign_key[1].split('.').next().unwrap_or_default().to_string();
+                    model.relations.push(Relation {
+                        field: column[1].to_string(),
+                        target: target_table,
+                        foreign_key: Some(column[1].to_string()),
+                        indexed: body_line.contains("index=True") || body_line.contains("primary_key=True") || body_line.contains("unique=True"),
+                        inverse: None,
+                        line: line_number,
+                    });
+                }
+            } else if let Some(relationship) = SQLALCHEMY_RELATIONSHIP.captures(body_line) {
+                model.fields.insert(normalize_identifier(&relationship[1]));
+                let target = relationship.get(3)
+                    .map(|m| m.as_str().to_string())
+                    .or_else(|| relationship.get(2).and_then(|annotation| {
+                        SQLALCHEMY_MAPPED_TARGET.captures(annotation.as_str()).map(|c| c[1].to_string())
+                    }));
+                if let Some(target) = target {
+                    model.relations.push(Relation {
+                        field: relationship[1].to_string(),
+                        target,
+                        foreign_key: None,
+                        indexed: true,
+                        inverse: BACK_POPULATES.captures(body_line).map(|c| c[1].to_string()),
+                        line: line_number,
+                    });
+                }
+            }
+        }
+        models.push(model);
+    }
+
+    models
+}
+
+/// JPA entities (Java and Kotlin)
+fn parse_jpa_entities(file: &str, content: &str) -> Vec<ModelDefinition> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut models = Vec::new();
+    let entity_starts: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|(_, line)| line.trim_start().starts_with("@Entity"))
+        .map(|(index, _)| index)
+        .collect();
+
+    for (position, &start) in entity_starts.iter().enumerate() {
+        let end = entity_starts.get(position + 1).copied().unwrap_or(lines.len());
+        let block = &lines[start..end];
+        let Some((class_offset, class_name)) = block.iter().enumerate()
+            .find_map(|(offset, line)| JAVA_CLASS.captures(line).map(|c| (offset, c[1].to_string())))
+        else {
+            continue;
+        };
+
+        let mut model = ModelDefinition::new(&class_name, SchemaSource::Jpa, file, start + class_offset + 1);
+        for line in &block[..class_offset] {
+            if let Some(table) = JPA_TABLE.captures(line) {
+                model.table = Some(table[1].to_string());
+            }
+        }
+        // Index declarations may span several annotation lines before the class
+        for columns in JPA_INDEX_COLUMNS.captures_iter(&block[..class_offset].join(" ")) {
+            if let Some(column) = columns[1].split(',').next() {
+                model.indexed_columns.insert(normalize_identifier(column));
+            }
+        }
+
+        let mut pending: Vec<&str> = Vec::new();
+        for (offset, line) in block.iter().enumerate().skip(class_offset + 1) {
+            let trimmed = line.trim();
+            if trimmed.starts_with('@') {
+                pending.push(trimmed);
+                continue;
+            }
+            let field = JPA_FIELD.captures(line)
+                .map(|c| (c[2].to_string(), c[1].to_string()))
+                .or_else(|| KOTLIN_FIELD.captures(line).map(|c| (c[1].to_string(), c[2].to_string())));
+            let Some((name, field_type)) = field else {
+                if !trimmed.is_empty() {
+                    pending.clear();
+                }
+                continue;
+            };
+            model.fields.insert(normalize_identifier(&name));
+
+            let annotations = pending.join(" ");
+            pending.clear();
+            let Some(kind) = RELATION_ANNOTATION.captures(&annotations).map(|c| c[1].to_string()) else {
+                continue;
+            };
+            let target = GENERIC_ELEMENT.captures(&field_type)
+                .map(|c| c[1].to_string())
+                .unwrap_or_else(|| field_type.trim().trim_end_matches('?').rsplit('.').next().unwrap_or_default().to_string());
+            let inverse = MAPPED_BY.captures(&annotations).map(|c| c[1].to_string());
+            let owns_foreign_key = matches!(kind.as_str(), "ManyToOne" | "OneToOne") && inverse.is_none();
+            let foreign_key = owns_foreign_key.then(|| {
+                JOIN_COLUMN.captures(&annotations)
+                    .map(|c| c[1].to_string())
+                    .unwrap_or_else(|| format!("{}_id", name))
+            });
+
+            model.relations.push(Relation {
+                field: name,
+                target,
+                foreign_key,
+                indexed: false,
+                inverse,
+                line: start + offset + 1,
+            });
+        }
+
+        models.push(model);
+    }
+
+    models
+}
+
+/// TypeORM entities
+fn parse_typeorm_entities(file: &str, content: &str) -> Vec<ModelDefinition> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut models = Vec::new();
+    let entity_starts: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|(_, line)| TYPEORM_ENTITY.is_match(line))
+        .map(|(index, _)| index)
+        .collect();
+
+    for (position, &start) in entity_starts.iter().enumerate() {
+        let end = entity_starts.get(position + 1).copied().unwrap_or(lines.len());
+        let block = &lines[start..end];
+        let Some((class_offset, class_name)) = block.iter().enumerate()
+            .find_map(|(offset, line)| JAVA_CLASS.captures(line).map(|c| (offset, c[1].to_string())))
+        else {
+            continue;
+        };
+
+        let mut model = ModelDefinition::new(&class_name, SchemaSource::TypeOrm, file, start + class_offset + 1);
+        if let Some(entity) = TYPEORM_ENTITY.captures(block[0]) {
+            model.table = entity.get(1).or(entity.get(2)).map(|m| m.as_str().to_string());
+        }
+        for line in &block[..class_offset] {
+            if let Some(columns) = TYPEORM_CLASS_INDEX.captures(line)
+                && let Some(column) = columns[1].split(',').next() {
+                model.indexed_columns.insert(normalize_identifier(&strip_quotes(column)));
+            }
+        }
+
+        let mut pending: Vec<&str> = Vec::new();
+        let mut foreign_keys: Vec<(usize, String)> = Vec::new();
+        for (offset, line) in block.iter().enumerate().skip(class_offset + 1) {
+            let trimmed = line.trim();
+            if trimmed.starts_with('@') {
+                pending.push(trimmed);
+                continue;
+            }
+            let Some(property) = TYPEORM_PROPERTY.captures(line) else {
+                if !trimmed.is_empty() {
+                    pending.clear();
+                }
+                continue;
+            };
+            let name = property[1].to_string();
+            model.fields.insert(normalize_identifier(&name));
+
+            let decorators = pending.join(" ");
+            pending.clear();
+            if decorators.contains("@Index(") || decorators.contains("@PrimaryColumn") || decorators.contains("@PrimaryGeneratedColumn") {
+                model.indexed_columns.insert(normalize_identifier(&name));
+            }
+            let Some(relation) = TYPEORM_RELATION.captures(&decorators) else {
+                continue;
+            };
+            let kind = relation[1].to_string();
+            let owns_foreign_key = kind == "ManyToOne" || (kind == "OneToOne" && decorators.contains("@JoinColumn"));
+            let foreign_key = owns_foreign_key.then(|| {
+                JOIN_COLUMN.captures(&decorators)
+                    .map(|c| c[1].to_string())
+                    .unwrap_or_else(|| format!("{}Id", name))
+            });
+            if let Some(column) = &foreign_key {
+                foreign_keys.push((model.relations.len(), column.clone()));
+            }
+            model.relations.push(Relation {
+                field: name,
+                target: relation[2].to_string(),
+                foreign_key,
+                indexed: decorators.contains("@Index("),
+                // Only the inverse side of OneToMany is mandatory in TypeORM
+                inverse: if kind == "OneToMany" { relation.get(3).map(|m| m.as_str().to_string()) } else { None },
+                line: start + offset + 1,
+            });
+        }
+
+        // A separate `@Index() userId` column also covers the relation's join column
+        for (relation_index, column) in foreign_keys {
+            if model.indexed_columns.contains(&normalize_identifier(&column)) {
+                model.relations[relation_index].indexed = true;
+            }
+        }
+
+        models.push(model);
+    }
+
+    models
+}
+
+/// Prisma schema models
+fn parse_prisma_models(file: &str, content: &str) -> Vec<ModelDefinition> {
+    let lines: Vec<&str> = content.lines().collect();
+    let model_names: BTreeSet<String> = lines.iter()
+        .filter_map(|line| PRISMA_MODEL.captures(line).map(|c| c[1].to_string()))
+        .collect();
+
+    let mut models = Vec::new();
+    let mut current: Option<ModelDefinition> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(captures) = PRISMA_MODEL.captures(line) {
+            current = Some(ModelDefinition::new(&captures[1], SchemaSource::Prisma, file, index + 1));
+            continue;
+        }
+        let Some(model) = current.as_mut() else {
+            continue;
+        };
+        let trimmed = line.trim();
+        if trimmed.starts_with('}') {
+            models.extend(current.take());
+            continue;
+        }
+        if let Some(map) = PRISMA_MAP.captures(trimmed) {
+            model.table = Some(map[1].to_string());
+            continue;
+        }
+        if let Some(block_index) = PRISMA_BLOCK_INDEX.captures(trimmed) {
+            if let Some(column) = block_index[1].split(',').next() {
+                let column = column.split('(').next().unwrap_or_default();
+                model.indexed_columns.insert(normalize_identifier(column));
+            }
+            continue;
+        }
+        if trimmed.starts_with("//") {
+            continue;
+        }
+        let Some(field) = PRISMA_FIELD.captures(trimmed) else {
+            continue;
+        };
+        model.fields.insert(normalize_identifier(&field[1]));
+        let attributes = &field[4];
+        if attributes.contains("@id") || attributes.contains("@unique") {
+            model.indexed_columns.insert(normalize_identifier(&field[1]));
+        }
+        if !model_names.contains(&field[2]) {
+            continue;
+        }
+        model.relations.push(Relation {
+            field: field[1].to_string(),
+            target: field[2].to_string(),
+            foreign_key: PRISMA_RELATION_FIELDS.captures(attributes).map(|c| c[1].to_string()),
+            indexed: false,
+            inverse: None,
+            line: index + 1,
+        });
+    }
+
+    models
+}
+
+/// Mongoose models and `ref` relationships
+fn parse_mongoose_models(file: &str, content: &str) -> Vec<ModelDefinition> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((model_line, model_name)) = lines.iter().enumerate()
+        .find_map(|(index, line)| MONGOOSE_MODEL.captures(line).map(|c| (index, c[1].to_string())))
+    else {
+        return Vec::new();
+    };
+
+    let mut model = ModelDefinition::new(&model_name, SchemaSource::Mongoose, file, model_line + 1);
+    let mut current_key: Option<String> = None;
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(key) = MONGOOSE_KEY.captures(line) {
+            current_key = Some(key[1].to_string());
+            model.fields.insert(normalize_identifier(&key[1]));
+        }
+        if let Some(reference) = MONGOOSE_REF.captures(line) {
+            model.relations.push(Relation {
+                field: current_key.clone().unwrap_or_else(|| reference[1].to_lowercase()),
+                target: reference[1].to_string(),
+                foreign_key: None,
+                indexed: line.contains("index: true"),
+                inverse: None,
+                line: index + 1,
+            });
+        }
+    }
+
+    vec![model]
+}
+
+/// Find the model a relation target refers to, by model name or table name
+fn resolve_model<'a>(models: &'a [ModelDefinition], target: &str, source: &ModelDefinition) -> Option<&'a ModelDefinition> {
+    let normalized = normalize_identifier(target);
+    models.iter()
+        .find(|model| normalize_identifier(&model.name) == normalized && model.orm == source.orm)
+        .or_else(|| models.iter().find(|model| normalize_identifier(&model.name) == normalized))
+        .or_else(|| models.iter().find(|model| model.table_candidates().contains(&normalized)))
+}
+
+/// Lowercased identifier without quotes, schema prefix or underscores
+fn normalize_identifier(name: &str) -> String {
+    strip_quotes(name)
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .replace('_', "")
+        .to_lowercase()
+}
+
+fn strip_quotes(name: &str) -> String {
+    name.trim()
+        .trim_matches(|c| matches!(c, '"' | '\'' | '`' | '[' | ']'))
+        .replace(['"', '`', '[', ']'], "")
+}
+
+fn is_framework_table(table: &str) -> bool {
+    let table = strip_quotes(table).to_lowercase();
+    let table = table.rsplit('.').next().unwrap_or_default();
+    FRAMEWORK_TABLE_PREFIXES.iter().any(|prefix| table.starts_with(prefix))
+}
+
+/// Many-to-many join tables such as `user_roles` connect two known models
+fn is_join_table(table: &str, known: &BTreeSet<String>) -> bool {
+    let table = strip_quotes(table).to_lowercase();
+    let parts: Vec<&str> = table.split('_').filter(|part| !part.is_empty()).collect();
+    parts.len() >= 2 && parts.iter().all(|part| known.contains(&normalize_identifier(part)))
+}
+
+fn parent_dir(file: &str) -> String {
+    file.rsplit_once('/').map(|(dir, _)| dir.to_string()).unwrap_or_else(|| ".".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::{analyze, write};
+    use tempfile::TempDir;
+
+    fn findings<'a>(result: &'a StatusAnalysisResult, kind: &str) -> Vec<&'a str> {
+        result.missing_implementations.iter()
+            .filter(|missing| missing.implementation_type == kind)
+            .map(|missing| missing.description.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_sqlalchemy_models_against_alembic_migrations() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "app/models.py", r#"from sqlalchemy import Column, ForeignKey, Integer, String
+from sqlalchemy.orm import relationship
+from app.db import Base
+
+class User(Base):
+    __tablename__ = "users"
+    id = Column(Integer, primary_key=True)
+    orders = relationship("Order", back_populates="buyer")
+
+class Order(Base):
+    __tablename__ = "orders"
+    id = Column(Integer, primary_key=True)
+    user_id = Column(Integer, ForeignKey("users.id"))
+    owner = relationship("User", back_populates="orders")
+
+class Invoice(Base):
+    __tablename__ = "invoices"
+    id = Column(Integer, primary_key=True)
+    order_id = Column(Integer, ForeignKey("orders.id"), index=True)
+    customer = relationship("Customer")
+"#);
+        write(temp_dir.path(), "alembic/versions/001_initial.py", r#"from alembic import op
+import sqlalchemy as sa
+
+def upgrade():
+    op.create_table('users', sa.Column('id', sa.Integer(), primary_key=True))
+    op.create_table('orders', sa.Column('id', sa.Integer()), sa.Column('user_id', sa.Integer()))
+    op.create_table('audit_log', sa.Column('id', sa.Integer()))
+    op.create_table('alembic_version', sa.Column('version_num', sa.String(32)))
+"#);
+
+        let result = analyze(&DatabaseAnalyzer::new(DatabaseAnalysisConfig::default(), 0.15), temp_dir.path());
+
+        assert_eq!(findings(&result, "Database Migration"), vec!["Model Invoice has no migration creating its table"]);
+        assert_eq!(findings(&result, "Database Model"), vec!["Table audit_log is created by a Alembic migration but no model defines it"]);
+        assert_eq!(findings(&result, "Database Index"), vec!["Foreign key Order.user_id has no index"]);
+        assert_eq!(findings(&result, "Database Relation"), vec!["Invoice.customer references Customer, which no model defines"]);
+
+        assert_eq!(result.partial_implementations.len(), 1);
+        assert_eq!(result.partial_implementations[0].missing_parts, vec!["Order.buyer".to_string()]);
+
+        let data_layer = &result.incomplete_features[0];
+        assert_eq!(data_layer.feature_category, "Database");
+        assert!(matches!(data_layer.priority, InferencePriority::High));
+    }
+
+    #[test]
+    fn test_jpa_entities_with_flyway_indexes() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/main/java/com/shop/Order.java", r#"package com.shop;
+
+@Entity
+@Table(name = "orders")
+public class Order {
+    @Id
+    private Long id;
+
+    @ManyToOne
+    @JoinColumn(name = "customer_id")
+    private Customer customer;
+
+    @ManyToOne
+    @JoinColumn(name = "store_id")
+    private Store store;
+
+    @OneToMany(mappedBy = "order")
+    private List<OrderLine> lines;
+}
+"#);
+        write(temp_dir.path(), "src/main/java/com/shop/Customer.java", "@Entity\npublic class Customer {\n    @Id\n    private Long id;\n}\n");
+        write(temp_dir.path(), "src/main/java/com/shop/OrderLine.java", "@Entity\n@Table(name = \"order_lines\")\npublic class OrderLine {\n    @Id\n    private Long id;\n}\n");
+        write(temp_dir.path(), "src/main/resources/db/migration/V1__init.sql", r#"CREATE TABLE customer (id BIGINT PRIMARY KEY);
+CREATE TABLE orders (id BIGINT PRIMARY KEY, customer_id BIGINT REFERENCES customer(id), store_id BIGINT);
+CREATE INDEX idx_orders_customer ON orders (customer_id);
+CREATE TABLE order_lines (id BIGINT PRIMARY KEY);
+"#);
+
+        let result = analyze(&DatabaseAnalyzer::new(DatabaseAnalysisConfig::default(), 0.15), temp_dir.path());
+
+        assert!(findings(&result, "Database Migration").is_empty());
+        assert!(findings(&result, "Database Model").is_empty());
+        assert_eq!(findings(&result, "Database Index"), vec!["Foreign key Order.store_id has no index"]);
+        assert_eq!(findings(&result, "Database Relation"), vec!["Order.store references Store, which no model defines"]);
+        assert_eq!(result.partial_implementations.len(), 1);
+        assert_eq!(result.partial_implementations[0].implementation_name, "Order.lines <-> OrderLine.order relationship");
+    }
+
+    #[test]
+    fn test_prisma_without_migrations() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "prisma/schema.prisma", r#"model User {
+  id    Int    @id @default(autoincrement())
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+
+  @@index([authorId])
+}
+"#);
+
+        let result = analyze(&DatabaseAnalyzer::new(DatabaseAnalysisConfig::default(), 0.15), temp_dir.path());
+
+        assert_eq!(findings(&result, "Database Migrations"), vec!["2 models are defined but the project has no schema migrations"]);
+        assert!(findings(&result, "Database Index").is_empty());
+        assert!(findings(&result, "Database Relation").is_empty());
+    }
+}
@@ -0,0 +1,531 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::analyzers::common::is_test_path;
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::ast_analyzer::{CodeSegment, SegmentType};
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, FeatureAnalysisConfig,
+    IncompleteFeature, InferencePriority, PartialImplementation
+};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Maximum number of locations listed per layer in a feature's evidence
+const MAX_LOCATIONS_PER_LAYER: usize = 3;
+
+/// Directories that group code by feature rather than by layer
+const FEATURE_DIRS: &[&str] = &["features", "feature", "modules", "domains", "domain"];
+
+/// File and directory names that say nothing about the feature they belong to
+const GENERIC_NAMES: &[&str] = &[
+    "index", "main", "app", "mod", "lib", "init", "__init__", "src", "source", "server", "client",
+    "models", "model", "views", "view", "routes", "route", "router", "urls", "serializers",
+    "schemas", "schema", "services", "service", "controllers", "controller", "handlers", "handler",
+    "api", "apis", "utils", "util", "helpers", "helper", "common", "shared", "core", "types",
+    "config", "settings", "db", "database", "test", "tests", "spec", "conftest", "setup",
+    "components", "component", "pages", "page", "screens", "entities", "entity", "repositories",
+    "repository", "layout", "constants", "store", "hooks", "middleware", "migrations", "java",
+    "com", "org", "internal", "pkg", "cmd", "web", "frontend", "backend", "public", "static",
+    "assets", "styles", "admin", "apps", "__tests__", "e2e", "integration", "unit", "fixtures",
+];
+
+/// Suffixes naming a layer rather than a feature (`OrderService`, `order_routes`)
+const LAYER_SUFFIXES: &[&str] = &[
+    "controller", "service", "repository", "repo", "model", "entity", "router", "routes", "route",
+    "handler", "handlers", "page", "view", "views", "screen", "component", "form", "list", "detail",
+    "details", "schema", "dao", "api", "resource", "resolver", "serializer", "impl", "store",
+    "slice", "tests", "test", "spec",
+];
+
+/// Layer of a feature's vertical slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Layer {
+    Ui,
+    Api,
+    Service,
+    Persistence,
+    Tests,
+}
+
+impl Layer {
+    const ALL: [Layer; 5] = [Layer::Ui, Layer::Api, Layer::Service, Layer::Persistence, Layer::Tests];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Layer::Ui => "UI component",
+            Layer::Api => "API route",
+            Layer::Service => "Service",
+            Layer::Persistence => "Persistence model",
+            Layer::Tests => "Tests",
+        }
+    }
+
+    fn recommendation(&self, feature: &str) -> String {
+        match self {
+            Layer::Ui => format!("Add a UI component or view for {}", feature),
+            Layer::Api => format!("Expose {} through an API route", feature),
+            Layer::Service => format!("Move {} business logic into a service", feature),
+            Layer::Persistence => format!("Add a persistence model for {}", feature),
+            Layer::Tests => format!("Add tests covering {}", feature),
+        }
+    }
+}
+
+static UI_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(^|/)(components?|pages|screens|views|ui|widgets|layouts)/").unwrap()
+});
+static API_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)((^|/)(controllers?|routes?|routers?|handlers?|api|endpoints?|resolvers?|resources)/|controller|router|routes|handler|resolver|endpoint|(^|/)views\.py$|viewsets?\.py$)").unwrap()
+});
+static SERVICE_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)((^|/)(services?|usecases?|use_cases|interactors?|domain)/|service|usecase|interactor)").unwrap()
+});
+static PERSISTENCE_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)((^|/)(models?|entities|entity|repositories|repository|repos?|daos?|schemas?|db)/|repository|entity|model|dao|(^|/)models\.py$)").unwrap()
+});
+
+/// Feature grouping and vertical-slice completeness analyzer
+/// Groups code segments into features by route prefix, feature directory, file name and business
+/// hint, then checks that each feature has a UI component, API route, service, persistence model
+/// and tests
+pub struct FeatureAnalyzer {
+    config: FeatureAnalysisConfig,
+    weight: f32,
+}
+
+/// Code belonging to one feature, grouped by layer
+#[derive(Debug, Default)]
+struct FeatureSlice {
+    /// Layer -> `file:line` locations
+    layers: BTreeMap<Layer, BTreeSet<String>>,
+    ui_files: BTreeSet<PathBuf>,
+    business_hints: HashMap<String, usize>,
+}
+
+impl FeatureSlice {
+    fn add(&mut self, layer: Layer, location: String) {
+        self.layers.entry(layer).or_default().insert(location);
+    }
+
+    fn has(&self, layer: Layer) -> bool {
+        self.layers.contains_key(&layer)
+    }
+
+    /// Most frequent business hint, used as the feature category
+    fn category(&self) -> String {
+        self.business_hints.iter()
+            .max_by(|(a_hint, a_count), (b_hint, b_count)| a_count.cmp(b_count).then(b_hint.cmp(a_hint)))
+            .map(|(hint, _)| hint.clone())
+            .unwrap_or_else(|| "Feature".to_string())
+    }
+}
+
+impl FeatureAnalyzer {
+    pub fn new(config: FeatureAnalysisConfig, weight: f32) -> Self {
+        Self { config, weight }
+    }
+
+    /// Group segments into features keyed by normalized feature name
+    fn group_features(&self, index: &ProjectFileIndex, segments: &[CodeSegment]) -> BTreeMap<String, FeatureSlice> {
+        let mut features: BTreeMap<String, FeatureSlice> = BTreeMap::new();
+
+        for segment in segments {
+            let file_path = &segment.metadata.file_path;
+            let relative = file_path.strip_prefix(index.root())
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            let Some((layer, key)) = classify_segment(segment, &relative) else {
+                continue;
+            };
+            let Some(key) = key
+                .or_else(|| file_feature_key(&relative))
+                .or_else(|| segment.business_hints.first().map(|hint| normalize_feature_key(hint)))
+            else {
+                continue;
+            };
+
+            let slice = features.entry(key).or_default();
+            slice.add(layer, format!("{}:{}", relative, segment.metadata.line_start));
+            if layer == Layer::Ui {
+                slice.ui_files.insert(file_path.clone());
+            }
+            for hint in &segment.business_hints {
+                *slice.business_hints.entry(hint.clone()).or_insert(0) += 1;
+            }
+        }
+
+        // Test files often hold only calls (`it(...)`, `test(...)`) that yield no segments
+        for file in index.with_extensions(&["ts", "tsx", "js", "jsx", "vue", "py", "java", "kt", "rs", "go", "rb", "cs"]) {
+            if !is_test_path(&file.relative) {
+                continue;
+            }
+            if let Some(key) = file_feature_key(&file.relative) {
+                features.entry(key).or_default().add(Layer::Tests, format!("{}:1", file.relative));
+            }
+        }
+
+        features
+    }
+
+    /// Layers every feature is expected to have: those the project uses anywhere
+    fn expected_layers(&self, features: &BTreeMap<String, FeatureSlice>) -> Vec<Layer> {
+        Layer::ALL.into_iter()
+            .filter(|layer| *layer != Layer::Tests || self.config.analyze_test_coverage_gaps)
+            .filter(|layer| features.values().any(|slice| slice.has(*layer)))
+            .collect()
+    }
+
+    fn generate_analysis_results(&self, features: BTreeMap<String, FeatureSlice>) -> StatusAnalysisResult {
+        let mut incomplete_features = Vec::new();
+        let mut partial_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+
+        let expected = self.expected_layers(&features);
+        analysis_notes.push(format!(
+            "Grouped code into {} candidate features; project layers: {}",
+            features.len(),
+            expected.iter().map(|layer| layer.label()).collect::<Vec<_>>().join(", ")
+        ));
+
+        let mut tracked = 0;
+        for (key, slice) in &features {
+            // A feature needs at least two application layers (or a route) to be a slice at all
+            let application_layers = slice.layers.keys().filter(|layer| **layer != Layer::Tests).count();
+            if application_layers < 2 && !slice.has(Layer::Api) {
+                continue;
+            }
+            tracked += 1;
+
+            let feature_name = display_name(key);
+            let present: Vec<Layer> = expected.iter().copied().filter(|layer| slice.has(*layer)).collect();
+            let missing: Vec<Layer> = expected.iter().copied().filter(|layer| !slice.has(*layer)).collect();
+            let completeness = present.len() as f32 / expected.len().max(1) as f32;
+
+            evidence.push(format!("Feature {}: {}", feature_name,
+                                  present.iter().map(|layer| layer.label()).collect::<Vec<_>>().join(", ")));
+
+            if self.config.detect_partial_implementations && !missing.is_empty() {
+                let mut feature_evidence = vec![format!(
+                    "{} of {} layers present ({:.0}% complete)",
+                    present.len(), expected.len(), completeness * 100.0
+                )];
+                for (layer, locations) in &slice.layers {
+                    for location in locations.iter().take(MAX_LOCATIONS_PER_LAYER) {
+                        feature_evidence.push(format!("{}: {}", layer.label(), location));
+                    }
+                }
+
+                incomplete_features.push(IncompleteFeature {
+                    feature_name: feature_name.clone(),
+                    feature_category: slice.category(),
+                    completeness_score: completeness,
+                    missing_components: missing.iter().map(|layer| layer.label().to_string()).collect(),
+                    evidence: feature_evidence,
+                    recommendations: missing.iter().map(|layer| layer.recommendation(&feature_name)).collect(),
+                    priority: match completeness {
+                        c if c < 0.5 => InferencePriority::High,
+                        c if c < 0.75 => InferencePriority::Medium,
+                        _ => InferencePriority::Low,
+                    },
+                });
+            }
+
+            if self.config.detect_missing_error_states && !slice.ui_files.is_empty()
+                && let Some(partial) = self.check_ui_states(&feature_name, slice) {
+                partial_implementations.push(partial);
+            }
+        }
+
+        analysis_notes.push(format!("{} features tracked, {} incomplete", tracked, incomplete_features.len()));
+
+        let confidence = if tracked == 0 { 0.3 } else if tracked < 3 { 0.55 } else { 0.7 };
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            incomplete_features,
+            partial_implementations,
+            confidence,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+
+    /// Check a feature's UI files for the configured completeness indicators
+    fn check_ui_states(&self, feature_name: &str, slice: &FeatureSlice) -> Option<PartialImplementation> {
+        let source: String = slice.ui_files.iter()
+            .filter_map(|file| fs::read_to_string(file).ok())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (implemented, missing): (Vec<&String>, Vec<&String>) = self.config.ui_completeness_indicators.iter()
+            .partition(|indicator| indicator_pattern(indicator).is_match(&source));
+        if missing.is_empty() {
+            return None;
+        }
+
+        let total = self.config.ui_completeness_indicators.len();
+        Some(PartialImplementation {
+            implementation_name: format!("{} UI states", feature_name),
+            implemented_parts: implemented.into_iter().cloned().collect(),
+            missing_parts: missing.iter().map(|indicator| indicator.to_string()).collect(),
+            completeness_percentage: (total - missing.len()) as f32 / total as f32 * 100.0,
+            category: "UI".to_string(),
+            evidence: slice.layers.get(&Layer::Ui)
+                .map(|locations| locations.iter().take(MAX_LOCATIONS_PER_LAYER).cloned().collect())
+                .unwrap_or_default(),
+            next_steps: missing.iter().map(|indicator| format!("Handle {} in the {} UI", indicator, feature_name)).collect(),
+        })
+    }
+}
+
+impl StatusAnalyzer for FeatureAnalyzer {
+    fn name(&self) -> &str {
+        "Feature Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        if !self.config.enable_feature_tracking {
+            return Ok(StatusAnalysisResult {
+                analyzer_name: self.name().to_string(),
+                confidence: 0.0,
+                analysis_notes: vec!["Feature tracking disabled".to_string()],
+                ..Default::default()
+            });
+        }
+
+        println!("Analyzing feature completeness for project: {}", index.root().display());
+
+        let segments = index.segments()?;
+        let features = self.group_features(index, segments);
+        let result = self.generate_analysis_results(features);
+
+        println!("Feature analysis completed: {} incomplete features found, {:.1}% confidence",
+                 result.incomplete_features.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Layer of a segment and, when the segment names it directly, its feature key
+fn classify_segment(segment: &CodeSegment, relative: &str) -> Option<(Layer, Option<String>)> {
+    if is_test_path(relative) {
+        return Some((Layer::Tests, None));
+    }
+
+    match &segment.segment_type {
+        SegmentType::Route(route) => {
+            let layer = if route.method == "VIEW" { Layer::Ui } else { Layer::Api };
+            Some((layer, route_feature_key(&route.path)))
+        }
+        SegmentType::Database(database) => Some((Layer::Persistence, Some(normalize_feature_key(&database.model_name)))),
+        SegmentType::Class(class) if class.is_react_component => Some((Layer::Ui, None)),
+        SegmentType::Configuration(_) => None,
+        _ => {
+            let extension = relative.rsplit('.').next().unwrap_or_default();
+            let layer = if matches!(extension, "tsx" | "jsx" | "vue" | "svelte") || UI_PATH.is_match(relative) && extension != "py" {
+                Layer::Ui
+            } else if API_PATH.is_match(relative) {
+                Layer::Api
+            } else if SERVICE_PATH.is_match(relative) {
+                Layer::Service
+            } else if PERSISTENCE_PATH.is_match(relative) {
+                Layer::Persistence
+            } else {
+                return None;
+            };
+            Some((layer, None))
+        }
+    }
+}
+
+/// First meaningful segment of a route path: `/api/v1/orders/:id` -> `order`
+fn route_feature_key(path: &str) -> Option<String> {
+    path.split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .filter(|segment| !segment.starts_with([':', '{', '<', '[', '*', '$']))
+        .find(|segment| {
+            let lower = segment.to_lowercase();
+            lower != "api" && !(lower.starts_with('v') && lower.len() > 1 && lower[1..].chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(normalize_feature_key)
+        .filter(|key| !key.is_empty() && !GENERIC_NAMES.contains(&key.as_str()))
+}
+
+/// Feature a file belongs to: its feature directory, else its layer-stripped file name, else
+/// its parent directory
+fn file_feature_key(relative: &str) -> Option<String> {
+    let components: Vec<&str> = relative.split('/').collect();
+    if let Some(position) = components.iter().position(|component| FEATURE_DIRS.contains(&component.to_lowercase().as_str()))
+        && let Some(feature_dir) = components.get(position + 1).filter(|_| position + 2 < components.len()) {
+        return Some(normalize_feature_key(feature_dir));
+    }
+
+    let file_name = components.last().copied().unwrap_or_default();
+    let stem = file_name.split('.').next().unwrap_or_default();
+    let key = normalize_feature_key(&strip_layer_affixes(stem));
+    if !key.is_empty() && !GENERIC_NAMES.contains(&key.as_str()) {
+        return Some(key);
+    }
+
+    components.iter().rev().skip(1)
+        .map(|directory| normalize_feature_key(directory))
+        .find(|key| !key.is_empty() && !GENERIC_NAMES.contains(&key.as_str()) && !LAYER_SUFFIXES.contains(&key.as_str()))
+}
+
+/// Remove test and layer prefixes/suffixes: `OrderServiceTest` -> `Order`, `test_orders` -> `orders`
+fn strip_layer_affixes(stem: &str) -> String {
+    let words = split_words(stem);
+    let mut words: Vec<String> = words.into_iter()
+        .skip_while(|word| word == "test" || word == "use")
+        .collect();
+    while words.len() > 1 && words.last().is_some_and(|word| LAYER_SUFFIXES.contains(&word.as_str())) {
+        words.pop();
+    }
+    words.join("")
+}
+
+/// Split `OrderService`, `order_service` and `order-service` into lowercase words
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (index, character) in name.char_indices() {
+        let previous_lower = index > 0 && name[..index].chars().last().is_some_and(|c| c.is_lowercase() || c.is_ascii_digit());
+        if character == '_' || character == '-' || character == ' ' || (character.is_uppercase() && previous_lower) {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            if !character.is_alphanumeric() {
+                continue;
+            }
+        }
+        current.extend(character.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Lowercase, separator-free, singular feature key: `Order_Items` -> `orderitem`
+fn normalize_feature_key(name: &str) -> String {
+    let key: String = split_words(name).concat();
+    if let Some(stem) = key.strip_suffix("ies").filter(|stem| stem.len() > 2) {
+        format!("{}y", stem)
+    } else if key.ends_with("sses") || key.ends_with("uses") {
+        key[..key.len() - 2].to_string()
+    } else if key.len() > 3 && key.ends_with('s') && !key.ends_with("ss") && !key.ends_with("us") && !key.ends_with("is") {
+        key[..key.len() - 1].to_string()
+    } else {
+        key
+    }
+}
+
+fn display_name(key: &str) -> String {
+    let mut characters = key.chars();
+    characters.next()
+        .map(|first| first.to_uppercase().chain(characters).collect())
+        .unwrap_or_default()
+}
+
+/// Pattern recognizing a UI completeness indicator such as "loading states"
+fn indicator_pattern(indicator: &str) -> Regex {
+    let lower = indicator.to_lowercase();
+    let pattern = if lower.contains("loading") {
+        r"(?i)\b(is_?loading|loading|is_?pending|spinner|skeleton|suspense)\b".to_string()
+    } else if lower.contains("error") {
+        r"(?i)\b(error|is_?error|catch|errorboundary|onerror)\b".to_string()
+    } else if lower.contains("empty") {
+        r"(?i)(\bempty\b|\.length\s*===?\s*0|\bno\s+\w+\s+(found|yet)\b|\bnothing\s+to\b|\bno results\b)".to_string()
+    } else if lower.contains("validation") {
+        r"(?i)\b(validat\w*|invalid|required|formstate|helpertext)\b".to_string()
+    } else {
+        format!(r"(?i)\b{}", regex::escape(lower.split_whitespace().next().unwrap_or_default()))
+    };
+    Regex::new(&pattern).unwrap_or_else(|_| Regex::new(&regex::escape(&lower)).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::{analyze, write};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_feature_missing_layers() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/routes/orders.ts", r#"import { Router } from 'express';
+import { listOrders } from '../services/orderService';
+const router = Router();
+router.get('/api/orders', async (req, res) => { res.json(await listOrders()); });
+router.get('/api/invoices', async (req, res) => { res.json([]); });
+export default router;
+"#);
+        write(temp_dir.path(), "src/services/orderService.ts", "export async function listOrders() {\n  return [];\n}\n");
+        write(temp_dir.path(), "src/services/invoiceService.ts", "export async function listInvoices() {\n  return [];\n}\n");
+        write(temp_dir.path(), "src/components/OrderList.tsx", r#"export function OrderList({ orders, isLoading, error }) {
+  if (isLoading) return <Spinner />;
+  if (error) return <p>{error.message}</p>;
+  if (orders.length === 0) return <p>No orders yet</p>;
+  return <ul>{orders.map(o => <li key={o.id}>{o.name}</li>)}</ul>;
+}
+"#);
+        write(temp_dir.path(), "tests/orderService.test.ts", "test('lists orders', async () => {\n  expect(await listOrders()).toEqual([]);\n});\n");
+
+        let result = analyze(&FeatureAnalyzer::new(FeatureAnalysisConfig::default(), 0.1), temp_dir.path());
+
+        let invoice = result.incomplete_features.iter().find(|feature| feature.feature_name == "Invoice").unwrap();
+        assert_eq!(invoice.missing_components, vec!["UI component".to_string(), "Tests".to_string()]);
+        assert!((invoice.completeness_score - 0.5).abs() < f32::EPSILON);
+        assert!(result.incomplete_features.iter().all(|feature| feature.feature_name != "Order"));
+
+        let order_states = result.partial_implementations.iter().find(|partial| partial.implementation_name == "Order UI states").unwrap();
+        assert_eq!(order_states.missing_parts, vec!["validation messages".to_string()]);
+    }
+
+    #[test]
+    fn test_feature_keys() {
+        assert_eq!(route_feature_key("/api/v1/orders/:id").as_deref(), Some("order"));
+        assert_eq!(route_feature_key("/:id"), None);
+        assert_eq!(file_feature_key("src/services/OrderServiceTest.java").as_deref(), Some("order"));
+        assert_eq!(file_feature_key("tests/test_order_items.py").as_deref(), Some("orderitem"));
+        assert_eq!(file_feature_key("src/features/billing/components/Summary.tsx").as_deref(), Some("billing"));
+        assert_eq!(file_feature_key("shop/categories/views.py").as_deref(), Some("category"));
+        assert_eq!(normalize_feature_key("Addresses"), "address");
+    }
+}
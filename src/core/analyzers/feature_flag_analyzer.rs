@@ -0,0 +1,757 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::analyzers::common::line_of;
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::ast_analyzer::{CodeSegment, SegmentType};
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, FeatureFlagAnalysisConfig,
+    FeatureFlag, IncompleteFeature, InferencePriority
+};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Source files that can hold flag checks
+const FLAG_SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "py", "java", "kt"];
+
+/// Framework prefixes that expose env vars to client bundles (`NEXT_PUBLIC_FEATURE_X`)
+const PUBLIC_ENV_PREFIXES: &[&str] = &["NEXT_PUBLIC_", "VITE_", "REACT_APP_", "NUXT_PUBLIC_", "EXPO_PUBLIC_"];
+
+/// Names too generic to tell whether code is reachable without a flag
+const GENERIC_NAMES: &[&str] = &[
+    "main", "new", "init", "__init__", "constructor", "render", "setup", "get", "set", "run",
+    "handle", "index", "app", "default", "toString", "equals", "hashCode",
+];
+
+/// Flag providers, in the order their checks are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum FlagProvider {
+    LaunchDarkly,
+    Unleash,
+    Environment,
+    Settings,
+    Custom,
+}
+
+impl FlagProvider {
+    fn label(&self) -> &'static str {
+        match self {
+            FlagProvider::LaunchDarkly => "LaunchDarkly",
+            FlagProvider::Unleash => "Unleash",
+            FlagProvider::Environment => "Environment",
+            FlagProvider::Settings => "Settings",
+            FlagProvider::Custom => "Custom",
+        }
+    }
+
+    fn confidence(&self) -> f32 {
+        match self {
+            FlagProvider::LaunchDarkly | FlagProvider::Unleash => 0.9,
+            FlagProvider::Custom => 0.8,
+            FlagProvider::Environment | FlagProvider::Settings => 0.7,
+        }
+    }
+}
+
+/// How a flag check switches code on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckKind {
+    /// Call or lookup whose value guards the surrounding condition
+    Expression,
+    /// Annotation that makes the annotated class or bean exist only when the flag is on
+    Annotation,
+}
+
+/// Built-in flag checks: provider, kind and a regex whose first matching group is the flag name
+static FLAG_PATTERNS: Lazy<Vec<(FlagProvider, CheckKind, Regex)>> = Lazy::new(|| {
+    let patterns: &[(FlagProvider, CheckKind, &str)] = &[
+        (FlagProvider::LaunchDarkly, CheckKind::Expression,
+         r#"\b(?:bool|string|number|json|int|double|float)?[vV]ariation(?:Detail)?\(\s*["'`]([\w.:-]+)["'`]"#),
+        (FlagProvider::LaunchDarkly, CheckKind::Expression,
+         r#"\buseLDFlag\(\s*["'`]([\w.:-]+)["'`]"#),
+        (FlagProvider::Unleash, CheckKind::Expression,
+         r#"\b(?:isEnabled|is_enabled|useFlag|useVariant|getVariant|get_variant)\(\s*["'`]([\w.:-]+)["'`]"#),
+        (FlagProvider::Environment, CheckKind::Expression,
+         r#"\bprocess\.env\.([A-Z][A-Z0-9_]*)|\bprocess\.env\[\s*["']([A-Z][A-Z0-9_]*)["']\s*\]|\bimport\.meta\.env\.([A-Z][A-Z0-9_]*)"#),
+        (FlagProvider::Environment, CheckKind::Expression,
+         r#"\bos\.(?:environ\.get|getenv)\(\s*["']([A-Z][A-Z0-9_]*)["']|\bos\.environ\[\s*["']([A-Z][A-Z0-9_]*)["']\s*\]|\bSystem\.getenv\(\s*"([A-Z][A-Z0-9_]*)""#),
+        (FlagProvider::Settings, CheckKind::Expression,
+         r#"\bsettings\.([A-Z][A-Z0-9_]*)\b"#),
+        (FlagProvider::Settings, CheckKind::Expression,
+         r#"\b(?:flag|switch|sample)_is_active\((?:\s*\w+\s*,)?\s*["']([\w.:-]+)["']"#),
+        (FlagProvider::Settings, CheckKind::Expression,
+         r#"\b(?:featureFlags|featureToggles)\.([A-Za-z]\w*)"#),
+        (FlagProvider::Settings, CheckKind::Annotation,
+         r#"@ConditionalOnProperty\([^)]*?\b(?:name|value)\s*=\s*\{?\s*"([\w.-]+)""#),
+    ];
+    patterns.iter()
+        .map(|(provider, kind, pattern)| (*provider, *kind, Regex::new(pattern).unwrap()))
+        .collect()
+});
+
+static IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z_$][\w$]*").unwrap()
+});
+
+/// `name = <check>` / `const name = await <check>`: the check's value is read through `name`
+static ASSIGNMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b([A-Za-z_$][\w$]*)\s*(?::\s*[\w<>\[\]]+\s*)?=\s*(?:await\s+)?$").unwrap()
+});
+
+static CONDITIONAL_PREFIX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\bif\b|\belif\b|\bwhile\b|\bwhen\b|&&|\?)[^;{}]*$").unwrap()
+});
+
+/// One check of a flag in the source
+#[derive(Debug, Clone)]
+struct FlagCheck {
+    name: String,
+    provider: FlagProvider,
+    file: String,
+    line: usize,
+}
+
+/// Byte range of a file that only runs when a flag is on
+#[derive(Debug, Clone)]
+struct GuardedRegion {
+    flag: String,
+    file: String,
+    start: usize,
+    end: usize,
+}
+
+/// Function or class whose reachability is judged
+#[derive(Debug, Clone)]
+struct CodeUnit {
+    name: String,
+    file: String,
+    line_start: usize,
+    line_end: usize,
+}
+
+/// Feature flag analyzer
+/// Inventories LaunchDarkly, Unleash, environment and settings flag checks, ties them to the
+/// segments they guard, and reports code that is only reachable with a flag switched on
+pub struct FeatureFlagAnalyzer {
+    config: FeatureFlagAnalysisConfig,
+    weight: f32,
+    custom_patterns: Vec<Regex>,
+}
+
+impl FeatureFlagAnalyzer {
+    pub fn new(config: FeatureFlagAnalysisConfig, weight: f32) -> Result<Self> {
+        let custom_patterns = config.custom_flag_patterns.iter()
+            .map(|pattern| Regex::new(pattern).with_context(|| format!("Invalid custom flag pattern '{}'", pattern)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { config, weight, custom_patterns })
+    }
+
+    /// Every flag checked in the project, with the code it guards
+    pub fn inventory(&self, index: &ProjectFileIndex) -> Result<Vec<FeatureFlag>> {
+        let sources: Vec<(String, String)> = index.with_extensions(FLAG_SOURCE_EXTENSIONS)
+            .filter_map(|file| fs::read_to_string(&file.path).ok().map(|content| (file.relative.clone(), content)))
+            .collect();
+
+        let mut checks = Vec::new();
+        let mut regions = Vec::new();
+        let mut annotated = Vec::new();
+        for (file, content) in &sources {
+            for (provider, kind, start, end, name) in self.find_checks(content) {
+                let line = line_of(content, start);
+                checks.push(FlagCheck { name: name.clone(), provider, file: file.clone(), line });
+                match kind {
+                    CheckKind::Annotation => annotated.push((name, file.clone(), declaration_line(content, end))),
+                    CheckKind::Expression => regions.extend(guarded_regions(content, start, end, file.ends_with(".py"))
+                        .into_iter()
+                        .map(|(start, end)| GuardedRegion { flag: name.clone(), file: file.clone(), start, end })),
+                }
+            }
+        }
+        if checks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let segments = index.segments()?;
+        let relative = |segment: &CodeSegment| segment.metadata.file_path.strip_prefix(index.root())
+            .unwrap_or(&segment.metadata.file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let units: Vec<CodeUnit> = segments.iter()
+            .filter_map(|segment| {
+                let name = match &segment.segment_type {
+                    SegmentType::Function(function) => &function.name,
+                    SegmentType::Class(class) => &class.name,
+                    _ => return None,
+                };
+                is_specific_name(name).then(|| CodeUnit {
+                    name: name.clone(),
+                    file: relative(segment),
+                    line_start: segment.metadata.line_start,
+                    line_end: segment.metadata.line_end,
+                })
+            })
+            .collect();
+
+        let flag_only = flag_only_units(&sources, &regions, &units);
+
+        let mut flags: BTreeMap<String, FeatureFlag> = BTreeMap::new();
+        for check in &checks {
+            let flag = flags.entry(check.name.clone()).or_insert_with(|| FeatureFlag {
+                name: check.name.clone(),
+                provider: check.provider.label().to_string(),
+                locations: Vec::new(),
+                guarded_segments: Vec::new(),
+                flag_only_components: Vec::new(),
+                likely_stale: false,
+                confidence: check.provider.confidence(),
+            });
+            flag.locations.push(format!("{}:{}", check.file, check.line));
+            if let Some(label) = enclosing_segment(segments, &check.file, check.line, &relative) {
+                push_unique(&mut flag.guarded_segments, label);
+            }
+        }
+        for (flag, unit) in flag_only {
+            if let Some(flag) = flags.get_mut(&flag) {
+                push_unique(&mut flag.flag_only_components, format!("{} ({}:{})", unit.name, unit.file, unit.line_start));
+            }
+        }
+        for (name, file, line) in annotated {
+            let unit = units.iter()
+                .filter(|unit| unit.file == file && (unit.line_start..=unit.line_end).contains(&line))
+                .max_by_key(|unit| unit.line_start);
+            if let (Some(flag), Some(unit)) = (flags.get_mut(&name), unit) {
+                push_unique(&mut flag.flag_only_components, format!("{} ({}:{})", unit.name, unit.file, unit.line_start));
+            }
+        }
+        for flag in flags.values_mut() {
+            flag.likely_stale = self.config.detect_stale_flags
+                && flag.locations.len() <= self.config.stale_reference_threshold;
+        }
+
+        Ok(flags.into_values().collect())
+    }
+
+    /// Flag checks in a file as (provider, kind, match start, match end, flag name)
+    fn find_checks(&self, content: &str) -> Vec<(FlagProvider, CheckKind, usize, usize, String)> {
+        let builtin = FLAG_PATTERNS.iter().map(|(provider, kind, regex)| (*provider, *kind, regex));
+        let custom = self.custom_patterns.iter().map(|regex| (FlagProvider::Custom, CheckKind::Expression, regex));
+
+        let mut seen = HashSet::new();
+        let mut checks = Vec::new();
+        for (provider, kind, regex) in builtin.chain(custom) {
+            for captures in regex.captures_iter(content) {
+                let (Some(whole), Some(name)) = (captures.get(0), captures.iter().skip(1).flatten().next()) else {
+                    continue;
+                };
+                let name = name.as_str();
+                let is_flag = match provider {
+                    FlagProvider::Environment => self.is_flag_env_var(name),
+                    FlagProvider::Settings if name.chars().all(|c| !c.is_ascii_lowercase()) => self.is_flag_env_var(name),
+                    _ => true,
+                };
+                if is_flag && seen.insert(whole.start()) {
+                    checks.push((provider, kind, whole.start(), whole.end(), name.to_string()));
+                }
+            }
+        }
+        checks.sort_by_key(|check| check.2);
+        checks
+    }
+
+    fn is_flag_env_var(&self, name: &str) -> bool {
+        let name = PUBLIC_ENV_PREFIXES.iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name);
+        self.config.env_flag_prefixes.iter().any(|prefix| name.starts_with(prefix.as_str()))
+    }
+
+    fn generate_analysis_results(&self, flags: Vec<FeatureFlag>) -> StatusAnalysisResult {
+        let mut incomplete_features = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+
+        let mut by_provider: BTreeMap<&str, usize> = BTreeMap::new();
+        for flag in &flags {
+            *by_provider.entry(flag.provider.as_str()).or_insert(0) += 1;
+        }
+        evidence.push(format!(
+            "Found {} feature flags ({})",
+            flags.len(),
+            by_provider.iter().map(|(provider, count)| format!("{}: {}", provider, count)).collect::<Vec<_>>().join(", "),
+        ));
+
+        for flag in &flags {
+            if !flag.flag_only_components.is_empty() {
+                incomplete_features.push(IncompleteFeature {
+                    feature_name: format!("Flagged feature: {}", flag.name),
+                    feature_category: "Feature Flag".to_string(),
+                    completeness_score: 0.5,
+                    missing_components: vec![format!("Rollout of flag {}", flag.name)],
+                    evidence: flag.flag_only_components.iter()
+                        .map(|component| format!("{} is only reachable with {} on", component, flag.name))
+                        .chain(flag.locations.iter().map(|location| format!("Checked at {}", location)))
+                        .collect(),
+                    recommendations: vec![
+                        format!("Finish the rollout of {} or remove the code behind it", flag.name),
+                        "Remove the flag check once the feature is generally available".to_string(),
+                    ],
+                    priority: InferencePriority::Medium,
+                });
+            }
+            if flag.likely_stale {
+                analysis_notes.push(format!(
+                    "Flag {} ({}) is only checked at {}; likely stale",
+                    flag.name, flag.provider, flag.locations.join(", "),
+                ));
+            }
+        }
+
+        let flagged = flags.iter().filter(|flag| !flag.flag_only_components.is_empty()).count();
+        let stale = flags.iter().filter(|flag| flag.likely_stale).count();
+        if flagged > 0 {
+            evidence.push(format!("{} flags guard code that is unreachable with the flag off", flagged));
+        }
+        if stale > 0 {
+            evidence.push(format!("{} flags are referenced in only one place", stale));
+        }
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            incomplete_features,
+            confidence: if flags.is_empty() { 0.3 } else { 0.75 },
+            feature_flags: flags,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatusAnalyzer for FeatureFlagAnalyzer {
+    fn name(&self) -> &str {
+        "Feature Flag Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn can_analyze_indexed(&self, index: &ProjectFileIndex) -> bool {
+        index.with_extensions(FLAG_SOURCE_EXTENSIONS).next().is_some()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Analyzing feature flags for project: {}", index.root().display());
+
+        let flags = self.inventory(index)?;
+        let result = self.generate_analysis_results(flags);
+
+        println!("Feature flag analysis completed: {} flags, {} flagged features, {:.1}% confidence",
+                 result.feature_flags.len(),
+                 result.incomplete_features.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Flag inventory of an indexed project for reports outside the status engine, using the
+/// `feature_flag_analysis` settings of the global analyzer config
+///
+/// A failure is recorded in `warnings` and leaves the inventory empty rather than failing the report.
+pub fn inventory_feature_flags(index: &ProjectFileIndex, warnings: &mut Vec<String>) -> Vec<FeatureFlag> {
+    let inventory = StatusInferenceConfig::from_global_config()
+        .and_then(|config| {
+            if !config.feature_flag_analysis.enable_flag_detection {
+                return Ok(Vec::new());
+            }
+            FeatureFlagAnalyzer::new(config.feature_flag_analysis, 0.0)?.inventory(index)
+        });
+    match inventory {
+        Ok(flags) => flags,
+        Err(e) => {
+            println!("Warning: Feature flag inventory skipped: {:#}", e);
+            warnings.push(format!("Feature flag inventory skipped: {:#}", e));
+            Vec::new()
+        }
+    }
+}
+
+/// Regions that run only when the check at `start..end` passes
+///
+/// A check inside a condition guards that condition's body; a check assigned to a variable guards
+/// the bodies of later conditions on that variable. Negated checks guard nothing.
+fn guarded_regions(content: &str, start: usize, end: usize, python: bool) -> Vec<(usize, usize)> {
+    let line_start = content[..start].rfind('\n').map_or(0, |position| position + 1);
+    let prefix = &content[line_start..start];
+
+    if let Some(assignment) = ASSIGNMENT.captures(prefix) {
+        let alias = &assignment[1];
+        let Ok(alias_regex) = Regex::new(&format!(r"\b{}\b", regex::escape(alias))) else {
+            return Vec::new();
+        };
+        return alias_regex.find_iter(&content[end..])
+            .filter_map(|found| conditional_body(content, end + found.start(), end + found.end(), python))
+            .collect();
+    }
+
+    conditional_body(content, start, end, python).into_iter().collect()
+}
+
+/// Body of the condition, `&&` operand or ternary branch the expression at `start..end` switches on
+fn conditional_body(content: &str, start: usize, end: usize, python: bool) -> Option<(usize, usize)> {
+    let line_start = content[..start].rfind('\n').map_or(0, |position| position + 1);
+    let line_end = content[end..].find('\n').map_or(content.len(), |position| end + position);
+    let prefix = &content[line_start..start];
+    let rest = &content[end..line_end];
+
+    if prefix.trim_end().ends_with('!') || prefix.trim_end().ends_with("not") {
+        return None;
+    }
+
+    if python {
+        let trimmed = prefix.trim_start();
+        if trimmed.starts_with("if ") || trimmed.starts_with("elif ") || trimmed.starts_with("while ") {
+            let indent = prefix.len() - trimmed.len();
+            let body_start = (line_end + 1).min(content.len());
+            let mut body_end = body_start;
+            for line in content[body_start..].split_inclusive('\n') {
+                let is_blank = line.trim().is_empty();
+                if !is_blank && line.len() - line.trim_start().len() <= indent {
+                    break;
+                }
+                body_end += line.len();
+            }
+            return (body_end > body_start).then_some((body_start, body_end));
+        }
+        return (rest.contains(" else ") || prefix.contains(" if ")).then_some((line_start, line_end));
+    }
+
+    let rest_trimmed = rest.trim_start();
+    if rest_trimmed.starts_with("&&") || rest_trimmed.starts_with('?') {
+        let operand = end + (rest.len() - rest_trimmed.len()) + if rest_trimmed.starts_with("&&") { 2 } else { 1 };
+        return Some((operand, expression_end(content, operand)));
+    }
+    if CONDITIONAL_PREFIX.is_match(prefix) {
+        if prefix.contains("&&") || prefix.trim_end().ends_with('?') {
+            return Some((end, expression_end(content, end)));
+        }
+        let after = &content[end..];
+        let brace = after.find('{')?;
+        if let Some(semicolon) = after[..brace].find(';') {
+            return Some((end, end + semicolon));
+        }
+        return Some((end + brace, block_end(content, end + brace)));
+    }
+    None
+}
+
+/// End of the expression starting at `start`: the first `;`, `:` or `,` outside brackets, or the
+/// bracket that closes the enclosing one
+fn expression_end(content: &str, start: usize) -> usize {
+    let mut depth = 0i32;
+    for (offset, c) in content[start..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth < 0 {
+                    return start + offset;
+                }
+            }
+            ';' | ':' | ',' if depth == 0 => return start + offset,
+            '\n' if depth == 0 && !content[start..start + offset].trim().is_empty() => return start + offset,
+            _ => {}
+        }
+    }
+    content.len()
+}
+
+/// Byte after the `}` matching the `{` at `open`
+fn block_end(content: &str, open: usize) -> usize {
+    let mut depth = 0i32;
+    for (offset, c) in content[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return open + offset + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    content.len()
+}
+
+/// Units referenced from guarded regions and nowhere else, paired with the guarding flag
+fn flag_only_units<'a>(
+    sources: &[(String, String)],
+    regions: &[GuardedRegion],
+    units: &'a [CodeUnit],
+) -> Vec<(String, &'a CodeUnit)> {
+    let mut guarded_references: Vec<(String, &CodeUnit)> = Vec::new();
+    let mut unit_names: HashMap<&str, Vec<&CodeUnit>> = HashMap::new();
+    for unit in units {
+        unit_names.entry(unit.name.as_str()).or_default().push(unit);
+    }
+    for region in regions {
+        let Some((_, content)) = sources.iter().find(|(file, _)| *file == region.file) else {
+            continue;
+        };
+        for identifier in IDENTIFIER.find_iter(&content[region.start..region.end]) {
+            for unit in unit_names.get(identifier.as_str()).into_iter().flatten() {
+                let defined_here = unit.file == region.file
+                    && (unit.line_start..=unit.line_end).contains(&line_of(content, region.start + identifier.start()));
+                if !defined_here && !guarded_references.iter().any(|(flag, known)| *flag == region.flag && std::ptr::eq(*known, *unit)) {
+                    guarded_references.push((region.flag.clone(), unit));
+                }
+            }
+        }
+    }
+    if guarded_references.is_empty() {
+        return Vec::new();
+    }
+
+    let candidates: HashSet<&str> = guarded_references.iter().map(|(_, unit)| unit.name.as_str()).collect();
+    let mut referenced_unguarded: HashSet<&str> = HashSet::new();
+    for (file, content) in sources {
+        let file_regions: Vec<&GuardedRegion> = regions.iter().filter(|region| region.file == *file).collect();
+        for identifier in IDENTIFIER.find_iter(content) {
+            let name = identifier.as_str();
+            if !candidates.contains(name) || referenced_unguarded.contains(name) {
+                continue;
+            }
+            if file_regions.iter().any(|region| (region.start..region.end).contains(&identifier.start())) {
+                continue;
+            }
+            let line = line_of(content, identifier.start());
+            let is_definition = unit_names[name].iter()
+                .any(|unit| unit.file == *file && (unit.line_start..=unit.line_end).contains(&line));
+            if !is_definition && !is_import_line(content, identifier.start()) {
+                referenced_unguarded.insert(name);
+            }
+        }
+    }
+
+    guarded_references.into_iter()
+        .filter(|(_, unit)| !referenced_unguarded.contains(unit.name.as_str()))
+        .collect()
+}
+
+/// Line of the declaration an annotation ending at `end` applies to, past any further annotations
+fn declaration_line(content: &str, end: usize) -> usize {
+    let mut line = line_of(content, end);
+    let next_line = content[end..].find('\n').map_or(content.len(), |position| end + position + 1);
+    for text in content[next_line..].lines() {
+        line += 1;
+        let text = text.trim_start();
+        if !text.is_empty() && !text.starts_with('@') {
+            break;
+        }
+    }
+    line
+}
+
+fn is_import_line(content: &str, position: usize) -> bool {
+    let line_start = content[..position].rfind('\n').map_or(0, |start| start + 1);
+    let line = content[line_start..].lines().next().unwrap_or_default().trim_start();
+    line.starts_with("import ")
+        || line.starts_with("from ")
+        || line.starts_with("export {")
+        || line.starts_with("export *")
+        || line.starts_with("module.exports")
+        || line.contains("require(")
+}
+
+/// Label of the innermost function, route or class containing a line
+fn enclosing_segment(
+    segments: &[CodeSegment],
+    file: &str,
+    line: usize,
+    relative: &impl Fn(&CodeSegment) -> String,
+) -> Option<String> {
+    segments.iter()
+        .filter(|segment| (segment.metadata.line_start..=segment.metadata.line_end).contains(&line))
+        .filter_map(|segment| {
+            let label = match &segment.segment_type {
+                SegmentType::Function(function) => format!("function {}", function.name),
+                SegmentType::Class(class) => format!("class {}", class.name),
+                SegmentType::Route(route) => format!("route {} {}", route.method.to_uppercase(), route.path),
+                _ => return None,
+            };
+            Some((segment, label))
+        })
+        .filter(|(segment, _)| relative(segment) == file)
+        .min_by_key(|(segment, _)| segment.metadata.line_end - segment.metadata.line_start)
+        .map(|(segment, label)| format!("{} ({}:{})", label, file, segment.metadata.line_start))
+}
+
+fn is_specific_name(name: &str) -> bool {
+    name.len() >= 3 && !name.starts_with('_') && !GENERIC_NAMES.contains(&name)
+}
+
+fn push_unique(items: &mut Vec<String>, item: String) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::write;
+    use tempfile::TempDir;
+
+    fn inventory(root: &Path) -> Vec<FeatureFlag> {
+        FeatureFlagAnalyzer::new(FeatureFlagAnalysisConfig::default(), 0.1).unwrap()
+            .inventory(&ProjectFileIndex::build(root))
+            .unwrap()
+    }
+
+    fn flag<'a>(flags: &'a [FeatureFlag], name: &str) -> &'a FeatureFlag {
+        flags.iter().find(|flag| flag.name == name).unwrap_or_else(|| panic!("flag {} not found", name))
+    }
+
+    #[test]
+    fn test_flag_only_components_and_stale_flags_in_typescript() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/Checkout.tsx", r#"import { useFlag } from '@unleash/proxy-client-react';
+import { NewCheckout } from './NewCheckout';
+import { LegacyCheckout } from './LegacyCheckout';
+
+export function Checkout() {
+  const newCheckout = useFlag('new-checkout');
+  if (newCheckout) {
+    return <NewCheckout />;
+  }
+  return <LegacyCheckout />;
+}
+"#);
+        write(temp_dir.path(), "src/NewCheckout.tsx", "export function NewCheckout() {\n  return <div>New</div>;\n}\n");
+        write(temp_dir.path(), "src/LegacyCheckout.tsx", "export function LegacyCheckout() {\n  return <div>Old</div>;\n}\n");
+        write(temp_dir.path(), "src/server.ts", r#"export function startServer() {
+  if (process.env.FEATURE_AUDIT_LOG === 'true') {
+    enableAuditLog();
+  }
+  const port = process.env.PORT;
+}
+
+export function enableAuditLog() {
+  return true;
+}
+
+export function healthCheck() {
+  enableAuditLog();
+  return process.env.FEATURE_AUDIT_LOG;
+}
+"#);
+
+        let flags = inventory(temp_dir.path());
+        assert_eq!(flags.iter().map(|flag| flag.name.as_str()).collect::<Vec<_>>(), vec!["FEATURE_AUDIT_LOG", "new-checkout"]);
+
+        let checkout = flag(&flags, "new-checkout");
+        assert_eq!(checkout.provider, "Unleash");
+        assert_eq!(checkout.flag_only_components, vec!["NewCheckout (src/NewCheckout.tsx:1)".to_string()]);
+        assert_eq!(checkout.guarded_segments, vec!["function Checkout (src/Checkout.tsx:5)".to_string()]);
+        assert!(checkout.likely_stale);
+
+        let audit = flag(&flags, "FEATURE_AUDIT_LOG");
+        assert_eq!(audit.provider, "Environment");
+        assert_eq!(audit.locations, vec!["src/server.ts:2".to_string(), "src/server.ts:14".to_string()]);
+        assert!(audit.flag_only_components.is_empty());
+        assert!(!audit.likely_stale);
+    }
+
+    #[test]
+    fn test_python_and_java_flags_flow_into_results() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "app/views.py", r#"from django.conf import settings
+from waffle import flag_is_active
+
+def dashboard(request):
+    if flag_is_active(request, "beta_reports"):
+        return render_beta_reports(request)
+    if not settings.FEATURE_LEGACY_EXPORT:
+        return None
+    return export_rows(request)
+
+def render_beta_reports(request):
+    return {"reports": []}
+
+def export_rows(request):
+    return []
+"#);
+        write(temp_dir.path(), "src/main/java/com/shop/LoyaltyConfig.java", r#"package com.shop;
+
+@Configuration
+@ConditionalOnProperty(name = "features.loyalty", havingValue = "true")
+public class LoyaltyConfig {
+    public LoyaltyService loyaltyService() {
+        return new LoyaltyService();
+    }
+}
+"#);
+
+        let analyzer = FeatureFlagAnalyzer::new(FeatureFlagAnalysisConfig::default(), 0.1).unwrap();
+        let flags = analyzer.inventory(&ProjectFileIndex::build(temp_dir.path())).unwrap();
+
+        assert_eq!(flag(&flags, "beta_reports").flag_only_components, vec!["render_beta_reports (app/views.py:11)".to_string()]);
+        assert!(flag(&flags, "FEATURE_LEGACY_EXPORT").flag_only_components.is_empty());
+        assert_eq!(flag(&flags, "features.loyalty").flag_only_components, vec!["LoyaltyConfig (src/main/java/com/shop/LoyaltyConfig.java:3)".to_string()]);
+
+        let result = analyzer.generate_analysis_results(flags);
+        assert_eq!(result.feature_flags.len(), 3);
+        assert_eq!(
+            result.incomplete_features.iter().map(|feature| feature.feature_name.as_str()).collect::<Vec<_>>(),
+            vec!["Flagged feature: beta_reports", "Flagged feature: features.loyalty"]
+        );
+        assert_eq!(result.analysis_notes.len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_custom_pattern_is_rejected() {
+        let config = FeatureFlagAnalysisConfig {
+            custom_flag_patterns: vec![r#"flags\.isOn\(("([\w-]+)""#.to_string()],
+            ..FeatureFlagAnalysisConfig::default()
+        };
+        assert!(FeatureFlagAnalyzer::new(config, 0.1).is_err());
+    }
+
+    #[test]
+    fn test_report_inventory_uses_global_config_and_shared_index() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/server.ts", "export function start() {\n  if (process.env.FF_FAST_SYNC) {\n    sync();\n  }\n}\n");
+
+        let mut warnings = Vec::new();
+        let flags = inventory_feature_flags(&ProjectFileIndex::build(temp_dir.path()), &mut warnings);
+        assert_eq!(flags.iter().map(|flag| flag.name.as_str()).collect::<Vec<_>>(), vec!["FF_FAST_SYNC"]);
+        assert!(warnings.is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+
+use crate::core::ast_analyzer::{ASTAnalyzer, CodeSegment};
+use crate::core::project_walker::ProjectWalker;
+
+/// A file found while indexing the project
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    pub path: PathBuf,
+    /// Path relative to the project root, `/`-separated
+    pub relative: String,
+    /// Lowercase extension without the dot, empty when the file has none
+    pub extension: String,
+    pub size: u64,
+}
+
+/// Files of a project, walked once and shared by every status analyzer
+///
+/// AST segments are extracted lazily on first request and then reused, so analyzers that work on
+/// segments (API, feature) parse the project once between them.
+#[derive(Debug)]
+pub struct ProjectFileIndex {
+    root: PathBuf,
+    files: Vec<IndexedFile>,
+    segments: OnceLock<std::result::Result<Vec<CodeSegment>, String>>,
+}
+
+impl ProjectFileIndex {
+    /// Walk the project, skipping ignored, vendored and generated files
+    pub fn build(root: &Path) -> Self {
+        let files = ProjectWalker::new(root)
+            .files()
+            .map(|path| IndexedFile {
+                relative: path.strip_prefix(root)
+                    .unwrap_or(&path)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                extension: path.extension()
+                    .map(|ext| ext.to_string_lossy().to_lowercase())
+                    .unwrap_or_default(),
+                size: path.metadata().map(|metadata| metadata.len()).unwrap_or(0),
+                path,
+            })
+            .collect();
+
+        Self {
+            root: root.to_path_buf(),
+            files,
+            segments: OnceLock::new(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn files(&self) -> &[IndexedFile] {
+        &self.files
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Files whose extension is one of `extensions`
+    pub fn with_extensions<'a>(&'a self, extensions: &'a [&str]) -> impl Iterator<Item = &'a IndexedFile> + 'a {
+        self.files.iter().filter(move |file| extensions.contains(&file.extension.as_str()))
+    }
+
+    /// AST segments of every indexed source file, extracted on first use
+    pub fn segments(&self) -> Result<&[CodeSegment]> {
+        self.segments
+            .get_or_init(|| {
+                let paths: Vec<PathBuf> = self.files.iter().map(|file| file.path.clone()).collect();
+                ASTAnalyzer::new()
+                    .and_then(|mut analyzer| analyzer.extract_segments_from_files(&paths, &[]))
+                    .map_err(|error| format!("{:#}", error))
+            })
+            .as_deref()
+            .map_err(|error| anyhow!("Failed to extract code segments: {}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_index_skips_dependency_dirs_and_caches_segments() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |relative: &str, content: &str| {
+            let path = temp_dir.path().join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("src/users.ts", "export function listUsers() { return []; }\n");
+        write("README.md", "# Project\n");
+        write("node_modules/lib/index.js", "function vendored() {}\n");
+
+        let index = ProjectFileIndex::build(temp_dir.path());
+        let mut relative: Vec<&str> = index.files().iter().map(|file| file.relative.as_str()).collect();
+        relative.sort();
+        assert_eq!(relative, vec!["README.md", "src/users.ts"]);
+        assert_eq!(index.with_extensions(&["ts", "js"]).count(), 1);
+
+        let first = index.segments().unwrap();
+        assert!(!first.is_empty());
+        assert!(first.iter().all(|segment| !segment.metadata.file_path.to_string_lossy().contains("node_modules")));
+        assert!(std::ptr::eq(first, index.segments().unwrap()));
+    }
+}
@@ -1,5 +1,26 @@
+pub mod api_analyzer;
+pub mod auth_analyzer;
+pub(crate) mod common;
 pub mod crud_analyzer;
+pub mod database_analyzer;
+pub mod feature_analyzer;
+pub mod feature_flag_analyzer;
+pub mod file_index;
+pub mod registry;
+pub mod rule_pack_analyzer;
+pub mod test_coverage_analyzer;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod cross_repository_analyzer;
 
+pub use api_analyzer::ApiAnalyzer;
+pub use auth_analyzer::AuthAnalyzer;
 pub use crud_analyzer::CrudAnalyzer;
+pub use database_analyzer::DatabaseAnalyzer;
+pub use feature_analyzer::FeatureAnalyzer;
+pub use feature_flag_analyzer::FeatureFlagAnalyzer;
+pub use file_index::{IndexedFile, ProjectFileIndex};
+pub use registry::{register_status_analyzer, StatusAnalyzerFactory, StatusAnalyzerRegistry};
+pub use rule_pack_analyzer::RulePackAnalyzer;
+pub use test_coverage_analyzer::TestCoverageAnalyzer;
 pub use cross_repository_analyzer::{CrossRepositoryAnalyzer, CrossRepositoryAnalysisResult, ProjectRelationship, ParentProjectContext};
\ No newline at end of file
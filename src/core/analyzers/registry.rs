@@ -0,0 +1,287 @@
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+
+use crate::core::analyzers::{ApiAnalyzer, AuthAnalyzer, CrudAnalyzer, DatabaseAnalyzer, FeatureAnalyzer, FeatureFlagAnalyzer, RulePackAnalyzer, TestCoverageAnalyzer};
+use crate::core::status_inference_engine::{StatusAnalyzer, StatusInferenceConfig};
+
+/// Builds zero or more analyzers for an engine from its configuration
+pub type StatusAnalyzerFactory =
+    Arc<dyn Fn(&StatusInferenceConfig) -> Result<Vec<Box<dyn StatusAnalyzer>>> + Send + Sync>;
+
+/// Process-wide registry used by `StatusInferenceEngine::with_config`
+static GLOBAL_REGISTRY: Lazy<RwLock<StatusAnalyzerRegistry>> =
+    Lazy::new(|| RwLock::new(StatusAnalyzerRegistry::with_builtin_analyzers()));
+
+/// Ordered, named set of analyzer factories
+///
+/// Downstream crates add their own `StatusAnalyzer` implementations either to a registry passed to
+/// `StatusInferenceEngine::with_registry`, or process-wide through `register_status_analyzer`.
+#[derive(Clone, Default)]
+pub struct StatusAnalyzerRegistry {
+    factories: Vec<(String, StatusAnalyzerFactory)>,
+}
+
+impl StatusAnalyzerRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the built-in CRUD, auth, API, database, feature, test coverage and feature
+    /// flag analyzers plus the configured rule packs
+    pub fn with_builtin_analyzers() -> Self {
+        let mut registry = Self::new();
+
+        registry.register("crud", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.crud_analysis.enable_crud_detection, || {
+                CrudAnalyzer::new(config.crud_analysis.clone(), config.plugins.weight_crud_analyzer)
+            }))
+        });
+        registry.register("auth", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.auth_analysis.enable_auth_detection, || {
+                AuthAnalyzer::new(config.auth_analysis.clone(), config.plugins.weight_auth_analyzer)
+            }))
+        });
+        registry.register("api", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.api_analysis.enable_api_completeness, || {
+                ApiAnalyzer::new(config.api_analysis.clone(), config.plugins.weight_api_analyzer)
+            }))
+        });
+        registry.register("database", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.database_analysis.enable_schema_analysis, || {
+                DatabaseAnalyzer::new(config.database_analysis.clone(), config.plugins.weight_database_analyzer)
+            }))
+        });
+        registry.register("feature", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.feature_analysis.enable_feature_tracking, || {
+                FeatureAnalyzer::new(config.feature_analysis.clone(), config.plugins.weight_feature_analyzer)
+            }))
+        });
+        registry.register("test_coverage", |config: &StatusInferenceConfig| {
+            Ok(enabled(config.test_coverage_analysis.enable_test_mapping || config.test_coverage_analysis.ingest_coverage_reports, || {
+                TestCoverageAnalyzer::new(config.test_coverage_analysis.clone(), config.plugins.weight_test_coverage_analyzer)
+            }))
+        });
+        registry.register("feature_flags", |config: &StatusInferenceConfig| {
+            if !config.feature_flag_analysis.enable_flag_detection {
+                return Ok(Vec::new());
+            }
+            let analyzer = FeatureFlagAnalyzer::new(config.feature_flag_analysis.clone(), config.plugins.weight_feature_flag_analyzer)?;
+            Ok(vec![Box::new(analyzer) as Box<dyn StatusAnalyzer>])
+        });
+        registry.register("rule_packs", |config: &StatusInferenceConfig| {
+            Ok(config.rule_packs.iter()
+                .filter(|pack| pack.enabled)
+                .filter_map(|pack| match RulePackAnalyzer::new(pack.clone()) {
+                    Ok(analyzer) => Some(Box::new(analyzer) as Box<dyn StatusAnalyzer>),
+                    Err(e) => {
+                        println!("Warning: Skipping invalid rule pack '{}': {:#}", pack.name, e);
+                        None
+                    }
+                })
+                .collect())
+        });
+
+        registry
+    }
+
+    /// Register a factory under `name`, replacing any factory already registered with that name
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+    where
+        F: Fn(&StatusInferenceConfig) -> Result<Vec<Box<dyn StatusAnalyzer>>> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let factory: StatusAnalyzerFactory = Arc::new(factory);
+        match self.factories.iter_mut().find(|(existing, _)| *existing == name) {
+            Some(entry) => entry.1 = factory,
+            None => self.factories.push((name, factory)),
+        }
+        self
+    }
+
+    /// Register a ready-made analyzer under its own name; each engine gets a clone
+    pub fn register_analyzer<A>(&mut self, analyzer: A) -> &mut Self
+    where
+        A: StatusAnalyzer + Clone + 'static,
+    {
+        let name = analyzer.name().to_string();
+        self.register(name, move |_: &StatusInferenceConfig| {
+            Ok(vec![Box::new(analyzer.clone()) as Box<dyn StatusAnalyzer>])
+        })
+    }
+
+    /// Remove the factory registered under `name`
+    pub fn unregister(&mut self, name: &str) -> bool {
+        let before = self.factories.len();
+        self.factories.retain(|(existing, _)| existing != name);
+        self.factories.len() != before
+    }
+
+    /// Registered factory names, in registration order
+    pub fn names(&self) -> Vec<&str> {
+        self.factories.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Instantiate every registered analyzer for the given configuration
+    pub fn build(&self, config: &StatusInferenceConfig) -> Result<Vec<Box<dyn StatusAnalyzer>>> {
+        let mut analyzers = Vec::new();
+        for (name, factory) in &self.factories {
+            analyzers.extend(factory(config)
+                .with_context(|| format!("Failed to create status analyzer '{}'", name))?);
+        }
+        Ok(analyzers)
+    }
+}
+
+fn enabled<A, F>(is_enabled: bool, create: F) -> Vec<Box<dyn StatusAnalyzer>>
+where
+    A: StatusAnalyzer + 'static,
+    F: FnOnce() -> A,
+{
+    if is_enabled {
+        vec![Box::new(create())]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Register a factory in the process-wide registry used by `StatusInferenceEngine::with_config`
+pub fn register_status_analyzer<F>(name: impl Into<String>, factory: F)
+where
+    F: Fn(&StatusInferenceConfig) -> Result<Vec<Box<dyn StatusAnalyzer>>> + Send + Sync + 'static,
+{
+    GLOBAL_REGISTRY.write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .register(name, factory);
+}
+
+/// Snapshot of the process-wide registry
+pub fn global_registry() -> StatusAnalyzerRegistry {
+    GLOBAL_REGISTRY.read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+    use crate::core::status_inference_engine::{StatusAnalysisResult, StatusInferenceEngine};
+    use crate::core::todo_scanner::TodoScanResult;
+
+    #[derive(Clone)]
+    struct AuditAnalyzer;
+
+    impl StatusAnalyzer for AuditAnalyzer {
+        fn name(&self) -> &str {
+            "Audit Analyzer"
+        }
+
+        fn analyze(
+            &self,
+            _project_path: &Path,
+            _documentation: &ExtractedDocumentationInfo,
+            _todo_results: &TodoScanResult,
+            _config: &StatusInferenceConfig,
+        ) -> Result<StatusAnalysisResult> {
+            Ok(StatusAnalysisResult {
+                analyzer_name: self.name().to_string(),
+                confidence: 1.0,
+                ..Default::default()
+            })
+        }
+
+        fn weight(&self) -> f32 {
+            0.1
+        }
+
+        fn can_analyze(&self, _project_path: &Path) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_registry_builds_builtin_and_custom_analyzers() {
+        let mut registry = StatusAnalyzerRegistry::with_builtin_analyzers();
+        registry.register_analyzer(AuditAnalyzer);
+        assert!(registry.unregister("crud"));
+        assert!(!registry.unregister("crud"));
+
+        let mut config = StatusInferenceConfig::default();
+        config.feature_analysis.enable_feature_tracking = false;
+
+        let engine = StatusInferenceEngine::with_registry(config, &registry).unwrap();
+        assert_eq!(
+            engine.analyzer_names(),
+            vec!["Auth Analyzer", "API Analyzer", "Database Analyzer", "Test Coverage Analyzer", "Feature Flag Analyzer", "Audit Analyzer"]
+        );
+    }
+
+    #[test]
+    fn test_rule_packs_loaded_from_analyzer_config() {
+        let shipped = StatusInferenceConfig::from_yaml_file(Path::new(crate::core::config::ANALYZER_CONFIG_FILE)).unwrap();
+        assert!(shipped.rule_packs.is_empty());
+        assert!((shipped.plugins.weight_crud_analyzer - 0.25).abs() < f32::EPSILON);
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("analyzer_config.yaml");
+        std::fs::write(&config_path, r#"
+status_inference:
+  enable_plugin_analyzers: true
+  rule_packs:
+    - name: billing
+      rules:
+        - id: audit
+          require:
+            regex: 'audit\.emit'
+          finding:
+            implementation_type: Audit Event
+            description: "{file} does not emit audit events"
+dual_category_analysis:
+  crud_analysis:
+    enable_crud_detection: false
+    required_operations: []
+    partial_implementation_threshold: 0.5
+    analyze_database_models: false
+    analyze_api_endpoints: false
+    analyze_frontend_forms: false
+"#).unwrap();
+
+        let config = StatusInferenceConfig::from_yaml_file(&config_path).unwrap();
+        let engine = StatusInferenceEngine::with_registry(config, &StatusAnalyzerRegistry::with_builtin_analyzers()).unwrap();
+        let names = engine.analyzer_names();
+        assert!(!names.contains(&"CRUD Analyzer"));
+        assert_eq!(names.last(), Some(&"Rule Pack: billing"));
+    }
+
+    #[test]
+    fn test_invalid_rule_pack_is_skipped() {
+        let rule_packs = serde_yaml::from_str(r#"
+- name: broken
+  rules:
+    - id: unclosed
+      require:
+        regex: 'audit\.emit('
+      finding:
+        implementation_type: Audit Event
+        description: "{file} does not emit audit events"
+- name: billing
+  rules:
+    - id: audit
+      require:
+        regex: 'audit\.emit'
+      finding:
+        implementation_type: Audit Event
+        description: "{file} does not emit audit events"
+"#).unwrap();
+        let config = StatusInferenceConfig { rule_packs, ..Default::default() };
+
+        let engine = StatusInferenceEngine::with_registry(config, &StatusAnalyzerRegistry::with_builtin_analyzers()).unwrap();
+        let names = engine.analyzer_names();
+        assert!(!names.contains(&"Rule Pack: broken"));
+        assert_eq!(names.last(), Some(&"Rule Pack: billing"));
+    }
+}
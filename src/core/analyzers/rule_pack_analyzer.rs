@@ -0,0 +1,588 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use regex::Regex;
+use tree_sitter::{Language, Parser, Query, QueryCursor, Tree};
+
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, RulePackConfig, RuleConfig,
+    RulePattern, RuleFindingKind, MissingImplementation, PartialImplementation
+};
+use crate::core::analyzers::common::line_of;
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Files larger than this are not evaluated
+const MAX_RULE_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Files a rule applies to when it lists no `files` globs
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "py", "java", "kt", "rs", "go", "rb", "cs", "php",
+];
+
+/// Grammars available to tree-sitter query patterns
+const QUERY_LANGUAGES: &[&str] = &["typescript", "tsx", "javascript", "python", "java", "rust", "go"];
+
+fn query_language(name: &str) -> Option<Language> {
+    match name {
+        "typescript" => Some(tree_sitter_typescript::language_typescript()),
+        "tsx" => Some(tree_sitter_typescript::language_tsx()),
+        "javascript" => Some(tree_sitter_javascript::language()),
+        "python" => Some(tree_sitter_python::language()),
+        "java" => Some(tree_sitter_java::language()),
+        "rust" => Some(tree_sitter_rust::language()),
+        "go" => Some(tree_sitter_go::language()),
+        _ => None,
+    }
+}
+
+fn language_for_extension(extension: &str) -> Option<&'static str> {
+    match extension {
+        "ts" | "mts" | "cts" => Some("typescript"),
+        "tsx" => Some("tsx"),
+        "js" | "jsx" | "mjs" | "cjs" => Some("javascript"),
+        "py" => Some("python"),
+        "java" => Some("java"),
+        "rs" => Some("rust"),
+        "go" => Some("go"),
+        _ => None,
+    }
+}
+
+/// Regex, or tree-sitter query compiled for every grammar it is valid in
+enum CompiledPattern {
+    Regex(Regex),
+    Query(HashMap<&'static str, Query>),
+}
+
+impl CompiledPattern {
+    fn compile(pattern: &RulePattern) -> Result<Self> {
+        match (&pattern.regex, &pattern.query) {
+            (Some(regex), None) => Ok(Self::Regex(Regex::new(regex).with_context(|| format!("Invalid regex '{}'", regex))?)),
+            (None, Some(source)) => {
+                let languages: Vec<&'static str> = match &pattern.language {
+                    Some(language) => vec![QUERY_LANGUAGES.iter().copied()
+                        .find(|known| known.eq_ignore_ascii_case(language))
+                        .ok_or_else(|| anyhow!("Unsupported query language '{}'", language))?],
+                    None => QUERY_LANGUAGES.to_vec(),
+                };
+
+                let mut queries = HashMap::new();
+                let mut last_error = None;
+                for language in languages {
+                    match query_language(language).map(|grammar| Query::new(grammar, source)) {
+                        Some(Ok(query)) => { queries.insert(language, query); }
+                        Some(Err(error)) => last_error = Some(format!("{:?}", error)),
+                        None => {}
+                    }
+                }
+                if queries.is_empty() {
+                    bail!("Invalid tree-sitter query '{}': {}", source, last_error.unwrap_or_default());
+                }
+                Ok(Self::Query(queries))
+            }
+            _ => bail!("A pattern needs exactly one of `regex` or `query`"),
+        }
+    }
+
+    fn uses_language(&self, language: Option<&str>) -> bool {
+        match self {
+            Self::Regex(_) => true,
+            Self::Query(queries) => language.is_some_and(|language| queries.contains_key(language)),
+        }
+    }
+}
+
+/// Region of a file a rule checks, e.g. one handler function
+#[derive(Debug, Clone)]
+struct Subject {
+    start: usize,
+    end: usize,
+    line: usize,
+    name: Option<String>,
+}
+
+/// A rule with its patterns and file globs compiled
+struct CompiledRule {
+    config: RuleConfig,
+    files: Vec<Regex>,
+    exclude: Vec<Regex>,
+    select: Option<CompiledPattern>,
+    require: Option<CompiledPattern>,
+    forbid: Option<CompiledPattern>,
+}
+
+impl CompiledRule {
+    fn compile(config: RuleConfig) -> Result<Self> {
+        let compile_pattern = |pattern: &Option<RulePattern>| pattern.as_ref().map(CompiledPattern::compile).transpose();
+        Ok(Self {
+            files: config.files.iter().map(|glob| glob_to_regex(glob)).collect::<Result<_>>()?,
+            exclude: config.exclude.iter().map(|glob| glob_to_regex(glob)).collect::<Result<_>>()?,
+            select: compile_pattern(&config.select).context("Invalid `select` pattern")?,
+            require: compile_pattern(&config.require).context("Invalid `require` pattern")?,
+            forbid: compile_pattern(&config.forbid).context("Invalid `forbid` pattern")?,
+            config,
+        })
+    }
+
+    fn applies_to(&self, relative: &str) -> bool {
+        let included = if self.files.is_empty() {
+            relative.rsplit_once('.').is_some_and(|(_, extension)| SOURCE_EXTENSIONS.contains(&extension))
+        } else {
+            self.files.iter().any(|glob| glob.is_match(relative))
+        };
+        included && !self.exclude.iter().any(|glob| glob.is_match(relative))
+    }
+
+    fn needs_tree(&self) -> bool {
+        [&self.select, &self.require, &self.forbid].into_iter()
+            .any(|pattern| matches!(pattern, Some(CompiledPattern::Query(_))))
+    }
+}
+
+/// Outcome of checking one subject
+struct Evaluation {
+    file: String,
+    subject: Subject,
+    snippet: String,
+    violated: bool,
+}
+
+/// Declarative analyzer driven by a `status_inference.rule_packs` entry
+///
+/// Each rule selects subjects (a regex match up to the next match, a tree-sitter query capture,
+/// or the whole file) in the files its globs match, and reports the subjects that lack a
+/// `require` pattern or contain a `forbid` pattern using the rule's finding template.
+pub struct RulePackAnalyzer {
+    name: String,
+    pack: RulePackConfig,
+    rules: Vec<CompiledRule>,
+}
+
+impl RulePackAnalyzer {
+    pub fn new(pack: RulePackConfig) -> Result<Self> {
+        let rules = pack.rules.iter()
+            .map(|rule| CompiledRule::compile(rule.clone()).with_context(|| format!("Invalid rule '{}'", rule.id)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: format!("Rule Pack: {}", pack.name),
+            pack,
+            rules,
+        })
+    }
+
+    /// Evaluate every rule against the project's files
+    fn evaluate(&self, index: &ProjectFileIndex) -> Vec<Vec<Evaluation>> {
+        let mut evaluations: Vec<Vec<Evaluation>> = self.rules.iter().map(|_| Vec::new()).collect();
+        let mut parser = Parser::new();
+
+        for file in index.files().iter().filter(|file| file.size <= MAX_RULE_FILE_SIZE) {
+            let relative = &file.relative;
+            let applicable: Vec<usize> = (0..self.rules.len()).filter(|&index| self.rules[index].applies_to(relative)).collect();
+            if applicable.is_empty() {
+                continue;
+            }
+            let Ok(source) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+
+            let language = relative.rsplit_once('.').and_then(|(_, extension)| language_for_extension(extension));
+            let mut tree: Option<Option<Tree>> = None;
+
+            for index in applicable {
+                let rule = &self.rules[index];
+                if rule.needs_tree() && tree.is_none() {
+                    tree = Some(language.and_then(query_language).and_then(|grammar| {
+                        parser.set_language(grammar).ok()?;
+                        parser.parse(&source, None)
+                    }));
+                }
+                let tree = tree.as_ref().and_then(|tree| tree.as_ref());
+                evaluations[index].extend(evaluate_rule(rule, relative, &source, language, tree));
+            }
+        }
+
+        evaluations
+    }
+
+    fn generate_analysis_results(&self, evaluations: Vec<Vec<Evaluation>>) -> StatusAnalysisResult {
+        let mut missing_implementations = Vec::new();
+        let mut partial_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+
+        for (rule, evaluations) in self.rules.iter().zip(evaluations) {
+            let config = &rule.config;
+            let violations: Vec<&Evaluation> = evaluations.iter().filter(|evaluation| evaluation.violated).collect();
+            analysis_notes.push(format!("Rule {}: {} subjects checked, {} violations", config.id, evaluations.len(), violations.len()));
+            if violations.is_empty() {
+                continue;
+            }
+
+            let template = &config.finding;
+            match template.kind {
+                RuleFindingKind::Missing => {
+                    for violation in &violations {
+                        let render = |text: &str| self.render(text, config, violation);
+                        missing_implementations.push(MissingImplementation {
+                            implementation_type: template.implementation_type.clone(),
+                            expected_location: template.expected_location.as_deref()
+                                .map(render)
+                                .unwrap_or_else(|| format!("{}:{}", violation.file, violation.subject.line)),
+                            description: render(&template.description),
+                            confidence: template.confidence,
+                            evidence: vec![format!("{}:{}: {}", violation.file, violation.subject.line, violation.snippet)],
+                            suggested_implementation: template.suggestion.as_deref().map(render),
+                        });
+                    }
+                }
+                RuleFindingKind::Partial => {
+                    let label = |evaluation: &Evaluation| match &evaluation.subject.name {
+                        Some(name) => format!("{} ({}:{})", name, evaluation.file, evaluation.subject.line),
+                        None => format!("{}:{}", evaluation.file, evaluation.subject.line),
+                    };
+                    let next_steps: BTreeSet<String> = violations.iter()
+                        .filter_map(|violation| template.suggestion.as_deref().map(|text| self.render(text, config, violation)))
+                        .collect();
+                    partial_implementations.push(PartialImplementation {
+                        implementation_name: config.description.clone().unwrap_or_else(|| config.id.clone()),
+                        implemented_parts: evaluations.iter().filter(|evaluation| !evaluation.violated).map(label).collect(),
+                        missing_parts: violations.iter().map(|violation| label(violation)).collect(),
+                        completeness_percentage: (evaluations.len() - violations.len()) as f32 / evaluations.len() as f32 * 100.0,
+                        category: self.pack.name.clone(),
+                        evidence: violations.iter()
+                            .map(|violation| format!("{}:{}: {}", violation.file, violation.subject.line, self.render(&template.description, config, violation)))
+                            .collect(),
+                        next_steps: next_steps.into_iter().collect(),
+                    });
+                }
+            }
+
+            evidence.push(format!("Rule {} violated {} times", config.id, violations.len()));
+        }
+
+        let checked: usize = analysis_notes.len();
+        let confidence = if self.rules.is_empty() || checked == 0 {
+            0.3
+        } else {
+            self.rules.iter().map(|rule| rule.config.finding.confidence).sum::<f32>() / self.rules.len() as f32
+        };
+
+        StatusAnalysisResult {
+            analyzer_name: self.name.clone(),
+            missing_implementations,
+            partial_implementations,
+            confidence,
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+
+    /// Fill `{pack}`, `{rule}`, `{file}`, `{line}`, `{name}` and `{match}` placeholders
+    fn render(&self, template: &str, rule: &RuleConfig, evaluation: &Evaluation) -> String {
+        template
+            .replace("{pack}", &self.pack.name)
+            .replace("{rule}", &rule.id)
+            .replace("{file}", &evaluation.file)
+            .replace("{line}", &evaluation.subject.line.to_string())
+            .replace("{name}", evaluation.subject.name.as_deref().unwrap_or("subject"))
+            .replace("{match}", &evaluation.snippet)
+    }
+}
+
+impl StatusAnalyzer for RulePackAnalyzer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn weight(&self) -> f32 {
+        self.pack.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir() && !self.rules.is_empty()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Evaluating rule pack '{}' for project: {}", self.pack.name, index.root().display());
+
+        let result = self.generate_analysis_results(self.evaluate(index));
+
+        println!("Rule pack '{}' completed: {} items found",
+                 self.pack.name,
+                 result.missing_implementations.len() + result.partial_implementations.len());
+
+        Ok(result)
+    }
+}
+
+/// Check one rule against one file
+fn evaluate_rule(rule: &CompiledRule, file: &str, source: &str, language: Option<&str>, tree: Option<&Tree>) -> Vec<Evaluation> {
+    // Query patterns only apply to files in a grammar they compiled for
+    let patterns = [&rule.select, &rule.require, &rule.forbid];
+    if patterns.iter().any(|pattern| pattern.as_ref().is_some_and(|pattern| !pattern.uses_language(language))) {
+        return Vec::new();
+    }
+
+    let subjects = match &rule.select {
+        None => vec![Subject { start: 0, end: source.len(), line: 1, name: None }],
+        Some(pattern) => select_subjects(pattern, source, language, tree),
+    };
+
+    subjects.into_iter()
+        .map(|subject| {
+            let missing_required = rule.require.as_ref()
+                .is_some_and(|pattern| !pattern_matches(pattern, source, &subject, language, tree));
+            let has_forbidden = rule.forbid.as_ref()
+                .is_some_and(|pattern| pattern_matches(pattern, source, &subject, language, tree));
+            let unconditional = rule.require.is_none() && rule.forbid.is_none();
+            let snippet = source[subject.start..subject.end].lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or_default()
+                .to_string();
+
+            Evaluation {
+                file: file.to_string(),
+                violated: missing_required || has_forbidden || unconditional,
+                subject,
+                snippet,
+            }
+        })
+        .collect()
+}
+
+fn select_subjects(pattern: &CompiledPattern, source: &str, language: Option<&str>, tree: Option<&Tree>) -> Vec<Subject> {
+    match pattern {
+        CompiledPattern::Regex(regex) => {
+            let matches: Vec<regex::Captures> = regex.captures_iter(source).collect();
+            matches.iter().enumerate()
+                .map(|(index, captures)| {
+                    let start = captures.get(0).map_or(0, |m| m.start());
+                    Subject {
+                        start,
+                        end: matches.get(index + 1).and_then(|next| next.get(0)).map_or(source.len(), |m| m.start()),
+                        line: line_of(source, start),
+                        name: captures.name("name").map(|m| m.as_str().to_string()),
+                    }
+                })
+                .collect()
+        }
+        CompiledPattern::Query(queries) => {
+            let (Some(query), Some(tree)) = (language.and_then(|language| queries.get(language)), tree) else {
+                return Vec::new();
+            };
+            let subject_index = query.capture_index_for_name("subject");
+            let name_index = query.capture_index_for_name("name");
+            let mut cursor = QueryCursor::new();
+            let mut seen = BTreeSet::new();
+
+            cursor.matches(query, tree.root_node(), source.as_bytes())
+                .filter_map(|query_match| {
+                    let subject_node = query_match.captures.iter()
+                        .find(|capture| Some(capture.index) == subject_index)
+                        .map(|capture| capture.node);
+                    let (start, end, row) = match subject_node {
+                        Some(node) => (node.start_byte(), node.end_byte(), node.start_position().row),
+                        None => {
+                            let first = query_match.captures.iter().min_by_key(|capture| capture.node.start_byte())?;
+                            let end = query_match.captures.iter().map(|capture| capture.node.end_byte()).max()?;
+                            (first.node.start_byte(), end, first.node.start_position().row)
+                        }
+                    };
+                    if !seen.insert((start, end)) {
+                        return None;
+                    }
+                    let name = query_match.captures.iter()
+                        .find(|capture| Some(capture.index) == name_index)
+                        .and_then(|capture| capture.node.utf8_text(source.as_bytes()).ok())
+                        .map(str::to_string);
+                    Some(Subject { start, end, line: row + 1, name })
+                })
+                .collect()
+        }
+    }
+}
+
+fn pattern_matches(pattern: &CompiledPattern, source: &str, subject: &Subject, language: Option<&str>, tree: Option<&Tree>) -> bool {
+    match pattern {
+        CompiledPattern::Regex(regex) => regex.is_match(&source[subject.start..subject.end]),
+        CompiledPattern::Query(queries) => {
+            let (Some(query), Some(tree)) = (language.and_then(|language| queries.get(language)), tree) else {
+                return false;
+            };
+            let mut cursor = QueryCursor::new();
+            cursor.set_byte_range(subject.start..subject.end);
+            cursor.matches(query, tree.root_node(), source.as_bytes())
+                .any(|query_match| query_match.captures.iter().any(|capture| {
+                    capture.node.start_byte() >= subject.start && capture.node.end_byte() <= subject.end
+                }))
+        }
+    }
+}
+
+/// Translate a glob into an anchored regex; globs without `/` match the file name anywhere
+fn glob_to_regex(glob: &str) -> Result<Regex> {
+    let mut pattern = String::from(if glob.contains('/') { "^" } else { "(?:^|/)" });
+    let characters: Vec<char> = glob.trim_start_matches("./").chars().collect();
+    let mut index = 0;
+    let mut in_braces = false;
+
+    while index < characters.len() {
+        match characters[index] {
+            '*' if characters.get(index + 1) == Some(&'*') => {
+                if characters.get(index + 2) == Some(&'/') {
+                    pattern.push_str("(?:.*/)?");
+                    index += 1;
+                } else {
+                    pattern.push_str(".*");
+                }
+                index += 1;
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            '{' => {
+                in_braces = true;
+                pattern.push_str("(?:");
+            }
+            '}' if in_braces => {
+                in_braces = false;
+                pattern.push(')');
+            }
+            ',' if in_braces => pattern.push('|'),
+            character => pattern.push_str(&regex::escape(&character.to_string())),
+        }
+        index += 1;
+    }
+    pattern.push('$');
+
+    Regex::new(&pattern).with_context(|| format!("Invalid glob '{}'", glob))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::write;
+    use tempfile::TempDir;
+
+    fn pack(yaml: &str) -> RulePackAnalyzer {
+        RulePackAnalyzer::new(serde_yaml::from_str(yaml).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_regex_rule_requires_audit_event() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/billing/handlers.ts", r#"export async function chargeCard(req, res) {
+  await payments.charge(req.body);
+  audit.emit('charge', req.user);
+}
+
+export async function refund(req, res) {
+  await payments.refund(req.body);
+}
+"#);
+        write(temp_dir.path(), "src/catalog/handlers.ts", "export async function listProducts(req, res) {\n  res.json([]);\n}\n");
+
+        let analyzer = pack(r#"
+name: billing
+rules:
+  - id: billing-audit
+    files: ["**/billing/**/*.ts"]
+    select:
+      regex: '(?m)^export\s+async\s+function\s+(?P<name>\w+)'
+    require:
+      regex: 'audit\.emit\('
+    finding:
+      implementation_type: Audit Event
+      description: "Billing handler {name} does not emit an audit event"
+      suggestion: "Call audit.emit() in {name}"
+"#);
+        let result = analyzer.generate_analysis_results(analyzer.evaluate(&ProjectFileIndex::build(temp_dir.path())));
+
+        assert_eq!(result.analyzer_name, "Rule Pack: billing");
+        assert_eq!(result.missing_implementations.len(), 1);
+        let finding = &result.missing_implementations[0];
+        assert_eq!(finding.description, "Billing handler refund does not emit an audit event");
+        assert_eq!(finding.expected_location, "src/billing/handlers.ts:6");
+        assert_eq!(finding.suggested_implementation.as_deref(), Some("Call audit.emit() in refund"));
+    }
+
+    #[test]
+    fn test_query_rule_reports_partial_implementation() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "app/services.py", r#"def create_invoice(data):
+    validate(data)
+    return save(data)
+
+def delete_invoice(invoice_id):
+    return remove(invoice_id)
+"#);
+
+        let analyzer = pack(r#"
+name: validation
+rules:
+  - id: service-validates-input
+    description: Services validate their input
+    files: ["*.py"]
+    select:
+      query: '(function_definition name: (identifier) @name) @subject'
+      language: python
+    require:
+      query: '(call function: (identifier) @fn (#eq? @fn "validate"))'
+    finding:
+      kind: partial
+      implementation_type: Input Validation
+      description: "{name} skips validation"
+"#);
+        let result = analyzer.generate_analysis_results(analyzer.evaluate(&ProjectFileIndex::build(temp_dir.path())));
+
+        assert_eq!(result.partial_implementations.len(), 1);
+        let partial = &result.partial_implementations[0];
+        assert_eq!(partial.implementation_name, "Services validate their input");
+        assert_eq!(partial.implemented_parts, vec!["create_invoice (app/services.py:1)".to_string()]);
+        assert_eq!(partial.missing_parts, vec!["delete_invoice (app/services.py:5)".to_string()]);
+        assert!((partial.completeness_percentage - 50.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_patterns_and_globs() {
+        let invalid: RulePackConfig = serde_yaml::from_str(r#"
+name: broken
+rules:
+  - id: bad-regex
+    require:
+      regex: '('
+    finding:
+      implementation_type: X
+      description: Y
+"#).unwrap();
+        assert!(RulePackAnalyzer::new(invalid).is_err());
+
+        let glob = glob_to_regex("**/billing/**/*.{ts,py}").unwrap();
+        assert!(glob.is_match("src/billing/api/charge.ts"));
+        assert!(glob.is_match("billing/charge.py"));
+        assert!(!glob.is_match("src/catalog/list.ts"));
+        assert!(glob_to_regex("*.py").unwrap().is_match("app/services.py"));
+    }
+}
@@ -0,0 +1,794 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::analyzers::common::{is_test_path, line_of};
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::ast_analyzer::{CodeSegment, SegmentType};
+use crate::core::status_inference_engine::{
+    StatusAnalyzer, StatusAnalysisResult, StatusInferenceConfig, TestCoverageAnalysisConfig,
+    MissingImplementation, UntestedImplementation
+};
+use crate::core::documentation_extractor::ExtractedDocumentationInfo;
+use crate::core::todo_scanner::TodoScanResult;
+
+/// Maximum number of test-to-target mappings listed in the analysis notes
+const MAX_MAPPING_NOTES: usize = 20;
+
+/// Names too generic to tie a test to a specific function
+const GENERIC_FUNCTION_NAMES: &[&str] = &[
+    "main", "new", "default", "init", "__init__", "constructor", "render", "setup", "teardown",
+    "run", "get", "set", "call", "handle", "index", "app", "fmt", "from", "into", "drop", "clone",
+];
+
+/// Test framework a test case was written for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TestFramework {
+    Pytest,
+    JUnit,
+    Jest,
+    Deno,
+    Rust,
+    Go,
+}
+
+impl TestFramework {
+    fn label(&self) -> &'static str {
+        match self {
+            TestFramework::Pytest => "pytest",
+            TestFramework::JUnit => "JUnit",
+            TestFramework::Jest => "Jest/Vitest",
+            TestFramework::Deno => "Deno test",
+            TestFramework::Rust => "Rust #[test]",
+            TestFramework::Go => "Go testing",
+        }
+    }
+}
+
+static PYTEST_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:async\s+)?def\s+(test\w*)\s*\(").unwrap()
+});
+
+static JUNIT_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"@(?:Test|ParameterizedTest|RepeatedTest)\b[^{;]*?(?:void|fun)\s+`?([\w ]+?)`?\s*\(").unwrap()
+});
+
+static JEST_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?m)\b(?:it|test)(?:\.(?:only|skip|concurrent|each\([^)]*\)))?\(\s*["'`]([^"'`]+)["'`]"#).unwrap()
+});
+
+static DENO_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"Deno\.test\(\s*(?:\{\s*name:\s*["'`]([^"'`]+)["'`]|["'`]([^"'`]+)["'`]|(?:async\s+)?function\s+(\w+))"#).unwrap()
+});
+
+static RUST_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"#\[(?:[\w:]+::)?test\]\s*(?:#\[[^\]]*\]\s*)*(?:pub\s+)?(?:async\s+)?fn\s+(\w+)").unwrap()
+});
+
+static GO_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^func\s+(Test\w+)\s*\(\s*\w+\s+\*testing\.T\s*\)").unwrap()
+});
+
+static RUST_TEST_MODULE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"#\[cfg\(test\)\]").unwrap()
+});
+
+static IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z_$][\w$]*").unwrap()
+});
+
+static PATH_LITERAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"["'`](/[^"'`\s]*)["'`]"#).unwrap()
+});
+
+/// A single test case and what it mentions
+#[derive(Debug, Clone)]
+struct TestCase {
+    name: String,
+    framework: TestFramework,
+    file: String,
+    line: usize,
+    identifiers: HashSet<String>,
+    paths: Vec<String>,
+}
+
+/// Kind of code a test can exercise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum TargetKind {
+    Function,
+    Route,
+    Component,
+}
+
+impl TargetKind {
+    fn label(&self) -> &'static str {
+        match self {
+            TargetKind::Function => "function",
+            TargetKind::Route => "route",
+            TargetKind::Component => "component",
+        }
+    }
+}
+
+/// Function, route or component that tests are expected to exercise
+#[derive(Debug, Clone)]
+struct TestTarget {
+    kind: TargetKind,
+    /// Function/component name, or `METHOD /path` for routes
+    name: String,
+    /// Identifiers whose mention in a test counts as exercising the target
+    identifiers: Vec<String>,
+    /// Concrete request paths the route answers, with the HTTP method the request must use
+    route_pattern: Option<(Regex, String)>,
+    file: String,
+    line_start: usize,
+    line_end: usize,
+}
+
+impl TestTarget {
+    fn is_referenced_by(&self, case: &TestCase) -> bool {
+        self.identifiers.iter().any(|identifier| case.identifiers.contains(identifier))
+            || self.route_pattern.as_ref().is_some_and(|(pattern, method)| {
+                (case.identifiers.contains(method) || case.identifiers.contains(&method.to_uppercase()))
+                    && case.paths.iter().any(|path| pattern.is_match(path))
+            })
+    }
+}
+
+/// Line hit counts per source file, merged across coverage reports
+#[derive(Debug, Default)]
+struct CoverageData {
+    files: HashMap<String, BTreeMap<usize, u64>>,
+    reports: Vec<String>,
+}
+
+impl CoverageData {
+    fn record(&mut self, file: &str, line: usize, hits: u64) {
+        let file = file.trim().replace('\\', "/");
+        let file = file.trim_start_matches("./").to_string();
+        let lines = self.files.entry(file).or_default();
+        let entry = lines.entry(line).or_insert(0);
+        *entry = (*entry).max(hits);
+    }
+
+    /// Line hits for a project-relative file; report paths may be absolute or package-relative
+    fn lines_for(&self, relative: &str) -> Option<&BTreeMap<usize, u64>> {
+        self.files.get(relative).or_else(|| {
+            self.files.iter()
+                .filter(|(path, _)| relative.ends_with(&format!("/{}", path)) || path.ends_with(&format!("/{}", relative)))
+                .max_by_key(|(path, _)| path.len())
+                .map(|(_, lines)| lines)
+        })
+    }
+
+    /// Covered and instrumented line counts within a line range
+    fn coverage_of(&self, target: &TestTarget) -> Option<(usize, usize)> {
+        let lines = self.lines_for(&target.file)?;
+        let (covered, instrumented) = lines.range(target.line_start..=target.line_end.max(target.line_start))
+            .fold((0, 0), |(covered, instrumented), (_, hits)| (covered + usize::from(*hits > 0), instrumented + 1));
+        (instrumented > 0).then_some((covered, instrumented))
+    }
+}
+
+/// Test suite and code targets found in the project
+#[derive(Debug, Default)]
+struct TestInventory {
+    test_files: BTreeSet<String>,
+    cases: Vec<TestCase>,
+    targets: Vec<TestTarget>,
+    coverage: CoverageData,
+}
+
+/// Test coverage analyzer
+/// Finds test cases (pytest, JUnit, Jest/Vitest, Deno, Rust and Go tests), maps them to the
+/// functions, routes and components they reference, and reads local lcov, Cobertura and JaCoCo
+/// reports to report implemented code that nothing exercises
+pub struct TestCoverageAnalyzer {
+    config: TestCoverageAnalysisConfig,
+    weight: f32,
+}
+
+impl TestCoverageAnalyzer {
+    pub fn new(config: TestCoverageAnalysisConfig, weight: f32) -> Self {
+        Self { config, weight }
+    }
+
+    /// Collect test cases, test targets and coverage data
+    fn build_inventory(&self, index: &ProjectFileIndex, segments: &[CodeSegment]) -> TestInventory {
+        let mut inventory = TestInventory::default();
+        let mut rust_test_regions: HashMap<String, usize> = HashMap::new();
+
+        for file in index.files() {
+            let Some(framework) = candidate_framework(&file.relative, &file.extension) else {
+                continue;
+            };
+            let Ok(content) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+            let cases = extract_test_cases(framework, &file.relative, &content);
+            if framework == TestFramework::Rust
+                && let Some(found) = RUST_TEST_MODULE.find(&content)
+            {
+                rust_test_regions.insert(file.relative.clone(), line_of(&content, found.start()));
+            }
+            if !cases.is_empty() {
+                inventory.test_files.insert(file.relative.clone());
+                inventory.cases.extend(cases);
+            }
+        }
+
+        let case_names: HashSet<(&str, &str)> = inventory.cases.iter()
+            .map(|case| (case.file.as_str(), case.name.as_str()))
+            .collect();
+        let mut seen = HashSet::new();
+        for segment in segments {
+            let relative = segment.metadata.file_path.strip_prefix(index.root())
+                .unwrap_or(&segment.metadata.file_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if inventory.test_files.contains(&relative) && !rust_test_regions.contains_key(&relative) {
+                continue;
+            }
+            if rust_test_regions.get(&relative).is_some_and(|&start| segment.metadata.line_start >= start) {
+                continue;
+            }
+            let Some(target) = test_target(segment, &relative) else {
+                continue;
+            };
+            if case_names.contains(&(relative.as_str(), target.name.as_str())) {
+                continue;
+            }
+            if seen.insert((target.kind, target.name.clone(), relative)) {
+                inventory.targets.push(target);
+            }
+        }
+
+        fold_route_handlers(&mut inventory.targets);
+
+        if self.config.ingest_coverage_reports {
+            inventory.coverage = self.load_coverage_reports(index);
+        }
+
+        inventory
+    }
+
+    /// Read every coverage report found at a configured path or among the indexed files
+    fn load_coverage_reports(&self, index: &ProjectFileIndex) -> CoverageData {
+        let mut coverage = CoverageData::default();
+        let mut candidates: BTreeSet<String> = self.config.coverage_report_paths.iter()
+            .filter(|relative| index.root().join(relative).is_file())
+            .cloned()
+            .collect();
+        candidates.extend(index.files().iter()
+            .filter(|file| is_coverage_report_name(&file.relative))
+            .map(|file| file.relative.clone()));
+
+        for relative in candidates {
+            let Ok(content) = fs::read_to_string(index.root().join(&relative)) else {
+                continue;
+            };
+            let before = coverage.files.len();
+            if content.contains("SF:") && content.contains("end_of_record") {
+                parse_lcov(&content, index.root(), &mut coverage);
+            } else if content.contains("<report") && content.contains("<sourcefile") {
+                parse_jacoco(&content, &mut coverage);
+            } else if content.contains("<coverage") && content.contains("<class") {
+                parse_cobertura(&content, &mut coverage);
+            } else {
+                continue;
+            }
+            if coverage.files.len() > before {
+                coverage.reports.push(relative);
+            }
+        }
+
+        coverage
+    }
+
+    /// Turn the inventory into untested implementations
+    fn generate_analysis_results(&self, inventory: TestInventory) -> StatusAnalysisResult {
+        let mut missing_implementations = Vec::new();
+        let mut untested_implementations = Vec::new();
+        let mut evidence = Vec::new();
+        let mut analysis_notes = Vec::new();
+
+        let mut by_framework: BTreeMap<TestFramework, usize> = BTreeMap::new();
+        for case in &inventory.cases {
+            *by_framework.entry(case.framework).or_insert(0) += 1;
+        }
+        analysis_notes.push(format!(
+            "Found {} test cases in {} files ({})",
+            inventory.cases.len(),
+            inventory.test_files.len(),
+            by_framework.iter().map(|(framework, count)| format!("{}: {}", framework.label(), count)).collect::<Vec<_>>().join(", "),
+        ));
+        if !inventory.coverage.reports.is_empty() {
+            analysis_notes.push(format!("Coverage reports: {}", inventory.coverage.reports.join(", ")));
+        }
+
+        let has_coverage = !inventory.coverage.reports.is_empty();
+        if inventory.cases.is_empty() && !has_coverage {
+            if !inventory.targets.is_empty() {
+                missing_implementations.push(MissingImplementation {
+                    implementation_type: "Test Suite".to_string(),
+                    expected_location: "tests/".to_string(),
+                    description: format!("No tests found for {} functions, routes and components", inventory.targets.len()),
+                    confidence: 0.8,
+                    evidence: vec!["No pytest, JUnit, Jest/Vitest, Deno, Rust or Go test cases detected".to_string()],
+                    suggested_implementation: Some("Add a test suite covering the main routes and business logic".to_string()),
+                });
+            }
+            return StatusAnalysisResult {
+                analyzer_name: self.name().to_string(),
+                missing_implementations,
+                untested_implementations,
+                confidence: if inventory.targets.is_empty() { 0.3 } else { 0.6 },
+                evidence,
+                analysis_notes,
+                ..Default::default()
+            };
+        }
+
+        let mut tested = 0;
+        let mut mapping_notes = Vec::new();
+        for target in &inventory.targets {
+            let referencing: Vec<&TestCase> = if self.config.enable_test_mapping {
+                inventory.cases.iter().filter(|case| target.is_referenced_by(case)).collect()
+            } else {
+                Vec::new()
+            };
+            let coverage = inventory.coverage.coverage_of(target);
+            let line_coverage = coverage.map(|(covered, instrumented)| covered as f32 / instrumented as f32 * 100.0);
+            let location = format!("{}:{}", target.file, target.line_start);
+
+            let untested = match line_coverage {
+                Some(percentage) => percentage <= self.config.untested_line_coverage,
+                None => self.config.enable_test_mapping && referencing.is_empty(),
+            };
+            if !untested {
+                tested += 1;
+                if !referencing.is_empty() {
+                    mapping_notes.push(format!(
+                        "{} {} ({}) <- {}",
+                        target.kind.label(),
+                        target.name,
+                        location,
+                        referencing.iter().map(|case| format!("{} ({}:{})", case.name, case.file, case.line)).collect::<Vec<_>>().join(", "),
+                    ));
+                }
+                continue;
+            }
+
+            let mut target_evidence = Vec::new();
+            let confidence = match coverage {
+                Some((covered, instrumented)) => {
+                    target_evidence.push(format!("{}/{} lines covered", covered, instrumented));
+                    0.9
+                }
+                None => {
+                    target_evidence.push("Not referenced by any test case".to_string());
+                    0.6
+                }
+            };
+            untested_implementations.push(UntestedImplementation {
+                name: target.name.clone(),
+                kind: target.kind.label().to_string(),
+                location,
+                line_coverage,
+                confidence,
+                evidence: target_evidence,
+            });
+        }
+
+        evidence.push(format!("{} of {} functions, routes and components exercised by tests", tested, inventory.targets.len()));
+        for kind in [TargetKind::Route, TargetKind::Component, TargetKind::Function] {
+            let total = inventory.targets.iter().filter(|target| target.kind == kind).count();
+            let untested = untested_implementations.iter().filter(|untested| untested.kind == kind.label()).count();
+            if total > 0 {
+                evidence.push(format!("{} {}s untested out of {}", untested, kind.label(), total));
+            }
+        }
+        let omitted = mapping_notes.len().saturating_sub(MAX_MAPPING_NOTES);
+        analysis_notes.extend(mapping_notes.into_iter().take(MAX_MAPPING_NOTES));
+        if omitted > 0 {
+            analysis_notes.push(format!("... and {} more tested targets", omitted));
+        }
+
+        StatusAnalysisResult {
+            analyzer_name: self.name().to_string(),
+            missing_implementations,
+            untested_implementations,
+            confidence: if has_coverage { 0.9 } else { 0.65 },
+            evidence,
+            analysis_notes,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatusAnalyzer for TestCoverageAnalyzer {
+    fn name(&self) -> &str {
+        "Test Coverage Analyzer"
+    }
+
+    fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn can_analyze(&self, project_path: &Path) -> bool {
+        project_path.is_dir()
+    }
+
+    fn analyze(
+        &self,
+        project_path: &Path,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze_indexed(&ProjectFileIndex::build(project_path), documentation, todo_results, config)
+    }
+
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        _documentation: &ExtractedDocumentationInfo,
+        _todo_results: &TodoScanResult,
+        _config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        println!("Analyzing test coverage for project: {}", index.root().display());
+
+        let inventory = self.build_inventory(index, index.segments()?);
+        let result = self.generate_analysis_results(inventory);
+
+        println!("Test coverage analysis completed: {} untested items found, {:.1}% confidence",
+                 result.untested_implementations.len(),
+                 result.confidence * 100.0);
+
+        Ok(result)
+    }
+}
+
+/// Framework whose tests a file may hold, judged by its path and extension
+fn candidate_framework(relative: &str, extension: &str) -> Option<TestFramework> {
+    match extension {
+        "py" if is_test_path(relative) => Some(TestFramework::Pytest),
+        "java" | "kt" => Some(TestFramework::JUnit),
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "mts" | "cts" if is_test_path(relative) => Some(TestFramework::Jest),
+        "rs" => Some(TestFramework::Rust),
+        "go" if relative.ends_with("_test.go") => Some(TestFramework::Go),
+        _ => None,
+    }
+}
+
+/// Split a test file into test cases; each case runs until the next one starts
+fn extract_test_cases(framework: TestFramework, file: &str, content: &str) -> Vec<TestCase> {
+    let (framework, regex) = match framework {
+        TestFramework::Jest if content.contains("Deno.test(") => (TestFramework::Deno, &*DENO_CASE),
+        TestFramework::Jest => (TestFramework::Jest, &*JEST_CASE),
+        TestFramework::Pytest => (framework, &*PYTEST_CASE),
+        TestFramework::JUnit => (framework, &*JUNIT_CASE),
+        TestFramework::Deno => (framework, &*DENO_CASE),
+        TestFramework::Rust => (framework, &*RUST_CASE),
+        TestFramework::Go => (framework, &*GO_CASE),
+    };
+
+    let matches: Vec<(usize, String)> = regex.captures_iter(content)
+        .filter_map(|captures| {
+            let name = captures.iter().skip(1).flatten().next()?.as_str().trim().to_string();
+            Some((captures.get(0)?.start(), name))
+        })
+        .collect();
+
+    matches.iter().enumerate()
+        .map(|(position, (start, name))| {
+            let end = matches.get(position + 1).map_or(content.len(), |(next, _)| *next);
+            let body = &content[*start..end];
+            // Imports at the top of the file tell which module the names in the body come from
+            let header = &content[..matches.first().map_or(0, |(first, _)| *first)];
+            TestCase {
+                name: name.clone(),
+                framework,
+                file: file.to_string(),
+                line: line_of(content, *start),
+                identifiers: IDENTIFIER.find_iter(body)
+                    .map(|identifier| identifier.as_str().to_string())
+                    .filter(|identifier| !header.is_empty() || identifier != name)
+                    .collect(),
+                paths: PATH_LITERAL.captures_iter(body).map(|captures| captures[1].to_string()).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Target a code segment represents, if tests are expected to exercise it
+fn test_target(segment: &CodeSegment, relative: &str) -> Option<TestTarget> {
+    let extension = relative.rsplit('.').next().unwrap_or_default();
+    let is_ui_file = matches!(extension, "tsx" | "jsx" | "vue" | "svelte");
+    let target = |kind, name: &str, identifiers: Vec<String>, route_pattern| TestTarget {
+        kind,
+        name: name.to_string(),
+        identifiers,
+        route_pattern,
+        file: relative.to_string(),
+        line_start: segment.metadata.line_start,
+        line_end: segment.metadata.line_end,
+    };
+
+    match &segment.segment_type {
+        SegmentType::Route(route) => {
+            if route.method == "VIEW" || route.path.trim_matches('/').is_empty() {
+                return None;
+            }
+            let handler = route.handler.trim();
+            let identifiers = IDENTIFIER.find_iter(handler)
+                .last()
+                .map(|identifier| identifier.as_str().to_string())
+                .filter(|identifier| is_specific_name(identifier))
+                .into_iter()
+                .collect();
+            Some(target(
+                TargetKind::Route,
+                &format!("{} {}", route.method.to_uppercase(), route.path),
+                identifiers,
+                route_pattern(&route.path).map(|pattern| (pattern, route.method.to_lowercase())),
+            ))
+        }
+        SegmentType::Class(class) if class.is_react_component => {
+            Some(target(TargetKind::Component, &class.name, vec![class.name.clone()], None))
+        }
+        SegmentType::Function(function) if is_specific_name(&function.name) => {
+            let is_component = is_ui_file && function.name.starts_with(|c: char| c.is_ascii_uppercase());
+            let kind = if is_component { TargetKind::Component } else { TargetKind::Function };
+            Some(target(kind, &function.name, vec![function.name.clone()], None))
+        }
+        _ => None,
+    }
+}
+
+/// Decorated handlers lie inside their route segment; exercising the route exercises the handler
+fn fold_route_handlers(targets: &mut Vec<TestTarget>) {
+    let handlers: Vec<(usize, usize)> = targets.iter().enumerate()
+        .filter(|(_, target)| target.kind == TargetKind::Function)
+        .filter_map(|(position, function)| {
+            targets.iter()
+                .position(|route| {
+                    route.kind == TargetKind::Route
+                        && route.file == function.file
+                        && route.line_start <= function.line_start
+                        && function.line_end <= route.line_end
+                })
+                .map(|route| (position, route))
+        })
+        .collect();
+
+    for &(handler, route) in &handlers {
+        let name = targets[handler].name.clone();
+        if !targets[route].identifiers.contains(&name) {
+            targets[route].identifiers.push(name);
+        }
+    }
+    let mut position = 0;
+    targets.retain(|_| {
+        let keep = !handlers.iter().any(|&(handler, _)| handler == position);
+        position += 1;
+        keep
+    });
+}
+
+fn is_specific_name(name: &str) -> bool {
+    name.len() >= 3
+        && !name.starts_with('_')
+        && !GENERIC_FUNCTION_NAMES.contains(&name.to_lowercase().as_str())
+}
+
+/// Regex matching concrete request paths for a route, with parameters as wildcards
+fn route_pattern(path: &str) -> Option<Regex> {
+    let segments: Vec<String> = path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if segment.starts_with([':', '{', '<', '[', '*', '$']) {
+                r"[^/?#]+".to_string()
+            } else {
+                regex::escape(segment)
+            }
+        })
+        .collect();
+    Regex::new(&format!(r"^/{}/?(?:[?#].*)?$", segments.join("/"))).ok()
+}
+
+fn is_coverage_report_name(relative: &str) -> bool {
+    let file_name = relative.rsplit('/').next().unwrap_or_default().to_lowercase();
+    file_name == "lcov.info"
+        || file_name.ends_with(".lcov")
+        || (file_name.ends_with(".xml") && (file_name.contains("cobertura") || file_name.contains("jacoco") || file_name == "coverage.xml"))
+}
+
+/// `SF:<file>` / `DA:<line>,<hits>` records
+fn parse_lcov(content: &str, root: &Path, coverage: &mut CoverageData) {
+    let mut current: Option<String> = None;
+    for line in content.lines() {
+        if let Some(file) = line.strip_prefix("SF:") {
+            let path = Path::new(file.trim());
+            current = Some(path.strip_prefix(root).unwrap_or(path).to_string_lossy().to_string());
+        } else if let (Some(file), Some(record)) = (&current, line.strip_prefix("DA:")) {
+            let mut fields = record.split(',');
+            if let (Some(Ok(number)), Some(Ok(hits))) = (fields.next().map(str::parse), fields.next().map(|hits| hits.trim().parse())) {
+                coverage.record(file, number, hits);
+            }
+        } else if line.starts_with("end_of_record") {
+            current = None;
+        }
+    }
+}
+
+/// `<class filename="...">` elements holding `<line number=".." hits=".."/>`
+fn parse_cobertura(content: &str, coverage: &mut CoverageData) {
+    static CLASS: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<class\b[^>]*\bfilename="([^"]+)""#).unwrap());
+    static LINE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<line\b[^>]*\bnumber="(\d+)"[^>]*\bhits="(\d+)""#).unwrap());
+
+    let classes: Vec<(usize, String)> = CLASS.captures_iter(content)
+        .filter_map(|captures| Some((captures.get(0)?.start(), captures[1].to_string())))
+        .collect();
+    for (position, (start, file)) in classes.iter().enumerate() {
+        let end = classes.get(position + 1).map_or(content.len(), |(next, _)| *next);
+        for captures in LINE.captures_iter(&content[*start..end]) {
+            if let (Ok(number), Ok(hits)) = (captures[1].parse(), captures[2].parse()) {
+                coverage.record(file, number, hits);
+            }
+        }
+    }
+}
+
+/// `<package name="com/x">` / `<sourcefile name="Foo.java">` / `<line nr=".." ci=".."/>`
+fn parse_jacoco(content: &str, coverage: &mut CoverageData) {
+    static ELEMENT: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"<package\b[^>]*\bname="([^"]*)"|<sourcefile\b[^>]*\bname="([^"]+)"|<line\b[^>]*\bnr="(\d+)"[^>]*\bci="(\d+)""#).unwrap()
+    });
+
+    let mut package = String::new();
+    let mut source_file: Option<String> = None;
+    for captures in ELEMENT.captures_iter(content) {
+        if let Some(name) = captures.get(1) {
+            package = name.as_str().to_string();
+            source_file = None;
+        } else if let Some(name) = captures.get(2) {
+            source_file = Some(if package.is_empty() {
+                name.as_str().to_string()
+            } else {
+                format!("{}/{}", package, name.as_str())
+            });
+        } else if let (Some(file), Some(number), Some(covered)) = (&source_file, captures.get(3), captures.get(4))
+            && let (Ok(number), Ok(covered)) = (number.as_str().parse(), covered.as_str().parse())
+        {
+            coverage.record(file, number, covered);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::{analyze, write};
+    use tempfile::TempDir;
+
+    fn untested(result: &StatusAnalysisResult) -> Vec<(&str, &str)> {
+        let mut untested: Vec<(&str, &str)> = result.untested_implementations.iter()
+            .map(|untested| (untested.kind.as_str(), untested.name.as_str()))
+            .collect();
+        untested.sort();
+        untested
+    }
+
+    #[test]
+    fn test_maps_jest_and_pytest_cases_to_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "app/api.py", r#"from fastapi import FastAPI
+app = FastAPI()
+
+@app.get('/users/{user_id}')
+def read_user(user_id: int):
+    return {"id": user_id}
+
+@app.delete('/users/{user_id}')
+def delete_user(user_id: int):
+    return None
+"#);
+        write(temp_dir.path(), "src/components/UserCard.tsx", "export function UserCard({ user }) {\n  return <div>{user.name}</div>;\n}\n");
+        write(temp_dir.path(), "src/__tests__/users.test.js", r#"const request = require('supertest');
+describe('users', () => {
+  it('returns a user', async () => {
+    await request(app).get('/users/42').expect(200);
+  });
+});
+"#);
+        write(temp_dir.path(), "app/services.py", "def create_invoice(data):\n    return save(data)\n\ndef cancel_invoice(invoice_id):\n    return remove(invoice_id)\n");
+        write(temp_dir.path(), "tests/test_services.py", "from app.services import create_invoice\n\ndef test_create_invoice():\n    assert create_invoice({})\n");
+
+        let result = analyze(&TestCoverageAnalyzer::new(TestCoverageAnalysisConfig::default(), 0.15), temp_dir.path());
+
+        assert_eq!(untested(&result), vec![
+            ("component", "UserCard"),
+            ("function", "cancel_invoice"),
+            ("route", "DELETE /users/{user_id}"),
+        ]);
+        assert!(result.analysis_notes[0].contains("Found 2 test cases in 2 files"));
+        assert!(result.analysis_notes.iter().any(|note| note.starts_with("route GET /users/{user_id}") && note.contains("returns a user")));
+    }
+
+    #[test]
+    fn test_rust_and_junit_cases_and_coverage_reports() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "src/lib.rs", r#"pub fn parse_amount(input: &str) -> u64 {
+    input.parse().unwrap_or(0)
+}
+
+pub fn format_amount(amount: u64) -> String {
+    format!("{}.00", amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_amounts() {
+        assert_eq!(parse_amount("12"), 12);
+    }
+}
+"#);
+        write(temp_dir.path(), "src/main/java/com/shop/OrderService.java", r#"package com.shop;
+public class OrderService {
+    public Order placeOrder(Cart cart) {
+        return repository.save(new Order(cart));
+    }
+    public void cancelOrder(long id) {
+        repository.delete(id);
+    }
+}
+"#);
+        write(temp_dir.path(), "src/test/java/com/shop/OrderServiceTest.java", r#"package com.shop;
+class OrderServiceTest {
+    @Test
+    void placesOrder() {
+        service.placeOrder(cart);
+        service.cancelOrder(1L);
+    }
+}
+"#);
+        write(temp_dir.path(), "target/site/jacoco/jacoco.xml", r#"<report name="shop"><package name="com/shop"><sourcefile name="OrderService.java">
+<line nr="4" mi="0" ci="3" mb="0" cb="0"/>
+<line nr="7" mi="2" ci="0" mb="0" cb="0"/>
+</sourcefile></package></report>"#);
+
+        let result = analyze(&TestCoverageAnalyzer::new(TestCoverageAnalysisConfig::default(), 0.15), temp_dir.path());
+
+        assert_eq!(untested(&result), vec![("function", "cancelOrder"), ("function", "format_amount")]);
+        let cancel = result.untested_implementations.iter().find(|untested| untested.name == "cancelOrder").unwrap();
+        assert_eq!(cancel.line_coverage, Some(0.0));
+        assert_eq!(cancel.evidence, vec!["0/1 lines covered".to_string()]);
+        assert!(result.analysis_notes.iter().any(|note| note == "Coverage reports: target/site/jacoco/jacoco.xml"));
+    }
+
+    #[test]
+    fn test_coverage_report_parsers() {
+        let mut coverage = CoverageData::default();
+        parse_lcov("TN:\nSF:/repo/src/app.ts\nDA:1,4\nDA:2,0\nend_of_record\n", Path::new("/repo"), &mut coverage);
+        parse_cobertura(r#"<coverage><packages><package><classes>
+<class name="services" filename="app/services.py"><lines><line number="1" hits="1"/><line number="4" hits="0"/></lines></class>
+</classes></package></packages></coverage>"#, &mut coverage);
+
+        assert_eq!(coverage.lines_for("src/app.ts").unwrap().get(&2), Some(&0));
+        assert_eq!(coverage.lines_for("app/services.py").unwrap().len(), 2);
+        assert!(coverage.lines_for("app/other.py").is_none());
+
+        let cases = extract_test_cases(TestFramework::Jest, "api_test.ts", "Deno.test(\"adds numbers\", () => { add(1, 2); });\n");
+        assert_eq!(cases.len(), 1);
+        assert_eq!(cases[0].framework, TestFramework::Deno);
+        assert!(cases[0].identifiers.contains("add"));
+    }
+}
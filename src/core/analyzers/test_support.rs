@@ -0,0 +1,51 @@
+//! Fixtures shared by the status analyzer tests
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::documentation_extractor::{DocumentationCoverage, ExtractedDocumentationInfo};
+use crate::core::status_inference_engine::{StatusAnalysisResult, StatusAnalyzer, StatusInferenceConfig};
+use crate::core::todo_scanner::TodoScanner;
+
+/// Write `content` to `root/relative`, creating parent directories
+pub(crate) fn write(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Documentation info with nothing but the given API documentation
+pub(crate) fn documentation(api_documentation: Vec<String>) -> ExtractedDocumentationInfo {
+    ExtractedDocumentationInfo {
+        project_description: None,
+        installation_instructions: Vec::new(),
+        usage_examples: Vec::new(),
+        api_documentation,
+        architecture_info: Vec::new(),
+        contributing_guidelines: Vec::new(),
+        technologies: Vec::new(),
+        setup_commands: Vec::new(),
+        validation_conflicts: Vec::new(),
+        confidence_score: 0.0,
+        documentation_coverage: DocumentationCoverage {
+            sections_found: Vec::new(),
+            sections_missing: Vec::new(),
+            completeness_score: 0.0,
+            quality_indicators: Vec::new(),
+            improvement_suggestions: Vec::new(),
+        },
+    }
+}
+
+/// Run `analyzer` over the project at `root` the way the engine does, without documentation
+pub(crate) fn analyze(analyzer: &dyn StatusAnalyzer, root: &Path) -> StatusAnalysisResult {
+    analyze_documented(analyzer, root, &documentation(Vec::new()))
+}
+
+/// Run `analyzer` over the project at `root` with the given documentation
+pub(crate) fn analyze_documented(analyzer: &dyn StatusAnalyzer, root: &Path, documentation: &ExtractedDocumentationInfo) -> StatusAnalysisResult {
+    let todo_results = TodoScanner::new().unwrap().scan_project(root).unwrap();
+    analyzer.analyze_indexed(&ProjectFileIndex::build(root), documentation, &todo_results, &StatusInferenceConfig::default())
+        .unwrap()
+}
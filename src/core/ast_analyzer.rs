@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::fs;
-use tree_sitter::{Language, Parser, Tree, Node};
+use tree_sitter::Node;
 use serde::{Deserialize, Serialize};
 use anyhow::{Result, Context};
 use crate::core::types::{Framework, Language as AnalysisLanguage};
-use crate::core::extractors::{TypeScriptExtractor, PythonExtractor, JavaExtractor};
+use crate::core::extractors::{TypeScriptExtractor, PythonExtractor, JavaExtractor, RustExtractor, GoExtractor, VueExtractor};
+use crate::core::project_walker::ProjectWalker;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeSegment {
@@ -85,7 +86,7 @@ pub struct SegmentMetadata {
 }
 
 pub struct ASTAnalyzer {
-    parsers: HashMap<AnalysisLanguage, Parser>,
+    vue_extractor: VueExtractor,
     extractors: HashMap<AnalysisLanguage, Box<dyn SegmentExtractor>>,
 }
 
@@ -96,76 +97,60 @@ pub trait SegmentExtractor: Send + Sync {
 
 impl ASTAnalyzer {
     pub fn new() -> Result<Self> {
-        let mut parsers = HashMap::new();
+        // Extractors parse each file themselves with the grammar it needs (TSX for `.tsx`/`.jsx`,
+        // the TypeScript grammar for each Vue script block), so no tree is built up front
         let mut extractors: HashMap<AnalysisLanguage, Box<dyn SegmentExtractor>> = HashMap::new();
-
-        // Initialize TypeScript parser
-        let mut ts_parser = Parser::new();
-        ts_parser.set_language(tree_sitter_typescript::language_typescript())
-            .context("Failed to set TypeScript language")?;
-        parsers.insert(AnalysisLanguage::TypeScript, ts_parser);
         extractors.insert(AnalysisLanguage::TypeScript, Box::new(TypeScriptExtractor::new()));
-
-        // Initialize Python parser
-        let mut py_parser = Parser::new();
-        py_parser.set_language(tree_sitter_python::language())
-            .context("Failed to set Python language")?;
-        parsers.insert(AnalysisLanguage::Python, py_parser);
+        extractors.insert(AnalysisLanguage::JavaScript, Box::new(TypeScriptExtractor::new()));
         extractors.insert(AnalysisLanguage::Python, Box::new(PythonExtractor::new()));
-
-        // Initialize Java parser
-        let mut java_parser = Parser::new();
-        java_parser.set_language(tree_sitter_java::language())
-            .context("Failed to set Java language")?;
-        parsers.insert(AnalysisLanguage::Java, java_parser);
         extractors.insert(AnalysisLanguage::Java, Box::new(JavaExtractor::new()));
-
-        // Initialize JavaScript parser (use TypeScript extractor for JS files)
-        let mut js_parser = Parser::new();
-        js_parser.set_language(tree_sitter_javascript::language())
-            .context("Failed to set JavaScript language")?;
-        parsers.insert(AnalysisLanguage::JavaScript, js_parser);
-        extractors.insert(AnalysisLanguage::JavaScript, Box::new(TypeScriptExtractor::new()));
+        extractors.insert(AnalysisLanguage::Rust, Box::new(RustExtractor::new()));
+        extractors.insert(AnalysisLanguage::Go, Box::new(GoExtractor::new()));
 
         Ok(ASTAnalyzer {
-            parsers,
+            vue_extractor: VueExtractor::new(),
             extractors,
         })
     }
 
     pub fn extract_segments(&mut self, codebase_path: &Path, detected_frameworks: &[Framework]) -> Result<Vec<CodeSegment>> {
+        let files: Vec<PathBuf> = ProjectWalker::new(codebase_path).files().collect();
+
+        self.extract_segments_from_files(&files, detected_frameworks)
+    }
+
+    /// Extract segments from an already collected file list, skipping unsupported languages
+    pub fn extract_segments_from_files(&mut self, files: &[PathBuf], detected_frameworks: &[Framework]) -> Result<Vec<CodeSegment>> {
         let mut all_segments = Vec::new();
-        
-        for entry in walkdir::WalkDir::new(codebase_path)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-        {
-            let file_path = entry.path();
+
+        for file_path in files {
+            let file_path = file_path.as_path();
             let language = self.detect_file_language(file_path)?;
             
             if let Some(lang) = language {
                 let content = fs::read_to_string(file_path)
                     .context(format!("Failed to read file: {}", file_path.display()))?;
                 
-                if let Some(parser) = self.parsers.get_mut(&lang) {
-                    if let Some(tree) = parser.parse(&content, None) {
-                        if let Some(extractor) = self.extractors.get(&lang) {
-                            let mut segments = extractor.extract_segments(&content, file_path)?;
-                            
-                            // Add framework context to segments
-                            for segment in &mut segments {
-                                segment.framework_context = self.infer_framework_context(
-                                    &segment,
-                                    detected_frameworks,
-                                    file_path
-                                );
-                            }
-                            
-                            all_segments.extend(segments);
-                        }
+                // Vue SFCs are split into script blocks, which the Vue extractor parses individually
+                let extractor: Option<&dyn SegmentExtractor> = if file_path.extension().and_then(|e| e.to_str()) == Some("vue") {
+                    Some(&self.vue_extractor)
+                } else {
+                    self.extractors.get(&lang).map(|extractor| extractor.as_ref())
+                };
+
+                if let Some(extractor) = extractor {
+                    let mut segments = extractor.extract_segments(&content, file_path)?;
+
+                    // Add framework context to segments
+                    for segment in &mut segments {
+                        segment.framework_context = self.infer_framework_context(
+                            segment,
+                            detected_frameworks,
+                            file_path
+                        ).or(segment.framework_context.take());
                     }
+
+                    all_segments.extend(segments);
                 }
             }
         }
@@ -176,10 +161,12 @@ impl ASTAnalyzer {
     fn detect_file_language(&self, file_path: &Path) -> Result<Option<AnalysisLanguage>> {
         if let Some(extension) = file_path.extension().and_then(|e| e.to_str()) {
             match extension {
-                "ts" | "tsx" => Ok(Some(AnalysisLanguage::TypeScript)),
+                "ts" | "tsx" | "vue" => Ok(Some(AnalysisLanguage::TypeScript)),
                 "js" | "jsx" => Ok(Some(AnalysisLanguage::JavaScript)),
                 "py" => Ok(Some(AnalysisLanguage::Python)),
                 "java" => Ok(Some(AnalysisLanguage::Java)),
+                "rs" => Ok(Some(AnalysisLanguage::Rust)),
+                "go" => Ok(Some(AnalysisLanguage::Go)),
                 _ => Ok(None),
             }
         } else {
@@ -195,13 +182,27 @@ impl ASTAnalyzer {
         for framework in detected_frameworks {
             match framework {
                 Framework::React => {
-                    if file_path_str.contains("component") || 
+                    if segment.framework_context == Some(Framework::React) ||
+                       file_path_str.contains("component") || 
                        content_lower.contains("usestate") ||
                        content_lower.contains("useeffect") ||
                        content_lower.contains("jsx") {
                         return Some(Framework::React);
                     }
                 }
+                Framework::Vue => {
+                    if segment.framework_context == Some(Framework::Vue) ||
+                       file_path_str.ends_with(".vue") {
+                        return Some(Framework::Vue);
+                    }
+                }
+                Framework::Angular => {
+                    if segment.framework_context == Some(Framework::Angular) ||
+                       content_lower.contains("@ngmodule") ||
+                       file_path_str.ends_with(".component.ts") {
+                        return Some(Framework::Angular);
+                    }
+                }
                 Framework::NestJS => {
                     if content_lower.contains("@controller") ||
                        content_lower.contains("@injectable") ||
@@ -228,6 +229,40 @@ impl ASTAnalyzer {
                         return Some(Framework::SpringBoot);
                     }
                 }
+                Framework::Axum => {
+                    if content_lower.contains("axum::") ||
+                       content_lower.contains("router::new()") ||
+                       segment.framework_context == Some(Framework::Axum) {
+                        return Some(Framework::Axum);
+                    }
+                }
+                Framework::Actix => {
+                    if content_lower.contains("actix_web") ||
+                       content_lower.contains("web::") ||
+                       segment.framework_context == Some(Framework::Actix) {
+                        return Some(Framework::Actix);
+                    }
+                }
+                Framework::Warp => {
+                    if content_lower.contains("warp::") ||
+                       segment.framework_context == Some(Framework::Warp) {
+                        return Some(Framework::Warp);
+                    }
+                }
+                Framework::Gin => {
+                    if content_lower.contains("gin.context") ||
+                       content_lower.contains("gin.default()") ||
+                       segment.framework_context == Some(Framework::Gin) {
+                        return Some(Framework::Gin);
+                    }
+                }
+                Framework::Fiber => {
+                    if content_lower.contains("fiber.ctx") ||
+                       content_lower.contains("fiber.new(") ||
+                       segment.framework_context == Some(Framework::Fiber) {
+                        return Some(Framework::Fiber);
+                    }
+                }
                 _ => {}
             }
         }
@@ -36,6 +36,12 @@ impl BusinessContextGroundingEngine {
         })
     }
 
+    /// Use an existing LLM manager instead of the one configured by the environment
+    pub fn with_llm_manager(mut self, manager: LocalLLMManager) -> Self {
+        self.llm_manager = Some(manager);
+        self
+    }
+
     pub async fn ground_business_context(
         &mut self,
         project_context: &ProjectContext,
@@ -2,12 +2,12 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use serde::{Deserialize, Serialize};
-use walkdir;
 
 use crate::core::framework_detector::{FrameworkDetectionResult};
 use crate::core::types::{Framework, LanguageEcosystem};
 use crate::core::project_analyzer::ProjectContext;
 use crate::core::ast_analyzer::CodeSegment;
+use crate::core::project_walker::ProjectWalker;
 use crate::intelligence::llm_client::{LocalLLMManager, AnalysisType, ContextAwareAnalysisResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -537,9 +537,9 @@ impl BusinessDomainEngine {
     fn analyze_python_routes(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Flask route patterns
                     if *framework == Framework::Flask {
                         self.extract_flask_route_evidence(&content, domain_evidence, framework)?;
@@ -648,9 +648,9 @@ impl BusinessDomainEngine {
     fn analyze_python_services(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Service class names
                     if content.contains("class AuthService") || content.contains("class AuthenticationService") {
                         domain_evidence.entry(BusinessDomain::Authentication).or_insert_with(Vec::new).push(
@@ -744,9 +744,9 @@ impl BusinessDomainEngine {
     fn analyze_python_imports(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Authentication libraries
                     if content.contains("from flask_login import") || content.contains("import jwt") ||
                        content.contains("from passlib") || content.contains("import bcrypt") ||
@@ -786,9 +786,9 @@ impl BusinessDomainEngine {
     fn analyze_python_method_patterns(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     self.extract_python_method_evidence(&content, domain_evidence, framework)?;
                 }
             }
@@ -800,11 +800,10 @@ impl BusinessDomainEngine {
     fn analyze_python_model_relationships(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && 
-               (entry.path().extension().map_or(false, |ext| ext == "py") &&
-                (entry.path().to_string_lossy().contains("models") || entry.path().to_string_lossy().contains("schema"))) {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") &&
+               (file_path.to_string_lossy().contains("models") || file_path.to_string_lossy().contains("schema")) {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     self.extract_python_model_evidence(&content, domain_evidence, framework)?;
                 }
             }
@@ -816,9 +815,9 @@ impl BusinessDomainEngine {
     fn analyze_python_security_patterns(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     self.extract_python_security_evidence(&content, domain_evidence, framework)?;
                 }
             }
@@ -830,9 +829,9 @@ impl BusinessDomainEngine {
     fn analyze_python_business_logic(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>, framework: &Framework) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "py") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "py") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     self.extract_python_business_logic_evidence(&content, domain_evidence, framework)?;
                 }
             }
@@ -1112,16 +1111,15 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_controllers(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && 
-               entry.path().extension().map_or(false, |ext| ext == "ts") &&
-               entry.path().to_string_lossy().contains("controller") {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") &&
+               file_path.to_string_lossy().contains("controller") {
                 
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Authentication controllers
                     if content.contains("@Controller('auth')") || 
                        content.contains("@Controller('/auth')") ||
-                       entry.path().to_string_lossy().contains("auth") {
+                       file_path.to_string_lossy().contains("auth") {
                         domain_evidence.entry(BusinessDomain::Authentication).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::RoutePattern,
@@ -1137,7 +1135,7 @@ impl BusinessDomainEngine {
                     if content.contains("@Controller('users')") || 
                        content.contains("@Controller('/users')") ||
                        content.contains("@Controller('user')") ||
-                       entry.path().to_string_lossy().contains("user") {
+                       file_path.to_string_lossy().contains("user") {
                         domain_evidence.entry(BusinessDomain::UserManagement).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::RoutePattern,
@@ -1153,7 +1151,7 @@ impl BusinessDomainEngine {
                     if content.contains("@Controller('notifications')") || 
                        content.contains("@Controller('/notifications')") ||
                        content.contains("@Controller('notification')") ||
-                       entry.path().to_string_lossy().contains("notification") {
+                       file_path.to_string_lossy().contains("notification") {
                         domain_evidence.entry(BusinessDomain::Notification).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::RoutePattern,
@@ -1175,16 +1173,15 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_services(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && 
-               entry.path().extension().map_or(false, |ext| ext == "ts") &&
-               entry.path().to_string_lossy().contains("service") {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") &&
+               file_path.to_string_lossy().contains("service") {
                 
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Service class names
                     if content.contains("class AuthService") || 
                        content.contains("class AuthenticationService") ||
-                       entry.path().to_string_lossy().contains("auth") {
+                       file_path.to_string_lossy().contains("auth") {
                         domain_evidence.entry(BusinessDomain::Authentication).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::ServiceName,
@@ -1198,7 +1195,7 @@ impl BusinessDomainEngine {
 
                     if content.contains("class UserService") || 
                        content.contains("class UserManagementService") ||
-                       entry.path().to_string_lossy().contains("user") {
+                       file_path.to_string_lossy().contains("user") {
                         domain_evidence.entry(BusinessDomain::UserManagement).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::ServiceName,
@@ -1212,7 +1209,7 @@ impl BusinessDomainEngine {
 
                     if content.contains("class NotificationService") ||
                        content.contains("class NotificationsService") ||
-                       entry.path().to_string_lossy().contains("notification") {
+                       file_path.to_string_lossy().contains("notification") {
                         domain_evidence.entry(BusinessDomain::Notification).or_insert_with(Vec::new).push(
                             DomainEvidence {
                                 evidence_type: DomainEvidenceType::ServiceName,
@@ -1278,12 +1275,11 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_usecases(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && 
-               entry.path().extension().map_or(false, |ext| ext == "ts") &&
-               (entry.path().to_string_lossy().contains("usecase") || entry.path().to_string_lossy().contains("usecases")) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") &&
+               (file_path.to_string_lossy().contains("usecase") || file_path.to_string_lossy().contains("usecases")) {
                 
-                let file_path = entry.path().to_string_lossy();
+                let file_path = file_path.to_string_lossy();
                 
                 // Notification use cases
                 if file_path.contains("notification") {
@@ -1340,9 +1336,9 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_decorators(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "ts") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // E-commerce route decorators
                     let ecommerce_routes = [
                         "@Get('products')", "@Post('orders')", "@Get('cart')", "@Post('payment')",
@@ -1408,9 +1404,9 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_guards_pipes(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "ts") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // Authentication guards
                     if content.contains("@UseGuards(AuthGuard") || content.contains("@UseGuards(JwtAuthGuard") ||
                        content.contains("class AuthGuard") || content.contains("class JwtAuthGuard") {
@@ -1462,13 +1458,13 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_entities(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "ts") &&
-               (entry.path().to_string_lossy().contains("entity") || entry.path().to_string_lossy().contains("entities")) {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") &&
+               (file_path.to_string_lossy().contains("entity") || file_path.to_string_lossy().contains("entities")) {
+                if let Ok(content) = fs::read_to_string(&file_path) {
                     // TypeORM entity patterns
                     if content.contains("@Entity()") {
-                        let file_name = entry.path().file_stem()
+                        let file_name = file_path.file_stem()
                             .and_then(|name| name.to_str())
                             .unwrap_or("")
                             .to_lowercase();
@@ -1527,11 +1523,11 @@ impl BusinessDomainEngine {
     fn analyze_nestjs_dtos(&self, domain_evidence: &mut HashMap<BusinessDomain, Vec<DomainEvidence>>) -> Result<(), Box<dyn std::error::Error>> {
         let path = Path::new(&self.codebase_path);
         
-        for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "ts") &&
-               entry.path().to_string_lossy().contains("dto") {
-                if let Ok(content) = fs::read_to_string(entry.path()) {
-                    let file_path = entry.path().to_string_lossy().to_lowercase();
+        for file_path in ProjectWalker::new(path).files() {
+            if file_path.extension().map_or(false, |ext| ext == "ts") &&
+               file_path.to_string_lossy().contains("dto") {
+                if let Ok(content) = fs::read_to_string(&file_path) {
+                    let file_path = file_path.to_string_lossy().to_lowercase();
 
                     // Authentication DTOs
                     if file_path.contains("login") || file_path.contains("register") || 
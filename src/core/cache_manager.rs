@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use sha2::{Sha256, Digest};
+use crate::core::workspace_detector::WorkspaceLayout;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry<T> {
@@ -56,7 +57,8 @@ impl CacheManager {
         format!("{:x}", hasher.finalize())
     }
     
-    pub fn calculate_project_hash(&self, project_path: &str) -> Result<String> {
+    /// Hash of the files that shape the analysis; `workspace` adds the manifests of every package
+    pub fn calculate_project_hash(&self, project_path: &str, workspace: Option<&WorkspaceLayout>) -> Result<String> {
         let mut hasher = Sha256::new();
         let mut file_count = 0;
         let mut total_size = 0u64;
@@ -64,10 +66,22 @@ impl CacheManager {
         // Hash key files that indicate project structure changes
         let important_files = vec![
             "Cargo.toml", "package.json", "pom.xml", "requirements.txt",
-            "README.md", ".gitignore", "tsconfig.json", "setup.py"
+            "README.md", ".gitignore", "tsconfig.json", "setup.py",
+            "pnpm-workspace.yaml", "nx.json", "turbo.json", "settings.gradle", "settings.gradle.kts",
+            "pyproject.toml", "build.gradle", "build.gradle.kts"
         ];
         
-        for file_name in important_files {
+        // Workspace members change the analysis as much as the root does
+        let mut hashed_files: Vec<String> = important_files.iter().map(|file| file.to_string()).collect();
+        if let Some(layout) = workspace {
+            for package in layout.packages.iter().filter(|package| package.path != Path::new(".")) {
+                for file_name in &important_files {
+                    hashed_files.push(package.path.join(file_name).to_string_lossy().to_string());
+                }
+            }
+        }
+        
+        for file_name in &hashed_files {
             let file_path = Path::new(project_path).join(file_name);
             if file_path.exists() {
                 if let Ok(metadata) = std::fs::metadata(&file_path) {
@@ -225,13 +239,13 @@ mod tests {
         
         // Test set and get
         cache.set(cache_key, &test_data, file_hash).unwrap();
-        let retrieved: Option<Vec<&str>> = cache.get(cache_key).unwrap();
+        let retrieved: Option<Vec<String>> = cache.get(cache_key).unwrap();
         
-        assert_eq!(retrieved, Some(test_data));
+        assert_eq!(retrieved, Some(test_data.iter().map(|s| s.to_string()).collect()));
         
         // Test invalidation
         cache.invalidate(cache_key).unwrap();
-        let after_invalidation: Option<Vec<&str>> = cache.get(cache_key).unwrap();
+        let after_invalidation: Option<Vec<String>> = cache.get(cache_key).unwrap();
         assert_eq!(after_invalidation, None);
     }
     
@@ -245,8 +259,8 @@ mod tests {
         std::fs::write(temp_dir.path().join("README.md"), "# Test Project").unwrap();
         
         let cache = CacheManager::new().unwrap();
-        let hash1 = cache.calculate_project_hash(project_path).unwrap();
-        let hash2 = cache.calculate_project_hash(project_path).unwrap();
+        let hash1 = cache.calculate_project_hash(project_path, None).unwrap();
+        let hash2 = cache.calculate_project_hash(project_path, None).unwrap();
         
         assert_eq!(hash1, hash2); // Same project should have same hash
         
@@ -254,7 +268,7 @@ mod tests {
         std::fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"modified\"").unwrap();
         std::thread::sleep(Duration::from_millis(100)); // Ensure different timestamp
         
-        let hash3 = cache.calculate_project_hash(project_path).unwrap();
+        let hash3 = cache.calculate_project_hash(project_path, None).unwrap();
         assert_ne!(hash1, hash3); // Hash should change after modification
     }
 }
\ No newline at end of file
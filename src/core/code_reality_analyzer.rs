@@ -7,6 +7,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::core::config::get_config;
+use crate::core::project_walker::ProjectWalker;
 use crate::core::documentation_claims_extractor::{ClaimType, ClaimPriority};
 
 /// Types of implementation reality that can be detected from code
@@ -396,20 +397,8 @@ impl CodeRealityAnalyzer {
     
     /// Recursively scan directory for files
     fn scan_directory_recursive<P: AsRef<Path>>(&self, dir_path: P, files: &mut Vec<PathBuf>) -> Result<()> {
-        let dir_path = dir_path.as_ref();
-        
-        if let Ok(entries) = fs::read_dir(dir_path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                
-                if path.is_dir() {
-                    self.scan_directory_recursive(&path, files)?;
-                } else {
-                    files.push(path);
-                }
-            }
-        }
-        
+        files.extend(ProjectWalker::new(dir_path).files());
+
         Ok(())
     }
     
@@ -4,6 +4,16 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::core::types::Framework;
+
+/// Directory holding the JSON data files, relative to the working directory
+pub const CONFIG_DATA_DIR: &str = "configs/data";
+
+/// `frameworks.json` compiled into the binary, so detection works outside the repository checkout
+const BUNDLED_FRAMEWORK_RULES: &str = include_str!("../../configs/data/frameworks.json");
+
+/// Analyzer settings file, relative to the working directory
+pub const ANALYZER_CONFIG_FILE: &str = "configs/analyzer_config.yaml";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectTypeConfig {
@@ -31,6 +41,22 @@ pub struct FrameworkConfig {
     pub indicators: Vec<FrameworkIndicator>,
     #[serde(default)]
     pub deno_specific: Option<DenoSpecificConfig>,
+    /// Built-in framework this entry detects; entries without one are reported as custom frameworks
+    #[serde(default)]
+    pub framework: Option<Framework>,
+    /// Minimum share of indicator weight that must match (defaults to the rule engine's threshold)
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Where to read the framework version from, tried in order
+    #[serde(default)]
+    pub version_sources: Vec<VersionSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionSource {
+    /// Manifest file name, e.g. `package.json`, `requirements.txt`, `Cargo.toml`, `go.mod`, `pom.xml`
+    pub manifest: String,
+    pub package: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +72,12 @@ pub struct FrameworkIndicator {
     pub r#type: String,
     pub patterns: Vec<String>,
     pub confidence_weight: f32,
+    /// Detection fails outright when a required indicator does not match
+    #[serde(default)]
+    pub required: bool,
+    /// File globs (`*.py`, `settings.py`) restricting which files content patterns are searched in
+    #[serde(default)]
+    pub files: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,7 +199,7 @@ pub struct ConfigManager {
 
 impl ConfigManager {
     pub fn new() -> Result<Self> {
-        let config_path = Path::new("configs/data");
+        let config_path = Path::new(CONFIG_DATA_DIR);
         
         let project_types = Self::load_project_types(config_path)?;
         let frameworks = Self::load_frameworks(config_path)?;
@@ -209,21 +241,40 @@ impl ConfigManager {
     }
 
     fn load_frameworks(config_path: &Path) -> Result<HashMap<String, FrameworkConfig>> {
-        let file_path = config_path.join("frameworks.json");
-        let content = fs::read_to_string(&file_path)
-            .with_context(|| format!("Failed to read frameworks config from {:?}", file_path))?;
-        
-        let data: FrameworksData = serde_json::from_str(&content)
-            .with_context(|| "Failed to parse frameworks JSON")?;
-
         let mut map = HashMap::new();
-        for framework in data.frameworks {
+        for framework in Self::load_framework_rules(&config_path.join("frameworks.json"))? {
             map.insert(framework.id.clone(), framework);
         }
 
         Ok(map)
     }
 
+    /// Load framework detection rules from a `frameworks.json`-shaped file
+    pub fn load_framework_rules(file_path: &Path) -> Result<Vec<FrameworkConfig>> {
+        let content = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read frameworks config from {:?}", file_path))?;
+        
+        Self::parse_framework_rules(&content)
+            .with_context(|| format!("Invalid frameworks config {:?}", file_path))
+    }
+
+    /// Framework detection rules: `frameworks.json` in the config data directory when present, so a
+    /// checkout can customize them, otherwise the copy bundled with the binary
+    pub fn load_default_framework_rules() -> Result<Vec<FrameworkConfig>> {
+        let rules_file = Path::new(CONFIG_DATA_DIR).join("frameworks.json");
+        if rules_file.exists() {
+            return Self::load_framework_rules(&rules_file);
+        }
+        Self::parse_framework_rules(BUNDLED_FRAMEWORK_RULES).context("Invalid bundled frameworks config")
+    }
+
+    fn parse_framework_rules(content: &str) -> Result<Vec<FrameworkConfig>> {
+        let data: FrameworksData = serde_json::from_str(content)
+            .with_context(|| "Failed to parse frameworks JSON")?;
+
+        Ok(data.frameworks)
+    }
+
     fn load_business_domains(config_path: &Path) -> Result<HashMap<String, BusinessDomainConfig>> {
         let file_path = config_path.join("business_domains.json");
         let content = fs::read_to_string(&file_path)
@@ -422,6 +473,13 @@ mod tests {
         assert!(danet.unwrap().deno_specific.is_some());
     }
 
+    #[test]
+    fn test_bundled_framework_rules_parse() {
+        let rules = ConfigManager::parse_framework_rules(BUNDLED_FRAMEWORK_RULES).unwrap();
+        assert!(rules.iter().any(|rule| rule.id == "gin"));
+        assert!(rules.iter().all(|rule| !rule.indicators.is_empty()));
+    }
+
     #[test]
     fn test_project_type_lookup() {
         let config = get_config();
@@ -1,12 +1,12 @@
 use std::path::Path;
 use anyhow::{Result, Context as AnyhowContext};
-use walkdir;
 
 use crate::core::types::{AstSegment, Framework, Language};
 use crate::core::context_types::{ProjectContext, SegmentContext, EnhancedSegmentContext};
 use crate::core::hierarchical_context_manager::HierarchicalContextManager;
 use crate::core::ast_analyzer::{ASTAnalyzer, CodeSegment};
 use crate::core::config::Config;
+use crate::core::project_walker::ProjectWalker;
 
 pub struct ContextAwareASTAnalyzer {
     ast_analyzer: ASTAnalyzer,
@@ -140,19 +140,15 @@ impl ContextAwareASTAnalyzer {
     }
 
     async fn count_processed_files(&self, project_path: &Path) -> Result<usize> {
-        let mut count = 0;
-        let walker = walkdir::WalkDir::new(project_path);
-        
-        for entry in walker {
-            let entry = entry?;
-            if entry.file_type().is_file() {
-                if let Some(extension) = entry.path().extension() {
-                    if matches!(extension.to_str(), Some("rs") | Some("ts") | Some("js") | Some("py") | Some("java")) {
-                        count += 1;
-                    }
-                }
-            }
-        }
+        let count = ProjectWalker::new(project_path)
+            .files()
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|extension| extension.to_str()),
+                    Some("rs") | Some("go") | Some("ts") | Some("vue") | Some("js") | Some("py") | Some("java")
+                )
+            })
+            .count();
         
         Ok(count)
     }
@@ -6,7 +6,7 @@ use crate::core::project_classifier::ProjectType;
 use crate::core::business_domain_engine::{BusinessDomainEngine, BusinessDomain};
 use crate::core::framework_detector::FrameworkDetector;
 use crate::core::ast_analyzer::{CodeSegment, ASTAnalyzer, SegmentType, SegmentMetadata, ConfigSegment};
-use crate::intelligence::llm_client::{LocalLLMManager, ModelConfig};
+use crate::intelligence::llm_client::LocalLLMManager;
 
 /// Self-analysis test to validate context-aware classification
 pub struct ContextAwareSelfTest {
@@ -169,7 +169,7 @@ impl ContextAwareSelfTest {
 
     /// Check if LLM is available for testing
     async fn check_llm_availability(&self) -> bool {
-        match LocalLLMManager::new(Some(ModelConfig::default())).await {
+        match LocalLLMManager::new(None).await {
             Ok(llm) => {
                 match llm.ensure_model_ready().await {
                     Ok(_) => true,
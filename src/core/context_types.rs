@@ -177,6 +177,32 @@ impl CrossReferenceMap {
             .push(target);
     }
 
+    /// Collects the dependencies and cross references each enhanced segment carries
+    pub fn from_enhanced_segments(segments: &[EnhancedSegmentContext]) -> Self {
+        let mut map = Self::new();
+        for segment in segments {
+            let context = &segment.segment_context;
+            for dependency in &context.dependencies {
+                map.add_functional_dependency(context.segment_id.clone(), dependency.clone());
+            }
+            for reference in &segment.cross_references {
+                let (source, target) = (reference.source_segment.clone(), reference.target_segment.clone());
+                match reference.reference_type {
+                    CrossReferenceType::FunctionalDependency | CrossReferenceType::BusinessRule => {
+                        map.add_functional_dependency(source, target)
+                    }
+                    CrossReferenceType::DataFlow => map.add_data_flow(source, target),
+                    CrossReferenceType::ArchitecturalRelationship => {
+                        map.architectural_relationships.entry(source).or_default().push(target)
+                    }
+                    // Similar names alone are no reason to batch segments together
+                    CrossReferenceType::NamingConvention => {}
+                }
+            }
+        }
+        map
+    }
+
     pub fn get_related_segments(&self, segment_id: &SegmentId) -> Vec<SegmentId> {
         let mut related = Vec::new();
         
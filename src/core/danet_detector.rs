@@ -4,6 +4,7 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 use crate::core::config::get_config;
+use crate::core::project_walker::ProjectWalker;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DanetAnalysisResult {
@@ -226,19 +227,12 @@ impl DanetDetector {
         evidence: &mut Vec<DanetEvidence>,
         confidence: &mut f32
     ) -> Result<()> {
-        use walkdir::WalkDir;
-
         let mut danet_imports = 0;
         let mut nestjs_imports = 0;
         let mut deno_url_imports = 0;
 
-        for entry in WalkDir::new(project_path).max_depth(3) {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
-
-            let file_path = entry.path();
+        for path in ProjectWalker::new(project_path).max_depth(3).files() {
+            let file_path = path.as_path();
             if let Some(extension) = file_path.extension() {
                 if extension == "ts" || extension == "js" {
                     if let Ok(content) = fs::read_to_string(file_path) {
@@ -375,15 +369,9 @@ impl DanetDetector {
 
     async fn detect_danet_patterns(&self, project_path: &Path) -> Result<Vec<DanetPattern>> {
         let mut patterns = Vec::new();
-        use walkdir::WalkDir;
-
-        for entry in WalkDir::new(project_path).max_depth(3) {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
 
-            let file_path = entry.path();
+        for path in ProjectWalker::new(project_path).max_depth(3).files() {
+            let file_path = path.as_path();
             if let Some(extension) = file_path.extension() {
                 if extension == "ts" {
                     if let Ok(content) = fs::read_to_string(file_path) {
@@ -423,18 +411,10 @@ impl DanetDetector {
     }
 
     fn has_typescript_files(&self, project_path: &Path) -> bool {
-        use walkdir::WalkDir;
-
-        for entry in WalkDir::new(project_path).max_depth(2) {
-            if let Ok(entry) = entry {
-                if let Some(extension) = entry.path().extension() {
-                    if extension == "ts" && !entry.path().to_string_lossy().contains("node_modules") {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
+        ProjectWalker::new(project_path)
+            .max_depth(2)
+            .files()
+            .any(|path| path.extension().is_some_and(|extension| extension == "ts"))
     }
 
     /// Compare with NestJS patterns to provide differential analysis
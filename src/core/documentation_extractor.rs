@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 // Using standard println for logging - could be upgraded to proper logging later
 
 use crate::core::config::get_config;
+use crate::core::project_walker::ProjectWalker;
 
 /// Types of documentation sections that can be extracted
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -562,33 +563,15 @@ impl DocumentationExtractor {
 
     /// Recursively scan docs directory for markdown files
     fn scan_docs_directory(&self, docs_dir: &Path) -> Result<Vec<PathBuf>> {
-        let mut files = Vec::new();
-        
-        if let Ok(entries) = fs::read_dir(docs_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Some(extension) = path.extension() {
-                        if extension == "md" || extension == "rst" {
-                            // Check file size
-                            if let Ok(metadata) = entry.metadata() {
-                                let size_kb = metadata.len() / 1024;
-                                if size_kb <= self.config.max_file_size_kb {
-                                    files.push(path);
-                                } else {
-                                    // Debug: Skipping large documentation file
-                                }
-                            }
-                        }
-                    }
-                } else if path.is_dir() {
-                    // Recursively scan subdirectories
-                    if let Ok(sub_files) = self.scan_docs_directory(&path) {
-                        files.extend(sub_files);
-                    }
-                }
-            }
-        }
+        let files = ProjectWalker::new(docs_dir)
+            .files()
+            .filter(|path| path.extension().is_some_and(|extension| extension == "md" || extension == "rst"))
+            .filter(|path| {
+                fs::metadata(path)
+                    .map(|metadata| metadata.len() / 1024 <= self.config.max_file_size_kb)
+                    .unwrap_or(false)
+            })
+            .collect();
 
         Ok(files)
     }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::documentation_claims_extractor::{DocumentationClaimsExtractor, DocumentationClaimsResult, DocumentationClaim};
 use crate::core::code_reality_analyzer::{CodeRealityAnalyzer, CodeRealityResult, ImplementationEvidence, ImplementationLevel};
 use crate::core::conflict_resolution_engine::{ConflictResolutionEngine, ConflictResolutionResult, Conflict, ResolutionStrategy};
-use crate::core::todo_scanner::{TodoScanner, TodoScanResult};
+use crate::core::todo_scanner::{TicketSummary, TodoScanner, TodoScanResult};
 use crate::core::status_inference_engine::{StatusInferenceEngine, StatusAnalysisResult};
 
 /// Dual-category status analysis combining documentation claims with code reality
@@ -38,6 +38,8 @@ pub struct InferredStatusAnalysis {
     pub incomplete_features: Vec<IncompleteFeature>,
     pub missing_implementations: Vec<MissingImplementation>,
     pub partial_implementations: Vec<PartialImplementation>,
+    #[serde(default)]
+    pub untested_features: Vec<UntestedFeature>,
     pub inferred_completion_score: f32,
 }
 
@@ -126,6 +128,17 @@ pub struct PartialImplementation {
     pub completion_estimate: f32,
 }
 
+/// Implemented function, route or component that no test exercises
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UntestedFeature {
+    pub feature_name: String,
+    pub feature_kind: String, // "function", "route", "component"
+    pub location: String,
+    pub line_coverage: Option<f32>,
+    pub confidence: f32,
+    pub evidence: Vec<String>,
+}
+
 /// Comprehensive status of a feature
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureStatus {
@@ -190,6 +203,7 @@ pub enum StatusCategory {
     PartiallyImplemented,
     PlannedNotImplemented,
     UnplannedImplemented,
+    ImplementedUntested,
     Conflicted,
 }
 
@@ -378,7 +392,7 @@ impl DualCategoryStatusAnalyzer {
         let todo_scanner = TodoScanner::new()
             .context("Failed to create TODO scanner")?;
             
-        let status_engine = StatusInferenceEngine::new()
+        let status_engine = StatusInferenceEngine::from_global_config()
             .context("Failed to create status inference engine")?;
         
         println!("Initialized DualCategoryStatusAnalyzer with comprehensive analysis pipeline");
@@ -495,6 +509,10 @@ impl DualCategoryStatusAnalyzer {
                         by_priority: HashMap::new(),
                         by_file_type: HashMap::new(),
                         most_problematic_files: Vec::new(),
+                        by_age_bucket: HashMap::new(),
+                        by_owner: HashMap::new(),
+                        oldest_items: Vec::new(),
+                        tickets: TicketSummary::default(),
                     },
                     scan_metadata: crate::core::todo_scanner::TodoScanMetadata {
                         files_scanned: 0,
@@ -568,6 +586,7 @@ impl DualCategoryStatusAnalyzer {
                 incomplete_features: Vec::new(),
                 missing_implementations: Vec::new(),
                 partial_implementations: Vec::new(),
+                untested_features: Vec::new(),
                 inferred_completion_score: 1.0,
             },
             merged_status: MergedStatusAnalysis {
@@ -627,9 +646,48 @@ impl DualCategoryStatusAnalyzer {
         conflicts_result: &crate::core::conflict_resolution_engine::ConflictResolutionResult,
         status_result: &crate::core::status_inference_engine::DualCategoryStatusResult,
     ) -> Result<InferredStatusAnalysis> {
-        let incomplete_features = self.identify_incomplete_features(claims_result, reality_result, conflicts_result);
-        let missing_implementations = self.identify_missing_implementations(conflicts_result);
-        let partial_implementations = self.identify_partial_implementations(reality_result, conflicts_result);
+        let mut incomplete_features = self.identify_incomplete_features(claims_result, reality_result, conflicts_result);
+        let mut missing_implementations = self.identify_missing_implementations(conflicts_result);
+        let mut partial_implementations = self.identify_partial_implementations(reality_result, conflicts_result);
+        
+        // Fold in gaps found by the status inference analyzers (CRUD, auth, API, database, ...)
+        let inferred = &status_result.inferred_status;
+        incomplete_features.extend(inferred.incomplete_features.iter().map(|feature| IncompleteFeature {
+            feature_name: feature.feature_name.clone(),
+            claimed_capabilities: vec![feature.feature_category.clone()],
+            actual_implementation: None,
+            completion_percentage: feature.completeness_score,
+            missing_components: feature.missing_components.clone(),
+            evidence: feature.evidence.clone(),
+        }));
+        missing_implementations.extend(inferred.missing_implementations.iter().map(|missing| MissingImplementation {
+            feature_name: missing.description.clone(),
+            documentation_source: format!("Status inference: {}", missing.implementation_type),
+            expected_implementation: missing.suggested_implementation.clone()
+                .unwrap_or_else(|| format!("Implementation for {}", missing.implementation_type)),
+            suggested_location: Some(missing.expected_location.clone()),
+            priority_score: missing.confidence,
+            dependencies: Vec::new(),
+        }));
+        partial_implementations.extend(inferred.partial_implementations.iter().map(|partial| PartialImplementation {
+            feature_name: partial.implementation_name.clone(),
+            implemented_parts: partial.implemented_parts.clone(),
+            missing_parts: partial.missing_parts.clone(),
+            implementation_quality: match partial.completeness_percentage {
+                c if c > 80.0 => ImplementationQuality::Development,
+                c if c > 50.0 => ImplementationQuality::Prototype,
+                _ => ImplementationQuality::Skeleton,
+            },
+            completion_estimate: partial.completeness_percentage / 100.0,
+        }));
+        let untested_features = inferred.untested_implementations.iter().map(|untested| UntestedFeature {
+            feature_name: untested.name.clone(),
+            feature_kind: untested.kind.clone(),
+            location: untested.location.clone(),
+            line_coverage: untested.line_coverage,
+            confidence: untested.confidence,
+            evidence: untested.evidence.clone(),
+        }).collect();
         
         // Calculate inferred completion score
         let base_score = reality_result.summary.overall_implementation_score;
@@ -643,6 +701,7 @@ impl DualCategoryStatusAnalyzer {
             incomplete_features,
             missing_implementations,
             partial_implementations,
+            untested_features,
             inferred_completion_score,
         })
     }
@@ -891,6 +950,7 @@ impl DualCategoryStatusAnalyzer {
                 
             let status_category = match matching_impl {
                 Some(impl_evidence) => match impl_evidence.implementation_level {
+                    ImplementationLevel::Complete if self.lacks_tests(impl_evidence, inferred) => StatusCategory::ImplementedUntested,
                     ImplementationLevel::Complete => StatusCategory::CompletelyImplemented,
                     ImplementationLevel::Partial => StatusCategory::PartiallyImplemented,
                     _ => StatusCategory::PlannedNotImplemented,
@@ -940,9 +1000,48 @@ impl DualCategoryStatusAnalyzer {
             }
         }
         
+        // Untested code not already attributed to a documented feature
+        for untested in &inferred.untested_features {
+            let attributed = feature_statuses.iter().any(|fs| {
+                fs.status_category == StatusCategory::ImplementedUntested
+                    && fs.implementation_evidence.as_ref().is_some_and(|ie| Self::evidence_covers(ie, &untested.location))
+            });
+            if !attributed {
+                feature_statuses.push(FeatureStatus {
+                    feature_name: format!("{} {}", untested.feature_kind, untested.feature_name),
+                    documentation_claim: None,
+                    implementation_evidence: None,
+                    status_category: StatusCategory::ImplementedUntested,
+                    completion_level: CompletionLevel::NearComplete,
+                    confidence: untested.confidence,
+                    next_actions: self.generate_feature_actions(&StatusCategory::ImplementedUntested, &CompletionLevel::NearComplete),
+                });
+            }
+        }
+        
         feature_statuses
     }
     
+    /// Whether any untested function, route or component lives in the implementation's files
+    fn lacks_tests(&self, impl_evidence: &ImplementationEvidence, inferred: &InferredStatusAnalysis) -> bool {
+        inferred.untested_features.iter().any(|untested| Self::evidence_covers(impl_evidence, &untested.location))
+    }
+    
+    /// Whether `location` (`file` or `file:line`) names one of the evidence's source files,
+    /// matching whole path components so `user.ts` doesn't cover `superuser.ts`
+    fn evidence_covers(impl_evidence: &ImplementationEvidence, location: &str) -> bool {
+        let file = match location.rsplit_once(':') {
+            Some((file, line)) if !line.is_empty() && line.chars().all(|c| c.is_ascii_digit()) => file,
+            _ => location,
+        };
+        let wanted = path_components(file);
+        if wanted.is_empty() {
+            return false;
+        }
+        impl_evidence.source_files.iter()
+            .any(|source| path_components(&source.to_string_lossy()).ends_with(&wanted))
+    }
+    
     fn features_match(&self, claim_desc: &str, impl_desc: &str) -> bool {
         // Simple similarity check
         let claim_desc_lower = claim_desc.to_lowercase();
@@ -970,6 +1069,7 @@ impl DualCategoryStatusAnalyzer {
             ],
             (StatusCategory::CompletelyImplemented, _) => vec!["Consider enhancements".to_string()],
             (StatusCategory::UnplannedImplemented, _) => vec!["Add documentation".to_string()],
+            (StatusCategory::ImplementedUntested, _) => vec!["Add tests".to_string()],
             (StatusCategory::Conflicted, _) => vec!["Resolve conflicts between docs and code".to_string()],
             _ => vec!["Review implementation status".to_string()],
         }
@@ -1199,6 +1299,21 @@ impl DualCategoryStatusAnalyzer {
             });
         }
         
+        if !inferred.untested_features.is_empty() {
+            let mut affected_components: Vec<String> = inferred.untested_features.iter()
+                .map(|untested| untested.location.rsplit_once(':').map_or(untested.location.as_str(), |(file, _)| file).to_string())
+                .collect();
+            affected_components.sort();
+            affected_components.dedup();
+            recommendations.push(ConsistencyRecommendation {
+                recommendation_type: RecommendationType::ImproveTesting,
+                description: format!("Add tests for {} untested functions, routes and components", inferred.untested_features.len()),
+                priority: RecommendationPriority::Medium,
+                affected_components,
+                estimated_impact: "Safer changes to implemented features".to_string(),
+            });
+        }
+        
         recommendations
     }
 }
@@ -1219,10 +1334,19 @@ impl crate::core::documentation_claims_extractor::ClaimType {
     }
 }
 
+/// Normal components of a `/` or `\`-separated path, ignoring `.` segments
+fn path_components(path: &str) -> Vec<&str> {
+    path.split(['/', '\\'])
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
     use tempfile::TempDir;
+    use crate::core::code_reality_analyzer::RealityType;
     
     #[test]
     fn test_dual_category_analyzer_creation() {
@@ -1235,10 +1359,15 @@ mod tests {
         let analyzer = DualCategoryStatusAnalyzer::new().unwrap();
         
         assert_eq!(
-            analyzer.estimate_effort_from_description("refactor entire authentication system"),
+            analyzer.estimate_effort_from_description("refactor the entire authentication system so sessions, tokens and roles share one store"),
             EffortLevel::Large
         );
         
+        assert_eq!(
+            analyzer.estimate_effort_from_description("refactor entire authentication system"),
+            EffortLevel::Medium
+        );
+        
         assert_eq!(
             analyzer.estimate_effort_from_description("fix typo"),
             EffortLevel::Small
@@ -1284,4 +1413,25 @@ mod tests {
             "database connection pooling"
         ));
     }
+
+    #[test]
+    fn test_evidence_covers_whole_path_components() {
+        let evidence = ImplementationEvidence {
+            reality_type: RealityType::ApiEndpointsImplemented,
+            description: "User routes".to_string(),
+            source_files: vec![PathBuf::from("/repo/src/routes/superuser.ts"), PathBuf::from("/repo/./src/api/orders.ts")],
+            line_numbers: Vec::new(),
+            confidence: 0.8,
+            implementation_level: ImplementationLevel::Complete,
+            code_snippets: Vec::new(),
+            dependencies: Vec::new(),
+            patterns_matched: Vec::new(),
+        };
+
+        assert!(DualCategoryStatusAnalyzer::evidence_covers(&evidence, "src/routes/superuser.ts:12"));
+        assert!(DualCategoryStatusAnalyzer::evidence_covers(&evidence, "./src/api/orders.ts"));
+        assert!(!DualCategoryStatusAnalyzer::evidence_covers(&evidence, "user.ts:3"));
+        assert!(!DualCategoryStatusAnalyzer::evidence_covers(&evidence, "routes/user.ts"));
+        assert!(!DualCategoryStatusAnalyzer::evidence_covers(&evidence, ":7"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,586 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tree_sitter::Node;
+use anyhow::Result;
+use crate::core::ast_analyzer::{
+    CodeSegment, SegmentExtractor, SegmentType, SegmentMetadata,
+    FunctionSegment, ClassSegment, InterfaceSegment, RouteSegment, DatabaseSegment
+};
+use crate::core::types::Framework;
+
+const GIN_METHODS: [&str; 8] = ["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "Any"];
+const FIBER_METHODS: [&str; 8] = ["Get", "Post", "Put", "Delete", "Patch", "Head", "Options", "All"];
+const NET_HTTP_HANDLERS: [&str; 2] = ["HandleFunc", "Handle"];
+
+pub struct GoExtractor;
+
+impl Default for GoExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// File-level facts gathered before segment extraction
+struct GoFileContext {
+    framework: Option<Framework>,
+    group_prefixes: HashMap<String, String>,
+    receiver_methods: HashMap<String, Vec<String>>,
+    table_names: HashMap<String, String>,
+    gorm_models: HashSet<String>,
+}
+
+impl GoExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn extract_function_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let function_name = self.get_field_text(node, "name", source)?;
+        let parameters = self.extract_parameters(node, source);
+        let return_type = self.get_field_text(node, "result", source);
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Function(FunctionSegment {
+                name: function_name,
+                parameters,
+                return_type,
+                is_async: false, // Go concurrency is expressed at the call site with `go`
+                decorators: Vec::new(),
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: None,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    fn extract_type_segment(&self, node: &Node, source: &str, file_path: &Path, context: &GoFileContext) -> Option<CodeSegment> {
+        let type_name = self.get_field_text(node, "name", source)?;
+        let type_node = node.child_by_field_name("type")?;
+
+        match type_node.kind() {
+            "struct_type" => {
+                // GORM models become DatabaseSegments instead
+                if context.gorm_models.contains(&type_name) {
+                    return Some(CodeSegment {
+                        segment_type: SegmentType::Database(DatabaseSegment {
+                            model_name: type_name.clone(),
+                            table_name: context.table_names.get(&type_name).cloned(),
+                            fields: self.extract_struct_fields(&type_node, source),
+                            relationships: self.extract_gorm_relationships(&type_node, source, context),
+                        }),
+                        content: self.get_node_text(node, source).to_string(),
+                        metadata: self.build_metadata(node, file_path),
+                        framework_context: None,
+                        business_hints: self.extract_business_hints(node, source),
+                    });
+                }
+
+                Some(CodeSegment {
+                    segment_type: SegmentType::Class(ClassSegment {
+                        name: type_name.clone(),
+                        extends: None,
+                        implements: self.extract_embedded_types(&type_node, source),
+                        is_react_component: false,
+                        props: Vec::new(),
+                        hooks: Vec::new(),
+                        methods: context.receiver_methods.get(&type_name).cloned().unwrap_or_default(),
+                    }),
+                    content: self.get_node_text(node, source).to_string(),
+                    metadata: self.build_metadata(node, file_path),
+                    framework_context: None,
+                    business_hints: self.extract_business_hints(node, source),
+                })
+            }
+            "interface_type" => {
+                let mut cursor = type_node.walk();
+                let mut extends = Vec::new();
+                let mut methods = Vec::new();
+
+                for child in type_node.named_children(&mut cursor) {
+                    match child.kind() {
+                        "method_spec" => methods.extend(self.get_field_text(&child, "name", source)),
+                        "type_identifier" | "qualified_type" | "constraint_elem" => {
+                            extends.push(self.get_node_text(&child, source).to_string());
+                        }
+                        _ => {}
+                    }
+                }
+
+                Some(CodeSegment {
+                    segment_type: SegmentType::Interface(InterfaceSegment {
+                        name: type_name,
+                        extends,
+                        properties: methods, // Go interfaces declare methods, not properties
+                    }),
+                    content: self.get_node_text(node, source).to_string(),
+                    metadata: self.build_metadata(node, file_path),
+                    framework_context: None,
+                    business_hints: self.extract_business_hints(node, source),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Handles `r.GET(...)` (Gin), `app.Post(...)` (Fiber) and `http.HandleFunc(...)` registrations
+    fn extract_route_segment(&self, node: &Node, source: &str, file_path: &Path, context: &GoFileContext) -> Option<CodeSegment> {
+        let function = node.child_by_field_name("function")?;
+        if function.kind() != "selector_expression" {
+            return None;
+        }
+        let method_name = self.get_field_text(&function, "field", source)?;
+        let receiver = self.get_field_text(&function, "operand", source)?;
+
+        let arguments = node.child_by_field_name("arguments")?;
+        let mut cursor = arguments.walk();
+        let args: Vec<Node> = arguments.named_children(&mut cursor).collect();
+        if args.len() < 2 {
+            return None;
+        }
+        let raw_path = self.string_literal_value(&args[0], source)?;
+
+        // Gin and Fiber method names are common words (`Get`, `Post`, `Handle`), so they only count as
+        // route registrations in files that import the framework; `http.Post(url, ...)` is a client call
+        let (method, path, framework, handler_args) = match context.framework {
+            Some(Framework::Gin) if GIN_METHODS.contains(&method_name.as_str()) => {
+                (method_name.to_uppercase(), raw_path, context.framework, &args[1..])
+            }
+            // r.Handle("GET", "/users", handler)
+            Some(Framework::Gin) if method_name == "Handle" && args.len() >= 3 => {
+                let path = self.string_literal_value(&args[1], source)?;
+                (raw_path.to_uppercase(), path, context.framework, &args[2..])
+            }
+            Some(Framework::Fiber) if FIBER_METHODS.contains(&method_name.as_str()) => {
+                let method = if method_name == "All" { "ANY".to_string() } else { method_name.to_uppercase() };
+                (method, raw_path, context.framework, &args[1..])
+            }
+            _ if NET_HTTP_HANDLERS.contains(&method_name.as_str()) => {
+                // Go 1.22 patterns may carry the method: "GET /users/{id}"
+                let (method, path) = match raw_path.split_once(' ') {
+                    Some((method, path)) => (method.to_uppercase(), path.trim().to_string()),
+                    None => (self.extract_chained_methods(node, source).unwrap_or_else(|| "ANY".to_string()), raw_path),
+                };
+                (method, path, None, &args[1..])
+            }
+            _ => return None,
+        };
+
+        let prefix = context.group_prefixes.get(&receiver).cloned().unwrap_or_default();
+        let full_path = self.join_paths(&prefix, &path);
+
+        let handler_node = handler_args.last()?;
+        let handler = if handler_node.kind() == "func_literal" {
+            match framework {
+                Some(fw) => format!("{} Route Handler", fw),
+                None => "HTTP Handler".to_string(),
+            }
+        } else {
+            self.get_node_text(handler_node, source).to_string()
+        };
+        let middleware = handler_args[..handler_args.len() - 1]
+            .iter()
+            .filter(|arg| arg.kind() != "func_literal")
+            .map(|arg| self.get_node_text(arg, source).to_string())
+            .collect();
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Route(RouteSegment {
+                path: full_path,
+                method,
+                handler,
+                middleware,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: framework,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    // Helper methods
+    fn get_node_text<'a>(&self, node: &Node, source: &'a str) -> &'a str {
+        &source[node.start_byte()..node.end_byte()]
+    }
+
+    fn get_field_text(&self, node: &Node, field: &str, source: &str) -> Option<String> {
+        node.child_by_field_name(field)
+            .map(|child| self.get_node_text(&child, source).to_string())
+    }
+
+    fn build_metadata(&self, node: &Node, file_path: &Path) -> SegmentMetadata {
+        SegmentMetadata {
+            line_start: node.start_position().row + 1,
+            line_end: node.end_position().row + 1,
+            file_path: file_path.to_path_buf(),
+            byte_start: node.start_byte(),
+            byte_end: node.end_byte(),
+        }
+    }
+
+    fn string_literal_value(&self, node: &Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "interpreted_string_literal" | "raw_string_literal" => {
+                Some(self.get_node_text(node, source).trim_matches(|c| c == '"' || c == '`').to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn join_paths(&self, prefix: &str, path: &str) -> String {
+        if prefix.is_empty() {
+            return path.to_string();
+        }
+        format!("{}/{}", prefix.trim_end_matches('/'), path.trim_start_matches('/'))
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    fn extract_parameters(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut parameters = Vec::new();
+
+        if let Some(params) = node.child_by_field_name("parameters") {
+            let mut cursor = params.walk();
+            parameters.extend(
+                params.named_children(&mut cursor)
+                    .filter(|param| param.kind() == "parameter_declaration" || param.kind() == "variadic_parameter_declaration")
+                    .map(|param| self.get_node_text(&param, source).to_string())
+            );
+        }
+
+        parameters
+    }
+
+    /// Receiver type name without pointer, e.g. `(s *UserService)` -> `UserService`
+    fn get_receiver_type(&self, node: &Node, source: &str) -> Option<String> {
+        let receiver = node.child_by_field_name("receiver")?;
+        let param = receiver.named_child(0)?;
+        let type_text = self.get_field_text(&param, "type", source)?;
+        let type_text = type_text.trim_start_matches('*');
+        Some(type_text.split('[').next().unwrap_or(type_text).to_string())
+    }
+
+    fn extract_struct_fields(&self, struct_node: &Node, source: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+
+        for field in self.field_declarations(struct_node) {
+            let mut cursor = field.walk();
+            fields.extend(
+                field.children_by_field_name("name", &mut cursor)
+                    .map(|name| self.get_node_text(&name, source).to_string())
+            );
+        }
+
+        fields
+    }
+
+    fn extract_embedded_types(&self, struct_node: &Node, source: &str) -> Vec<String> {
+        self.field_declarations(struct_node)
+            .into_iter()
+            .filter(|field| field.child_by_field_name("name").is_none())
+            .filter_map(|field| self.get_field_text(&field, "type", source))
+            .collect()
+    }
+
+    fn field_declarations<'a>(&self, struct_node: &Node<'a>) -> Vec<Node<'a>> {
+        let mut declarations = Vec::new();
+        let mut cursor = struct_node.walk();
+
+        for child in struct_node.named_children(&mut cursor) {
+            if child.kind() == "field_declaration_list" {
+                let mut list_cursor = child.walk();
+                declarations.extend(
+                    child.named_children(&mut list_cursor)
+                        .filter(|field| field.kind() == "field_declaration")
+                );
+            }
+        }
+
+        declarations
+    }
+
+    fn is_gorm_model(&self, struct_node: &Node, source: &str) -> bool {
+        self.field_declarations(struct_node).iter().any(|field| {
+            let type_text = self.get_field_text(field, "type", source).unwrap_or_default();
+            let tag_text = self.get_field_text(field, "tag", source).unwrap_or_default();
+            (field.child_by_field_name("name").is_none() && type_text == "gorm.Model") ||
+            tag_text.contains("gorm:\"")
+        })
+    }
+
+    fn extract_gorm_relationships(&self, struct_node: &Node, source: &str, context: &GoFileContext) -> Vec<String> {
+        let mut relationships = Vec::new();
+
+        for field in self.field_declarations(struct_node) {
+            let name = match self.get_field_text(&field, "name", source) {
+                Some(name) => name,
+                None => continue,
+            };
+            let type_text = self.get_field_text(&field, "type", source).unwrap_or_default();
+            let tag_text = self.get_field_text(&field, "tag", source).unwrap_or_default();
+            let referenced_type = type_text.trim_start_matches("[]").trim_start_matches('*');
+
+            if tag_text.contains("foreignKey:") ||
+               tag_text.contains("many2many:") ||
+               tag_text.contains("references:") ||
+               context.gorm_models.contains(referenced_type) {
+                relationships.push(name);
+            }
+        }
+
+        relationships
+    }
+
+    /// gorilla/mux style `.HandleFunc("/x", h).Methods("GET", "POST")`
+    fn extract_chained_methods(&self, node: &Node, source: &str) -> Option<String> {
+        let selector = node.parent().filter(|p| p.kind() == "selector_expression")?;
+        if self.get_field_text(&selector, "field", source)? != "Methods" {
+            return None;
+        }
+        let call = selector.parent().filter(|p| p.kind() == "call_expression")?;
+        let arguments = call.child_by_field_name("arguments")?;
+        let mut cursor = arguments.walk();
+        let methods: Vec<String> = arguments.named_children(&mut cursor)
+            .filter_map(|arg| self.string_literal_value(&arg, source))
+            .map(|m| m.to_uppercase())
+            .collect();
+
+        if methods.is_empty() { None } else { Some(methods.join(",")) }
+    }
+
+    fn detect_file_framework(&self, source: &str) -> Option<Framework> {
+        if source.contains("\"github.com/gin-gonic/gin\"") {
+            Some(Framework::Gin)
+        } else if source.contains("\"github.com/gofiber/fiber") {
+            Some(Framework::Fiber)
+        } else {
+            None
+        }
+    }
+
+    fn build_file_context(&self, root: &Node, source: &str) -> GoFileContext {
+        let mut context = GoFileContext {
+            framework: self.detect_file_framework(source),
+            group_prefixes: HashMap::new(),
+            receiver_methods: HashMap::new(),
+            table_names: HashMap::new(),
+            gorm_models: HashSet::new(),
+        };
+        self.collect_file_context(root, source, &mut context);
+        context
+    }
+
+    fn collect_file_context(&self, node: &Node, source: &str, context: &mut GoFileContext) {
+        match node.kind() {
+            "method_declaration" => {
+                if let (Some(receiver), Some(name)) = (self.get_receiver_type(node, source), self.get_field_text(node, "name", source)) {
+                    // func (User) TableName() string { return "users" }
+                    if name == "TableName" {
+                        let body = self.get_field_text(node, "body", source).unwrap_or_default();
+                        if let Some(table) = body.split('"').nth(1) {
+                            context.table_names.insert(receiver.clone(), table.to_string());
+                        }
+                    }
+                    context.receiver_methods.entry(receiver).or_default().push(name);
+                }
+            }
+            "type_spec" => {
+                let gorm_struct = node.child_by_field_name("type")
+                    .filter(|t| t.kind() == "struct_type" && self.is_gorm_model(t, source));
+                if let (Some(name), Some(_)) = (self.get_field_text(node, "name", source), gorm_struct) {
+                    context.gorm_models.insert(name);
+                }
+            }
+            "short_var_declaration" | "assignment_statement" => {
+                // api := r.Group("/api/v1")
+                if let Some((variable, prefix)) = self.parse_group_assignment(node, source, context) {
+                    context.group_prefixes.insert(variable, prefix);
+                }
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_file_context(&child, source, context);
+        }
+    }
+
+    fn parse_group_assignment(&self, node: &Node, source: &str, context: &GoFileContext) -> Option<(String, String)> {
+        let variable = node.child_by_field_name("left")?.named_child(0)?;
+        let call = node.child_by_field_name("right")?.named_child(0)?;
+        if call.kind() != "call_expression" {
+            return None;
+        }
+        let function = call.child_by_field_name("function")?;
+        if context.framework.is_none() || function.kind() != "selector_expression" || self.get_field_text(&function, "field", source)? != "Group" {
+            return None;
+        }
+        let operand = self.get_field_text(&function, "operand", source)?;
+        let path = self.string_literal_value(&call.child_by_field_name("arguments")?.named_child(0)?, source)?;
+        let parent_prefix = context.group_prefixes.get(&operand).cloned().unwrap_or_default();
+
+        Some((self.get_node_text(&variable, source).to_string(), self.join_paths(&parent_prefix, &path)))
+    }
+}
+
+impl SegmentExtractor for GoExtractor {
+    fn extract_segments(&self, source: &str, file_path: &Path) -> Result<Vec<CodeSegment>> {
+        let mut segments = Vec::new();
+
+        // Parse the source code with tree-sitter
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_go::language())?;
+
+        if let Some(tree) = parser.parse(source, None) {
+            let root = tree.root_node();
+            let context = self.build_file_context(&root, source);
+            self.traverse_node(&root, source, file_path, &context, &mut segments);
+        }
+
+        Ok(segments)
+    }
+
+    fn extract_business_hints(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut hints = Vec::new();
+        let content = self.get_node_text(node, source).to_lowercase();
+
+        // Authentication hints
+        if content.contains("auth") || content.contains("login") || content.contains("password") ||
+           content.contains("jwt") || content.contains("token") {
+            hints.push("Authentication".to_string());
+        }
+
+        // User management hints
+        if content.contains("user") || content.contains("profile") || content.contains("account") ||
+           content.contains("permission") || content.contains("role") {
+            hints.push("User Management".to_string());
+        }
+
+        // Notification hints
+        if content.contains("notification") || content.contains("email") || content.contains("sms") ||
+           content.contains("alert") || content.contains("message") {
+            hints.push("Notification".to_string());
+        }
+
+        // Payment hints
+        if content.contains("payment") || content.contains("billing") || content.contains("subscription") ||
+           content.contains("invoice") || content.contains("charge") {
+            hints.push("Payment".to_string());
+        }
+
+        // Analytics hints
+        if content.contains("analytics") || content.contains("tracking") || content.contains("metrics") ||
+           content.contains("report") || content.contains("dashboard") {
+            hints.push("Analytics".to_string());
+        }
+
+        // E-commerce hints
+        if content.contains("product") || content.contains("order") || content.contains("cart") ||
+           content.contains("inventory") || content.contains("catalog") {
+            hints.push("E-commerce".to_string());
+        }
+
+        hints
+    }
+}
+
+impl GoExtractor {
+    fn traverse_node(&self, node: &Node, source: &str, file_path: &Path, context: &GoFileContext, segments: &mut Vec<CodeSegment>) {
+        match node.kind() {
+            "function_declaration" | "method_declaration" => {
+                if let Some(segment) = self.extract_function_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "type_spec" => {
+                if let Some(segment) = self.extract_type_segment(node, source, file_path, context) {
+                    segments.push(segment);
+                }
+            }
+            "call_expression" => {
+                if let Some(segment) = self.extract_route_segment(node, source, file_path, context) {
+                    segments.push(segment);
+                }
+            }
+            _ => {}
+        }
+
+        // Recursively traverse child nodes
+        let mut cursor = node.walk();
+        if cursor.goto_first_child() {
+            loop {
+                self.traverse_node(&cursor.node(), source, file_path, context, segments);
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(source: &str) -> Vec<(String, String, String)> {
+        GoExtractor::new()
+            .extract_segments(source, Path::new("main.go"))
+            .unwrap()
+            .into_iter()
+            .filter_map(|segment| match segment.segment_type {
+                SegmentType::Route(route) => Some((route.method, route.path, route.handler)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gin_routes_require_the_gin_import() {
+        let gin = r#"
+package main
+
+import "github.com/gin-gonic/gin"
+
+func main() {
+	r := gin.Default()
+	api := r.Group("/api")
+	api.GET("/users", listUsers)
+	r.Handle("DELETE", "/users/:id", auth, deleteUser)
+}
+"#;
+        let found = routes(gin);
+        assert!(found.contains(&("GET".into(), "/api/users".into(), "listUsers".into())));
+        assert!(found.contains(&("DELETE".into(), "/users/:id".into(), "deleteUser".into())));
+
+        let client = r#"
+package main
+
+import "net/http"
+
+func notify(body io.Reader) {
+	http.Post("https://hooks.example.com/notify", "application/json", body)
+	http.HandleFunc("GET /health", health)
+}
+"#;
+        assert_eq!(routes(client), vec![("GET".to_string(), "/health".to_string(), "health".to_string())]);
+    }
+
+    #[test]
+    fn test_fiber_routes() {
+        let source = r#"
+package main
+
+import "github.com/gofiber/fiber/v2"
+
+func main() {
+	app := fiber.New()
+	app.Post("/orders", createOrder)
+	app.All("/ping", ping)
+}
+"#;
+        let found = routes(source);
+        assert!(found.contains(&("POST".into(), "/orders".into(), "createOrder".into())));
+        assert!(found.contains(&("ANY".into(), "/ping".into(), "ping".into())));
+    }
+}
@@ -1,4 +1,5 @@
 use std::path::Path;
+use super::span_decorated_declaration;
 use tree_sitter::{Node, Tree, TreeCursor};
 use anyhow::Result;
 use crate::core::ast_analyzer::{
@@ -713,12 +714,19 @@ impl JavaExtractor {
                     segments.push(segment);
                 }
             }
-            _ => {
-                // Check if this might be a route definition
-                if let Some(segment) = self.extract_route_segment(node, source, file_path) {
+            // Spring mappings come from their annotation, not from every node enclosing it
+            "annotation" | "marker_annotation" => {
+                if let Some(mut segment) = self.extract_route_segment(node, source, file_path) {
+                    let declaration = node.parent()
+                        .filter(|parent| parent.kind() == "modifiers")
+                        .and_then(|modifiers| modifiers.parent());
+                    if let Some(declaration) = declaration {
+                        span_decorated_declaration(&mut segment, node, &declaration, source);
+                    }
                     segments.push(segment);
                 }
             }
+            _ => {}
         }
 
         // Recursively traverse child nodes
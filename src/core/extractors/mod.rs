@@ -1,7 +1,26 @@
 pub mod typescript;
 pub mod python;
 pub mod java;
+pub mod rust;
+pub mod go;
+pub mod vue;
 
 pub use typescript::TypeScriptExtractor;
 pub use python::PythonExtractor;
-pub use java::JavaExtractor;
\ No newline at end of file
+pub use java::JavaExtractor;
+pub use rust::RustExtractor;
+pub use go::GoExtractor;
+pub use vue::VueExtractor;
+
+
+use tree_sitter::Node;
+
+use crate::core::ast_analyzer::CodeSegment;
+
+/// Widen a route segment parsed from a decorator or annotation to the declaration it decorates,
+/// so the route covers its handler
+pub(crate) fn span_decorated_declaration(segment: &mut CodeSegment, decorator: &Node, declaration: &Node, source: &str) {
+    segment.content = source[decorator.start_byte()..declaration.end_byte()].to_string();
+    segment.metadata.line_end = declaration.end_position().row + 1;
+    segment.metadata.byte_end = declaration.end_byte();
+}
@@ -1,4 +1,5 @@
 use std::path::Path;
+use super::span_decorated_declaration;
 use tree_sitter::{Node, Tree, TreeCursor};
 use anyhow::Result;
 use crate::core::ast_analyzer::{
@@ -376,6 +377,19 @@ impl PythonExtractor {
         relationships
     }
 
+    /// Undecorated functions taking `request` first, as Django function views do
+    fn is_django_view(&self, node: &Node, source: &str) -> bool {
+        let decorated = node.parent().is_some_and(|parent| parent.kind() == "decorated_definition");
+        let first_parameter = node.child_by_field_name("parameters").and_then(|parameters| {
+            self.get_node_text(&parameters, source)
+                .trim_matches(|c| c == '(' || c == ')')
+                .split(',')
+                .map(|parameter| parameter.split([':', '=']).next().unwrap_or("").trim().to_string())
+                .find(|name| name != "self")
+        });
+        !decorated && first_parameter.as_deref() == Some("request")
+    }
+
     fn parse_route_pattern(&self, content: &str) -> Option<RouteInfo> {
         // Flask route patterns
         if content.contains("@app.route") || content.contains("@bp.route") {
@@ -617,18 +631,26 @@ impl PythonExtractor {
                 if let Some(segment) = self.extract_function_segment(node, source, file_path) {
                     segments.push(segment);
                 }
+                if self.is_django_view(node, source)
+                    && let Some(segment) = self.extract_route_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
             }
             "class_definition" => {
                 if let Some(segment) = self.extract_class_segment(node, source, file_path) {
                     segments.push(segment);
                 }
             }
-            _ => {
-                // Check if this might be a route definition or other pattern
-                if let Some(segment) = self.extract_route_segment(node, source, file_path) {
+            // Flask and FastAPI routes come from their decorator, not from every node enclosing it
+            "decorator" => {
+                if let Some(mut segment) = self.extract_route_segment(node, source, file_path) {
+                    if let Some(definition) = node.parent().filter(|parent| parent.kind() == "decorated_definition") {
+                        span_decorated_declaration(&mut segment, node, &definition, source);
+                    }
                     segments.push(segment);
                 }
             }
+            _ => {}
         }
 
         // Recursively traverse child nodes
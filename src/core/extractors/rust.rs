@@ -0,0 +1,790 @@
+use std::path::Path;
+use tree_sitter::Node;
+use anyhow::Result;
+use regex::Regex;
+use crate::core::ast_analyzer::{
+    CodeSegment, SegmentExtractor, SegmentType, SegmentMetadata,
+    FunctionSegment, ClassSegment, RouteSegment, DatabaseSegment
+};
+use crate::core::types::Framework;
+
+const HTTP_METHODS: [&str; 7] = ["get", "post", "put", "delete", "patch", "head", "options"];
+
+const DIESEL_DERIVES: [&str; 5] = ["Queryable", "Insertable", "Identifiable", "Selectable", "AsChangeset"];
+
+pub struct RustExtractor;
+
+impl Default for RustExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RustExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn extract_function_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let function_name = self.get_field_text(node, "name", source)?;
+        let parameters = self.extract_parameters(node, source);
+        let return_type = self.get_field_text(node, "return_type", source);
+        let is_async = self.is_async_function(node, source);
+        let attributes = self.extract_attributes(node, source);
+        let framework_context = self.detect_attribute_framework(&attributes);
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Function(FunctionSegment {
+                name: function_name,
+                parameters,
+                return_type,
+                is_async,
+                decorators: attributes,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    fn extract_struct_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let struct_name = self.get_field_text(node, "name", source)?;
+        let attributes = self.extract_attributes(node, source);
+
+        // ORM models (diesel, sqlx, sea-orm) become DatabaseSegments instead
+        if self.is_database_model(&attributes) {
+            return Some(CodeSegment {
+                segment_type: SegmentType::Database(DatabaseSegment {
+                    model_name: struct_name,
+                    table_name: self.extract_table_name(&attributes),
+                    fields: self.extract_struct_fields(node, source),
+                    relationships: self.extract_relationships(&attributes),
+                }),
+                content: self.get_node_text(node, source).to_string(),
+                metadata: self.build_metadata(node, file_path),
+                framework_context: None,
+                business_hints: self.extract_business_hints(node, source),
+            });
+        }
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Class(ClassSegment {
+                name: struct_name,
+                extends: None,
+                implements: self.extract_derives(&attributes),
+                is_react_component: false,
+                props: Vec::new(),
+                hooks: Vec::new(),
+                methods: Vec::new(),
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: None,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    fn extract_impl_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let type_name = self.get_field_text(node, "type", source)?;
+        let implements = self.get_field_text(node, "trait", source).into_iter().collect();
+        let methods = self.extract_body_methods(node, source);
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Class(ClassSegment {
+                name: type_name,
+                extends: None,
+                implements,
+                is_react_component: false,
+                props: Vec::new(),
+                hooks: Vec::new(),
+                methods,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: None,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    fn extract_trait_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let trait_name = self.get_field_text(node, "name", source)?;
+        let supertraits = self.get_field_text(node, "bounds", source)
+            .map(|bounds| {
+                bounds.trim_start_matches(':')
+                    .split('+')
+                    .map(|b| b.trim().to_string())
+                    .filter(|b| !b.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let methods = self.extract_body_methods(node, source);
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Class(ClassSegment {
+                name: trait_name,
+                extends: None,
+                implements: supertraits,
+                is_react_component: false,
+                props: Vec::new(),
+                hooks: Vec::new(),
+                methods,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: None,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    /// Handles `#[get("/path")]`-style actix-web attribute macros on handler functions
+    fn extract_attribute_route_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let attributes = self.extract_attributes(node, source);
+        let route_info = attributes.iter().find_map(|attr| self.parse_route_attribute(attr))?;
+        let handler = self.get_field_text(node, "name", source)?;
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Route(RouteSegment {
+                path: route_info.path,
+                method: route_info.method,
+                handler,
+                middleware: route_info.middleware,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: Some(Framework::Actix),
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    /// Handles axum `Router::route` and actix `App::route`/`web::resource(..).route(..)` calls
+    fn extract_router_call_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let function = node.child_by_field_name("function")?;
+        if function.kind() != "field_expression" {
+            return None;
+        }
+        let method_name = self.get_field_text(&function, "field", source)?;
+        if method_name != "route" {
+            return None;
+        }
+
+        let arguments = node.child_by_field_name("arguments")?;
+        let mut cursor = arguments.walk();
+        let args: Vec<Node> = arguments.named_children(&mut cursor).collect();
+
+        let (path, method_router) = match args.as_slice() {
+            [path_arg, router_arg] => (self.string_literal_value(path_arg, source)?, *router_arg),
+            [router_arg] => {
+                // actix: web::resource("/users").route(web::get().to(handler))
+                let receiver = function.child_by_field_name("value")?;
+                (self.find_resource_path(&receiver, source)?, *router_arg)
+            }
+            _ => return None,
+        };
+
+        let mut methods = Vec::new();
+        let mut handlers = Vec::new();
+        self.collect_method_handlers(&method_router, source, &mut methods, &mut handlers);
+
+        if methods.is_empty() {
+            return None;
+        }
+
+        // Chained calls span the whole builder, so start the segment at `.route` itself
+        let route_start = function.child_by_field_name("field").unwrap_or(function);
+        let content = &source[route_start.start_byte()..node.end_byte()];
+
+        let router_text = self.get_node_text(&method_router, source);
+        let framework = if router_text.contains("web::") || router_text.contains(".to(") {
+            Framework::Actix
+        } else {
+            Framework::Axum
+        };
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Route(RouteSegment {
+                path,
+                method: methods.join(","),
+                handler: if handlers.is_empty() {
+                    format!("{} Route Handler", framework)
+                } else {
+                    handlers.join(",")
+                },
+                middleware: self.extract_route_layers(&method_router, source),
+            }),
+            content: content.to_string(),
+            metadata: SegmentMetadata {
+                line_start: route_start.start_position().row + 1,
+                line_end: node.end_position().row + 1,
+                file_path: file_path.to_path_buf(),
+                byte_start: route_start.start_byte(),
+                byte_end: node.end_byte(),
+            },
+            framework_context: Some(framework),
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    /// Handles warp filter chains such as `warp::path!("users" / u32).and(warp::get()).and_then(get_user)`
+    fn extract_warp_filter_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+        let content = self.get_node_text(node, source);
+        if !content.trim_start().starts_with("warp::") || !content.contains("warp::path") {
+            return None;
+        }
+
+        // Only the outermost expression of a filter chain describes the whole route
+        if node.parent().is_some_and(|parent| matches!(parent.kind(), "field_expression" | "arguments" | "call_expression")) {
+            return None;
+        }
+
+        let path = self.extract_warp_path(content);
+        let method = HTTP_METHODS.iter()
+            .find(|m| content.contains(&format!("warp::{}()", m)))
+            .map(|m| m.to_uppercase())
+            .unwrap_or_else(|| "ANY".to_string());
+        let handler = Regex::new(r"\.(?:and_then|map)\(\s*([A-Za-z_][A-Za-z0-9_:]*)\s*\)")
+            .ok()
+            .and_then(|re| re.captures_iter(content).last())
+            .map(|caps| caps[1].to_string())
+            .unwrap_or_else(|| "Warp Filter Handler".to_string());
+        let middleware = Regex::new(r"\.with\(\s*([^)]*\)?)\s*\)")
+            .map(|re| re.captures_iter(content).map(|caps| caps[1].trim().to_string()).collect())
+            .unwrap_or_default();
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Route(RouteSegment {
+                path,
+                method,
+                handler,
+                middleware,
+            }),
+            content: content.to_string(),
+            metadata: self.build_metadata(node, file_path),
+            framework_context: Some(Framework::Warp),
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    /// Handles diesel `table! { users (id) { ... } }` schema declarations
+    fn extract_diesel_table_segments(&self, node: &Node, source: &str, file_path: &Path) -> Vec<CodeSegment> {
+        let mut segments = Vec::new();
+        let macro_name = match self.get_field_text(node, "macro", source) {
+            Some(name) => name,
+            None => return segments,
+        };
+        if macro_name != "table" && macro_name != "diesel::table" {
+            return segments;
+        }
+
+        let content = self.get_node_text(node, source);
+        let table_re = match Regex::new(r"(?s)([A-Za-z_][A-Za-z0-9_]*)\s*(?:\([^)]*\))?\s*\{([^{}]*)\}") {
+            Ok(re) => re,
+            Err(_) => return segments,
+        };
+        let column_re = match Regex::new(r"([A-Za-z_][A-Za-z0-9_]*)\s*->") {
+            Ok(re) => re,
+            Err(_) => return segments,
+        };
+
+        for caps in table_re.captures_iter(content) {
+            let table = caps[1].to_string();
+            let fields = column_re.captures_iter(&caps[2])
+                .map(|c| c[1].to_string())
+                .collect();
+
+            segments.push(CodeSegment {
+                segment_type: SegmentType::Database(DatabaseSegment {
+                    model_name: table.clone(),
+                    table_name: Some(table),
+                    fields,
+                    relationships: Vec::new(),
+                }),
+                content: content.to_string(),
+                metadata: self.build_metadata(node, file_path),
+                framework_context: None,
+                business_hints: self.extract_business_hints(node, source),
+            });
+        }
+
+        segments
+    }
+
+    // Helper methods
+    fn get_node_text<'a>(&self, node: &Node, source: &'a str) -> &'a str {
+        &source[node.start_byte()..node.end_byte()]
+    }
+
+    fn get_field_text(&self, node: &Node, field: &str, source: &str) -> Option<String> {
+        node.child_by_field_name(field)
+            .map(|child| self.get_node_text(&child, source).to_string())
+    }
+
+    fn build_metadata(&self, node: &Node, file_path: &Path) -> SegmentMetadata {
+        SegmentMetadata {
+            line_start: node.start_position().row + 1,
+            line_end: node.end_position().row + 1,
+            file_path: file_path.to_path_buf(),
+            byte_start: node.start_byte(),
+            byte_end: node.end_byte(),
+        }
+    }
+
+    fn string_literal_value(&self, node: &Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "string_literal" | "raw_string_literal" => {
+                let text = self.get_node_text(node, source);
+                Some(text.trim_start_matches('r').trim_matches('#').trim_matches('"').to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn extract_parameters(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut parameters = Vec::new();
+
+        if let Some(params) = node.child_by_field_name("parameters") {
+            let mut cursor = params.walk();
+            for param in params.named_children(&mut cursor) {
+                if param.kind() == "parameter" {
+                    parameters.push(self.get_node_text(&param, source).to_string());
+                }
+            }
+        }
+
+        parameters
+    }
+
+    fn is_async_function(&self, node: &Node, source: &str) -> bool {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .any(|child| child.kind() == "function_modifiers" && self.get_node_text(&child, source).contains("async"))
+    }
+
+    /// Outer attributes are siblings that precede the item in tree-sitter-rust
+    fn extract_attributes(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut attributes = Vec::new();
+        let mut current = node.prev_sibling();
+
+        while let Some(sibling) = current {
+            match sibling.kind() {
+                "attribute_item" => attributes.insert(0, self.get_node_text(&sibling, source).to_string()),
+                "line_comment" | "block_comment" => {}
+                _ => break,
+            }
+            current = sibling.prev_sibling();
+        }
+
+        attributes
+    }
+
+    fn extract_derives(&self, attributes: &[String]) -> Vec<String> {
+        let mut derives = Vec::new();
+
+        for attr in attributes {
+            if let Some(start) = attr.find("derive(") {
+                let after_derive = &attr[start + 7..];
+                if let Some(end) = after_derive.find(')') {
+                    derives.extend(
+                        after_derive[..end]
+                            .split(',')
+                            .map(|d| d.trim().to_string())
+                            .filter(|d| !d.is_empty())
+                    );
+                }
+            }
+        }
+
+        derives
+    }
+
+    fn extract_body_methods(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut methods = Vec::new();
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            methods.extend(
+                body.named_children(&mut cursor)
+                    .filter(|item| item.kind() == "function_item" || item.kind() == "function_signature_item")
+                    .filter_map(|item| self.get_field_text(&item, "name", source))
+            );
+        }
+
+        methods
+    }
+
+    fn extract_struct_fields(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            fields.extend(
+                body.named_children(&mut cursor)
+                    .filter(|field| field.kind() == "field_declaration")
+                    .filter_map(|field| self.get_field_text(&field, "name", source))
+            );
+        }
+
+        fields
+    }
+
+    fn is_database_model(&self, attributes: &[String]) -> bool {
+        let derives = self.extract_derives(attributes);
+
+        derives.iter().any(|d| {
+            let name = d.rsplit("::").next().unwrap_or(d);
+            // diesel
+            DIESEL_DERIVES.contains(&name) ||
+            // sqlx
+            name == "FromRow" ||
+            // sea-orm
+            name == "DeriveEntityModel"
+        }) ||
+        attributes.iter().any(|attr| attr.contains("sea_orm(table_name") || attr.contains("diesel(table_name"))
+    }
+
+    fn extract_table_name(&self, attributes: &[String]) -> Option<String> {
+        for attr in attributes {
+            if let Some(start) = attr.find("table_name") {
+                let after_key = &attr[start + "table_name".len()..];
+                if let Some(equals_pos) = after_key.find('=') {
+                    let value = after_key[equals_pos + 1..]
+                        .trim_start()
+                        .split([',', ')', ']'])
+                        .next()
+                        .unwrap_or("")
+                        .trim()
+                        .trim_matches('"');
+                    // diesel accepts a schema path such as crate::schema::users
+                    let value = value.rsplit("::").next().unwrap_or(value);
+                    if !value.is_empty() {
+                        return Some(value.to_string());
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn extract_relationships(&self, attributes: &[String]) -> Vec<String> {
+        let mut relationships = Vec::new();
+
+        // diesel associations: #[diesel(belongs_to(User))] or #[belongs_to(User)]
+        if let Ok(re) = Regex::new(r"belongs_to\(\s*([A-Za-z_][A-Za-z0-9_:]*)") {
+            for attr in attributes {
+                for caps in re.captures_iter(attr) {
+                    relationships.push(caps[1].to_string());
+                }
+            }
+        }
+
+        relationships
+    }
+
+    fn parse_route_attribute(&self, attribute: &str) -> Option<RouteInfo> {
+        let inner = attribute.trim_start_matches("#[").trim_end_matches(']').trim();
+        let paren = inner.find('(')?;
+        let macro_path = inner[..paren].trim();
+        let macro_name = macro_path.rsplit("::").next().unwrap_or(macro_path);
+        // Search after the opening paren: `#[doc = "a) b("]` has its last `)` before its first `(`
+        let close = paren + 1 + inner[paren + 1..].rfind(')')?;
+        let args = &inner[paren + 1..close];
+        let path = args.split('"').nth(1)?.to_string();
+
+        let method = if HTTP_METHODS.contains(&macro_name) {
+            macro_name.to_uppercase()
+        } else if macro_name == "route" {
+            // #[route("/", method = "GET", method = "POST")]
+            let methods: Vec<String> = args.split("method")
+                .skip(1)
+                .filter_map(|m| m.split('"').nth(1))
+                .map(|m| m.to_uppercase())
+                .collect();
+            if methods.is_empty() { "ANY".to_string() } else { methods.join(",") }
+        } else {
+            return None;
+        };
+
+        let middleware = args.split("wrap")
+            .skip(1)
+            .filter_map(|w| w.split('"').nth(1))
+            .map(|w| w.to_string())
+            .collect();
+
+        Some(RouteInfo {
+            path,
+            method,
+            middleware,
+        })
+    }
+
+    fn find_resource_path(&self, node: &Node, source: &str) -> Option<String> {
+        let text = self.get_node_text(node, source);
+        let start = text.find("resource(")?;
+        text[start..].split('"').nth(1).map(|p| p.to_string())
+    }
+
+    /// Walks an axum `MethodRouter` (`get(a).post(b)`) or actix `web::get().to(a)` expression
+    fn collect_method_handlers(&self, node: &Node, source: &str, methods: &mut Vec<String>, handlers: &mut Vec<String>) {
+        // Inner calls first so chains report methods in source order
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if child.kind() != "arguments" || node.kind() != "call_expression" {
+                self.collect_method_handlers(&child, source, methods, handlers);
+            } else {
+                // Arguments of chained calls can themselves be method routers (e.g. web::get())
+                let mut arg_cursor = child.walk();
+                for arg in child.named_children(&mut arg_cursor) {
+                    if arg.kind() == "call_expression" {
+                        self.collect_method_handlers(&arg, source, methods, handlers);
+                    }
+                }
+            }
+        }
+
+        if let Some(callee) = self.get_callee_name(node, source) {
+            // Closures are inline handlers with no meaningful name
+            let first_arg = node.child_by_field_name("arguments")
+                .and_then(|args| args.named_child(0))
+                .filter(|arg| arg.kind() != "closure_expression")
+                .map(|arg| self.get_node_text(&arg, source).to_string());
+
+            if HTTP_METHODS.contains(&callee.as_str()) {
+                methods.push(callee.to_uppercase());
+                handlers.extend(first_arg);
+            } else if callee == "any" {
+                methods.push("ANY".to_string());
+                handlers.extend(first_arg);
+            } else if callee == "to" {
+                handlers.extend(first_arg);
+            }
+        }
+    }
+
+    fn get_callee_name(&self, node: &Node, source: &str) -> Option<String> {
+        if node.kind() != "call_expression" {
+            return None;
+        }
+        let function = node.child_by_field_name("function")?;
+        match function.kind() {
+            "identifier" => Some(self.get_node_text(&function, source).to_string()),
+            "scoped_identifier" => self.get_field_text(&function, "name", source),
+            "field_expression" => self.get_field_text(&function, "field", source),
+            _ => None,
+        }
+    }
+
+    fn extract_route_layers(&self, node: &Node, source: &str) -> Vec<String> {
+        let text = self.get_node_text(node, source);
+        Regex::new(r"\.(?:layer|route_layer|wrap)\(\s*([A-Za-z_][A-Za-z0-9_:]*)")
+            .map(|re| re.captures_iter(text).map(|caps| caps[1].to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    fn extract_warp_path(&self, content: &str) -> String {
+        let mut segments = Vec::new();
+
+        if let Some(start) = content.find("warp::path!(") {
+            let after_macro = &content[start + "warp::path!(".len()..];
+            if let Some(end) = after_macro.find(')') {
+                for part in after_macro[..end].split('/') {
+                    let part = part.trim();
+                    if part.is_empty() || part == ".." {
+                        continue;
+                    }
+                    if part.starts_with('"') {
+                        segments.push(part.trim_matches('"').to_string());
+                    } else {
+                        segments.push(format!("{{{}}}", part));
+                    }
+                }
+            }
+        } else if let Ok(re) = Regex::new(r#"warp::path\(\s*"([^"]*)"\s*\)|warp::path::param::<([^>]+)>\(\)"#) {
+            for caps in re.captures_iter(content) {
+                if let Some(literal) = caps.get(1) {
+                    segments.push(literal.as_str().to_string());
+                } else if let Some(param) = caps.get(2) {
+                    segments.push(format!("{{{}}}", param.as_str()));
+                }
+            }
+        }
+
+        format!("/{}", segments.join("/"))
+    }
+
+    fn detect_attribute_framework(&self, attributes: &[String]) -> Option<Framework> {
+        if attributes.iter().any(|attr| self.parse_route_attribute(attr).is_some() || attr.contains("actix_web::main")) {
+            Some(Framework::Actix)
+        } else {
+            None
+        }
+    }
+}
+
+struct RouteInfo {
+    path: String,
+    method: String,
+    middleware: Vec<String>,
+}
+
+impl SegmentExtractor for RustExtractor {
+    fn extract_segments(&self, source: &str, file_path: &Path) -> Result<Vec<CodeSegment>> {
+        let mut segments = Vec::new();
+
+        // Parse the source code with tree-sitter
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_rust::language())?;
+
+        if let Some(tree) = parser.parse(source, None) {
+            let root = tree.root_node();
+            self.traverse_node(&root, source, file_path, &mut segments);
+        }
+
+        Ok(segments)
+    }
+
+    fn extract_business_hints(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut hints = Vec::new();
+        let content = self.get_node_text(node, source).to_lowercase();
+
+        // Authentication hints
+        if content.contains("auth") || content.contains("login") || content.contains("password") ||
+           content.contains("jwt") || content.contains("token") {
+            hints.push("Authentication".to_string());
+        }
+
+        // User management hints
+        if content.contains("user") || content.contains("profile") || content.contains("account") ||
+           content.contains("permission") || content.contains("role") {
+            hints.push("User Management".to_string());
+        }
+
+        // Notification hints
+        if content.contains("notification") || content.contains("email") || content.contains("sms") ||
+           content.contains("alert") || content.contains("message") {
+            hints.push("Notification".to_string());
+        }
+
+        // Payment hints
+        if content.contains("payment") || content.contains("billing") || content.contains("subscription") ||
+           content.contains("invoice") || content.contains("charge") {
+            hints.push("Payment".to_string());
+        }
+
+        // Analytics hints
+        if content.contains("analytics") || content.contains("tracking") || content.contains("metrics") ||
+           content.contains("report") || content.contains("dashboard") {
+            hints.push("Analytics".to_string());
+        }
+
+        // E-commerce hints
+        if content.contains("product") || content.contains("order") || content.contains("cart") ||
+           content.contains("inventory") || content.contains("catalog") {
+            hints.push("E-commerce".to_string());
+        }
+
+        hints
+    }
+}
+
+impl RustExtractor {
+    fn traverse_node(&self, node: &Node, source: &str, file_path: &Path, segments: &mut Vec<CodeSegment>) {
+        match node.kind() {
+            "function_item" => {
+                if let Some(segment) = self.extract_function_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+                if let Some(segment) = self.extract_attribute_route_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "struct_item" => {
+                if let Some(segment) = self.extract_struct_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "impl_item" => {
+                if let Some(segment) = self.extract_impl_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "trait_item" => {
+                if let Some(segment) = self.extract_trait_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "macro_invocation" => {
+                segments.extend(self.extract_diesel_table_segments(node, source, file_path));
+            }
+            "call_expression" => {
+                if let Some(segment) = self.extract_router_call_segment(node, source, file_path) {
+                    segments.push(segment);
+                } else if let Some(segment) = self.extract_warp_filter_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            _ => {}
+        }
+
+        // Recursively traverse child nodes
+        let mut cursor = node.walk();
+        if cursor.goto_first_child() {
+            loop {
+                self.traverse_node(&cursor.node(), source, file_path, segments);
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(source: &str) -> Vec<(String, String, String, Option<Framework>)> {
+        RustExtractor::new()
+            .extract_segments(source, Path::new("src/main.rs"))
+            .unwrap()
+            .into_iter()
+            .filter_map(|segment| match segment.segment_type {
+                SegmentType::Route(route) => Some((route.method, route.path, route.handler, segment.framework_context)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_extracts_axum_actix_and_warp_routes() {
+        let source = r#"
+fn app() -> Router {
+    Router::new()
+        .route("/users", get(list_users).post(create_user))
+        .route("/users/:id", delete(delete_user))
+}
+
+#[get("/health")]
+async fn health() -> impl Responder { HttpResponse::Ok() }
+
+fn filters() {
+    let orders = warp::path!("orders" / u32).and(warp::get()).and_then(get_order);
+}
+"#;
+        let found = routes(source);
+
+        assert!(found.contains(&("GET,POST".into(), "/users".into(), "list_users,create_user".into(), Some(Framework::Axum))));
+        assert!(found.contains(&("DELETE".into(), "/users/:id".into(), "delete_user".into(), Some(Framework::Axum))));
+        assert!(found.contains(&("GET".into(), "/health".into(), "health".into(), Some(Framework::Actix))));
+        assert!(found.iter().any(|(method, path, _, _)| method == "GET" && path.starts_with("/orders")));
+    }
+
+    #[test]
+    fn test_attribute_with_closing_paren_before_opening_paren_is_ignored() {
+        let source = r#"
+#[doc = "a) b("]
+fn documented() {}
+"#;
+        assert!(routes(source).is_empty());
+        assert!(RustExtractor::new().parse_route_attribute(r#"#[doc = "a) b("]"#).is_none());
+    }
+}
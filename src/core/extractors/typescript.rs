@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::path::Path;
+use super::span_decorated_declaration;
 use tree_sitter::{Node, Tree, TreeCursor};
 use anyhow::Result;
 use crate::core::ast_analyzer::{
@@ -9,11 +11,74 @@ use crate::core::types::Framework;
 
 pub struct TypeScriptExtractor;
 
+impl Default for TypeScriptExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wrappers that turn a render function into a component: `memo(...)`, `forwardRef(...)`
+const COMPONENT_WRAPPERS: [&str; 4] = ["memo", "forwardRef", "React.memo", "React.forwardRef"];
+
+/// Class decorators that mark Angular building blocks
+const ANGULAR_DECORATORS: [&str; 5] = ["Component", "Injectable", "NgModule", "Directive", "Pipe"];
+
+/// Lifecycle hooks Angular invokes on components and directives
+const ANGULAR_LIFECYCLE_HOOKS: [&str; 8] = [
+    "ngOnChanges", "ngOnInit", "ngDoCheck", "ngAfterContentInit",
+    "ngAfterContentChecked", "ngAfterViewInit", "ngAfterViewChecked", "ngOnDestroy",
+];
+
+/// Route properties naming guards, reported as route middleware
+const ROUTE_GUARD_KEYS: [&str; 5] = ["canActivate", "canActivateChild", "canMatch", "canLoad", "beforeEnter"];
+
+/// File-level type information used to resolve component props
+struct TypeScriptFileContext {
+    type_properties: HashMap<String, Vec<String>>,
+}
+
 impl TypeScriptExtractor {
     pub fn new() -> Self {
         Self
     }
 
+    /// Grammar for a file: TSX for `.tsx`/`.jsx`/`.js` (JSX is legal there), plain TypeScript for `.ts`
+    /// where angle-bracket type assertions would otherwise be misparsed as JSX
+    pub fn language_for_path(file_path: &Path) -> tree_sitter::Language {
+        Self::language_for_extension(file_path.extension().and_then(|e| e.to_str()).unwrap_or(""))
+    }
+
+    pub fn language_for_extension(extension: &str) -> tree_sitter::Language {
+        match extension {
+            "ts" | "mts" | "cts" => tree_sitter_typescript::language_typescript(),
+            _ => tree_sitter_typescript::language_tsx(),
+        }
+    }
+
+    /// Extract segments from a TypeScript/JavaScript source using an explicit grammar,
+    /// for sources embedded in other files such as Vue SFC `<script>` blocks
+    pub fn extract_with_language(&self, source: &str, file_path: &Path, language: tree_sitter::Language) -> Result<Vec<CodeSegment>> {
+        // Parse the source code with tree-sitter
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(language)?;
+
+        Ok(parser.parse(source, None)
+            .map(|tree| self.extract_from_tree(&tree, source, file_path))
+            .unwrap_or_default())
+    }
+
+    /// Extract segments from a tree the caller already parsed
+    pub fn extract_from_tree(&self, tree: &tree_sitter::Tree, source: &str, file_path: &Path) -> Vec<CodeSegment> {
+        let mut segments = Vec::new();
+        let root = tree.root_node();
+        let mut context = TypeScriptFileContext { type_properties: HashMap::new() };
+        self.collect_type_properties(&root, source, &mut context.type_properties);
+
+        // Traverse the AST to find relevant segments
+        self.traverse_node(&root, source, file_path, &context, &mut segments);
+        segments
+    }
+
     fn extract_function_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
         let function_name = self.get_function_name(node, source)?;
         let parameters = self.extract_parameters(node, source);
@@ -42,15 +107,34 @@ impl TypeScriptExtractor {
         })
     }
 
-    fn extract_class_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
+    fn extract_class_segment(&self, node: &Node, source: &str, file_path: &Path, context: &TypeScriptFileContext) -> Option<CodeSegment> {
         let class_name = self.get_class_name(node, source)?;
         let extends = self.get_extends_clause(node, source);
         let implements = self.get_implements_clause(node, source);
-        let is_react_component = self.is_react_component(node, source);
-        let props = self.extract_react_props(node, source);
-        let hooks = self.extract_react_hooks(node, source);
+        let angular_decorator = self.class_decorator_names(node, source)
+            .into_iter()
+            .find(|decorator| ANGULAR_DECORATORS.contains(&decorator.as_str()));
+        let is_react_component = angular_decorator.is_none() && self.is_react_component(node, source);
         let methods = self.extract_class_methods(node, source);
 
+        // React class components cannot call hooks; Angular components report their lifecycle hooks
+        let (props, hooks) = match angular_decorator.as_deref() {
+            Some("Component") | Some("Directive") => (
+                self.extract_angular_inputs(node, source),
+                methods.iter().filter(|m| ANGULAR_LIFECYCLE_HOOKS.contains(&m.as_str())).cloned().collect(),
+            ),
+            Some(_) => (Vec::new(), Vec::new()),
+            None if is_react_component => (self.extract_react_props(node, source, context), Vec::new()),
+            None => (Vec::new(), Vec::new()),
+        };
+        let framework_context = if is_react_component {
+            Some(Framework::React)
+        } else if angular_decorator.is_some() {
+            Some(Framework::Angular)
+        } else {
+            None
+        };
+
         Some(CodeSegment {
             segment_type: SegmentType::Class(ClassSegment {
                 name: class_name,
@@ -69,7 +153,232 @@ impl TypeScriptExtractor {
                 byte_start: node.start_byte(),
                 byte_end: node.end_byte(),
             },
-            framework_context: if is_react_component { Some(Framework::React) } else { None },
+            framework_context,
+            business_hints: self.extract_business_hints(node, source),
+        })
+    }
+
+    /// Decorator names on a class, including those attached to an enclosing `export` statement
+    fn class_decorator_names(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut decorators = Vec::new();
+        let export_statement = node.parent().filter(|parent| parent.kind() == "export_statement");
+
+        for owner in std::iter::once(*node).chain(export_statement) {
+            let mut cursor = owner.walk();
+            for child in owner.children(&mut cursor).filter(|child| child.kind() == "decorator") {
+                let name = child.named_child(0).and_then(|expression| match expression.kind() {
+                    "call_expression" => self.get_field_text(&expression, "function", source),
+                    _ => Some(self.get_node_text(&expression, source).to_string()),
+                });
+                decorators.extend(name);
+            }
+        }
+
+        decorators
+    }
+
+    /// Angular component inputs: `@Input() name` fields and signal inputs (`name = input<string>()`)
+    fn extract_angular_inputs(&self, node: &Node, source: &str) -> Vec<String> {
+        let Some(body) = node.child_by_field_name("body") else {
+            return Vec::new();
+        };
+
+        let mut inputs = Vec::new();
+        let mut cursor = body.walk();
+        for field in body.named_children(&mut cursor).filter(|child| child.kind() == "public_field_definition") {
+            let mut field_cursor = field.walk();
+            let has_input_decorator = field.children(&mut field_cursor)
+                .filter(|child| child.kind() == "decorator")
+                .any(|decorator| self.get_node_text(&decorator, source).starts_with("@Input"));
+            let is_signal_input = field.child_by_field_name("value")
+                .filter(|value| value.kind() == "call_expression")
+                .and_then(|value| self.get_field_text(&value, "function", source))
+                .is_some_and(|callee| matches!(callee.as_str(), "input" | "input.required" | "model"));
+
+            if has_input_decorator || is_signal_input {
+                inputs.extend(self.get_field_text(&field, "name", source));
+            }
+        }
+
+        inputs
+    }
+
+    /// Router configuration: Angular `Routes` arrays, `RouterModule.forRoot/forChild([...])` and
+    /// `provideRouter([...])`, and vue-router `RouteRecordRaw[]` arrays and `createRouter({ routes: [...] })`
+    fn extract_router_config_segments(&self, node: &Node, source: &str, file_path: &Path) -> Vec<CodeSegment> {
+        let config = match node.kind() {
+            "variable_declarator" => {
+                let annotation = node.child_by_field_name("type")
+                    .map(|annotation| self.get_node_text(&annotation, source))
+                    .unwrap_or("");
+                let framework = if annotation.contains("RouteRecordRaw") {
+                    Some(Framework::Vue)
+                } else if annotation.contains("Routes") || annotation.contains("Route[]") {
+                    Some(Framework::Angular)
+                } else {
+                    None
+                };
+                framework.zip(node.child_by_field_name("value"))
+            }
+            "call_expression" => {
+                let callee = self.get_field_text(node, "function", source).unwrap_or_default();
+                let first_argument = node.child_by_field_name("arguments").and_then(|args| args.named_child(0));
+                match callee.as_str() {
+                    "RouterModule.forRoot" | "RouterModule.forChild" | "provideRouter" => {
+                        first_argument.map(|routes| (Framework::Angular, routes))
+                    }
+                    "createRouter" => first_argument
+                        .and_then(|options| self.object_property(&options, "routes", source))
+                        .map(|routes| (Framework::Vue, routes)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let mut segments = Vec::new();
+        if let Some((framework, routes)) = config.filter(|(_, routes)| routes.kind() == "array") {
+            self.collect_route_config(&routes, "", &framework, source, file_path, &mut segments);
+        }
+        segments
+    }
+
+    fn collect_route_config(&self, routes: &Node, prefix: &str, framework: &Framework, source: &str, file_path: &Path, segments: &mut Vec<CodeSegment>) {
+        let mut cursor = routes.walk();
+        for route in routes.named_children(&mut cursor).filter(|child| child.kind() == "object") {
+            let Some(path) = self.object_property(&route, "path", source)
+                .map(|path| self.get_node_text(&path, source).trim_matches(|c| c == '\'' || c == '"' || c == '`').to_string()) else {
+                continue;
+            };
+            let full_path = if path.starts_with('/') {
+                path
+            } else {
+                format!("{}/{}", prefix.trim_end_matches('/'), path)
+            };
+
+            let handler = ["component", "loadComponent", "loadChildren", "redirectTo", "name"].iter()
+                .find_map(|key| self.object_property(&route, key, source).map(|value| (key, value)))
+                .map(|(key, value)| {
+                    let target = match value.kind() {
+                        "string" => self.get_node_text(&value, source).trim_matches(|c| c == '\'' || c == '"').to_string(),
+                        "identifier" | "member_expression" => self.get_node_text(&value, source).to_string(),
+                        // Lazy routes: `() => import('./admin/admin.module')`
+                        _ => self.find_descendant(&value, "string_fragment")
+                            .map(|fragment| self.get_node_text(&fragment, source).to_string())
+                            .unwrap_or_default(),
+                    };
+                    match *key {
+                        "component" | "loadComponent" | "loadChildren" => target,
+                        _ => format!("{}:{}", key, target),
+                    }
+                })
+                .unwrap_or_default();
+
+            let middleware = ROUTE_GUARD_KEYS.iter()
+                .filter_map(|key| self.object_property(&route, key, source))
+                .flat_map(|guards| {
+                    let mut guard_cursor = guards.walk();
+                    let names: Vec<String> = if guards.kind() == "array" {
+                        guards.named_children(&mut guard_cursor)
+                            .map(|guard| self.get_node_text(&guard, source).to_string())
+                            .collect()
+                    } else {
+                        vec![self.get_node_text(&guards, source).to_string()]
+                    };
+                    names
+                })
+                .collect();
+
+            segments.push(CodeSegment {
+                segment_type: SegmentType::Route(RouteSegment {
+                    path: full_path.clone(),
+                    // Client-side routes render a view rather than answer an HTTP verb
+                    method: "VIEW".to_string(),
+                    handler,
+                    middleware,
+                }),
+                content: self.get_node_text(&route, source).to_string(),
+                metadata: SegmentMetadata {
+                    line_start: route.start_position().row + 1,
+                    line_end: route.end_position().row + 1,
+                    file_path: file_path.to_path_buf(),
+                    byte_start: route.start_byte(),
+                    byte_end: route.end_byte(),
+                },
+                framework_context: Some(*framework),
+                business_hints: self.extract_business_hints(&route, source),
+            });
+
+            if let Some(children) = self.object_property(&route, "children", source).filter(|children| children.kind() == "array") {
+                self.collect_route_config(&children, &full_path, framework, source, file_path, segments);
+            }
+        }
+    }
+
+    /// Value of `key: value` (or a `key() {}` method) in an object literal
+    pub(crate) fn object_property<'a>(&self, object: &Node<'a>, key: &str, source: &str) -> Option<Node<'a>> {
+        if object.kind() != "object" {
+            return None;
+        }
+        let mut cursor = object.walk();
+        let property = object.named_children(&mut cursor)
+            .find(|member| matches!(member.kind(), "pair" | "method_definition") &&
+                self.get_field_text(member, if member.kind() == "pair" { "key" } else { "name" }, source).as_deref() == Some(key))?;
+        match property.kind() {
+            "pair" => property.child_by_field_name("value"),
+            _ => Some(property),
+        }
+    }
+
+    /// Function components: `function Card() { return <div/> }`, `const Card: FC<Props> = () => <div/>`
+    /// and wrapped forms such as `memo(forwardRef<Ref, Props>((props, ref) => ...))`
+    fn extract_component_segment(&self, node: &Node, source: &str, file_path: &Path, context: &TypeScriptFileContext) -> Option<CodeSegment> {
+        let name = self.get_field_text(node, "name", source)?;
+        if !name.chars().next().is_some_and(|c| c.is_ascii_uppercase()) {
+            return None;
+        }
+
+        let mut declared_types = Vec::new();
+        let (render_function, is_wrapped) = match node.kind() {
+            "function_declaration" => (*node, false),
+            "variable_declarator" => {
+                if let Some(annotation) = node.child_by_field_name("type") {
+                    declared_types.extend(self.collect_type_argument_names(&annotation, source));
+                }
+                let value = node.child_by_field_name("value")?;
+                let (render_function, wrapper_types) = self.unwrap_component_wrappers(&value, source)?;
+                declared_types.extend(wrapper_types);
+                (render_function, render_function.id() != value.id())
+            }
+            _ => return None,
+        };
+
+        if !is_wrapped && !self.contains_jsx(&render_function) {
+            return None;
+        }
+
+        let props = self.extract_component_props(&render_function, source, &declared_types, context);
+        let hooks = self.collect_hooks(&render_function, source);
+
+        Some(CodeSegment {
+            segment_type: SegmentType::Class(ClassSegment {
+                name,
+                extends: None,
+                implements: Vec::new(),
+                is_react_component: true,
+                props,
+                hooks,
+                methods: Vec::new(),
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: SegmentMetadata {
+                line_start: node.start_position().row + 1,
+                line_end: node.end_position().row + 1,
+                file_path: file_path.to_path_buf(),
+                byte_start: node.start_byte(),
+                byte_end: node.end_byte(),
+            },
+            framework_context: Some(Framework::React),
             business_hints: self.extract_business_hints(node, source),
         })
     }
@@ -101,33 +410,33 @@ impl TypeScriptExtractor {
     fn extract_route_segment(&self, node: &Node, source: &str, file_path: &Path) -> Option<CodeSegment> {
         // Look for Express.js, NestJS, or Next.js API routes
         let content = self.get_node_text(node, source);
-        
-        if let Some(route_info) = self.parse_route_pattern(content) {
-            return Some(CodeSegment {
-                segment_type: SegmentType::Route(RouteSegment {
-                    path: route_info.path,
-                    method: route_info.method,
-                    handler: route_info.handler,
-                    middleware: route_info.middleware,
-                }),
-                content: content.to_string(),
-                metadata: SegmentMetadata {
-                    line_start: node.start_position().row + 1,
-                    line_end: node.end_position().row + 1,
-                    file_path: file_path.to_path_buf(),
-                    byte_start: node.start_byte(),
-                    byte_end: node.end_byte(),
-                },
-                framework_context: self.detect_route_framework(content),
-                business_hints: self.extract_business_hints(node, source),
-            });
-        }
+        let route_info = self.parse_route_pattern(content)?;
+        Some(self.route_segment(node, source, file_path, route_info, self.detect_route_framework(content)))
+    }
 
-        None
+    fn route_segment(&self, node: &Node, source: &str, file_path: &Path, route_info: RouteInfo, framework: Option<Framework>) -> CodeSegment {
+        CodeSegment {
+            segment_type: SegmentType::Route(RouteSegment {
+                path: route_info.path,
+                method: route_info.method,
+                handler: route_info.handler,
+                middleware: route_info.middleware,
+            }),
+            content: self.get_node_text(node, source).to_string(),
+            metadata: SegmentMetadata {
+                line_start: node.start_position().row + 1,
+                line_end: node.end_position().row + 1,
+                file_path: file_path.to_path_buf(),
+                byte_start: node.start_byte(),
+                byte_end: node.end_byte(),
+            },
+            framework_context: framework,
+            business_hints: self.extract_business_hints(node, source),
+        }
     }
 
     // Helper methods
-    fn get_node_text<'a>(&self, node: &Node, source: &'a str) -> &'a str {
+    pub(crate) fn get_node_text<'a>(&self, node: &Node, source: &'a str) -> &'a str {
         &source[node.start_byte()..node.end_byte()]
     }
 
@@ -259,56 +568,250 @@ impl TypeScriptExtractor {
         implements
     }
 
+    /// Class components extend `Component`/`PureComponent` or render JSX from `render()`
     fn is_react_component(&self, node: &Node, source: &str) -> bool {
-        let content = self.get_node_text(node, source);
-        content.contains("React.Component") ||
-        content.contains("Component") ||
-        content.contains("jsx") ||
-        content.contains("tsx") ||
-        content.contains("useState") ||
-        content.contains("useEffect")
+        let extends_component = self.find_descendant(node, "extends_clause")
+            .and_then(|clause| clause.child_by_field_name("value"))
+            .map(|value| {
+                let base = self.get_node_text(&value, source);
+                base.ends_with("Component") || base.ends_with("PureComponent")
+            })
+            .unwrap_or(false);
+
+        extends_component || self.find_descendant(node, "class_body")
+            .map(|body| {
+                let mut cursor = body.walk();
+                body.named_children(&mut cursor).any(|member| {
+                    member.kind() == "method_definition" &&
+                    self.get_field_text(&member, "name", source).as_deref() == Some("render") &&
+                    self.contains_jsx(&member)
+                })
+            })
+            .unwrap_or(false)
     }
 
-    fn extract_react_props(&self, node: &Node, source: &str) -> Vec<String> {
+    /// Props of a class component: `Component<Props, State>` type arguments plus `this.props.x` accesses
+    fn extract_react_props(&self, node: &Node, source: &str, context: &TypeScriptFileContext) -> Vec<String> {
         let mut props = Vec::new();
-        let content = self.get_node_text(node, source);
-        
-        // Look for props destructuring patterns
-        for line in content.lines() {
-            if line.contains("props") || line.contains("const {") {
-                // Simple extraction - could be enhanced with proper AST traversal
-                if let Some(start) = line.find('{') {
-                    if let Some(end) = line.find('}') {
-                        let props_str = &line[start + 1..end];
-                        for prop in props_str.split(',') {
-                            props.push(prop.trim().to_string());
-                        }
-                    }
+
+        let props_type = self.find_descendant(node, "extends_clause")
+            .and_then(|clause| clause.child_by_field_name("type_arguments"))
+            .and_then(|type_args| type_args.named_child(0));
+        if let Some(props_type) = props_type {
+            let type_name = self.get_node_text(&props_type, source);
+            props.extend(context.type_properties.get(type_name).cloned().unwrap_or_default());
+        }
+
+        let mut accessed = Vec::new();
+        self.collect_this_props(node, source, &mut accessed);
+        for prop in accessed {
+            if !props.contains(&prop) {
+                props.push(prop);
+            }
+        }
+
+        props
+    }
+
+    fn collect_this_props(&self, node: &Node, source: &str, props: &mut Vec<String>) {
+        match node.kind() {
+            // this.props.title
+            "member_expression" => {
+                let object = node.child_by_field_name("object")
+                    .map(|object| self.get_node_text(&object, source));
+                if object == Some("this.props") {
+                    props.extend(self.get_field_text(node, "property", source));
                 }
             }
+            // const { title, subtitle } = this.props
+            "variable_declarator" => {
+                let value = node.child_by_field_name("value")
+                    .map(|value| self.get_node_text(&value, source));
+                if let Some(pattern) = node.child_by_field_name("name").filter(|_| value == Some("this.props")) {
+                    props.extend(self.object_pattern_names(&pattern, source));
+                }
+            }
+            _ => {}
         }
-        
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_this_props(&child, source, props);
+        }
+    }
+
+    /// Props of a function component: declared `Props` type members plus destructured parameter names
+    fn extract_component_props(&self, render_function: &Node, source: &str, declared_types: &[String], context: &TypeScriptFileContext) -> Vec<String> {
+        let mut props = Vec::new();
+        let mut type_names = declared_types.to_vec();
+
+        let first_param = render_function.child_by_field_name("parameters")
+            .and_then(|params| params.named_child(0))
+            .or_else(|| render_function.child_by_field_name("parameter"));
+
+        let mut destructured = Vec::new();
+        if let Some(param) = first_param {
+            if let Some(annotation) = param.child_by_field_name("type") {
+                type_names.extend(self.collect_type_names(&annotation, source));
+            }
+            if let Some(pattern) = param.child_by_field_name("pattern") {
+                destructured = self.object_pattern_names(&pattern, source);
+            }
+        }
+
+        for type_name in type_names {
+            if let Some(properties) = context.type_properties.get(&type_name) {
+                props.extend(properties.iter().cloned());
+            }
+        }
+        for name in destructured {
+            if !props.contains(&name) {
+                props.push(name);
+            }
+        }
+
         props
     }
 
-    fn extract_react_hooks(&self, node: &Node, source: &str) -> Vec<String> {
-        let mut hooks = Vec::new();
-        let content = self.get_node_text(node, source);
-        
-        let hook_patterns = [
-            "useState", "useEffect", "useContext", "useReducer",
-            "useCallback", "useMemo", "useRef", "useLayoutEffect"
-        ];
-        
-        for hook in hook_patterns {
-            if content.contains(hook) {
-                hooks.push(hook.to_string());
+    pub(crate) fn object_pattern_names(&self, pattern: &Node, source: &str) -> Vec<String> {
+        let mut names = Vec::new();
+        if pattern.kind() != "object_pattern" {
+            return names;
+        }
+
+        let mut cursor = pattern.walk();
+        for child in pattern.named_children(&mut cursor) {
+            match child.kind() {
+                "shorthand_property_identifier_pattern" => names.push(self.get_node_text(&child, source).to_string()),
+                "pair_pattern" => names.extend(self.get_field_text(&child, "key", source)),
+                "object_assignment_pattern" => names.extend(self.get_field_text(&child, "left", source)),
+                "rest_pattern" => names.push(format!("...{}", self.get_node_text(&child, source).trim_start_matches("..."))),
+                _ => {}
             }
         }
-        
+
+        names
+    }
+
+    /// Hooks called inside a component body, including custom `useXxx` hooks and `React.useXxx`
+    pub(crate) fn collect_hooks(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut hooks = Vec::new();
+        self.collect_hook_calls(node, source, &mut hooks);
         hooks
     }
 
+    fn collect_hook_calls(&self, node: &Node, source: &str, hooks: &mut Vec<String>) {
+        if node.kind() == "call_expression" {
+            let callee = node.child_by_field_name("function").and_then(|function| match function.kind() {
+                "identifier" => Some(self.get_node_text(&function, source).to_string()),
+                "member_expression" => self.get_field_text(&function, "property", source),
+                _ => None,
+            });
+            if let Some(callee) = callee {
+                let is_hook = callee.strip_prefix("use")
+                    .and_then(|rest| rest.chars().next())
+                    .is_some_and(|c| c.is_ascii_uppercase());
+                if is_hook && !hooks.contains(&callee) {
+                    hooks.push(callee);
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_hook_calls(&child, source, hooks);
+        }
+    }
+
+    /// Peels `memo(...)`/`forwardRef(...)` wrappers, returning the render function and any
+    /// type arguments given to the wrappers (e.g. `forwardRef<HTMLDivElement, Props>`)
+    fn unwrap_component_wrappers<'a>(&self, value: &Node<'a>, source: &str) -> Option<(Node<'a>, Vec<String>)> {
+        match value.kind() {
+            "arrow_function" | "function" | "function_expression" => Some((*value, Vec::new())),
+            "call_expression" => {
+                let callee = self.get_field_text(value, "function", source)?;
+                if !COMPONENT_WRAPPERS.contains(&callee.as_str()) {
+                    return None;
+                }
+                let mut type_names = value.child_by_field_name("type_arguments")
+                    .map(|args| self.collect_type_names(&args, source))
+                    .unwrap_or_default();
+                let inner = value.child_by_field_name("arguments")?.named_child(0)?;
+                let (render_function, inner_types) = self.unwrap_component_wrappers(&inner, source)?;
+                type_names.extend(inner_types);
+                Some((render_function, type_names))
+            }
+            _ => None,
+        }
+    }
+
+    /// Type arguments of generic annotations such as `React.FC<Props>`
+    fn collect_type_argument_names(&self, annotation: &Node, source: &str) -> Vec<String> {
+        self.find_descendant(annotation, "type_arguments")
+            .map(|args| self.collect_type_names(&args, source))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn collect_type_names(&self, node: &Node, source: &str) -> Vec<String> {
+        let mut names = Vec::new();
+        if node.kind() == "type_identifier" {
+            names.push(self.get_node_text(node, source).to_string());
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            names.extend(self.collect_type_names(&child, source));
+        }
+        names
+    }
+
+    fn contains_jsx(&self, node: &Node) -> bool {
+        if matches!(node.kind(), "jsx_element" | "jsx_self_closing_element") {
+            return true;
+        }
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).any(|child| self.contains_jsx(&child))
+    }
+
+    pub(crate) fn find_descendant<'a>(&self, node: &Node<'a>, kind: &str) -> Option<Node<'a>> {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if child.kind() == kind {
+                return Some(child);
+            }
+            if let Some(found) = self.find_descendant(&child, kind) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    pub(crate) fn get_field_text(&self, node: &Node, field: &str, source: &str) -> Option<String> {
+        node.child_by_field_name(field)
+            .map(|child| self.get_node_text(&child, source).to_string())
+    }
+
+    /// Member names of `interface X { ... }` and `type X = { ... }` declarations in the file
+    pub(crate) fn collect_type_properties(&self, node: &Node, source: &str, type_properties: &mut HashMap<String, Vec<String>>) {
+        if matches!(node.kind(), "interface_declaration" | "type_alias_declaration") {
+            let body = node.child_by_field_name("body").or_else(|| node.child_by_field_name("value"));
+            if let (Some(name), Some(body)) = (self.get_field_text(node, "name", source), body) {
+                let mut cursor = body.walk();
+                let members: Vec<String> = body.named_children(&mut cursor)
+                    .filter(|member| matches!(member.kind(), "property_signature" | "method_signature"))
+                    .filter_map(|member| self.get_field_text(&member, "name", source))
+                    .collect();
+                if !members.is_empty() {
+                    type_properties.insert(name, members);
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_type_properties(&child, source, type_properties);
+        }
+    }
+
     fn extract_class_methods(&self, node: &Node, source: &str) -> Vec<String> {
         let mut methods = Vec::new();
         let mut cursor = node.walk();
@@ -320,7 +823,7 @@ impl TypeScriptExtractor {
                     if body_cursor.goto_first_child() {
                         loop {
                             if body_cursor.node().kind() == "method_definition" {
-                                if let Some(method_name) = self.get_function_name(&body_cursor.node(), source) {
+                                if let Some(method_name) = self.get_field_text(&body_cursor.node(), "name", source) {
                                     methods.push(method_name);
                                 }
                             }
@@ -364,6 +867,46 @@ impl TypeScriptExtractor {
         properties
     }
 
+    /// `export default function handler(req, res)`, the shape of a Next.js API route
+    fn is_default_function_export(&self, node: &Node) -> bool {
+        let mut cursor = node.walk();
+        let is_default = node.children(&mut cursor).any(|child| child.kind() == "default");
+        let exported = node.child_by_field_name("declaration").or_else(|| node.child_by_field_name("value"));
+        is_default && exported.is_some_and(|exported| {
+            matches!(exported.kind(), "function_declaration" | "function" | "arrow_function" | "generator_function_declaration")
+        })
+    }
+
+    /// `app.get('/users', ...)`, `userRouter.post('/orders', ...)`: an HTTP verb called on any receiver
+    /// with a string path and a handler, so routers are recognised whatever they are named
+    fn parse_express_route_call(&self, node: &Node, source: &str) -> Option<RouteInfo> {
+        let callee = node.child_by_field_name("function").filter(|callee| callee.kind() == "member_expression")?;
+        let verb = self.get_node_text(&callee.child_by_field_name("property")?, source);
+        if !matches!(verb, "get" | "post" | "put" | "patch" | "delete") {
+            return None;
+        }
+
+        let arguments = node.child_by_field_name("arguments")?;
+        let mut cursor = arguments.walk();
+        let arguments: Vec<Node> = arguments.named_children(&mut cursor).collect();
+        let [path, .., _handler] = arguments.as_slice() else {
+            return None;
+        };
+        let mut cursor = path.walk();
+        let is_literal_path = path.kind() == "string"
+            || (path.kind() == "template_string" && !path.named_children(&mut cursor).any(|part| part.kind() == "template_substitution"));
+        if !is_literal_path {
+            return None;
+        }
+
+        Some(RouteInfo {
+            path: self.get_node_text(path, source).trim_matches(|c| c == '\'' || c == '"' || c == '`').to_string(),
+            method: verb.to_uppercase(),
+            handler: "Express Route Handler".to_string(),
+            middleware: Vec::new(),
+        })
+    }
+
     fn parse_route_pattern(&self, content: &str) -> Option<RouteInfo> {
         // NestJS route patterns
         if content.contains("@Get") || content.contains("@Post") || 
@@ -372,8 +915,8 @@ impl TypeScriptExtractor {
         }
         
         // Express.js route patterns
-        if content.contains("app.get") || content.contains("app.post") ||
-           content.contains("router.get") || content.contains("router.post") {
+        let express_verbs = ["get", "post", "put", "patch", "delete"];
+        if ["app", "router"].iter().any(|object| express_verbs.iter().any(|verb| content.contains(&format!("{}.{}(", object, verb)))) {
             return self.parse_express_route(content);
         }
         
@@ -492,26 +1035,18 @@ impl ToTitleCase for str {
 
 impl SegmentExtractor for TypeScriptExtractor {
     fn extract_segments(&self, source: &str, file_path: &Path) -> Result<Vec<CodeSegment>> {
-        let mut segments = Vec::new();
-
-        // Parse the source code with tree-sitter
-        let mut parser = tree_sitter::Parser::new();
-        parser.set_language(tree_sitter_typescript::language_typescript())?;
-        
-        if let Some(tree) = parser.parse(source, None) {
-            let root = tree.root_node();
-            let mut cursor = root.walk();
-
-            // Traverse the AST to find relevant segments
-            self.traverse_node(&root, source, file_path, &mut segments);
-        }
-
-        Ok(segments)
+        self.extract_with_language(source, file_path, Self::language_for_path(file_path))
     }
 
     fn extract_business_hints(&self, node: &Node, source: &str) -> Vec<String> {
+        self.business_hints_for_text(self.get_node_text(node, source))
+    }
+}
+
+impl TypeScriptExtractor {
+    pub(crate) fn business_hints_for_text(&self, text: &str) -> Vec<String> {
         let mut hints = Vec::new();
-        let content = self.get_node_text(node, source).to_lowercase();
+        let content = text.to_lowercase();
 
         // Authentication hints
         if content.contains("auth") || content.contains("login") || content.contains("password") {
@@ -540,18 +1075,36 @@ impl SegmentExtractor for TypeScriptExtractor {
 
         hints
     }
-}
 
-impl TypeScriptExtractor {
-    fn traverse_node(&self, node: &Node, source: &str, file_path: &Path, segments: &mut Vec<CodeSegment>) {
+    fn traverse_node(&self, node: &Node, source: &str, file_path: &Path, context: &TypeScriptFileContext, segments: &mut Vec<CodeSegment>) {
         match node.kind() {
-            "function_declaration" | "arrow_function" | "method_definition" => {
+            "function_declaration" => {
                 if let Some(segment) = self.extract_function_segment(node, source, file_path) {
                     segments.push(segment);
                 }
+                if let Some(segment) = self.extract_component_segment(node, source, file_path, context) {
+                    segments.push(segment);
+                }
+            }
+            "arrow_function" | "method_definition" => {
+                if let Some(segment) = self.extract_function_segment(node, source, file_path) {
+                    segments.push(segment);
+                }
+            }
+            "variable_declarator" => {
+                if let Some(segment) = self.extract_component_segment(node, source, file_path, context) {
+                    segments.push(segment);
+                }
+                segments.extend(self.extract_router_config_segments(node, source, file_path));
+            }
+            "call_expression" => {
+                segments.extend(self.extract_router_config_segments(node, source, file_path));
+                if let Some(route_info) = self.parse_express_route_call(node, source) {
+                    segments.push(self.route_segment(node, source, file_path, route_info, Some(Framework::Express)));
+                }
             }
             "class_declaration" => {
-                if let Some(segment) = self.extract_class_segment(node, source, file_path) {
+                if let Some(segment) = self.extract_class_segment(node, source, file_path, context) {
                     segments.push(segment);
                 }
             }
@@ -560,19 +1113,34 @@ impl TypeScriptExtractor {
                     segments.push(segment);
                 }
             }
-            _ => {
-                // Check if this might be a route definition
+            // Routes come from the node that declares them (NestJS decorators, Next.js default
+            // exports), not from every node enclosing it
+            "decorator" => {
+                if let Some(mut segment) = self.extract_route_segment(node, source, file_path) {
+                    // Method decorators precede the method as siblings in the class body
+                    let mut sibling = node.next_named_sibling();
+                    while let Some(next) = sibling.filter(|next| next.kind() == "decorator") {
+                        sibling = next.next_named_sibling();
+                    }
+                    if let Some(method) = sibling.filter(|method| method.kind() == "method_definition") {
+                        span_decorated_declaration(&mut segment, node, &method, source);
+                    }
+                    segments.push(segment);
+                }
+            }
+            "export_statement" if self.is_default_function_export(node) => {
                 if let Some(segment) = self.extract_route_segment(node, source, file_path) {
                     segments.push(segment);
                 }
             }
+            _ => {}
         }
 
         // Recursively traverse child nodes
         let mut cursor = node.walk();
         if cursor.goto_first_child() {
             loop {
-                self.traverse_node(&cursor.node(), source, file_path, segments);
+                self.traverse_node(&cursor.node(), source, file_path, context, segments);
                 if !cursor.goto_next_sibling() {
                     break;
                 }
@@ -581,3 +1149,62 @@ impl TypeScriptExtractor {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tsx_function_component_with_props_and_hooks() {
+        let source = r#"
+interface CardProps { title: string; onClose: () => void }
+
+export function UserCard({ title, onClose }: CardProps) {
+  const [open, setOpen] = useState(false);
+  useEffect(() => setOpen(true), []);
+  return <div className="card" onClick={onClose}>{open && <h2>{title}</h2>}</div>;
+}
+"#;
+        let segments = TypeScriptExtractor::new().extract_segments(source, Path::new("src/UserCard.tsx")).unwrap();
+        let component = segments.iter().find_map(|segment| match &segment.segment_type {
+            SegmentType::Class(class) if class.is_react_component => Some(class),
+            _ => None,
+        }).expect("UserCard is recognised as a React component");
+
+        assert_eq!(component.name, "UserCard");
+        assert_eq!(component.props, vec!["title".to_string(), "onClose".to_string()]);
+        assert_eq!(component.hooks, vec!["useState".to_string(), "useEffect".to_string()]);
+    }
+
+    #[test]
+    fn test_ts_type_assertion_is_not_parsed_as_jsx() {
+        let source = "export function parse(raw: unknown) {\n  const user = <User>raw;\n  return user.name;\n}\n";
+        let segments = TypeScriptExtractor::new().extract_segments(source, Path::new("src/parse.ts")).unwrap();
+
+        assert!(segments.iter().any(|segment| matches!(&segment.segment_type, SegmentType::Function(function) if function.name == "parse")));
+        assert!(!segments.iter().any(|segment| matches!(&segment.segment_type, SegmentType::Class(class) if class.is_react_component)));
+    }
+
+    #[test]
+    fn test_express_routes_on_any_router_receiver() {
+        let source = r#"
+const userRouter = express.Router();
+userRouter.get('/users', listUsers);
+api.post("/orders", requireAuth, createOrder);
+v1.delete(`/items/:id`, (req, res) => res.sendStatus(204));
+const cached = cache.get('session');
+const user = users.get(id, fallback);
+"#;
+        let segments = TypeScriptExtractor::new().extract_segments(source, Path::new("src/routes.ts")).unwrap();
+        let routes: Vec<(String, String)> = segments.iter().filter_map(|segment| match &segment.segment_type {
+            SegmentType::Route(route) => Some((route.method.clone(), route.path.clone())),
+            _ => None,
+        }).collect();
+
+        assert_eq!(routes, vec![
+            ("GET".to_string(), "/users".to_string()),
+            ("POST".to_string(), "/orders".to_string()),
+            ("DELETE".to_string(), "/items/:id".to_string()),
+        ]);
+    }
+}
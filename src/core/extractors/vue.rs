@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+use std::path::Path;
+use tree_sitter::{Node, Tree};
+use anyhow::Result;
+use crate::core::ast_analyzer::{
+    CodeSegment, SegmentExtractor, SegmentType, SegmentMetadata, ClassSegment
+};
+use crate::core::extractors::TypeScriptExtractor;
+use crate::core::types::Framework;
+
+/// Composition API lifecycle registrations reported alongside composables
+const VUE_LIFECYCLE_HOOKS: [&str; 8] = [
+    "onBeforeMount", "onMounted", "onBeforeUpdate", "onUpdated",
+    "onBeforeUnmount", "onUnmounted", "onActivated", "onDeactivated",
+];
+
+/// Options API lifecycle methods
+const VUE_OPTIONS_HOOKS: [&str; 8] = [
+    "beforeCreate", "created", "beforeMount", "mounted",
+    "beforeUpdate", "updated", "beforeUnmount", "unmounted",
+];
+
+/// Extracts segments from Vue single-file components by running each `<script>` /
+/// `<script setup>` block through the TypeScript extractor, then adds one component
+/// segment describing the SFC itself
+pub struct VueExtractor {
+    script_extractor: TypeScriptExtractor,
+}
+
+impl Default for VueExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `<script>` block and its position inside the SFC
+struct ScriptBlock<'a> {
+    content: &'a str,
+    byte_offset: usize,
+    line_offset: usize,
+    lang: String,
+    is_setup: bool,
+}
+
+/// Component facts accumulated across all script blocks of one SFC
+#[derive(Default)]
+struct VueComponentInfo {
+    props: Vec<String>,
+    hooks: Vec<String>,
+    methods: Vec<String>,
+}
+
+impl VueExtractor {
+    pub fn new() -> Self {
+        Self {
+            script_extractor: TypeScriptExtractor::new(),
+        }
+    }
+
+    /// Split an SFC into its script blocks; `<template>` and `<style>` are ignored
+    fn script_blocks<'a>(&self, source: &'a str) -> Vec<ScriptBlock<'a>> {
+        let mut blocks = Vec::new();
+        let mut search_from = 0;
+
+        while let Some(open_start) = source[search_from..].find("<script").map(|i| search_from + i) {
+            let Some(open_end) = source[open_start..].find('>').map(|i| open_start + i) else {
+                break;
+            };
+            let Some(close_start) = source[open_end..].find("</script>").map(|i| open_end + i) else {
+                break;
+            };
+
+            let attributes = &source[open_start + "<script".len()..open_end];
+            let byte_offset = open_end + 1;
+            blocks.push(ScriptBlock {
+                content: &source[byte_offset..close_start],
+                byte_offset,
+                line_offset: source[..byte_offset].matches('\n').count(),
+                lang: self.attribute_value(attributes, "lang").unwrap_or_else(|| "js".to_string()),
+                is_setup: attributes.split_whitespace().any(|attribute| attribute == "setup"),
+            });
+
+            search_from = close_start + "</script>".len();
+        }
+
+        blocks
+    }
+
+    fn attribute_value(&self, attributes: &str, name: &str) -> Option<String> {
+        let start = attributes.find(&format!("{}=", name))? + name.len() + 1;
+        let value = attributes[start..].trim_start_matches(['"', '\'']);
+        let end = value.find(['"', '\'', ' ', '/']).unwrap_or(value.len());
+        Some(value[..end].to_string())
+    }
+
+    /// Move segment positions from block-relative to file-relative
+    fn offset_segment(&self, segment: &mut CodeSegment, block: &ScriptBlock) {
+        segment.metadata.line_start += block.line_offset;
+        segment.metadata.line_end += block.line_offset;
+        segment.metadata.byte_start += block.byte_offset;
+        segment.metadata.byte_end += block.byte_offset;
+        if segment.framework_context.is_none() {
+            segment.framework_context = Some(Framework::Vue);
+        }
+    }
+
+    fn collect_component_info(&self, tree: &Tree, block: &ScriptBlock, info: &mut VueComponentInfo) {
+        let root = tree.root_node();
+        let source = block.content;
+        let ts = &self.script_extractor;
+
+        let mut type_properties = HashMap::new();
+        ts.collect_type_properties(&root, source, &mut type_properties);
+        self.collect_define_props(&root, source, &type_properties, &mut info.props);
+
+        for hook in ts.collect_hooks(&root, source) {
+            push_unique(&mut info.hooks, hook);
+        }
+        self.collect_lifecycle_calls(&root, source, &mut info.hooks);
+
+        if block.is_setup {
+            // Top-level functions in `<script setup>` are exposed to the template
+            let mut cursor = root.walk();
+            for statement in root.named_children(&mut cursor) {
+                match statement.kind() {
+                    "function_declaration" => {
+                        if let Some(name) = ts.get_field_text(&statement, "name", source) {
+                            push_unique(&mut info.methods, name);
+                        }
+                    }
+                    "lexical_declaration" => {
+                        let mut declarator_cursor = statement.walk();
+                        for declarator in statement.named_children(&mut declarator_cursor) {
+                            let is_function = declarator.child_by_field_name("value")
+                                .is_some_and(|value| matches!(value.kind(), "arrow_function" | "function" | "function_expression"));
+                            if let Some(name) = ts.get_field_text(&declarator, "name", source).filter(|_| is_function) {
+                                push_unique(&mut info.methods, name);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        } else if let Some(options) = self.component_options(&root, source) {
+            self.collect_options_api(&options, source, info);
+        }
+    }
+
+    /// `defineProps<{ title: string }>()`, `defineProps<Props>()`, `defineProps({ title: String })`
+    /// and `defineProps(['title'])`
+    fn collect_define_props(&self, node: &Node, source: &str, type_properties: &HashMap<String, Vec<String>>, props: &mut Vec<String>) {
+        let ts = &self.script_extractor;
+
+        if node.kind() == "call_expression" && ts.get_field_text(node, "function", source).as_deref() == Some("defineProps") {
+            if let Some(type_argument) = node.child_by_field_name("type_arguments").and_then(|args| args.named_child(0)) {
+                if type_argument.kind() == "object_type" {
+                    let mut cursor = type_argument.walk();
+                    for member in type_argument.named_children(&mut cursor) {
+                        if let Some(name) = ts.get_field_text(&member, "name", source) {
+                            push_unique(props, name);
+                        }
+                    }
+                } else {
+                    for type_name in ts.collect_type_names(&type_argument, source) {
+                        for name in type_properties.get(&type_name).into_iter().flatten() {
+                            push_unique(props, name.clone());
+                        }
+                    }
+                }
+            }
+            if let Some(argument) = node.child_by_field_name("arguments").and_then(|args| args.named_child(0)) {
+                for name in self.prop_names(&argument, source) {
+                    push_unique(props, name);
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_define_props(&child, source, type_properties, props);
+        }
+    }
+
+    /// Prop names from a runtime declaration: an object of prop options or an array of names
+    fn prop_names(&self, declaration: &Node, source: &str) -> Vec<String> {
+        let ts = &self.script_extractor;
+        let mut names = Vec::new();
+        let mut cursor = declaration.walk();
+
+        match declaration.kind() {
+            "object" => {
+                for member in declaration.named_children(&mut cursor) {
+                    match member.kind() {
+                        "pair" => names.extend(ts.get_field_text(&member, "key", source)),
+                        "shorthand_property_identifier" => names.push(ts.get_node_text(&member, source).to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            "array" => {
+                for element in declaration.named_children(&mut cursor) {
+                    if element.kind() == "string" {
+                        names.push(ts.get_node_text(&element, source).trim_matches(|c| c == '\'' || c == '"').to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        names
+    }
+
+    fn collect_lifecycle_calls(&self, node: &Node, source: &str, hooks: &mut Vec<String>) {
+        let lifecycle_hook = Some(node)
+            .filter(|node| node.kind() == "call_expression")
+            .and_then(|node| self.script_extractor.get_field_text(node, "function", source))
+            .filter(|callee| VUE_LIFECYCLE_HOOKS.contains(&callee.as_str()));
+        if let Some(callee) = lifecycle_hook {
+            push_unique(hooks, callee);
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_lifecycle_calls(&child, source, hooks);
+        }
+    }
+
+    /// The options object of `export default { ... }` or `export default defineComponent({ ... })`
+    fn component_options<'a>(&self, root: &Node<'a>, source: &str) -> Option<Node<'a>> {
+        let mut cursor = root.walk();
+        let export = root.named_children(&mut cursor)
+            .find(|statement| statement.kind() == "export_statement" &&
+                self.script_extractor.get_node_text(statement, source).starts_with("export default"))?;
+        let value = export.child_by_field_name("value")?;
+
+        match value.kind() {
+            "object" => Some(value),
+            "call_expression" => value.child_by_field_name("arguments")
+                .and_then(|args| args.named_child(0))
+                .filter(|options| options.kind() == "object"),
+            _ => None,
+        }
+    }
+
+    fn collect_options_api(&self, options: &Node, source: &str, info: &mut VueComponentInfo) {
+        let ts = &self.script_extractor;
+
+        if let Some(props) = ts.object_property(options, "props", source) {
+            for name in self.prop_names(&props, source) {
+                push_unique(&mut info.props, name);
+            }
+        }
+
+        if let Some(methods) = ts.object_property(options, "methods", source).filter(|methods| methods.kind() == "object") {
+            let mut cursor = methods.walk();
+            for method in methods.named_children(&mut cursor) {
+                let field = if method.kind() == "pair" { "key" } else { "name" };
+                if let Some(name) = ts.get_field_text(&method, field, source) {
+                    push_unique(&mut info.methods, name);
+                }
+            }
+        }
+
+        for hook in VUE_OPTIONS_HOOKS {
+            if ts.object_property(options, hook, source).is_some() {
+                push_unique(&mut info.hooks, hook.to_string());
+            }
+        }
+    }
+
+    /// `user-card.vue` -> `UserCard`
+    fn component_name(&self, file_path: &Path) -> String {
+        let stem = file_path.file_stem().and_then(|s| s.to_str()).unwrap_or("Component");
+        stem.split(['-', '_', '.'])
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars.next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+impl SegmentExtractor for VueExtractor {
+    fn extract_segments(&self, source: &str, file_path: &Path) -> Result<Vec<CodeSegment>> {
+        let mut segments = Vec::new();
+        let mut info = VueComponentInfo::default();
+
+        for block in self.script_blocks(source) {
+            // One parse per block serves both the script segments and the component facts
+            let mut parser = tree_sitter::Parser::new();
+            parser.set_language(TypeScriptExtractor::language_for_extension(&block.lang))?;
+            let Some(tree) = parser.parse(block.content, None) else {
+                continue;
+            };
+
+            for mut segment in self.script_extractor.extract_from_tree(&tree, block.content, file_path) {
+                self.offset_segment(&mut segment, &block);
+                segments.push(segment);
+            }
+            self.collect_component_info(&tree, &block, &mut info);
+        }
+
+        // The SFC itself is the component, spanning the whole file
+        segments.push(CodeSegment {
+            segment_type: SegmentType::Class(ClassSegment {
+                name: self.component_name(file_path),
+                extends: None,
+                implements: Vec::new(),
+                is_react_component: false,
+                props: info.props,
+                hooks: info.hooks,
+                methods: info.methods,
+            }),
+            content: source.to_string(),
+            metadata: SegmentMetadata {
+                line_start: 1,
+                line_end: source.lines().count().max(1),
+                file_path: file_path.to_path_buf(),
+                byte_start: 0,
+                byte_end: source.len(),
+            },
+            framework_context: Some(Framework::Vue),
+            business_hints: self.script_extractor.business_hints_for_text(source),
+        });
+
+        Ok(segments)
+    }
+
+    fn extract_business_hints(&self, node: &Node, source: &str) -> Vec<String> {
+        self.script_extractor.extract_business_hints(node, source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_setup_component_with_offset_segments() {
+        let source = r#"<template>
+  <button @click="save">{{ label }}</button>
+</template>
+
+<script setup lang="ts">
+const props = defineProps<{ label: string; userId: number }>();
+onMounted(() => load(props.userId));
+function save() {
+  emit('saved');
+}
+</script>
+"#;
+        let segments = VueExtractor::new().extract_segments(source, Path::new("src/components/save-button.vue")).unwrap();
+
+        let component = segments.iter().find_map(|segment| match &segment.segment_type {
+            SegmentType::Class(class) if class.name == "SaveButton" => Some(class),
+            _ => None,
+        }).expect("the SFC becomes a component segment");
+        assert_eq!(component.props, vec!["label".to_string(), "userId".to_string()]);
+        assert!(component.hooks.contains(&"onMounted".to_string()));
+
+        let save = segments.iter()
+            .find(|segment| matches!(&segment.segment_type, SegmentType::Function(function) if function.name == "save"))
+            .expect("script functions are extracted");
+        assert_eq!(save.metadata.line_start, 8);
+        assert_eq!(&source[save.metadata.byte_start..save.metadata.byte_start + 13], "function save");
+        assert_eq!(save.framework_context, Some(Framework::Vue));
+    }
+}
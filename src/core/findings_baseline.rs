@@ -0,0 +1,553 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::conflict_resolution_engine::{Conflict, ConflictSeverity};
+use crate::core::status_inference_engine::MissingImplementation;
+use crate::core::todo_scanner::{TodoPriority, TodoScanResult};
+use crate::core::ComponentInfo;
+use crate::intelligence::IntelligentAnalysis;
+
+/// Format version written to baseline files
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Line references (`file.ts:42`, `:42:7`, `line 42`) dropped from identifiers before fingerprinting
+static LINE_REFERENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i):\d+(:\d+)?\b|\blines? \d+(-\d+)?\b").unwrap()
+});
+
+/// Category of a finding tracked across runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    Todo,
+    Conflict,
+    MissingImplementation,
+    AntiPattern,
+}
+
+impl FindingKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Todo => "todo",
+            Self::Conflict => "conflict",
+            Self::MissingImplementation => "missing_implementation",
+            Self::AntiPattern => "anti_pattern",
+        }
+    }
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FindingKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "todo" | "todos" => Ok(Self::Todo),
+            "conflict" | "conflicts" => Ok(Self::Conflict),
+            "missing_implementation" | "missing_implementations" | "missing" => Ok(Self::MissingImplementation),
+            "anti_pattern" | "anti_patterns" => Ok(Self::AntiPattern),
+            _ => anyhow::bail!("Unknown finding kind '{}'; use todo, conflict, missing_implementation or anti_pattern", s),
+        }
+    }
+}
+
+/// Severity shared by every finding kind, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl FindingSeverity {
+    /// Severity of a missing implementation by what is missing
+    ///
+    /// Confidences are scaled by the analyzer's weight, so they rank findings of one analyzer but
+    /// say little about how serious a finding is.
+    fn for_missing_implementation(implementation_type: &str) -> Self {
+        match implementation_type {
+            t if t.starts_with("Authentication") => Self::High,
+            "API Error Handling" | "Database Relation" | "Database Index" | "Test Suite" => Self::Low,
+            _ => Self::Medium,
+        }
+    }
+}
+
+impl fmt::Display for FindingSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        })
+    }
+}
+
+impl FromStr for FindingSeverity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            _ => anyhow::bail!("Unknown severity '{}'; use low, medium, high or critical", s),
+        }
+    }
+}
+
+impl From<&TodoPriority> for FindingSeverity {
+    fn from(priority: &TodoPriority) -> Self {
+        match priority {
+            TodoPriority::Low => Self::Low,
+            TodoPriority::Medium => Self::Medium,
+            TodoPriority::High => Self::High,
+            TodoPriority::Critical => Self::Critical,
+        }
+    }
+}
+
+impl From<&ConflictSeverity> for FindingSeverity {
+    fn from(severity: &ConflictSeverity) -> Self {
+        match severity {
+            ConflictSeverity::Low => Self::Low,
+            ConflictSeverity::Medium => Self::Medium,
+            ConflictSeverity::High => Self::High,
+            ConflictSeverity::Critical => Self::Critical,
+        }
+    }
+}
+
+/// A TODO, conflict, missing implementation or anti-pattern with a fingerprint that survives
+/// line shifts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    /// Hash of kind, rule, file and a stable identifier (the TODO text, documented claim, expected
+    /// location or component); neither the line number nor the message is part of it
+    pub fingerprint: String,
+    pub kind: FindingKind,
+    /// Same ids as the SARIF rules, e.g. `todo/fixme` or `conflict/claimed-but-not-implemented`
+    pub rule_id: String,
+    pub severity: FindingSeverity,
+    /// Path relative to the project root, when the finding has one
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Finding {
+    /// `file:line`, or the file alone
+    pub fn location(&self) -> Option<String> {
+        let file = self.file.as_ref()?;
+        Some(match self.line {
+            Some(line) => format!("{}:{}", file, line),
+            None => file.clone(),
+        })
+    }
+}
+
+/// Turns scan results into fingerprinted findings
+///
+/// Identical findings in the same file (two `TODO: add tests` comments, say) are told apart by
+/// their order in the file, so each keeps its own fingerprint.
+pub struct FindingsCollector {
+    root: PathBuf,
+    findings: Vec<(Finding, String)>,
+}
+
+impl FindingsCollector {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            findings: Vec::new(),
+        }
+    }
+
+    pub fn with_todos(mut self, scan: &TodoScanResult) -> Self {
+        for todo in &scan.todo_items {
+            let file = self.relative(&todo.file_path);
+            let rule_id = format!("todo/{}", rule_slug(todo.todo_type.as_str()));
+            self.push(
+                FindingKind::Todo,
+                rule_id,
+                (&todo.priority).into(),
+                (Some(file), Some(todo.line_number as usize)),
+                &todo.description,
+                todo.description.clone(),
+            );
+        }
+        self
+    }
+
+    pub fn with_conflicts(mut self, conflicts: &[Conflict]) -> Self {
+        for conflict in conflicts {
+            let evidence = conflict.implementation_evidence.as_ref()
+                .and_then(|evidence| Some((evidence.source_files.first()?, evidence.line_numbers.first().copied())));
+            let claim = conflict.documentation_claim.as_ref()
+                .map(|claim| (&claim.source_file, Some(claim.line_number)));
+            let (file, line) = match evidence.or(claim) {
+                Some((file, line)) => (Some(self.relative(file)), line),
+                None => (None, None),
+            };
+            let identifier = match (&conflict.documentation_claim, &conflict.implementation_evidence) {
+                (Some(claim), _) => claim.description.clone(),
+                (None, Some(evidence)) => format!("{:?}", evidence.reality_type),
+                (None, None) => String::new(),
+            };
+            let rule_id = format!("conflict/{}", rule_slug(&format!("{:?}", conflict.conflict_type)));
+            self.push(FindingKind::Conflict, rule_id, (&conflict.severity).into(), (file, line), &identifier, conflict.description.clone());
+        }
+        self
+    }
+
+    pub fn with_missing_implementations(mut self, missing_implementations: &[MissingImplementation]) -> Self {
+        for missing in missing_implementations {
+            let (file, line) = match parse_file_location(&missing.expected_location) {
+                Some((file, line)) => (Some(self.relative(Path::new(&file))), line),
+                None => (None, None),
+            };
+            let message = match &file {
+                Some(_) => missing.description.clone(),
+                None => format!("{} ({})", missing.description, missing.expected_location),
+            };
+            let rule_id = format!("missing-implementation/{}", rule_slug(&missing.implementation_type));
+            let severity = FindingSeverity::for_missing_implementation(&missing.implementation_type);
+            self.push(FindingKind::MissingImplementation, rule_id, severity, (file, line), &missing.expected_location, message);
+        }
+        self
+    }
+
+    /// Refactoring opportunities found by `IntelligenceEngine`, located at their component's file
+    pub fn with_anti_patterns(mut self, intelligence: &IntelligentAnalysis, components: &[ComponentInfo]) -> Self {
+        let files: HashMap<&str, &str> = components.iter()
+            .map(|component| (component.name.as_str(), component.file_path.as_str()))
+            .collect();
+        for opportunity in &intelligence.refactoring_opportunities {
+            let file = files.get(opportunity.component.as_str()).map(|file| self.relative(Path::new(file)));
+            let severity = match opportunity.issue_type.as_str() {
+                "Incomplete Implementation" => FindingSeverity::Low,
+                _ => FindingSeverity::Medium,
+            };
+            let rule_id = format!("anti-pattern/{}", rule_slug(&opportunity.issue_type));
+            self.push(FindingKind::AntiPattern, rule_id, severity, (file, None), &opportunity.component, format!("{}: {}", opportunity.component, opportunity.description));
+        }
+        self
+    }
+
+    /// Findings in file and line order, each with a unique fingerprint
+    pub fn collect(mut self) -> Vec<Finding> {
+        self.findings.sort_by(|(a, _), (b, _)| (&a.file, a.line, a.kind).cmp(&(&b.file, b.line, b.kind)));
+
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        self.findings.into_iter()
+            .map(|(mut finding, key)| {
+                let occurrence = occurrences.entry(key.clone()).or_insert(0);
+                finding.fingerprint = fingerprint(&format!("{}\u{0}{}", key, occurrence));
+                *occurrence += 1;
+                finding
+            })
+            .collect()
+    }
+
+    /// `identifier` tells findings of one rule and file apart; messages carry counts and scores
+    /// that change between runs, so they are not fingerprinted
+    fn push(&mut self, kind: FindingKind, rule_id: String, severity: FindingSeverity, (file, line): (Option<String>, Option<usize>), identifier: &str, message: String) {
+        let key = [kind.as_str(), &rule_id, file.as_deref().unwrap_or(""), &normalize_identifier(identifier)].join("\u{0}");
+        self.findings.push((
+            Finding { fingerprint: String::new(), kind, rule_id, severity, file, line, message },
+            key,
+        ));
+    }
+
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root).unwrap_or(path).to_string_lossy().replace('\\', "/")
+    }
+}
+
+/// Findings saved from an earlier run, to gate later runs on new findings only
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindingsBaseline {
+    pub version: u32,
+    pub created_at: String,
+    pub findings: Vec<Finding>,
+}
+
+impl FindingsBaseline {
+    pub fn new(findings: Vec<Finding>) -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            findings,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read findings baseline {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse findings baseline {}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            anyhow::bail!("Findings baseline {} has version {}, expected {}", path.display(), baseline.version, BASELINE_VERSION);
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write findings baseline {}", path.display()))
+    }
+
+    /// Split the current findings into added and unchanged, and list the baseline's resolved ones
+    pub fn compare(&self, current: &[Finding]) -> FindingsDiff {
+        let known: HashSet<&str> = self.findings.iter().map(|finding| finding.fingerprint.as_str()).collect();
+        let present: HashSet<&str> = current.iter().map(|finding| finding.fingerprint.as_str()).collect();
+
+        let (unchanged, added) = current.iter().cloned()
+            .partition(|finding| known.contains(finding.fingerprint.as_str()));
+        let resolved = self.findings.iter()
+            .filter(|finding| !present.contains(finding.fingerprint.as_str()))
+            .cloned()
+            .collect();
+        FindingsDiff { added, resolved, unchanged }
+    }
+}
+
+/// Result of comparing a run against a baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindingsDiff {
+    pub added: Vec<Finding>,
+    pub resolved: Vec<Finding>,
+    pub unchanged: Vec<Finding>,
+}
+
+impl FindingsDiff {
+    /// Limits the new findings exceed, as messages; empty when the run passes
+    pub fn threshold_violations(&self, thresholds: &FindingThresholds) -> Vec<String> {
+        let gated: Vec<&Finding> = self.added.iter()
+            .filter(|finding| finding.severity >= thresholds.min_severity)
+            .collect();
+
+        let mut violations = Vec::new();
+        if let Some(limit) = thresholds.max_new_findings
+            && gated.len() > limit
+        {
+            violations.push(format!("{} new findings at {} severity or above (limit {})", gated.len(), thresholds.min_severity, limit));
+        }
+
+        let mut kind_limits: Vec<_> = thresholds.max_new_by_kind.iter().collect();
+        kind_limits.sort();
+        for (kind, limit) in kind_limits {
+            let count = gated.iter().filter(|finding| finding.kind == *kind).count();
+            if count > *limit {
+                violations.push(format!("{} new {} findings at {} severity or above (limit {})", count, kind, thresholds.min_severity, limit));
+            }
+        }
+        violations
+    }
+}
+
+/// How many new findings a run may add before it fails
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindingThresholds {
+    /// Limit on all new findings together; `None` leaves only the per-kind limits
+    pub max_new_findings: Option<usize>,
+    pub max_new_by_kind: HashMap<FindingKind, usize>,
+    /// New findings below this severity are reported but never fail the run
+    pub min_severity: FindingSeverity,
+}
+
+impl Default for FindingThresholds {
+    fn default() -> Self {
+        Self {
+            max_new_findings: Some(0),
+            max_new_by_kind: HashMap::new(),
+            min_severity: FindingSeverity::Low,
+        }
+    }
+}
+
+/// Kebab-case rule id component: `ClaimedButNotImplemented` and `API Endpoint` become
+/// `claimed-but-not-implemented` and `api-endpoint`
+pub(crate) fn rule_slug(text: &str) -> String {
+    let mut slug = String::new();
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+                slug.push('-');
+            }
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        previous = Some(c);
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// `path:line` or a bare path; descriptions such as `POST /users` or `Entity: User` are not files
+pub(crate) fn parse_file_location(text: &str) -> Option<(String, Option<usize>)> {
+    let text = text.trim();
+    if text.is_empty() || text == "." || text.contains(char::is_whitespace) {
+        return None;
+    }
+    match text.rsplit_once(':') {
+        Some((file, line)) if !file.is_empty() && !line.is_empty() && line.chars().all(|c| c.is_ascii_digit()) => {
+            Some((file.to_string(), line.parse().ok()))
+        }
+        _ => Some((text.to_string(), None)),
+    }
+}
+
+fn normalize_identifier(identifier: &str) -> String {
+    LINE_REFERENCE_REGEX.replace_all(identifier, "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn fingerprint(key: &str) -> String {
+    let digest = Sha256::digest(key.as_bytes());
+    digest.iter().take(8).map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support;
+    use crate::core::status_inference_engine::StatusInferenceEngine;
+    use crate::core::todo_scanner::TodoScanner;
+    use tempfile::TempDir;
+
+    fn scan(root: &Path) -> Vec<Finding> {
+        let todos = TodoScanner::new().unwrap().scan_project(root).unwrap();
+        let missing = MissingImplementation {
+            implementation_type: "Error Handling".to_string(),
+            expected_location: "src/orders.ts:12".to_string(),
+            description: "POST /orders at src/orders.ts:12 has no error handling".to_string(),
+            confidence: 0.9,
+            evidence: Vec::new(),
+            suggested_implementation: None,
+        };
+        FindingsCollector::new(root)
+            .with_todos(&todos)
+            .with_missing_implementations(&[missing])
+            .collect()
+    }
+
+    #[test]
+    fn test_baseline_diff_survives_line_shifts_and_gates_on_thresholds() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("src/orders.ts");
+        std::fs::create_dir_all(source.parent().unwrap()).unwrap();
+        std::fs::write(&source, "// TODO: add tests\nfunction a() {}\n// TODO: add tests\n// FIXME: rounding\n").unwrap();
+
+        let baseline_path = temp_dir.path().join("ci/findings-baseline.json");
+        FindingsBaseline::new(scan(temp_dir.path())).save(&baseline_path).unwrap();
+        let baseline = FindingsBaseline::load(&baseline_path).unwrap();
+        assert_eq!(baseline.findings.len(), 4);
+
+        // Shift every line, fix the FIXME and add a TODO
+        std::fs::write(&source, "import x from 'x';\n\n// TODO: add tests\nfunction a() {}\n// TODO: add tests\n// TODO: tidy imports\n").unwrap();
+        let diff = baseline.compare(&scan(temp_dir.path()));
+
+        assert_eq!(diff.unchanged.len(), 3);
+        assert_eq!(diff.resolved.len(), 1);
+        assert_eq!(diff.resolved[0].rule_id, "todo/fixme");
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].line, Some(6));
+
+        assert_eq!(diff.threshold_violations(&FindingThresholds::default()).len(), 1);
+        let lenient = FindingThresholds {
+            max_new_findings: None,
+            max_new_by_kind: HashMap::from([(FindingKind::Todo, 1)]),
+            min_severity: FindingSeverity::Low,
+        };
+        assert!(diff.threshold_violations(&lenient).is_empty());
+        let critical_only = FindingThresholds { min_severity: FindingSeverity::Critical, ..FindingThresholds::default() };
+        assert!(diff.threshold_violations(&critical_only).is_empty());
+    }
+
+    #[test]
+    fn test_fingerprints_ignore_messages_with_counts() {
+        let temp_dir = TempDir::new().unwrap();
+        let crud = |description: &str| MissingImplementation {
+            implementation_type: "CRUD Operations".to_string(),
+            expected_location: "Entity: User".to_string(),
+            description: description.to_string(),
+            confidence: 0.7,
+            evidence: Vec::new(),
+            suggested_implementation: None,
+        };
+        let before = FindingsCollector::new(temp_dir.path())
+            .with_missing_implementations(&[crud("User has 2 of 4 CRUD operations")])
+            .collect();
+        let after = FindingsCollector::new(temp_dir.path())
+            .with_missing_implementations(&[crud("User has 3 of 4 CRUD operations")])
+            .collect();
+        assert_eq!(before[0].fingerprint, after[0].fingerprint);
+
+        let other_entity = FindingsCollector::new(temp_dir.path())
+            .with_missing_implementations(&[MissingImplementation { expected_location: "Entity: Order".to_string(), ..crud("Order has 2 of 4 CRUD operations") }])
+            .collect();
+        assert_ne!(before[0].fingerprint, other_entity[0].fingerprint);
+    }
+
+    #[test]
+    fn test_missing_documented_endpoint_gates_at_medium() {
+        let temp_dir = TempDir::new().unwrap();
+        let controller = temp_dir.path().join("src/main/java/com/x/UserController.java");
+        std::fs::create_dir_all(controller.parent().unwrap()).unwrap();
+        std::fs::write(&controller, r#"package com.x;
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+  @GetMapping("/{id}")
+  public User get(@PathVariable Long id) { return repo.findById(id).orElseThrow(); }
+}
+"#).unwrap();
+        let todos = TodoScanner::new().unwrap().scan_project(temp_dir.path()).unwrap();
+        let documentation = test_support::documentation(vec!["DELETE /users/:id - remove a user".to_string()]);
+
+        // Through the engine, which scales the API analyzer's confidences by its weight
+        let status = StatusInferenceEngine::new().unwrap()
+            .analyze_project_status(temp_dir.path(), &documentation, &todos)
+            .unwrap();
+        let current = FindingsCollector::new(temp_dir.path())
+            .with_missing_implementations(&status.inferred_status.missing_implementations)
+            .collect();
+        let endpoint = current.iter()
+            .find(|finding| finding.rule_id == "missing-implementation/documented-api-endpoint")
+            .expect("documented endpoint finding");
+        assert_eq!(endpoint.severity, FindingSeverity::Medium);
+
+        let diff = FindingsBaseline::new(Vec::new()).compare(&current);
+        let medium = FindingThresholds {
+            max_new_findings: None,
+            max_new_by_kind: HashMap::from([(FindingKind::MissingImplementation, 0)]),
+            min_severity: FindingSeverity::Medium,
+        };
+        assert_eq!(diff.threshold_violations(&medium).len(), 1);
+    }
+}
@@ -0,0 +1,624 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use once_cell::unsync::OnceCell;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::core::config::{ConfigManager, FrameworkConfig, FrameworkIndicator};
+use crate::core::framework_detector::{DetectionEvidence, EvidenceType};
+use crate::core::lockfile_resolver::{framework_packages, InstalledVersion, LockfileResolver};
+use crate::core::project_walker::ProjectWalker;
+use crate::core::types::{Framework, LanguageEcosystem};
+
+/// Share of a rule's indicator weight that must match when the rule sets no `min_confidence`
+pub const DEFAULT_MIN_CONFIDENCE: f32 = 0.35;
+
+/// Files larger than this are skipped by content indicators
+const MAX_SCANNED_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Files loaded for content indicators: sources of every supported language plus common config formats
+const TEXT_EXTENSIONS: [&str; 22] = [
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "py", "java", "kt", "rs", "go",
+    "json", "jsonc", "toml", "yaml", "yml", "xml", "gradle", "kts", "properties", "cfg",
+];
+
+/// Manifests read for `package_dependency` / `cargo_dependency` indicators
+const MANIFESTS: [&str; 10] = [
+    "package.json", "requirements.txt", "pyproject.toml", "Pipfile", "Cargo.toml",
+    "go.mod", "pom.xml", "build.gradle", "build.gradle.kts", "deno.json",
+];
+
+/// File and pattern that satisfied each content indicator of a rule, keyed by indicator index
+type ContentMatches = HashMap<usize, (PathBuf, String)>;
+
+/// A framework matched by a declarative rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleBasedDetection {
+    pub rule_id: String,
+    pub name: String,
+    /// Built-in framework the rule maps to; `None` for custom/internal frameworks
+    pub framework: Option<Framework>,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub installed_version: Option<InstalledVersion>,
+    pub confidence: f32,
+    pub evidence: Vec<DetectionEvidence>,
+    pub ecosystem: LanguageEcosystem,
+}
+
+/// Evaluates `frameworks.json` entries against a codebase.
+///
+/// Supported indicator types:
+/// - `package_dependency` / `cargo_dependency`: dependency declared in a manifest (`package.json`,
+///   `requirements.txt`, `pyproject.toml`, `Pipfile`, `Cargo.toml`, `go.mod`, `pom.xml`, Gradle, `deno.json`)
+/// - `file_extension`: any file with the extension
+/// - `file_structure` / `deno_config` / `config_file`: `dir/` patterns match directories, `.suffix`
+///   patterns match file name endings, anything else matches a file path or name
+/// - any other type (`import_pattern`, `decorator_pattern`, `annotation_pattern`, `route_pattern`, ...):
+///   text searched in source files of the rule's language, or in the indicator's `files` globs
+pub struct FrameworkRuleEngine {
+    codebase_path: PathBuf,
+    rules: Vec<FrameworkConfig>,
+    files: Vec<PathBuf>,
+    directories: HashSet<String>,
+    manifests: OnceCell<HashMap<String, HashMap<String, Option<String>>>>,
+    lockfiles: OnceCell<LockfileResolver>,
+}
+
+impl FrameworkRuleEngine {
+    pub fn new(codebase_path: &Path, rules: Vec<FrameworkConfig>) -> Self {
+        let mut files = Vec::new();
+        let mut directories = HashSet::new();
+
+        // Generated files (lockfiles, build manifests) still count as framework evidence
+        for entry in ProjectWalker::new(codebase_path).include_generated(true).entries() {
+            let Ok(relative) = entry.path.strip_prefix(codebase_path) else {
+                continue;
+            };
+            if entry.is_dir {
+                directories.insert(relative.to_string_lossy().replace('\\', "/"));
+            } else {
+                files.push(relative.to_path_buf());
+            }
+        }
+
+        Self {
+            codebase_path: codebase_path.to_path_buf(),
+            rules,
+            files,
+            directories,
+            manifests: OnceCell::new(),
+            lockfiles: OnceCell::new(),
+        }
+    }
+
+    /// Engine over the rules in a `frameworks.json`-shaped file
+    pub fn from_rules_file(codebase_path: &Path, rules_file: &Path) -> anyhow::Result<Self> {
+        Ok(Self::new(codebase_path, ConfigManager::load_framework_rules(rules_file)?))
+    }
+
+    pub fn rules(&self) -> &[FrameworkConfig] {
+        &self.rules
+    }
+
+    /// Evaluate all rules, returning matches ordered by confidence
+    pub fn detect_all(&self) -> Vec<RuleBasedDetection> {
+        let rules: Vec<&FrameworkConfig> = self.rules.iter().collect();
+        let content_matches = self.scan_contents(&rules);
+        let mut detections: Vec<RuleBasedDetection> = rules.iter()
+            .zip(&content_matches)
+            .filter_map(|(rule, matches)| self.evaluate_with(rule, matches))
+            .collect();
+        detections.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        detections
+    }
+
+    /// Confidence is the matched share of the rule's total indicator weight
+    pub fn evaluate(&self, rule: &FrameworkConfig) -> Option<RuleBasedDetection> {
+        let content_matches = self.scan_contents(&[rule]);
+        self.evaluate_with(rule, &content_matches[0])
+    }
+
+    fn evaluate_with(&self, rule: &FrameworkConfig, content_matches: &ContentMatches) -> Option<RuleBasedDetection> {
+        let total_weight: f32 = rule.indicators.iter().map(|i| i.confidence_weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let mut matched_weight = 0.0;
+        let mut evidence = Vec::new();
+        for (index, indicator) in rule.indicators.iter().enumerate() {
+            match self.match_indicator(indicator, content_matches.get(&index)) {
+                Some((evidence_type, source, pattern)) => {
+                    matched_weight += indicator.confidence_weight;
+                    evidence.push(DetectionEvidence {
+                        evidence_type,
+                        source,
+                        pattern,
+                        confidence_weight: indicator.confidence_weight,
+                    });
+                }
+                None if indicator.required => return None,
+                None => {}
+            }
+        }
+
+        let confidence = (matched_weight / total_weight).min(1.0);
+        if evidence.is_empty() || confidence < rule.min_confidence.unwrap_or(DEFAULT_MIN_CONFIDENCE) {
+            return None;
+        }
+
+        Some(RuleBasedDetection {
+            rule_id: rule.id.clone(),
+            name: rule.name.clone(),
+            framework: rule.framework,
+            version: self.resolve_version(rule),
+            installed_version: self.resolve_installed_version(rule),
+            confidence,
+            evidence,
+            ecosystem: rule_ecosystem(rule),
+        })
+    }
+
+    fn match_indicator(&self, indicator: &FrameworkIndicator, content_match: Option<&(PathBuf, String)>) -> Option<(EvidenceType, String, String)> {
+        match indicator.r#type.as_str() {
+            "package_dependency" | "cargo_dependency" => {
+                let manifests = self.manifests();
+                indicator.patterns.iter().find_map(|pattern| {
+                    manifests.iter()
+                        .filter(|(manifest, _)| indicator.r#type != "cargo_dependency" || manifest.as_str() == "Cargo.toml")
+                        .find(|(_, dependencies)| dependencies.iter().any(|(name, spec)| dependency_matches(pattern, name, spec.as_deref())))
+                        .map(|(manifest, _)| (EvidenceType::ConfigFile, manifest.clone(), format!("{} dependency", pattern)))
+                })
+            }
+            "file_extension" => indicator.patterns.iter().find_map(|pattern| {
+                self.files.iter()
+                    .find(|file| file.to_string_lossy().ends_with(pattern.as_str()))
+                    .map(|file| (EvidenceType::FileStructure, file.to_string_lossy().to_string(), format!("{} files", pattern)))
+            }),
+            "file_structure" | "deno_config" | "config_file" => {
+                let evidence_type = if indicator.r#type == "file_structure" { EvidenceType::FileStructure } else { EvidenceType::ConfigFile };
+                indicator.patterns.iter().find_map(|pattern| {
+                    self.find_structure(pattern).map(|source| (evidence_type.clone(), source, pattern.clone()))
+                })
+            }
+            indicator_type => {
+                let evidence_type = if indicator_type == "import_pattern" { EvidenceType::ImportPattern } else { EvidenceType::ContentPattern };
+                content_match.map(|(file, pattern)| (evidence_type, file.to_string_lossy().to_string(), pattern.clone()))
+            }
+        }
+    }
+
+    /// Content indicator matches for each rule, keyed by indicator index.
+    ///
+    /// One pass over the project reads each file at most once, and only while an indicator that
+    /// applies to it is still unmatched, so file contents are never held beyond the file being checked
+    fn scan_contents(&self, rules: &[&FrameworkConfig]) -> Vec<ContentMatches> {
+        let mut matches = vec![ContentMatches::new(); rules.len()];
+        let mut pending: Vec<(usize, usize)> = rules.iter()
+            .enumerate()
+            .flat_map(|(rule_index, rule)| {
+                rule.indicators.iter()
+                    .enumerate()
+                    .filter(|(_, indicator)| is_content_indicator(&indicator.r#type))
+                    .map(move |(indicator_index, _)| (rule_index, indicator_index))
+            })
+            .collect();
+
+        let text_files = self.files.iter()
+            .filter(|file| file.extension().and_then(|e| e.to_str()).is_some_and(|e| TEXT_EXTENSIONS.contains(&e)));
+        for file in text_files {
+            if pending.is_empty() {
+                break;
+            }
+            let applicable: Vec<(usize, usize)> = pending.iter()
+                .copied()
+                .filter(|&(rule_index, indicator_index)| {
+                    indicator_applies(rules[rule_index], &rules[rule_index].indicators[indicator_index], file)
+                })
+                .collect();
+            if applicable.is_empty() {
+                continue;
+            }
+
+            let path = self.codebase_path.join(file);
+            let within_size = fs::metadata(&path).map(|metadata| metadata.len() <= MAX_SCANNED_FILE_SIZE).unwrap_or(false);
+            let Some(content) = within_size.then(|| fs::read_to_string(&path).ok()).flatten() else {
+                continue;
+            };
+
+            for (rule_index, indicator_index) in applicable {
+                let indicator = &rules[rule_index].indicators[indicator_index];
+                if let Some(pattern) = indicator.patterns.iter().find(|pattern| content.contains(pattern.as_str())) {
+                    matches[rule_index].insert(indicator_index, (file.clone(), pattern.clone()));
+                    pending.retain(|&entry| entry != (rule_index, indicator_index));
+                }
+            }
+        }
+
+        matches
+    }
+
+    fn find_structure(&self, pattern: &str) -> Option<String> {
+        if let Some(dir) = pattern.strip_suffix('/') {
+            let suffix = format!("/{}", dir);
+            return self.directories.iter()
+                .find(|candidate| candidate.as_str() == dir || candidate.ends_with(&suffix))
+                .cloned();
+        }
+
+        self.files.iter()
+            .find(|file| {
+                let path = file.to_string_lossy().replace('\\', "/");
+                let name = file.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                // `.module.ts` matches `user.module.ts`; a dotfile such as `.babelrc` matches itself
+                if pattern.starts_with('.') {
+                    name.ends_with(pattern)
+                } else {
+                    path == pattern || name == pattern
+                }
+            })
+            .map(|file| file.to_string_lossy().to_string())
+    }
+
+    /// Version from the rule's `version_sources`, falling back to its first declared dependency
+    fn resolve_version(&self, rule: &FrameworkConfig) -> Option<String> {
+        let manifests = self.manifests();
+        let lookup = |manifest: Option<&str>, package: &str| -> Option<String> {
+            manifests.iter()
+                .filter(|(name, _)| manifest.is_none_or(|m| m == name.as_str()))
+                .find_map(|(_, dependencies)| dependencies.get(&package.to_lowercase()).cloned().flatten())
+        };
+
+        if let Some(version) = rule.version_sources.iter().find_map(|source| lookup(Some(&source.manifest), &source.package)) {
+            return Some(version);
+        }
+
+        package_patterns(rule).find_map(|package| lookup(None, package))
+    }
+
+    /// Lockfile version of the rule's `version_sources` packages, then its declared dependencies,
+    /// then the packages of its built-in framework
+    fn resolve_installed_version(&self, rule: &FrameworkConfig) -> Option<InstalledVersion> {
+        let lockfiles = self.lockfiles.get_or_init(|| LockfileResolver::new(&self.codebase_path));
+        rule.version_sources.iter().map(|source| source.package.as_str())
+            .chain(package_patterns(rule).map(String::as_str))
+            .chain(rule.framework.map(framework_packages).unwrap_or_default().iter().copied())
+            .find_map(|package| lockfiles.installed_version(package))
+            .cloned()
+    }
+
+    /// Dependencies per manifest file name, keyed by lowercase package name
+    fn manifests(&self) -> &HashMap<String, HashMap<String, Option<String>>> {
+        self.manifests.get_or_init(|| {
+            let mut manifests: HashMap<String, HashMap<String, Option<String>>> = HashMap::new();
+
+            // Shallow manifests first so the root project wins over nested packages
+            let mut manifest_files: Vec<&PathBuf> = self.files.iter()
+                .filter(|file| file.file_name().and_then(|n| n.to_str()).is_some_and(|name| {
+                    MANIFESTS.contains(&name) || (name.starts_with("requirements") && name.ends_with(".txt"))
+                }))
+                .collect();
+            manifest_files.sort_by_key(|file| file.components().count());
+
+            for file in manifest_files {
+                let name = file.file_name().and_then(|n| n.to_str()).unwrap_or("").to_string();
+                let Ok(content) = fs::read_to_string(self.codebase_path.join(file)) else {
+                    continue;
+                };
+                let manifest_key = if name.starts_with("requirements") { "requirements.txt".to_string() } else { name.clone() };
+                let dependencies = manifests.entry(manifest_key).or_default();
+                for (package, version) in parse_manifest(&name, &content) {
+                    dependencies.entry(package.to_lowercase()).or_insert(version);
+                }
+            }
+
+            manifests
+        })
+    }
+}
+
+fn rule_ecosystem(rule: &FrameworkConfig) -> LanguageEcosystem {
+    match rule.ecosystem.as_str() {
+        "JavaScript" if rule.language.eq_ignore_ascii_case("TypeScript") => LanguageEcosystem::TypeScript,
+        "JavaScript" => LanguageEcosystem::JavaScript,
+        "TypeScript" => LanguageEcosystem::TypeScript,
+        "Python" => LanguageEcosystem::Python,
+        "Java" => LanguageEcosystem::Java,
+        "Rust" => LanguageEcosystem::Rust,
+        "Go" => LanguageEcosystem::Go,
+        "Deno" => LanguageEcosystem::Deno,
+        _ => LanguageEcosystem::Mixed,
+    }
+}
+
+/// Indicator types matched against file contents rather than manifests or the file tree
+fn is_content_indicator(indicator_type: &str) -> bool {
+    !matches!(indicator_type, "package_dependency" | "cargo_dependency" | "file_extension" | "file_structure" | "deno_config" | "config_file")
+}
+
+/// Whether a content indicator searches `file`: its `files` globs when given, else the rule's language
+fn indicator_applies(rule: &FrameworkConfig, indicator: &FrameworkIndicator, file: &Path) -> bool {
+    if indicator.files.is_empty() {
+        let extensions = language_extensions(&rule.language);
+        file.extension().and_then(|e| e.to_str()).is_some_and(|e| extensions.contains(&e))
+    } else {
+        indicator.files.iter().any(|glob| glob_matches(glob, file))
+    }
+}
+
+fn language_extensions(language: &str) -> &'static [&'static str] {
+    match language.to_lowercase().as_str() {
+        "typescript" | "javascript" => &["ts", "tsx", "js", "jsx", "mjs", "cjs", "vue"],
+        "python" => &["py"],
+        "java" | "kotlin" => &["java", "kt"],
+        "rust" => &["rs"],
+        "go" => &["go"],
+        _ => &["ts", "tsx", "js", "jsx", "py", "java", "kt", "rs", "go"],
+    }
+}
+
+/// `*.py` matches by extension/suffix, `dir/*.ts` by prefix and suffix, anything else by file name or path
+fn glob_matches(glob: &str, file: &Path) -> bool {
+    let path = file.to_string_lossy().replace('\\', "/");
+    match glob.split_once('*') {
+        Some((prefix, suffix)) => path.starts_with(prefix) && path.ends_with(suffix),
+        None => path == glob || file.file_name().and_then(|n| n.to_str()) == Some(glob),
+    }
+}
+
+/// Exact (case-insensitive) name match, prefix match for patterns ending in `*` (artifact families
+/// such as `spring-boot-starter*`), and URL patterns matched against the dependency specifier
+/// (Deno imports)
+fn dependency_matches(pattern: &str, name: &str, spec: Option<&str>) -> bool {
+    let pattern_lower = pattern.to_lowercase();
+    if pattern.contains("://") {
+        return spec.is_some_and(|spec| spec.contains(pattern)) || name.contains(&pattern_lower);
+    }
+    match pattern_lower.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => name == pattern_lower,
+    }
+}
+
+/// Dependency patterns that name one package, for version lookups; `*` families name none
+fn package_patterns(rule: &FrameworkConfig) -> impl Iterator<Item = &String> {
+    rule.indicators.iter()
+        .filter(|indicator| matches!(indicator.r#type.as_str(), "package_dependency" | "cargo_dependency"))
+        .flat_map(|indicator| indicator.patterns.iter())
+        .filter(|pattern| !pattern.ends_with('*'))
+}
+
+/// `(package, version)` pairs declared in a manifest
+pub(crate) fn parse_manifest(file_name: &str, content: &str) -> Vec<(String, Option<String>)> {
+    match file_name {
+        "package.json" | "deno.json" => parse_json_manifest(content),
+        "pyproject.toml" | "Pipfile" | "Cargo.toml" => parse_toml_manifest(content),
+        "go.mod" => parse_go_mod(content),
+        "pom.xml" => parse_pom(content),
+        "build.gradle" | "build.gradle.kts" => parse_gradle(content),
+        name if name.starts_with("requirements") => content.lines().filter_map(parse_requirement).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse_json_manifest(content: &str) -> Vec<(String, Option<String>)> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies", "imports"].iter()
+        .filter_map(|section| json.get(section).and_then(|deps| deps.as_object()))
+        .flat_map(|deps| deps.iter())
+        .map(|(name, version)| (name.clone(), version.as_str().map(|v| v.to_string())))
+        .collect()
+}
+
+fn parse_toml_manifest(content: &str) -> Vec<(String, Option<String>)> {
+    let Ok(toml) = content.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+    let mut dependencies = Vec::new();
+
+    let tables = [
+        toml.get("dependencies"),
+        toml.get("dev-dependencies"),
+        toml.get("workspace").and_then(|w| w.get("dependencies")),
+        toml.get("packages"),
+        toml.get("dev-packages"),
+        toml.get("tool").and_then(|t| t.get("poetry")).and_then(|p| p.get("dependencies")),
+    ];
+    for table in tables.into_iter().flatten().filter_map(|t| t.as_table()) {
+        for (name, spec) in table {
+            let version = spec.as_str()
+                .or_else(|| spec.get("version").and_then(|v| v.as_str()))
+                .filter(|v| *v != "*")
+                .map(|v| v.to_string());
+            dependencies.push((name.clone(), version));
+        }
+    }
+
+    // PEP 621 `[project] dependencies = ["fastapi>=0.110"]`
+    if let Some(requirements) = toml.get("project").and_then(|p| p.get("dependencies")).and_then(|d| d.as_array()) {
+        dependencies.extend(requirements.iter().filter_map(|r| r.as_str()).filter_map(parse_requirement));
+    }
+
+    dependencies
+}
+
+/// `Flask==2.3.0`, `fastapi>=0.100`, `django[bcrypt]~=4.2 ; python_version > "3.8"`
+fn parse_requirement(line: &str) -> Option<(String, Option<String>)> {
+    let line = line.split('#').next()?.trim();
+    if line.is_empty() || line.starts_with('-') {
+        return None;
+    }
+
+    let name_end = line.find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_' || c == '.')).unwrap_or(line.len());
+    let name = line[..name_end].to_string();
+    let spec = line[name_end..].split(';').next().unwrap_or("").trim();
+    let spec = if spec.starts_with('[') { spec.split_once(']').map(|(_, rest)| rest.trim()).unwrap_or("") } else { spec };
+
+    let version = if let Some(pinned) = spec.strip_prefix("==") {
+        Some(pinned.trim().to_string())
+    } else if spec.is_empty() {
+        None
+    } else {
+        Some(spec.to_string())
+    };
+
+    Some((name, version))
+}
+
+fn parse_go_mod(content: &str) -> Vec<(String, Option<String>)> {
+    let mut dependencies = Vec::new();
+    let mut in_require_block = false;
+
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        if line.starts_with("require (") {
+            in_require_block = true;
+            continue;
+        }
+        if in_require_block && line == ")" {
+            in_require_block = false;
+            continue;
+        }
+
+        let requirement = if in_require_block { Some(line) } else { line.strip_prefix("require ") };
+        let mut parts = requirement.unwrap_or("").split_whitespace();
+        if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+            dependencies.push((module.to_string(), Some(version.to_string())));
+        }
+    }
+
+    dependencies
+}
+
+fn parse_pom(content: &str) -> Vec<(String, Option<String>)> {
+    let block = Regex::new(r"(?s)<(dependency|parent|plugin)>(.*?)</(dependency|parent|plugin)>").unwrap();
+    let artifact = Regex::new(r"<artifactId>\s*([^<\s]+)\s*</artifactId>").unwrap();
+    let version = Regex::new(r"<version>\s*([^<\s]+)\s*</version>").unwrap();
+
+    block.captures_iter(content)
+        .filter_map(|captures| {
+            let body = captures.get(2)?.as_str();
+            let name = artifact.captures(body)?.get(1)?.as_str().to_string();
+            let version = version.captures(body).and_then(|v| v.get(1)).map(|v| v.as_str().to_string());
+            Some((name, version))
+        })
+        .collect()
+}
+
+fn parse_gradle(content: &str) -> Vec<(String, Option<String>)> {
+    let coordinate = Regex::new(r#"["']([\w.\-]+):([\w.\-]+)(?::([\w.\-]+))?["']"#).unwrap();
+    let plugin = Regex::new(r#"id\s*\(?\s*["']([\w.\-]+)["']\s*\)?\s*version\s*["']([\w.\-]+)["']"#).unwrap();
+
+    let mut dependencies: Vec<(String, Option<String>)> = coordinate.captures_iter(content)
+        .map(|c| (c[2].to_string(), c.get(3).map(|v| v.as_str().to_string())))
+        .collect();
+    dependencies.extend(plugin.captures_iter(content).map(|c| (c[1].to_string(), Some(c[2].to_string()))));
+    dependencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{FrameworkIndicator, VersionSource};
+    use tempfile::TempDir;
+
+    fn indicator(indicator_type: &str, patterns: &[&str], weight: f32) -> FrameworkIndicator {
+        FrameworkIndicator {
+            r#type: indicator_type.to_string(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            confidence_weight: weight,
+            required: false,
+            files: Vec::new(),
+        }
+    }
+
+    fn rule(id: &str, language: &str, indicators: Vec<FrameworkIndicator>) -> FrameworkConfig {
+        FrameworkConfig {
+            id: id.to_string(),
+            name: id.to_string(),
+            language: language.to_string(),
+            category: "backend".to_string(),
+            ecosystem: language.to_string(),
+            indicators,
+            deno_specific: None,
+            framework: None,
+            min_confidence: None,
+            version_sources: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_custom_rule_matches_dependency_and_imports() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), r#"{"dependencies": {"@acme/web-kit": "^2.1.0"}}"#).unwrap();
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        fs::write(temp_dir.path().join("src/server.ts"), "import { kit } from '@acme/web-kit';\nkit.serve();").unwrap();
+
+        let mut acme = rule("acme_kit", "TypeScript", vec![
+            indicator("package_dependency", &["@acme/web-kit"], 0.9),
+            indicator("import_pattern", &["from '@acme/web-kit'"], 0.8),
+            indicator("file_structure", &["src/handlers/"], 0.5),
+        ]);
+        acme.version_sources = vec![VersionSource { manifest: "package.json".to_string(), package: "@acme/web-kit".to_string() }];
+
+        let engine = FrameworkRuleEngine::new(temp_dir.path(), vec![acme]);
+        let detections = engine.detect_all();
+
+        assert_eq!(detections.len(), 1);
+        let detection = &detections[0];
+        assert_eq!(detection.rule_id, "acme_kit");
+        assert!(detection.framework.is_none());
+        assert_eq!(detection.version.as_deref(), Some("^2.1.0"));
+        assert!((detection.confidence - 1.7 / 2.2).abs() < 0.001);
+        assert_eq!(detection.evidence.len(), 2);
+    }
+
+    #[test]
+    fn test_required_indicator_and_threshold() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("app.py"), "import os\n").unwrap();
+
+        let mut flask = rule("flask", "Python", vec![
+            indicator("package_dependency", &["Flask"], 0.9),
+            indicator("file_extension", &[".py"], 0.2),
+        ]);
+        flask.indicators[0].required = true;
+
+        let engine = FrameworkRuleEngine::new(temp_dir.path(), vec![flask]);
+        assert!(engine.detect_all().is_empty());
+
+        // Flask extensions are not Flask
+        fs::write(temp_dir.path().join("requirements.txt"), "flask-cors==4.0.0\nflask-sqlalchemy==3.1.1\n").unwrap();
+        let engine = FrameworkRuleEngine::new(temp_dir.path(), engine.rules().to_vec());
+        assert!(engine.detect_all().is_empty());
+
+        fs::write(temp_dir.path().join("requirements.txt"), "flask==3.0.2\n").unwrap();
+        let engine = FrameworkRuleEngine::new(temp_dir.path(), engine.rules().to_vec());
+        let detections = engine.detect_all();
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].version.as_deref(), Some("3.0.2"));
+    }
+
+    #[test]
+    fn test_manifest_parsing() {
+        let pom = "<project><parent><artifactId>spring-boot-starter-parent</artifactId><version>3.2.1</version></parent>\
+                   <dependencies><dependency><artifactId>spring-boot-starter-web</artifactId></dependency></dependencies></project>";
+        let deps = parse_manifest("pom.xml", pom);
+        assert!(deps.contains(&("spring-boot-starter-parent".to_string(), Some("3.2.1".to_string()))));
+        assert!(dependency_matches("spring-boot-starter*", "spring-boot-starter-web", None));
+        assert!(!dependency_matches("spring-boot-starter", "spring-boot-starter-web", None));
+        assert!(!dependency_matches("react", "react-icons", None));
+
+        let cargo = "[dependencies]\naxum = \"0.7\"\ntokio = { version = \"1\", features = [\"full\"] }\n";
+        let deps = parse_manifest("Cargo.toml", cargo);
+        assert!(deps.contains(&("axum".to_string(), Some("0.7".to_string()))));
+        assert!(deps.contains(&("tokio".to_string(), Some("1".to_string()))));
+
+        let go_mod = "module x\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.1\n)\n";
+        assert_eq!(parse_manifest("go.mod", go_mod), vec![("github.com/gin-gonic/gin".to_string(), Some("v1.9.1".to_string()))]);
+    }
+}
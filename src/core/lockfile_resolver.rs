@@ -0,0 +1,456 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::core::types::Framework;
+
+/// Lockfiles read from the project root, in priority order: when two lockfiles pin the same
+/// package the first one listed wins
+const LOCKFILES: [&str; 9] = [
+    "package-lock.json", "npm-shrinkwrap.json", "yarn.lock", "pnpm-lock.yaml",
+    "poetry.lock", "Pipfile.lock", "Cargo.lock", "deno.lock", "gradle.lockfile",
+];
+
+/// Directory holding per-configuration lockfiles of Gradle's older locking layout
+const GRADLE_LOCKS_DIR: &str = "gradle/dependency-locks";
+
+/// The exact version of a package pinned by a lockfile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledVersion {
+    pub version: String,
+    /// Lockfile the version was read from, relative to the project root
+    pub lockfile: String,
+}
+
+/// Resolves the versions that actually ship from the lockfiles of a project.
+///
+/// Manifests only declare ranges (`^18.2.0`, `>=2.0`); the lockfile records what the package
+/// manager installed. Packages are keyed by lowercase name; Gradle entries are reachable by
+/// `group:artifact`, by artifact and by group, deno.lock entries by their bare JSR/npm name.
+#[derive(Debug, Default)]
+pub struct LockfileResolver {
+    versions: HashMap<String, InstalledVersion>,
+}
+
+impl LockfileResolver {
+    pub fn new(project_root: &Path) -> Self {
+        let mut resolver = Self::default();
+
+        for lockfile in LOCKFILES {
+            if let Ok(content) = fs::read_to_string(project_root.join(lockfile)) {
+                resolver.insert_all(lockfile, parse_lockfile(lockfile, &content));
+            }
+        }
+
+        if let Ok(entries) = fs::read_dir(project_root.join(GRADLE_LOCKS_DIR)) {
+            let mut lockfiles: Vec<_> = entries.filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "lockfile"))
+                .collect();
+            lockfiles.sort();
+            for path in lockfiles {
+                if let Ok(content) = fs::read_to_string(&path) {
+                    let name = format!("{}/{}", GRADLE_LOCKS_DIR, path.file_name().unwrap_or_default().to_string_lossy());
+                    resolver.insert_all(&name, parse_gradle_lockfile(&content));
+                }
+            }
+        }
+
+        resolver
+    }
+
+    /// Add the versions pinned at `root` for packages this resolver has no entry for. Workspace
+    /// members usually share the lockfile at the workspace root
+    pub fn with_fallback(mut self, root: &Path) -> Self {
+        for (package, installed) in Self::new(root).versions {
+            self.versions.entry(package).or_insert(installed);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// Installed version of a package, by the name its ecosystem uses
+    pub fn installed_version(&self, package: &str) -> Option<&InstalledVersion> {
+        self.versions.get(&package.to_lowercase())
+    }
+
+    /// Installed version of the first of `packages` found in a lockfile
+    pub fn installed_version_of_any<'a, I>(&self, packages: I) -> Option<&InstalledVersion>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        packages.into_iter().find_map(|package| self.installed_version(package))
+    }
+
+    /// Installed version of the package that ships a built-in framework
+    pub fn framework_version(&self, framework: Framework) -> Option<&InstalledVersion> {
+        self.installed_version_of_any(framework_packages(framework).iter().copied())
+    }
+
+    fn insert_all(&mut self, lockfile: &str, packages: Vec<(String, String)>) {
+        for (package, version) in packages {
+            self.versions.entry(package.to_lowercase()).or_insert_with(|| InstalledVersion {
+                version,
+                lockfile: lockfile.to_string(),
+            });
+        }
+    }
+}
+
+/// Packages whose installed version is reported as the framework version
+pub fn framework_packages(framework: Framework) -> &'static [&'static str] {
+    match framework {
+        Framework::React => &["react"],
+        Framework::NextJS => &["next"],
+        Framework::NestJS => &["@nestjs/core"],
+        Framework::Express => &["express"],
+        Framework::Vue => &["vue"],
+        Framework::Angular => &["@angular/core"],
+        Framework::Flask => &["flask"],
+        Framework::FastAPI => &["fastapi"],
+        Framework::Django => &["django"],
+        Framework::SpringBoot => &["org.springframework.boot:spring-boot", "org.springframework.boot"],
+        Framework::Quarkus => &["io.quarkus:quarkus-core", "io.quarkus"],
+        Framework::Danet => &["@danet/core", "danet"],
+        Framework::Axum => &["axum"],
+        Framework::Warp => &["warp"],
+        Framework::Actix => &["actix-web"],
+        Framework::Gin => &["github.com/gin-gonic/gin"],
+        Framework::Fiber => &["github.com/gofiber/fiber/v2", "github.com/gofiber/fiber"],
+        Framework::Unknown => &[],
+    }
+}
+
+/// `(package, version)` pairs pinned by a lockfile, identified by its file name
+pub fn parse_lockfile(file_name: &str, content: &str) -> Vec<(String, String)> {
+    match file_name {
+        "package-lock.json" | "npm-shrinkwrap.json" => parse_package_lock(content),
+        "yarn.lock" => parse_yarn_lock(content),
+        "pnpm-lock.yaml" => parse_pnpm_lock(content),
+        "poetry.lock" | "Cargo.lock" => parse_toml_packages(content),
+        "Pipfile.lock" => parse_pipfile_lock(content),
+        "deno.lock" => parse_deno_lock(content),
+        name if name.ends_with(".lockfile") => parse_gradle_lockfile(content),
+        _ => Vec::new(),
+    }
+}
+
+/// npm lockfile v2/v3 `packages["node_modules/<name>"]`, falling back to v1 `dependencies`
+fn parse_package_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    let mut packages = Vec::new();
+
+    if let Some(entries) = lock.get("packages").and_then(|p| p.as_object()) {
+        for (path, entry) in entries {
+            // Only top-level installs; nested `node_modules/a/node_modules/b` copies serve a single dependent
+            let Some(name) = path.strip_prefix("node_modules/").filter(|name| !name.contains("/node_modules/")) else {
+                continue;
+            };
+            if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+                packages.push((name.to_string(), version.to_string()));
+            }
+        }
+    }
+
+    if let Some(entries) = lock.get("dependencies").and_then(|d| d.as_object()) {
+        for (name, entry) in entries {
+            if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+                packages.push((name.clone(), version.to_string()));
+            }
+        }
+    }
+
+    packages
+}
+
+/// Classic (`version "1.2.3"`) and Berry (`version: 1.2.3`) yarn lockfiles
+fn parse_yarn_lock(content: &str) -> Vec<(String, String)> {
+    let mut packages = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            // `react@^18.2.0, react@^18.0.0:` or `"@babel/core@npm:^7.0.0":`
+            current = line.trim_end_matches(':')
+                .split(", ")
+                .filter_map(|descriptor| yarn_descriptor_name(descriptor.trim_matches('"')))
+                .collect();
+            current.dedup();
+            continue;
+        }
+
+        // Exactly two spaces: deeper lines list the entry's own dependencies
+        let Some(version) = line.strip_prefix("  version").filter(|rest| rest.starts_with([' ', ':'])) else {
+            continue;
+        };
+        let version = version.trim_start_matches(':').trim().trim_matches('"');
+        for name in current.drain(..) {
+            packages.push((name, version.to_string()));
+        }
+    }
+
+    packages
+}
+
+/// Package name of a yarn descriptor: `@scope/name@npm:^1.0.0` -> `@scope/name`
+fn yarn_descriptor_name(descriptor: &str) -> Option<String> {
+    let at = descriptor.get(1..)?.find('@')? + 1;
+    Some(descriptor[..at].to_string())
+}
+
+/// pnpm lockfiles v5 to v9: root importer versions first, then every entry under `packages`
+fn parse_pnpm_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = serde_yaml::from_str::<serde_yaml::Value>(content) else {
+        return Vec::new();
+    };
+    let mut packages = Vec::new();
+
+    // v6+ keeps direct dependencies under `importers`, v5 single-project lockfiles at the top level
+    let root = lock.get("importers").and_then(|importers| importers.get(".")).unwrap_or(&lock);
+    for section in ["dependencies", "devDependencies", "optionalDependencies"] {
+        let Some(dependencies) = root.get(section).and_then(|d| d.as_mapping()) else {
+            continue;
+        };
+        for (name, entry) in dependencies {
+            let version = entry.get("version").and_then(|v| v.as_str()).or_else(|| entry.as_str());
+            if let (Some(name), Some(version)) = (name.as_str(), version) {
+                packages.push((name.to_string(), strip_peer_suffix(version).to_string()));
+            }
+        }
+    }
+
+    // v9 `react@18.2.0`, v6 `/react@18.2.0(react-dom@18.2.0)`, v5 `/react/18.2.0_react-dom@18.2.0`
+    let package_key = Regex::new(r"^/?((?:@[^/@]+/)?[^/@]+)[/@](\d[^_(]*)").expect("valid pnpm package key regex");
+    if let Some(entries) = lock.get("packages").and_then(|p| p.as_mapping()) {
+        for key in entries.keys().filter_map(|key| key.as_str()) {
+            if let Some(captures) = package_key.captures(key) {
+                packages.push((captures[1].to_string(), captures[2].to_string()));
+            }
+        }
+    }
+
+    packages
+}
+
+/// `18.2.0(react-dom@18.2.0)` or `18.2.0_react-dom@18.2.0` -> `18.2.0`
+fn strip_peer_suffix(version: &str) -> &str {
+    version.split(['(', '_']).next().unwrap_or(version)
+}
+
+/// `[[package]]` tables with `name` and `version`, shared by poetry.lock and Cargo.lock
+fn parse_toml_packages(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = content.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+
+    lock.get("package")
+        .and_then(|p| p.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|package| {
+            let name = package.get("name")?.as_str()?;
+            let version = package.get("version")?.as_str()?;
+            Some((name.to_string(), version.to_string()))
+        })
+        .collect()
+}
+
+/// `default` and `develop` sections, pinned as `"version": "==2.3.0"`
+fn parse_pipfile_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    ["default", "develop"].iter()
+        .filter_map(|section| lock.get(section).and_then(|s| s.as_object()))
+        .flatten()
+        .filter_map(|(name, entry)| {
+            let version = entry.get("version")?.as_str()?;
+            Some((name.clone(), version.trim_start_matches("==").to_string()))
+        })
+        .collect()
+}
+
+/// deno.lock v3 (`packages.specifiers`) and v4 (top-level `specifiers`, `jsr`, `npm`), plus
+/// versioned `remote` URLs such as `https://deno.land/x/danet@2.3.0/mod.ts`
+fn parse_deno_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    let mut packages = Vec::new();
+    let sections = lock.get("packages").unwrap_or(&lock);
+
+    if let Some(specifiers) = sections.get("specifiers").and_then(|s| s.as_object()) {
+        for (specifier, resolved) in specifiers {
+            let Some(resolved) = resolved.as_str() else {
+                continue;
+            };
+            // v3 resolves to `jsr:@std/path@0.220.1`; v4 to the bare `0.220.1`
+            let resolved = if resolved.contains(':') {
+                resolved.to_string()
+            } else {
+                format!("{}@{}", specifier_name(specifier).unwrap_or_default(), resolved)
+            };
+            if let Some((name, version)) = split_name_version(strip_registry(&resolved)) {
+                packages.push((name, version));
+            }
+        }
+    }
+
+    for registry in ["jsr", "npm"] {
+        if let Some(entries) = sections.get(registry).and_then(|e| e.as_object()) {
+            packages.extend(entries.keys().filter_map(|key| split_name_version(key)));
+        }
+    }
+
+    if let Some(remote) = lock.get("remote").and_then(|r| r.as_object()) {
+        for url in remote.keys() {
+            if let Some(module) = url.strip_prefix("https://deno.land/x/") {
+                let module = module.split('/').next().unwrap_or(module);
+                if let Some((name, version)) = split_name_version(module) {
+                    packages.push((name, version.trim_start_matches('v').to_string()));
+                }
+            }
+        }
+    }
+
+    packages
+}
+
+fn strip_registry(specifier: &str) -> &str {
+    specifier.strip_prefix("jsr:").or_else(|| specifier.strip_prefix("npm:")).unwrap_or(specifier)
+}
+
+/// `jsr:@danet/core@^2.0.0` -> `@danet/core`
+fn specifier_name(specifier: &str) -> Option<String> {
+    split_name_version(strip_registry(specifier)).map(|(name, _)| name)
+}
+
+/// `@scope/name@1.2.3` -> (`@scope/name`, `1.2.3`), dropping npm peer suffixes
+fn split_name_version(value: &str) -> Option<(String, String)> {
+    let at = value.get(1..)?.find('@')? + 1;
+    let version = strip_peer_suffix(&value[at + 1..]);
+    (!version.is_empty()).then(|| (value[..at].to_string(), version.to_string()))
+}
+
+/// `group:artifact:version=configurations` lines; `empty=` and comments are skipped
+fn parse_gradle_lockfile(content: &str) -> Vec<(String, String)> {
+    let mut packages = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('#') || line.starts_with("empty=") {
+            continue;
+        }
+        let coordinates = line.split('=').next().unwrap_or(line);
+        let mut parts = coordinates.split(':');
+        let (Some(group), Some(artifact), Some(version)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let version = version.to_string();
+        packages.push((format!("{}:{}", group, artifact), version.clone()));
+        packages.push((artifact.to_string(), version.clone()));
+        packages.push((group.to_string(), version));
+    }
+
+    packages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_javascript_lockfiles() {
+        let package_lock = r#"{
+            "lockfileVersion": 3,
+            "packages": {
+                "": {"dependencies": {"react": "^18.2.0"}},
+                "node_modules/react": {"version": "18.2.0"},
+                "node_modules/@nestjs/core": {"version": "10.3.1"},
+                "node_modules/foo/node_modules/react": {"version": "16.14.0"}
+            }
+        }"#;
+        let packages = parse_lockfile("package-lock.json", package_lock);
+        assert!(packages.contains(&("react".to_string(), "18.2.0".to_string())));
+        assert!(packages.contains(&("@nestjs/core".to_string(), "10.3.1".to_string())));
+        assert!(!packages.iter().any(|(_, version)| version == "16.14.0"));
+
+        let yarn_classic = "# yarn lockfile v1\n\nreact@^18.0.0, react@^18.2.0:\n  version \"18.2.0\"\n  resolved \"https://registry.yarnpkg.com/react/-/react-18.2.0.tgz\"\n\n\"@angular/core@^17.0.0\":\n  version \"17.1.2\"\n";
+        let packages = parse_lockfile("yarn.lock", yarn_classic);
+        assert_eq!(packages, vec![
+            ("react".to_string(), "18.2.0".to_string()),
+            ("@angular/core".to_string(), "17.1.2".to_string()),
+        ]);
+
+        let yarn_berry = "__metadata:\n  version: 6\n\n\"vue@npm:^3.3.0\":\n  version: 3.4.15\n  resolution: \"vue@npm:3.4.15\"\n";
+        assert_eq!(parse_lockfile("yarn.lock", yarn_berry), vec![("vue".to_string(), "3.4.15".to_string())]);
+
+        let pnpm = "lockfileVersion: '9.0'\nimporters:\n  .:\n    dependencies:\n      next:\n        specifier: ^14.0.0\n        version: 14.1.0(react@18.2.0)\npackages:\n  react@18.2.0:\n    resolution: {integrity: sha512-x}\n  '@vue/shared@3.4.15':\n    resolution: {integrity: sha512-y}\n";
+        let packages = parse_lockfile("pnpm-lock.yaml", pnpm);
+        assert!(packages.contains(&("next".to_string(), "14.1.0".to_string())));
+        assert!(packages.contains(&("react".to_string(), "18.2.0".to_string())));
+        assert!(packages.contains(&("@vue/shared".to_string(), "3.4.15".to_string())));
+    }
+
+    #[test]
+    fn test_python_rust_deno_and_gradle_lockfiles() {
+        let poetry = "[[package]]\nname = \"fastapi\"\nversion = \"0.109.0\"\n\n[[package]]\nname = \"pydantic\"\nversion = \"2.5.3\"\n";
+        assert!(parse_lockfile("poetry.lock", poetry).contains(&("fastapi".to_string(), "0.109.0".to_string())));
+
+        let pipfile = r#"{"default": {"flask": {"version": "==3.0.1"}}, "develop": {"pytest": {"version": "==7.4.4"}}}"#;
+        let packages = parse_lockfile("Pipfile.lock", pipfile);
+        assert!(packages.contains(&("flask".to_string(), "3.0.1".to_string())));
+        assert!(packages.contains(&("pytest".to_string(), "7.4.4".to_string())));
+
+        let cargo = "version = 3\n\n[[package]]\nname = \"axum\"\nversion = \"0.7.4\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n";
+        assert_eq!(parse_lockfile("Cargo.lock", cargo), vec![("axum".to_string(), "0.7.4".to_string())]);
+
+        let deno_v3 = r#"{"version": "3", "packages": {"specifiers": {"jsr:@danet/core@^2.0.0": "jsr:@danet/core@2.3.0"}}, "remote": {"https://deno.land/x/oak@v12.6.1/mod.ts": "abc"}}"#;
+        let packages = parse_lockfile("deno.lock", deno_v3);
+        assert!(packages.contains(&("@danet/core".to_string(), "2.3.0".to_string())));
+        assert!(packages.contains(&("oak".to_string(), "12.6.1".to_string())));
+
+        let deno_v4 = r#"{"version": "4", "specifiers": {"jsr:@danet/core@2": "2.4.1", "npm:express@4": "4.18.2"}}"#;
+        let packages = parse_lockfile("deno.lock", deno_v4);
+        assert!(packages.contains(&("@danet/core".to_string(), "2.4.1".to_string())));
+        assert!(packages.contains(&("express".to_string(), "4.18.2".to_string())));
+
+        let gradle = "# This is a Gradle generated file\norg.springframework.boot:spring-boot:3.2.2=compileClasspath,runtimeClasspath\nempty=annotationProcessor\n";
+        let packages = parse_lockfile("gradle.lockfile", gradle);
+        assert!(packages.contains(&("org.springframework.boot:spring-boot".to_string(), "3.2.2".to_string())));
+        assert!(packages.contains(&("spring-boot".to_string(), "3.2.2".to_string())));
+    }
+
+    #[test]
+    fn test_resolver_prefers_higher_priority_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package-lock.json"), r#"{"packages": {"node_modules/react": {"version": "18.2.0"}}}"#).unwrap();
+        fs::write(temp_dir.path().join("yarn.lock"), "react@^18.0.0:\n  version \"18.0.0\"\n").unwrap();
+        fs::create_dir_all(temp_dir.path().join(GRADLE_LOCKS_DIR)).unwrap();
+        fs::write(temp_dir.path().join(GRADLE_LOCKS_DIR).join("compileClasspath.lockfile"),
+            "org.springframework.boot:spring-boot:3.1.5\n").unwrap();
+
+        let resolver = LockfileResolver::new(temp_dir.path());
+
+        let react = resolver.framework_version(Framework::React).unwrap();
+        assert_eq!(react.version, "18.2.0");
+        assert_eq!(react.lockfile, "package-lock.json");
+
+        let spring = resolver.framework_version(Framework::SpringBoot).unwrap();
+        assert_eq!(spring.version, "3.1.5");
+        assert_eq!(spring.lockfile, "gradle/dependency-locks/compileClasspath.lockfile");
+
+        assert!(resolver.framework_version(Framework::Flask).is_none());
+    }
+}
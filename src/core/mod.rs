@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 pub mod framework_detector;
+pub mod framework_rules;
+pub mod lockfile_resolver;
+pub mod workspace_detector;
+pub mod workspace_analyzer;
+pub mod project_walker;
 pub mod enhanced_framework_detector;
 pub mod business_domain_engine;
 pub mod integration_demo;
@@ -31,6 +36,7 @@ pub mod conflict_resolution_engine;
 pub mod dual_category_status_analyzer;
 pub mod todo_scanner;
 pub mod status_inference_engine;
+pub mod findings_baseline;
 pub mod analyzers;
 pub mod cache_manager;
 pub mod performance_monitor;
@@ -55,9 +61,12 @@ pub struct CodebaseAnalysis {
     pub implementation_analysis: ImplementationAnalysis,
     pub status_intelligence: StatusIntelligence,
     pub integration_points: IntegrationPoints,
+    /// Per-package breakdown when the analyzed path is a monorepo/workspace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<workspace_analyzer::WorkspaceBreakdown>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ProjectType {
     React,
     NextJS,
@@ -159,6 +168,9 @@ pub struct ProductRequirementDocument {
     pub features: Vec<FeatureDescription>,
     pub technical_requirements: Vec<String>,
     pub business_context: String,
+    /// Flags checked in the code, with the features only reachable through them
+    #[serde(default)]
+    pub feature_flags: Vec<status_inference_engine::FeatureFlag>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -246,6 +258,9 @@ pub struct FrameworkAnalysis {
 pub struct DetectedFramework {
     pub name: String,
     pub version: Option<String>,
+    /// Exact version pinned by the project's lockfile, next to the declared `version` range
+    #[serde(default)]
+    pub installed_version: Option<String>,
     pub confidence: f32,
     pub evidence: Vec<String>,
     pub usage_extent: UsageExtent,
@@ -7,6 +7,7 @@ use std::fs;
 
 use crate::core::config::get_config;
 use crate::core::project_classifier::{ProjectClassifier, ProjectType};
+use crate::core::workspace_detector::{WorkspaceDetector, WorkspaceLayout};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectContext {
@@ -18,6 +19,9 @@ pub struct ProjectContext {
     pub business_domain_hints: Vec<String>,
     pub metadata: ProjectMetadata,
     pub documentation_analysis: DocumentationAnalysis,
+    /// Packages and their dependency edges when the project is a monorepo
+    #[serde(default)]
+    pub workspace: Option<WorkspaceLayout>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -691,6 +695,7 @@ impl ProjectAnalyzer {
             business_domain_hints,
             metadata,
             documentation_analysis,
+            workspace: WorkspaceDetector::new(project_path).detect().filter(|layout| layout.is_monorepo()),
         })
     }
 
@@ -0,0 +1,263 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
+
+/// Project-specific ignore file, read in every directory like `.gitignore`
+pub const ANALYZER_IGNORE_FILE: &str = ".analyzerignore";
+
+/// Dependency, VCS and tool-cache directories that never contain first-party code, ignored or not
+pub const SKIPPED_DIRS: &[&str] = &[
+    "node_modules", "bower_components", "jspm_packages", ".pnpm-store", ".git", ".hg", ".svn",
+    "Pods", "venv", ".venv", "virtualenv", "__pycache__", ".tox", ".mypy_cache", ".pytest_cache",
+    ".gradle", ".next", ".nuxt", ".svelte-kit", ".specstory",
+];
+
+/// Conventional build-output and vendored-code directory names
+///
+/// Some projects keep first-party sources under these names (a Go `build` package, patched code
+/// in `vendor`), so by default they are only skipped when the project has no root ignore file
+/// describing its own build output. `ProjectWalker::skip_build_output` overrides that.
+pub const BUILD_OUTPUT_DIRS: &[&str] = &["target", "dist", "build", "out", "vendor", "third_party", "coverage"];
+
+/// File name suffixes of minified bundles and compiler output
+const GENERATED_SUFFIXES: &[&str] = &[
+    ".min.js", ".min.css", ".bundle.js", ".map", "_pb2.py", "_pb2_grpc.py", ".pb.go", ".g.dart",
+    ".designer.cs",
+];
+
+/// Header markers of generated files, matched case-insensitively in the first lines
+const GENERATED_MARKERS: &[&str] = &[
+    "@generated", "do not edit", "auto-generated", "autogenerated", "automatically generated",
+];
+const GENERATED_HEADER_LINES: usize = 5;
+const GENERATED_HEADER_BYTES: usize = 1024;
+
+/// A file or directory that survived the project's ignore rules
+#[derive(Debug, Clone)]
+pub struct ProjectEntry {
+    pub path: PathBuf,
+    /// 0 for the walk root
+    pub depth: usize,
+    pub is_dir: bool,
+}
+
+/// The one way the analyzer walks a project
+///
+/// Honors `.gitignore`, `.ignore`, `.git/info/exclude` and `.analyzerignore` (with the usual
+/// precedence, so `!pattern` in `.analyzerignore` re-includes a file), paths marked
+/// `linguist-generated` or `linguist-vendored` in `.gitattributes`, the dependency directories in
+/// `SKIPPED_DIRS`, the build-output directories in `BUILD_OUTPUT_DIRS` when the project has no
+/// ignore file of its own, and files whose header says they are generated. The user's global gitignore is
+/// not read, so results do not depend on the machine running the analysis.
+#[derive(Debug, Clone)]
+pub struct ProjectWalker {
+    root: PathBuf,
+    max_depth: Option<usize>,
+    include_generated: bool,
+    /// `None` skips `BUILD_OUTPUT_DIRS` only when the root has no ignore file
+    skip_build_output: Option<bool>,
+}
+
+impl ProjectWalker {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            max_depth: None,
+            include_generated: false,
+            skip_build_output: None,
+        }
+    }
+
+    /// Descend at most `depth` levels below the root
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Keep generated files (but still skip ignored and vendored paths)
+    pub fn include_generated(mut self, include: bool) -> Self {
+        self.include_generated = include;
+        self
+    }
+
+    /// Always (or never) skip the directories in `BUILD_OUTPUT_DIRS`, whatever the ignore files say
+    pub fn skip_build_output(mut self, skip: bool) -> Self {
+        self.skip_build_output = Some(skip);
+        self
+    }
+
+    /// Files and directories, root included, in file-name order
+    pub fn entries(&self) -> impl Iterator<Item = ProjectEntry> + use<> {
+        let attributes = gitattribute_exclusions(&self.root);
+        let include_generated = self.include_generated;
+        let skip_build_output = self.skip_build_output.unwrap_or_else(|| !has_ignore_file(&self.root));
+
+        let mut builder = WalkBuilder::new(&self.root);
+        builder
+            .hidden(false)
+            .git_global(false)
+            .require_git(false)
+            .follow_links(false)
+            .max_depth(self.max_depth)
+            .add_custom_ignore_filename(ANALYZER_IGNORE_FILE)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(move |entry| {
+                let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+                if entry.depth() == 0 {
+                    return true;
+                }
+                if is_dir && let Some(name) = entry.file_name().to_str()
+                    && (SKIPPED_DIRS.contains(&name) || (skip_build_output && BUILD_OUTPUT_DIRS.contains(&name)))
+                {
+                    return false;
+                }
+                !attributes.matched_path_or_any_parents(entry.path(), is_dir).is_ignore()
+            });
+
+        builder.build()
+            .filter_map(|entry| entry.ok())
+            .filter_map(move |entry| {
+                let is_dir = entry.file_type()?.is_dir();
+                if !is_dir && !entry.file_type()?.is_file() {
+                    return None;
+                }
+                if !is_dir && !include_generated && is_generated_file(entry.path()) {
+                    return None;
+                }
+                Some(ProjectEntry {
+                    depth: entry.depth(),
+                    is_dir,
+                    path: entry.into_path(),
+                })
+            })
+    }
+
+    /// Files only
+    pub fn files(&self) -> impl Iterator<Item = PathBuf> + use<> {
+        self.entries().filter(|entry| !entry.is_dir).map(|entry| entry.path)
+    }
+}
+
+/// Whether the project root has a `.gitignore`, `.ignore` or `.analyzerignore`
+fn has_ignore_file(root: &Path) -> bool {
+    [".gitignore", ".ignore", ANALYZER_IGNORE_FILE].iter().any(|name| root.join(name).is_file())
+}
+
+/// Whether a file is minified or compiler output, by name or by a marker in its header
+pub fn is_generated_file(path: &Path) -> bool {
+    let name = path.file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+    if GENERATED_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+        return true;
+    }
+
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+    let mut head = Vec::with_capacity(GENERATED_HEADER_BYTES);
+    if file.take(GENERATED_HEADER_BYTES as u64).read_to_end(&mut head).is_err() {
+        return false;
+    }
+    String::from_utf8_lossy(&head)
+        .lines()
+        .take(GENERATED_HEADER_LINES)
+        .any(|line| {
+            let line = line.to_lowercase();
+            GENERATED_MARKERS.iter().any(|marker| line.contains(marker))
+        })
+}
+
+/// Patterns from the root `.gitattributes` carrying `linguist-generated` or `linguist-vendored`
+fn gitattribute_exclusions(root: &Path) -> Gitignore {
+    let mut builder = GitignoreBuilder::new(root);
+    if let Ok(content) = std::fs::read_to_string(root.join(".gitattributes")) {
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            let Some(pattern) = parts.next().filter(|pattern| !pattern.starts_with('#')) else {
+                continue;
+            };
+            let excluded = parts.any(|attribute| {
+                matches!(
+                    attribute,
+                    "linguist-generated" | "linguist-generated=true" | "linguist-vendored" | "linguist-vendored=true"
+                )
+            });
+            if excluded {
+                let _ = builder.add_line(None, pattern);
+            }
+        }
+    }
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_walker_honors_ignore_files_attributes_and_generated_markers() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |relative: &str, content: &str| {
+            let path = temp_dir.path().join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(".gitignore", "/release/\n*.log\n");
+        write(".ignore", "scratch.py\n");
+        write(".analyzerignore", "sdk/\n!keep.log\n");
+        write(".gitattributes", "schema/*.ts linguist-generated\nsrc/*.ts text eol=lf\n");
+        write("src/app.ts", "export const app = 1;\n");
+        write("src/api.ts", "// Code generated by openapi-generator. DO NOT EDIT.\nexport const api = 1;\n");
+        write("src/app.min.js", "var a=1;\n");
+        write("schema/types.ts", "export type Id = string;\n");
+        write("release/app.js", "var built = 1;\n");
+        write("sdk/client.py", "def call(): pass\n");
+        write("node_modules/lib/index.js", "module.exports = 1;\n");
+        write("debug.log", "noise\n");
+        write("keep.log", "kept\n");
+        write("scratch.py", "print(1)\n");
+
+        let relative = |walker: &ProjectWalker| -> Vec<String> {
+            walker.files()
+                .map(|path| path.strip_prefix(temp_dir.path()).unwrap().to_string_lossy().replace('\\', "/"))
+                .collect()
+        };
+
+        assert_eq!(
+            relative(&ProjectWalker::new(temp_dir.path())),
+            vec![".analyzerignore", ".gitattributes", ".gitignore", ".ignore", "keep.log", "src/app.ts"]
+        );
+        assert!(relative(&ProjectWalker::new(temp_dir.path()).include_generated(true)).contains(&"src/api.ts".to_string()));
+        assert_eq!(relative(&ProjectWalker::new(temp_dir.path()).max_depth(1)).len(), 5);
+    }
+
+    #[test]
+    fn test_build_output_dirs_skipped_only_without_ignore_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |relative: &str| {
+            let path = temp_dir.path().join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "package build\n").unwrap();
+        };
+        write("main.go");
+        write("build/version.go");
+        write("coverage/lcov.info");
+        write("node_modules/lib/index.js");
+
+        let relative = |walker: ProjectWalker| -> Vec<String> {
+            walker.files()
+                .map(|path| path.strip_prefix(temp_dir.path()).unwrap().to_string_lossy().replace('\\', "/"))
+                .collect()
+        };
+
+        assert_eq!(relative(ProjectWalker::new(temp_dir.path())), vec!["main.go"]);
+
+        fs::write(temp_dir.path().join(".gitignore"), "coverage/\n").unwrap();
+        assert_eq!(relative(ProjectWalker::new(temp_dir.path())), vec![".gitignore", "build/version.go", "main.go"]);
+        assert_eq!(relative(ProjectWalker::new(temp_dir.path()).skip_build_output(true)), vec![".gitignore", "main.go"]);
+    }
+}
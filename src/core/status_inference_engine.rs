@@ -1,19 +1,22 @@
 use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::core::config::get_config;
+use crate::core::config::ANALYZER_CONFIG_FILE;
 use crate::core::documentation_extractor::ExtractedDocumentationInfo;
 use crate::core::todo_scanner::TodoScanResult;
-use crate::core::analyzers::CrudAnalyzer;
+use crate::core::analyzers::file_index::ProjectFileIndex;
+use crate::core::analyzers::registry::{global_registry, StatusAnalyzerRegistry};
 
 /// Core status inference engine that combines actual TODO analysis with intelligent inference
 pub struct StatusInferenceEngine {
     config: StatusInferenceConfig,
-    analyzers: Vec<Box<dyn StatusAnalyzer>>,
-    weights: AnalyzerWeights,
+    analyzers: Vec<Arc<dyn StatusAnalyzer>>,
 }
 
 /// Configuration for status inference analysis
@@ -28,7 +31,23 @@ pub struct StatusInferenceConfig {
     pub api_analysis: ApiAnalysisConfig,
     pub database_analysis: DatabaseAnalysisConfig,
     pub feature_analysis: FeatureAnalysisConfig,
+    #[serde(default)]
+    pub test_coverage_analysis: TestCoverageAnalysisConfig,
+    #[serde(default)]
+    pub feature_flag_analysis: FeatureFlagAnalysisConfig,
     pub plugins: AnalyzerWeights,
+    /// Declarative analyzers defined under `status_inference.rule_packs`
+    #[serde(default)]
+    pub rule_packs: Vec<RulePackConfig>,
+    /// Run analyzers concurrently; each still runs isolated on its own thread when disabled
+    #[serde(default = "default_true")]
+    pub parallel_analyzers: bool,
+    /// Seconds an analyzer may run before it is recorded as timed out
+    #[serde(default = "default_analyzer_timeout_secs")]
+    pub analyzer_timeout_secs: u64,
+    /// Per-analyzer overrides of `analyzer_timeout_secs`, keyed by analyzer name
+    #[serde(default)]
+    pub analyzer_timeouts: HashMap<String, u64>,
 }
 
 impl Default for StatusInferenceConfig {
@@ -42,11 +61,63 @@ impl Default for StatusInferenceConfig {
             api_analysis: ApiAnalysisConfig::default(),
             database_analysis: DatabaseAnalysisConfig::default(),
             feature_analysis: FeatureAnalysisConfig::default(),
+            test_coverage_analysis: TestCoverageAnalysisConfig::default(),
+            feature_flag_analysis: FeatureFlagAnalysisConfig::default(),
             plugins: AnalyzerWeights::default(),
+            rule_packs: Vec::new(),
+            parallel_analyzers: true,
+            analyzer_timeout_secs: default_analyzer_timeout_secs(),
+            analyzer_timeouts: HashMap::new(),
         }
     }
 }
 
+impl StatusInferenceConfig {
+    /// Load the global analyzer config file, falling back to defaults when the file is absent
+    pub fn from_global_config() -> Result<Self> {
+        let config_path = Path::new(ANALYZER_CONFIG_FILE);
+        if config_path.exists() {
+            Self::from_yaml_file(config_path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Load the configuration from `analyzer_config.yaml`
+    ///
+    /// Top-level flags and `rule_packs` come from the `status_inference` section; the per-analyzer
+    /// blocks (`crud_analysis`, ..., `plugins`) from `dual_category_analysis`. Missing keys keep
+    /// their defaults.
+    pub fn from_yaml_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read analyzer config from {:?}", path))?;
+        let document: serde_yaml::Value = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse analyzer config {:?}", path))?;
+
+        let mut merged = serde_yaml::to_value(Self::default())?;
+        let serde_yaml::Value::Mapping(merged_map) = &mut merged else {
+            unreachable!("StatusInferenceConfig serializes to a mapping");
+        };
+
+        let analyzer_blocks = ["crud_analysis", "auth_analysis", "api_analysis", "database_analysis", "feature_analysis", "test_coverage_analysis", "feature_flag_analysis", "plugins"];
+        if let Some(serde_yaml::Value::Mapping(dual)) = document.get("dual_category_analysis") {
+            for key in analyzer_blocks {
+                if let Some(value) = dual.get(key) {
+                    merged_map.insert(key.into(), value.clone());
+                }
+            }
+        }
+        if let Some(serde_yaml::Value::Mapping(section)) = document.get("status_inference") {
+            for (key, value) in section {
+                merged_map.insert(key.clone(), value.clone());
+            }
+        }
+
+        serde_yaml::from_value(merged)
+            .with_context(|| format!("Invalid status_inference configuration in {:?}", path))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrudAnalysisConfig {
     pub enable_crud_detection: bool,
@@ -122,6 +193,31 @@ pub struct FeatureAnalysisConfig {
     pub ui_completeness_indicators: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TestCoverageAnalysisConfig {
+    pub enable_test_mapping: bool,
+    pub ingest_coverage_reports: bool,
+    /// Report locations relative to the project root, probed in addition to indexed files
+    /// named like lcov, Cobertura or JaCoCo reports
+    pub coverage_report_paths: Vec<String>,
+    /// Targets whose covered-line percentage is at or below this count as untested
+    pub untested_line_coverage: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeatureFlagAnalysisConfig {
+    pub enable_flag_detection: bool,
+    /// Report flags checked in at most `stale_reference_threshold` places
+    pub detect_stale_flags: bool,
+    pub stale_reference_threshold: usize,
+    /// Env var prefixes treated as flags (`FEATURE_`, `FF_`, ...)
+    pub env_flag_prefixes: Vec<String>,
+    /// Extra flag checks for in-house SDKs: regexes whose first capture group is the flag name
+    pub custom_flag_patterns: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyzerWeights {
     pub weight_crud_analyzer: f32,
@@ -129,9 +225,117 @@ pub struct AnalyzerWeights {
     pub weight_api_analyzer: f32,
     pub weight_database_analyzer: f32,
     pub weight_feature_analyzer: f32,
+    #[serde(default = "default_test_coverage_weight")]
+    pub weight_test_coverage_analyzer: f32,
+    #[serde(default = "default_feature_flag_weight")]
+    pub weight_feature_flag_analyzer: f32,
     pub weight_ui_analyzer: f32,
 }
 
+/// A named set of declarative completeness rules, run as one analyzer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulePackConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_rule_pack_weight")]
+    pub weight: f32,
+    pub rules: Vec<RuleConfig>,
+}
+
+/// A single rule: select subjects in matching files, then check what they must (not) contain
+///
+/// Without `select` the whole file is the subject. A rule with neither `require` nor `forbid`
+/// reports every selected subject.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleConfig {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Globs relative to the project root (`**/billing/**/*.ts`); all source files when empty
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default)]
+    pub select: Option<RulePattern>,
+    #[serde(default)]
+    pub require: Option<RulePattern>,
+    #[serde(default)]
+    pub forbid: Option<RulePattern>,
+    pub finding: RuleFindingTemplate,
+}
+
+/// Regex or tree-sitter query pattern
+///
+/// Regex subjects extend from a match to the next match; query subjects are the node captured as
+/// `@subject` (or the whole match). A `name` group/capture fills the `{name}` placeholder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulePattern {
+    #[serde(default)]
+    pub regex: Option<String>,
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Grammar for `query` (`typescript`, `tsx`, `javascript`, `python`, `java`, `rust`, `go`);
+    /// inferred from the file extension when omitted
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// How a rule violation is reported
+///
+/// `description`, `suggestion` and `expected_location` accept `{pack}`, `{rule}`, `{file}`,
+/// `{line}`, `{name}` and `{match}` placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleFindingTemplate {
+    #[serde(default)]
+    pub kind: RuleFindingKind,
+    pub implementation_type: String,
+    pub description: String,
+    #[serde(default)]
+    pub suggestion: Option<String>,
+    #[serde(default)]
+    pub expected_location: Option<String>,
+    #[serde(default = "default_rule_confidence")]
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleFindingKind {
+    /// One `MissingImplementation` per violating subject
+    #[default]
+    Missing,
+    /// One `PartialImplementation` per rule, comparing compliant and violating subjects
+    Partial,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_test_coverage_weight() -> f32 {
+    0.15
+}
+
+fn default_feature_flag_weight() -> f32 {
+    0.10
+}
+
+fn default_analyzer_timeout_secs() -> u64 {
+    300
+}
+
+fn default_rule_pack_weight() -> f32 {
+    0.1
+}
+
+fn default_rule_confidence() -> f32 {
+    0.7
+}
+
 // Note: Default implementations moved above to avoid duplication
 
 impl Default for ApiAnalysisConfig {
@@ -183,6 +387,35 @@ impl Default for FeatureAnalysisConfig {
     }
 }
 
+impl Default for TestCoverageAnalysisConfig {
+    fn default() -> Self {
+        Self {
+            enable_test_mapping: true,
+            ingest_coverage_reports: true,
+            coverage_report_paths: vec![
+                "coverage/lcov.info".to_string(),
+                "coverage/cobertura-coverage.xml".to_string(),
+                "coverage.xml".to_string(),
+                "target/site/jacoco/jacoco.xml".to_string(),
+                "build/reports/jacoco/test/jacocoTestReport.xml".to_string(),
+            ],
+            untested_line_coverage: 0.0,
+        }
+    }
+}
+
+impl Default for FeatureFlagAnalysisConfig {
+    fn default() -> Self {
+        Self {
+            enable_flag_detection: true,
+            detect_stale_flags: true,
+            stale_reference_threshold: 1,
+            env_flag_prefixes: vec!["FEATURE_".to_string(), "FF_".to_string(), "FLAG_".to_string(), "ENABLE_".to_string()],
+            custom_flag_patterns: Vec::new(),
+        }
+    }
+}
+
 impl Default for AnalyzerWeights {
     fn default() -> Self {
         Self {
@@ -191,6 +424,8 @@ impl Default for AnalyzerWeights {
             weight_api_analyzer: 0.20,
             weight_database_analyzer: 0.15,
             weight_feature_analyzer: 0.15,
+            weight_test_coverage_analyzer: default_test_coverage_weight(),
+            weight_feature_flag_analyzer: default_feature_flag_weight(),
             weight_ui_analyzer: 0.05,
         }
     }
@@ -224,6 +459,10 @@ pub struct InferredImplementationStatus {
     pub incomplete_features: Vec<IncompleteFeature>,
     pub missing_implementations: Vec<MissingImplementation>,
     pub partial_implementations: Vec<PartialImplementation>,
+    #[serde(default)]
+    pub untested_implementations: Vec<UntestedImplementation>,
+    #[serde(default)]
+    pub feature_flags: Vec<FeatureFlag>,
     pub inference_confidence: f32,
     pub analyzer_contributions: HashMap<String, f32>,
 }
@@ -237,6 +476,26 @@ pub struct StatusAnalysisMetadata {
     pub analysis_duration_ms: u64,
     pub files_analyzed: u32,
     pub warnings: Vec<String>,
+    /// Analyzers that errored, panicked or timed out; their findings are missing from the result
+    #[serde(default)]
+    pub analyzer_failures: Vec<AnalyzerFailure>,
+}
+
+/// An analyzer run that produced no result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyzerFailure {
+    pub analyzer_name: String,
+    pub kind: AnalyzerFailureKind,
+    pub message: String,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalyzerFailureKind {
+    Error,
+    Panic,
+    Timeout,
 }
 
 /// Explicit TODO item found in code
@@ -296,6 +555,36 @@ pub struct PartialImplementation {
     pub next_steps: Vec<String>,
 }
 
+/// Implemented function, route or component that no test exercises
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UntestedImplementation {
+    pub name: String,
+    /// `function`, `route` or `component`
+    pub kind: String,
+    pub location: String,
+    /// Covered-line percentage from a coverage report, when one covers the file
+    pub line_coverage: Option<f32>,
+    pub confidence: f32,
+    pub evidence: Vec<String>,
+}
+
+/// A feature flag and the code it guards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureFlag {
+    pub name: String,
+    /// `LaunchDarkly`, `Unleash`, `Environment`, `Settings` or `Custom`
+    pub provider: String,
+    /// `file:line` of every check of the flag
+    pub locations: Vec<String>,
+    /// Functions, routes and components containing a check of the flag
+    pub guarded_segments: Vec<String>,
+    /// Functions and components only ever reached from code the flag switches on
+    pub flag_only_components: Vec<String>,
+    /// Checked in so few places that the flag is probably left over from a finished rollout
+    pub likely_stale: bool,
+    pub confidence: f32,
+}
+
 /// Priority levels for inferred items
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InferencePriority {
@@ -324,15 +613,37 @@ pub trait StatusAnalyzer: Send + Sync {
     
     /// Check if this analyzer can handle the given project
     fn can_analyze(&self, project_path: &Path) -> bool;
+
+    /// Analyze using the engine's shared file index instead of walking the project again
+    ///
+    /// Defaults to `analyze` on the index root, so analyzers with their own traversal keep working.
+    fn analyze_indexed(
+        &self,
+        index: &ProjectFileIndex,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
+        config: &StatusInferenceConfig,
+    ) -> Result<StatusAnalysisResult> {
+        self.analyze(index.root(), documentation, todo_results, config)
+    }
+
+    /// `can_analyze` against the shared file index
+    fn can_analyze_indexed(&self, index: &ProjectFileIndex) -> bool {
+        self.can_analyze(index.root())
+    }
 }
 
 /// Result from individual status analyzer
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StatusAnalysisResult {
     pub analyzer_name: String,
     pub incomplete_features: Vec<IncompleteFeature>,
     pub missing_implementations: Vec<MissingImplementation>, 
     pub partial_implementations: Vec<PartialImplementation>,
+    #[serde(default)]
+    pub untested_implementations: Vec<UntestedImplementation>,
+    #[serde(default)]
+    pub feature_flags: Vec<FeatureFlag>,
     pub confidence: f32,
     pub evidence: Vec<String>,
     pub analysis_notes: Vec<String>,
@@ -344,74 +655,52 @@ impl StatusInferenceEngine {
         Self::with_config(StatusInferenceConfig::default())
     }
 
-    /// Create status inference engine with custom configuration
+    /// Create status inference engine with custom configuration, using the analyzers in the
+    /// global registry (built-ins, rule packs and anything registered via `register_status_analyzer`)
     pub fn with_config(config: StatusInferenceConfig) -> Result<Self> {
+        Self::with_registry(config, &global_registry())
+    }
+
+    /// Create status inference engine whose analyzers come from the given registry
+    pub fn with_registry(config: StatusInferenceConfig, registry: &StatusAnalyzerRegistry) -> Result<Self> {
         let mut engine = Self {
-            weights: config.plugins.clone(),
             config,
             analyzers: Vec::new(),
         };
 
-        engine.initialize_analyzers()
+        engine.initialize_analyzers(registry)
             .context("Failed to initialize status analyzers")?;
 
         println!("Initialized StatusInferenceEngine with {} analyzers", engine.analyzers.len());
         Ok(engine)
     }
 
-    /// Load configuration from global config file
+    /// Load configuration from the global analyzer config file, falling back to defaults when
+    /// the file is absent
     pub fn from_global_config() -> Result<Self> {
-        // For now, use default configuration until global config integration is complete
-        let config = StatusInferenceConfig::default();
-        Self::with_config(config)
+        Self::with_config(StatusInferenceConfig::from_global_config()?)
     }
 
-    /// Initialize plugin analyzers based on configuration
-    fn initialize_analyzers(&mut self) -> Result<()> {
-        if !self.config.enable_plugin_analyzers {
-            return Ok(());
-        }
-
-        // Initialize CRUD analyzer
-        if self.config.crud_analysis.enable_crud_detection {
-            self.analyzers.push(Box::new(CrudAnalyzer::new(
-                self.config.crud_analysis.clone(),
-                self.weights.weight_crud_analyzer,
-            )));
-        }
-
-        // Initialize Auth analyzer
-        if self.config.auth_analysis.enable_auth_detection {
-            self.analyzers.push(Box::new(AuthAnalyzer::new(
-                self.config.auth_analysis.clone(),
-                self.weights.weight_auth_analyzer,
-            )));
-        }
-
-        // Initialize API analyzer
-        if self.config.api_analysis.enable_api_completeness {
-            self.analyzers.push(Box::new(ApiAnalyzer::new(
-                self.config.api_analysis.clone(),
-                self.weights.weight_api_analyzer,
-            )));
-        }
+    /// Add an analyzer to this engine only
+    pub fn add_analyzer(&mut self, analyzer: Box<dyn StatusAnalyzer>) {
+        self.analyzers.push(Arc::from(analyzer));
+    }
 
-        // Initialize Database analyzer
-        if self.config.database_analysis.enable_schema_analysis {
-            self.analyzers.push(Box::new(DatabaseAnalyzer::new(
-                self.config.database_analysis.clone(),
-                self.weights.weight_database_analyzer,
-            )));
-        }
+    /// Names of the analyzers this engine runs, in order
+    pub fn analyzer_names(&self) -> Vec<&str> {
+        self.analyzers.iter().map(|analyzer| analyzer.name()).collect()
+    }
 
-        // Initialize Feature analyzer
-        if self.config.feature_analysis.enable_feature_tracking {
-            self.analyzers.push(Box::new(FeatureAnalyzer::new(
-                self.config.feature_analysis.clone(),
-                self.weights.weight_feature_analyzer,
-            )));
+    /// Instantiate plugin analyzers from the registry based on configuration
+    fn initialize_analyzers(&mut self, registry: &StatusAnalyzerRegistry) -> Result<()> {
+        if !self.config.enable_plugin_analyzers {
+            return Ok(());
         }
 
+        self.analyzers = registry.build(&self.config)?
+            .into_iter()
+            .map(Arc::from)
+            .collect();
         Ok(())
     }
 
@@ -421,17 +710,28 @@ impl StatusInferenceEngine {
         project_path: &Path,
         documentation: &ExtractedDocumentationInfo,
         todo_results: &TodoScanResult,
+    ) -> Result<DualCategoryStatusResult> {
+        self.analyze_indexed_project_status(&Arc::new(ProjectFileIndex::build(project_path)), documentation, todo_results)
+    }
+
+    /// Same as `analyze_project_status`, over an index the caller keeps using afterwards (its
+    /// code segments stay extracted)
+    pub fn analyze_indexed_project_status(
+        &self,
+        index: &Arc<ProjectFileIndex>,
+        documentation: &ExtractedDocumentationInfo,
+        todo_results: &TodoScanResult,
     ) -> Result<DualCategoryStatusResult> {
         let start_time = std::time::Instant::now();
         
-        println!("Starting dual-category status analysis for project: {}", project_path.display());
+        println!("Starting dual-category status analysis for project: {}", index.root().display());
 
         // Phase 1: Extract actual TODO status from direct sources
         let actual_status = self.extract_actual_todo_status(todo_results)?;
 
         // Phase 2: Perform intelligent inference analysis
-        let inferred_status = self.perform_inference_analysis(
-            project_path,
+        let (inferred_status, analyzer_failures) = self.perform_inference_analysis(
+            index,
             documentation, 
             todo_results,
         )?;
@@ -443,7 +743,10 @@ impl StatusInferenceEngine {
             total_confidence: self.calculate_total_confidence(&actual_status, &inferred_status),
             analysis_duration_ms: start_time.elapsed().as_millis() as u64,
             files_analyzed: todo_results.scan_metadata.files_scanned as u32,
-            warnings: Vec::new(),
+            warnings: analyzer_failures.iter()
+                .map(|failure| format!("Analyzer {} failed ({:?}): {}", failure.analyzer_name, failure.kind, failure.message))
+                .collect(),
+            analyzer_failures,
         };
 
         let result = DualCategoryStatusResult {
@@ -500,27 +803,68 @@ impl StatusInferenceEngine {
     }
 
     /// Perform intelligent inference analysis using plugin analyzers
+    ///
+    /// The index is shared by every analyzer. Each analyzer runs on its own
+    /// thread with its own timeout; errors, panics and timeouts are returned as failures instead
+    /// of aborting the run.
     fn perform_inference_analysis(
         &self,
-        project_path: &Path,
+        index: &Arc<ProjectFileIndex>,
         documentation: &ExtractedDocumentationInfo,
         todo_results: &TodoScanResult,
-    ) -> Result<InferredImplementationStatus> {
+    ) -> Result<(InferredImplementationStatus, Vec<AnalyzerFailure>)> {
         let mut all_incomplete_features = Vec::new();
         let mut all_missing_implementations = Vec::new();
         let mut all_partial_implementations = Vec::new();
+        let mut all_untested_implementations = Vec::new();
+        let mut all_feature_flags = Vec::new();
         let mut analyzer_contributions = HashMap::new();
+        let mut failures = Vec::new();
+
+        if self.analyzers.is_empty() {
+            return Ok((InferredImplementationStatus {
+                incomplete_features: all_incomplete_features,
+                missing_implementations: all_missing_implementations,
+                partial_implementations: all_partial_implementations,
+                untested_implementations: all_untested_implementations,
+                feature_flags: all_feature_flags,
+                inference_confidence: 0.0,
+                analyzer_contributions,
+            }, failures));
+        }
+
+        let inputs = Arc::new(AnalyzerInputs {
+            index: Arc::clone(index),
+            documentation: documentation.clone(),
+            todo_results: todo_results.clone(),
+            config: self.config.clone(),
+        });
+        println!("Indexed {} files for {} analyzers", inputs.index.len(), self.analyzers.len());
+        // Extract segments up front so the cost isn't charged to whichever analyzer's timeout asks first
+        if let Err(e) = inputs.index.segments() {
+            println!("Warning: {:#}", e);
+        }
 
-        // Run each analyzer
-        for analyzer in &self.analyzers {
-            if !analyzer.can_analyze(project_path) {
-                continue;
+        let mut outcomes = Vec::with_capacity(self.analyzers.len());
+        if self.config.parallel_analyzers {
+            let running: Vec<_> = self.analyzers.iter()
+                .map(|analyzer| (analyzer, Instant::now(), spawn_analyzer(analyzer, &inputs)))
+                .collect();
+            for (analyzer, started, receiver) in running {
+                outcomes.push((analyzer, self.await_analyzer(analyzer.name(), started, receiver)));
+            }
+        } else {
+            for analyzer in &self.analyzers {
+                let started = Instant::now();
+                let receiver = spawn_analyzer(analyzer, &inputs);
+                outcomes.push((analyzer, self.await_analyzer(analyzer.name(), started, receiver)));
             }
+        }
 
-            println!("Running analyzer: {}", analyzer.name());
-            
-            match analyzer.analyze(project_path, documentation, todo_results, &self.config) {
-                Ok(mut result) => {
+        for (analyzer, (outcome, elapsed)) in outcomes {
+            match outcome {
+                AnalyzerOutcome::Skipped => {}
+                AnalyzerOutcome::Completed(mut result) => {
                     let contribution_score = result.confidence * analyzer.weight();
                     analyzer_contributions.insert(analyzer.name().to_string(), contribution_score);
 
@@ -531,13 +875,24 @@ impl StatusInferenceEngine {
                     for implementation in &mut result.missing_implementations {
                         implementation.confidence *= analyzer.weight();
                     }
+                    for implementation in &mut result.untested_implementations {
+                        implementation.confidence *= analyzer.weight();
+                    }
 
                     all_incomplete_features.extend(result.incomplete_features);
                     all_missing_implementations.extend(result.missing_implementations);
                     all_partial_implementations.extend(result.partial_implementations);
+                    all_untested_implementations.extend(result.untested_implementations);
+                    all_feature_flags.extend(result.feature_flags);
                 }
-                Err(e) => {
-                    println!("Warning: Analyzer {} failed: {}", analyzer.name(), e);
+                AnalyzerOutcome::Failed(kind, message) => {
+                    println!("Warning: Analyzer {} failed ({:?}): {}", analyzer.name(), kind, message);
+                    failures.push(AnalyzerFailure {
+                        analyzer_name: analyzer.name().to_string(),
+                        kind,
+                        message,
+                        duration_ms: elapsed.as_millis() as u64,
+                    });
                 }
             }
         }
@@ -549,13 +904,39 @@ impl StatusInferenceEngine {
             analyzer_contributions.values().sum::<f32>() / analyzer_contributions.len() as f32
         };
 
-        Ok(InferredImplementationStatus {
+        Ok((InferredImplementationStatus {
             incomplete_features: all_incomplete_features,
             missing_implementations: all_missing_implementations,
             partial_implementations: all_partial_implementations,
+            untested_implementations: all_untested_implementations,
+            feature_flags: all_feature_flags,
             inference_confidence,
             analyzer_contributions,
-        })
+        }, failures))
+    }
+
+    /// Wait for an analyzer's outcome until its timeout, measured from when it started
+    fn await_analyzer(
+        &self,
+        name: &str,
+        started: Instant,
+        receiver: mpsc::Receiver<AnalyzerOutcome>,
+    ) -> (AnalyzerOutcome, Duration) {
+        let timeout = Duration::from_secs(
+            self.config.analyzer_timeouts.get(name).copied().unwrap_or(self.config.analyzer_timeout_secs)
+        );
+        let outcome = match receiver.recv_timeout(timeout.saturating_sub(started.elapsed())) {
+            Ok(outcome) => outcome,
+            Err(mpsc::RecvTimeoutError::Timeout) => AnalyzerOutcome::Failed(
+                AnalyzerFailureKind::Timeout,
+                format!("No result after {}s", timeout.as_secs()),
+            ),
+            Err(mpsc::RecvTimeoutError::Disconnected) => AnalyzerOutcome::Failed(
+                AnalyzerFailureKind::Panic,
+                "Analyzer thread exited without a result".to_string(),
+            ),
+        };
+        (outcome, started.elapsed())
     }
 
     /// Calculate confidence score for actual TODO analysis
@@ -575,157 +956,207 @@ impl StatusInferenceEngine {
     }
 }
 
-impl Default for StatusInferenceEngine {
-    fn default() -> Self {
-        Self::new().expect("Failed to create default StatusInferenceEngine")
-    }
+/// Everything an analyzer thread needs, shared by all analyzers of one run
+struct AnalyzerInputs {
+    index: Arc<ProjectFileIndex>,
+    documentation: ExtractedDocumentationInfo,
+    todo_results: TodoScanResult,
+    config: StatusInferenceConfig,
 }
 
-// Forward declarations for analyzer implementations
-// These will be implemented in separate modules for clarity and maintainability
-
-pub struct AuthAnalyzer {
-    config: AuthAnalysisConfig,
-    weight: f32,
+enum AnalyzerOutcome {
+    Completed(StatusAnalysisResult),
+    /// `can_analyze` declined the project
+    Skipped,
+    Failed(AnalyzerFailureKind, String),
 }
 
-pub struct ApiAnalyzer {
-    config: ApiAnalysisConfig,
-    weight: f32,
-}
+/// Run an analyzer on its own thread, catching panics
+///
+/// A timed-out analyzer cannot be stopped; its thread is left to finish and its result dropped.
+fn spawn_analyzer(analyzer: &Arc<dyn StatusAnalyzer>, inputs: &Arc<AnalyzerInputs>) -> mpsc::Receiver<AnalyzerOutcome> {
+    let (sender, receiver) = mpsc::channel();
+    let analyzer = Arc::clone(analyzer);
+    let inputs = Arc::clone(inputs);
+
+    let spawned = std::thread::Builder::new()
+        .name(format!("status-analyzer: {}", analyzer.name()))
+        .spawn(move || {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                if !analyzer.can_analyze_indexed(&inputs.index) {
+                    return AnalyzerOutcome::Skipped;
+                }
+                println!("Running analyzer: {}", analyzer.name());
+                match analyzer.analyze_indexed(&inputs.index, &inputs.documentation, &inputs.todo_results, &inputs.config) {
+                    Ok(result) => AnalyzerOutcome::Completed(result),
+                    Err(error) => AnalyzerOutcome::Failed(AnalyzerFailureKind::Error, format!("{:#}", error)),
+                }
+            }))
+            .unwrap_or_else(|payload| AnalyzerOutcome::Failed(AnalyzerFailureKind::Panic, panic_message(payload.as_ref())));
+            let _ = sender.send(outcome);
+        });
+    if let Err(error) = spawned {
+        println!("Warning: Failed to spawn analyzer thread: {}", error);
+    }
 
-pub struct DatabaseAnalyzer {
-    config: DatabaseAnalysisConfig,
-    weight: f32,
+    receiver
 }
 
-pub struct FeatureAnalyzer {
-    config: FeatureAnalysisConfig,
-    weight: f32,
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Analyzer panicked".to_string())
 }
 
-// Implementation stubs - to be implemented in the next steps
-
-impl AuthAnalyzer {
-    pub fn new(config: AuthAnalysisConfig, weight: f32) -> Self {
-        Self { config, weight }
+impl Default for StatusInferenceEngine {
+    fn default() -> Self {
+        Self::new().expect("Failed to create default StatusInferenceEngine")
     }
 }
 
-impl StatusAnalyzer for AuthAnalyzer {
-    fn name(&self) -> &str { "Auth Analyzer" }
-    fn weight(&self) -> f32 { self.weight }
-    fn can_analyze(&self, _project_path: &Path) -> bool { true }
-    
-    fn analyze(
-        &self,
-        _project_path: &Path,
-        _documentation: &ExtractedDocumentationInfo,
-        _todo_results: &TodoScanResult,
-        _config: &StatusInferenceConfig,
-    ) -> Result<StatusAnalysisResult> {
-        // Stub implementation - will be enhanced in next step
-        Ok(StatusAnalysisResult {
-            analyzer_name: self.name().to_string(),
-            incomplete_features: Vec::new(),
-            missing_implementations: Vec::new(),
-            partial_implementations: Vec::new(),
-            confidence: 0.5,
-            evidence: Vec::new(),
-            analysis_notes: vec!["Auth analyzer stub - implementation pending".to_string()],
-        })
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzers::test_support::documentation;
+    use crate::core::todo_scanner::TodoScanner;
+    use tempfile::TempDir;
+
+    /// Analyzer whose `analyze_indexed` behaves as configured
+    struct ScriptedAnalyzer {
+        name: &'static str,
+        weight: f32,
+        behavior: fn(&ProjectFileIndex) -> Result<StatusAnalysisResult>,
     }
-}
 
-impl ApiAnalyzer {
-    pub fn new(config: ApiAnalysisConfig, weight: f32) -> Self {
-        Self { config, weight }
-    }
-}
+    impl StatusAnalyzer for ScriptedAnalyzer {
+        fn name(&self) -> &str {
+            self.name
+        }
 
-impl StatusAnalyzer for ApiAnalyzer {
-    fn name(&self) -> &str { "API Analyzer" }
-    fn weight(&self) -> f32 { self.weight }
-    fn can_analyze(&self, _project_path: &Path) -> bool { true }
-    
-    fn analyze(
-        &self,
-        _project_path: &Path,
-        _documentation: &ExtractedDocumentationInfo,
-        _todo_results: &TodoScanResult,
-        _config: &StatusInferenceConfig,
-    ) -> Result<StatusAnalysisResult> {
-        // Stub implementation - will be enhanced in next step
-        Ok(StatusAnalysisResult {
-            analyzer_name: self.name().to_string(),
-            incomplete_features: Vec::new(),
-            missing_implementations: Vec::new(),
-            partial_implementations: Vec::new(),
-            confidence: 0.5,
-            evidence: Vec::new(),
-            analysis_notes: vec!["API analyzer stub - implementation pending".to_string()],
-        })
-    }
-}
+        fn analyze(
+            &self,
+            project_path: &Path,
+            _documentation: &ExtractedDocumentationInfo,
+            _todo_results: &TodoScanResult,
+            _config: &StatusInferenceConfig,
+        ) -> Result<StatusAnalysisResult> {
+            (self.behavior)(&ProjectFileIndex::build(project_path))
+        }
 
-impl DatabaseAnalyzer {
-    pub fn new(config: DatabaseAnalysisConfig, weight: f32) -> Self {
-        Self { config, weight }
+        fn analyze_indexed(
+            &self,
+            index: &ProjectFileIndex,
+            _documentation: &ExtractedDocumentationInfo,
+            _todo_results: &TodoScanResult,
+            _config: &StatusInferenceConfig,
+        ) -> Result<StatusAnalysisResult> {
+            (self.behavior)(index)
+        }
+
+        fn weight(&self) -> f32 {
+            self.weight
+        }
+
+        fn can_analyze(&self, _project_path: &Path) -> bool {
+            true
+        }
     }
-}
 
-impl StatusAnalyzer for DatabaseAnalyzer {
-    fn name(&self) -> &str { "Database Analyzer" }
-    fn weight(&self) -> f32 { self.weight }
-    fn can_analyze(&self, _project_path: &Path) -> bool { true }
-    
-    fn analyze(
-        &self,
-        _project_path: &Path,
-        _documentation: &ExtractedDocumentationInfo,
-        _todo_results: &TodoScanResult,
-        _config: &StatusInferenceConfig,
-    ) -> Result<StatusAnalysisResult> {
-        // Stub implementation - will be enhanced in next step
+    fn missing_per_file(index: &ProjectFileIndex) -> Result<StatusAnalysisResult> {
         Ok(StatusAnalysisResult {
-            analyzer_name: self.name().to_string(),
-            incomplete_features: Vec::new(),
-            missing_implementations: Vec::new(),
-            partial_implementations: Vec::new(),
-            confidence: 0.5,
-            evidence: Vec::new(),
-            analysis_notes: vec!["Database analyzer stub - implementation pending".to_string()],
+            analyzer_name: "Indexed".to_string(),
+            missing_implementations: index.files().iter()
+                .map(|file| MissingImplementation {
+                    implementation_type: "Indexed File".to_string(),
+                    expected_location: file.relative.clone(),
+                    description: file.relative.clone(),
+                    confidence: 1.0,
+                    evidence: Vec::new(),
+                    suggested_implementation: None,
+                })
+                .collect(),
+            confidence: 1.0,
+            ..Default::default()
         })
     }
-}
 
-impl FeatureAnalyzer {
-    pub fn new(config: FeatureAnalysisConfig, weight: f32) -> Self {
-        Self { config, weight }
+    #[test]
+    fn test_failing_analyzers_are_recorded_without_aborting_the_run() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("main.py"), "print('hello')\n").unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("node_modules/pkg")).unwrap();
+        std::fs::write(temp_dir.path().join("node_modules/pkg/index.js"), "module.exports = {};\n").unwrap();
+        let todo_results = TodoScanner::new().unwrap().scan_project(temp_dir.path()).unwrap();
+
+        let mut config = StatusInferenceConfig::default();
+        config.analyzer_timeouts.insert("Slow".to_string(), 0);
+        let mut engine = StatusInferenceEngine::with_registry(config, &StatusAnalyzerRegistry::new()).unwrap();
+        engine.add_analyzer(Box::new(ScriptedAnalyzer { name: "Indexed", weight: 1.0, behavior: missing_per_file }));
+        engine.add_analyzer(Box::new(ScriptedAnalyzer { name: "Panicking", weight: 1.0, behavior: |_| panic!("analyzer bug") }));
+        engine.add_analyzer(Box::new(ScriptedAnalyzer { name: "Erroring", weight: 1.0, behavior: |_| anyhow::bail!("bad input") }));
+        engine.add_analyzer(Box::new(ScriptedAnalyzer {
+            name: "Slow",
+            weight: 1.0,
+            behavior: |index| {
+                std::thread::sleep(Duration::from_millis(500));
+                missing_per_file(index)
+            },
+        }));
+
+        let result = engine.analyze_project_status(temp_dir.path(), &documentation(Vec::new()), &todo_results).unwrap();
+
+        let missing: Vec<&str> = result.inferred_status.missing_implementations.iter()
+            .map(|missing| missing.description.as_str())
+            .collect();
+        assert_eq!(missing, vec!["main.py"]);
+
+        let failures: Vec<(&str, AnalyzerFailureKind)> = result.analysis_metadata.analyzer_failures.iter()
+            .map(|failure| (failure.analyzer_name.as_str(), failure.kind))
+            .collect();
+        assert_eq!(failures, vec![
+            ("Panicking", AnalyzerFailureKind::Panic),
+            ("Erroring", AnalyzerFailureKind::Error),
+            ("Slow", AnalyzerFailureKind::Timeout),
+        ]);
+        assert!(result.analysis_metadata.analyzer_failures[0].message.contains("analyzer bug"));
+        assert_eq!(result.analysis_metadata.warnings.len(), 3);
     }
-}
 
-impl StatusAnalyzer for FeatureAnalyzer {
-    fn name(&self) -> &str { "Feature Analyzer" }
-    fn weight(&self) -> f32 { self.weight }
-    fn can_analyze(&self, _project_path: &Path) -> bool { true }
-    
-    fn analyze(
-        &self,
-        _project_path: &Path,
-        _documentation: &ExtractedDocumentationInfo,
-        _todo_results: &TodoScanResult,
-        _config: &StatusInferenceConfig,
-    ) -> Result<StatusAnalysisResult> {
-        // Stub implementation - will be enhanced in next step
-        Ok(StatusAnalysisResult {
-            analyzer_name: self.name().to_string(),
-            incomplete_features: Vec::new(),
-            missing_implementations: Vec::new(),
-            partial_implementations: Vec::new(),
-            confidence: 0.5,
-            evidence: Vec::new(),
-            analysis_notes: vec!["Feature analyzer stub - implementation pending".to_string()],
-        })
+    #[test]
+    fn test_analyzer_weight_scales_findings() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("main.py"), "print('hello')\n").unwrap();
+        let todo_results = TodoScanner::new().unwrap().scan_project(temp_dir.path()).unwrap();
+
+        let mut engine = StatusInferenceEngine::with_registry(StatusInferenceConfig::default(), &StatusAnalyzerRegistry::new()).unwrap();
+        engine.add_analyzer(Box::new(ScriptedAnalyzer {
+            name: "Light",
+            weight: 0.2,
+            behavior: |index| {
+                let mut result = missing_per_file(index)?;
+                result.confidence = 0.5;
+                result.incomplete_features.push(IncompleteFeature {
+                    feature_name: "checkout".to_string(),
+                    feature_category: "feature".to_string(),
+                    completeness_score: 0.8,
+                    missing_components: Vec::new(),
+                    evidence: Vec::new(),
+                    recommendations: Vec::new(),
+                    priority: InferencePriority::Medium,
+                });
+                Ok(result)
+            },
+        }));
+        engine.add_analyzer(Box::new(ScriptedAnalyzer { name: "Heavy", weight: 0.6, behavior: missing_per_file }));
+
+        let result = engine.analyze_project_status(temp_dir.path(), &documentation(Vec::new()), &todo_results).unwrap();
+        let inferred = &result.inferred_status;
+
+        assert!((inferred.incomplete_features[0].completeness_score - 0.16).abs() < 1e-6);
+        let mut confidences: Vec<f32> = inferred.missing_implementations.iter().map(|missing| missing.confidence).collect();
+        confidences.sort_by(f32::total_cmp);
+        assert_eq!(confidences, vec![0.2, 0.6]);
+        // (0.5 * 0.2 + 1.0 * 0.6) / 2
+        assert!((inferred.inference_confidence - 0.35).abs() < 1e-6);
     }
-}
\ No newline at end of file
+}
@@ -1,10 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::fs;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+
+use crate::core::project_walker::ProjectWalker;
+use crate::core::config::ANALYZER_CONFIG_FILE;
+
 // Using standard println for logging - could be upgraded to proper logging later
 
 // use crate::core::config::get_config;
@@ -85,6 +91,46 @@ impl TodoPriority {
             TodoType::Custom(_) => Self::Medium,
         }
     }
+
+    /// Raise the priority one level for every threshold (in days) the TODO's age has reached
+    pub fn escalated_for_age(self, age_days: u32, thresholds_days: &[u32]) -> Self {
+        let levels = thresholds_days.iter().filter(|threshold| age_days >= **threshold).count();
+        (0..levels).fold(self, |priority, _| priority.raised())
+    }
+
+    fn raised(self) -> Self {
+        match self {
+            Self::Low => Self::Medium,
+            Self::Medium => Self::High,
+            Self::High | Self::Critical => Self::Critical,
+        }
+    }
+}
+
+/// How long ago a TODO line was written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TodoAgeBucket {
+    UnderOneMonth,
+    UnderSixMonths,
+    UnderOneYear,
+    UnderThreeYears,
+    UnderFiveYears,
+    FiveYearsOrOlder,
+    Unknown,
+}
+
+impl TodoAgeBucket {
+    pub fn from_age_days(age_days: Option<u32>) -> Self {
+        match age_days {
+            None => Self::Unknown,
+            Some(days) if days < 30 => Self::UnderOneMonth,
+            Some(days) if days < 182 => Self::UnderSixMonths,
+            Some(days) if days < 365 => Self::UnderOneYear,
+            Some(days) if days < 3 * 365 => Self::UnderThreeYears,
+            Some(days) if days < 5 * 365 => Self::UnderFiveYears,
+            Some(_) => Self::FiveYearsOrOlder,
+        }
+    }
 }
 
 /// A single TODO item found in the codebase
@@ -100,6 +146,183 @@ pub struct ActualTodoItem {
     pub date_added: Option<String>,
     pub context_lines: Vec<String>,
     pub tags: Vec<String>,
+    /// Commit that last touched the line, when scanned with `git_blame`
+    #[serde(default)]
+    pub blame: Option<TodoBlame>,
+    /// Days since the line was committed, or since the date in its annotation
+    #[serde(default)]
+    pub age_days: Option<u32>,
+    /// Issue tracker tickets referenced in the description
+    #[serde(default)]
+    pub tickets: Vec<TicketReference>,
+}
+
+impl ActualTodoItem {
+    /// Person responsible for the TODO: the annotated author, else the author of the line's commit
+    pub fn owner(&self) -> Option<&str> {
+        self.author.as_deref()
+            .or_else(|| self.blame.as_ref().and_then(|blame| blame.author.as_deref()))
+    }
+}
+
+/// `git blame` information for a TODO line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoBlame {
+    /// `None` for lines not committed yet
+    pub commit_id: Option<String>,
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    /// RFC 3339 commit time
+    pub commit_date: Option<String>,
+}
+
+/// Issue tracker a ticket reference points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IssueTracker {
+    Jira,
+    GitHub,
+}
+
+/// Ticket referenced from a TODO, e.g. `TODO(PROJ-123)`, `FIXME #456` or an issue URL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TicketReference {
+    pub tracker: IssueTracker,
+    /// `PROJ-123` for Jira; `#456` or `owner/repo#456` for GitHub
+    pub key: String,
+    pub url: Option<String>,
+    /// Set once the ticket has been looked up in an issue tracker or issue export
+    #[serde(default)]
+    pub status: Option<TicketStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TicketStatus {
+    pub state: TicketState,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TicketState {
+    Open,
+    Closed,
+    NotFound,
+}
+
+/// Known ticket states, keyed by tracker and ticket key
+#[derive(Debug, Clone, Default)]
+pub struct IssueStatusIndex {
+    statuses: HashMap<(IssueTracker, String), TicketStatus>,
+    /// Trackers whose every ticket is in the index, so unknown keys are `NotFound`
+    complete_trackers: HashSet<IssueTracker>,
+}
+
+impl IssueStatusIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, tracker: IssueTracker, key: impl Into<String>, status: TicketStatus) {
+        self.statuses.insert((tracker, key.into()), status);
+    }
+
+    /// Load a JSON export of issues: an array (or a Jira search response's `issues`) of Jira
+    /// issues with a `key` and `status`, or GitHub issues with a `number` and `state`
+    pub fn from_export_file(path: &Path, closed_statuses: &[String]) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .context(format!("Failed to read issue export: {}", path.display()))?;
+        Self::from_export_json(&content, closed_statuses)
+            .context(format!("Invalid issue export: {}", path.display()))
+    }
+
+    pub fn from_export_json(json: &str, closed_statuses: &[String]) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let issues = match value {
+            serde_json::Value::Object(mut object) if object.contains_key("issues") => {
+                object.remove("issues").unwrap_or_default()
+            }
+            other => other,
+        };
+        let issues: Vec<ExportedIssue> = serde_json::from_value(issues)?;
+
+        let mut index = Self::new();
+        for issue in issues {
+            let (key, status, title) = match issue.fields {
+                Some(fields) => (issue.key, fields.status, fields.title),
+                None => (issue.key, issue.status, issue.title),
+            };
+            if let Some(key) = key {
+                let status_name = status.map(ExportedStatus::into_name).unwrap_or_default();
+                let closed = closed_statuses.iter().any(|closed| closed.eq_ignore_ascii_case(&status_name));
+                index.insert(IssueTracker::Jira, key, TicketStatus {
+                    state: if closed { TicketState::Closed } else { TicketState::Open },
+                    title,
+                });
+                index.complete_trackers.insert(IssueTracker::Jira);
+            } else if let Some(number) = issue.number {
+                let key = format!("{}#{}", issue.repository.unwrap_or_default(), number);
+                let closed = issue.state.is_some_and(|state| state.eq_ignore_ascii_case("closed"));
+                index.insert(IssueTracker::GitHub, key, TicketStatus {
+                    state: if closed { TicketState::Closed } else { TicketState::Open },
+                    title,
+                });
+                index.complete_trackers.insert(IssueTracker::GitHub);
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Fill in the status of every ticket reference that is not resolved yet
+    pub fn resolve(&self, todo_items: &mut [ActualTodoItem]) {
+        for ticket in todo_items.iter_mut().flat_map(|item| item.tickets.iter_mut()) {
+            if ticket.status.is_some() {
+                continue;
+            }
+            ticket.status = match self.statuses.get(&(ticket.tracker, ticket.key.clone())) {
+                Some(status) => Some(status.clone()),
+                None if self.complete_trackers.contains(&ticket.tracker) => Some(TicketStatus {
+                    state: TicketState::NotFound,
+                    title: None,
+                }),
+                None => None,
+            };
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportedIssue {
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    number: Option<u64>,
+    /// `owner/repo` of a GitHub issue; issues without one belong to the scanned repository
+    #[serde(default)]
+    repository: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    status: Option<ExportedStatus>,
+    #[serde(default, alias = "summary")]
+    title: Option<String>,
+    /// Jira REST responses nest everything but the key under `fields`
+    #[serde(default)]
+    fields: Option<Box<ExportedIssue>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ExportedStatus {
+    Name(String),
+    Object { name: String },
+}
+
+impl ExportedStatus {
+    fn into_name(self) -> String {
+        match self {
+            Self::Name(name) | Self::Object { name } => name,
+        }
+    }
 }
 
 /// Results from scanning for TODO comments
@@ -118,6 +341,86 @@ pub struct TodoSummary {
     pub by_priority: HashMap<TodoPriority, usize>,
     pub by_file_type: HashMap<String, usize>,
     pub most_problematic_files: Vec<ProblematicFile>,
+    #[serde(default)]
+    pub by_age_bucket: HashMap<TodoAgeBucket, usize>,
+    /// Items per owner; items without one are counted under `unassigned`
+    #[serde(default)]
+    pub by_owner: HashMap<String, usize>,
+    /// Oldest dated items first
+    #[serde(default)]
+    pub oldest_items: Vec<AgedTodo>,
+    #[serde(default)]
+    pub tickets: TicketSummary,
+}
+
+/// How TODOs relate to issue tracker tickets
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketSummary {
+    pub with_ticket: usize,
+    pub without_ticket: usize,
+    /// Ticket references per resolved state; unresolved references are not counted
+    pub by_state: HashMap<TicketState, usize>,
+    /// TODOs referencing a ticket that has been closed
+    pub closed_ticket_items: Vec<TicketedTodo>,
+    /// TODOs referencing no ticket at all
+    pub unticketed_items: Vec<TicketedTodo>,
+}
+
+impl TicketSummary {
+    pub fn from_items(todo_items: &[ActualTodoItem]) -> Self {
+        let mut summary = Self::default();
+        for item in todo_items {
+            if item.tickets.is_empty() {
+                summary.without_ticket += 1;
+                summary.unticketed_items.push(TicketedTodo::from(item));
+                continue;
+            }
+
+            summary.with_ticket += 1;
+            let states: Vec<TicketState> = item.tickets.iter()
+                .filter_map(|ticket| ticket.status.as_ref().map(|status| status.state))
+                .collect();
+            for state in &states {
+                *summary.by_state.entry(*state).or_insert(0) += 1;
+            }
+            if states.contains(&TicketState::Closed) {
+                summary.closed_ticket_items.push(TicketedTodo::from(item));
+            }
+        }
+        summary
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketedTodo {
+    pub file_path: PathBuf,
+    pub line_number: u32,
+    pub todo_type: TodoType,
+    pub description: String,
+    pub tickets: Vec<TicketReference>,
+}
+
+impl From<&ActualTodoItem> for TicketedTodo {
+    fn from(item: &ActualTodoItem) -> Self {
+        Self {
+            file_path: item.file_path.clone(),
+            line_number: item.line_number,
+            todo_type: item.todo_type.clone(),
+            description: item.description.clone(),
+            tickets: item.tickets.clone(),
+        }
+    }
+}
+
+/// A TODO item ranked by age
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgedTodo {
+    pub file_path: PathBuf,
+    pub line_number: u32,
+    pub todo_type: TodoType,
+    pub description: String,
+    pub age_days: u32,
+    pub owner: Option<String>,
 }
 
 /// File with high concentration of TODO items
@@ -151,8 +454,67 @@ pub struct TodoScanConfig {
     pub context_lines_after: u32,
     pub max_scan_depth: u32,
     pub max_file_size_mb: u64,
+    /// Record the commit author and date of every TODO line with `git blame`; needs the
+    /// `integrations` feature
+    #[serde(default)]
+    pub git_blame: bool,
+    /// Ages in days at which a TODO's priority is raised by one more level
+    #[serde(default = "default_priority_escalation_days")]
+    pub priority_escalation_days: Vec<u32>,
+    /// Jira project keys recognised in ticket references; any `ABC-123` token counts when empty
+    #[serde(default)]
+    pub ticket_project_keys: Vec<String>,
+    /// JSON export of Jira/GitHub issues used to resolve ticket references offline,
+    /// relative to the project root
+    #[serde(default)]
+    pub issue_export_path: Option<PathBuf>,
+    /// Jira statuses that count as closed
+    #[serde(default = "default_closed_ticket_statuses")]
+    pub closed_ticket_statuses: Vec<String>,
+}
+
+impl TodoScanConfig {
+    /// Load the configuration from the `status_analysis` section of `analyzer_config.yaml`;
+    /// missing keys keep their defaults
+    pub fn from_yaml_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read analyzer config from {:?}", path))?;
+        let document: serde_yaml::Value = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse analyzer config {:?}", path))?;
+
+        let mut merged = serde_yaml::to_value(Self::default())?;
+        if let (serde_yaml::Value::Mapping(merged_map), Some(serde_yaml::Value::Mapping(section))) =
+            (&mut merged, document.get("status_analysis"))
+        {
+            for (key, value) in section {
+                merged_map.insert(key.clone(), value.clone());
+            }
+        }
+
+        serde_yaml::from_value(merged)
+            .with_context(|| format!("Invalid status_analysis section in {:?}", path))
+    }
 }
 
+fn default_priority_escalation_days() -> Vec<u32> {
+    vec![365, 3 * 365]
+}
+
+fn default_closed_ticket_statuses() -> Vec<String> {
+    ["Done", "Closed", "Resolved", "Won't Do", "Cancelled"].iter().map(|status| status.to_string()).collect()
+}
+
+static TICKET_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"https?://(?:github\.com/([\w.-]+/[\w.-]+)/(?:issues|pull)/(\d+)|[^\s()]+/browse/([A-Z][A-Z0-9_]+-\d+))").unwrap()
+});
+static GITHUB_REF_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^\w/&#])((?:[\w.-]+/[\w.-]+)?)#(\d+)\b").unwrap()
+});
+static JIRA_KEY_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b([A-Z][A-Z0-9_]+)-(\d+)\b").unwrap());
+
+/// Prefixes of `ABC-123`-shaped tokens that are standards or algorithms rather than Jira keys
+const NON_TICKET_PREFIXES: &[&str] = &["UTF", "ISO", "RFC", "SHA", "CVE", "MD", "AES", "TLS", "HTTP", "ES", "IPV", "X"];
+
 impl Default for TodoScanConfig {
     fn default() -> Self {
         Self {
@@ -175,17 +537,17 @@ impl Default for TodoScanConfig {
                 "*.h".to_string(),
                 "*.go".to_string(),
             ],
-            exclude_file_patterns: vec![
-                "target/**".to_string(),
-                "node_modules/**".to_string(),
-                "*.min.js".to_string(),
-                "*.bundle.js".to_string(),
-                ".git/**".to_string(),
-            ],
+            // Ignored, vendored and generated files are already skipped by `ProjectWalker`
+            exclude_file_patterns: Vec::new(),
             context_lines_before: 2,
             context_lines_after: 1,
             max_scan_depth: 10,
             max_file_size_mb: 10,
+            git_blame: false,
+            priority_escalation_days: default_priority_escalation_days(),
+            ticket_project_keys: Vec::new(),
+            issue_export_path: None,
+            closed_ticket_statuses: default_closed_ticket_statuses(),
         }
     }
 }
@@ -204,6 +566,11 @@ impl TodoScanner {
         let config = Self::load_config()
             .context("Failed to load TODO scanner configuration")?;
 
+        Self::with_config(config)
+    }
+
+    /// Create a TODO scanner with an explicit configuration
+    pub fn with_config(config: TodoScanConfig) -> Result<Self> {
         let mut scanner = Self {
             config: config.clone(),
             comment_regexes: HashMap::new(),
@@ -218,11 +585,19 @@ impl TodoScanner {
         Ok(scanner)
     }
 
-    /// Load configuration from the analyzer config file
+    pub fn config(&self) -> &TodoScanConfig {
+        &self.config
+    }
+
+    /// Load configuration from the analyzer config file, falling back to defaults when the
+    /// file is absent
     fn load_config() -> Result<TodoScanConfig> {
-        // This is a placeholder - you'll need to integrate with the config system
-        // For now, return default config
-        Ok(TodoScanConfig::default())
+        let config_path = Path::new(ANALYZER_CONFIG_FILE);
+        if config_path.exists() {
+            TodoScanConfig::from_yaml_file(config_path)
+        } else {
+            Ok(TodoScanConfig::default())
+        }
     }
 
     /// Compile regex patterns for efficient matching
@@ -305,6 +680,26 @@ impl TodoScanner {
             }
         }
 
+        if self.config.git_blame
+            && let Err(e) = attach_git_blame(project_path, &mut todo_items)
+        {
+            let error_msg = format!("git blame unavailable: {:#}", e);
+            println!("Warning: {}", error_msg);
+            scan_errors.push(error_msg);
+        }
+        self.apply_age_escalation(&mut todo_items, Utc::now());
+
+        if let Some(export_path) = &self.config.issue_export_path {
+            match IssueStatusIndex::from_export_file(&project_path.join(export_path), &self.config.closed_ticket_statuses) {
+                Ok(index) => index.resolve(&mut todo_items),
+                Err(e) => {
+                    let error_msg = format!("Issue export unavailable: {:#}", e);
+                    println!("Warning: {}", error_msg);
+                    scan_errors.push(error_msg);
+                }
+            }
+        }
+
         let scan_duration_ms = start_time.elapsed().as_millis() as u64;
 
         // Generate summary
@@ -331,55 +726,23 @@ impl TodoScanner {
 
     /// Find all source files matching include patterns
     fn find_source_files(&self, project_path: &Path) -> Result<Vec<PathBuf>> {
-        let mut source_files = Vec::new();
-        
-        self.walk_directory(project_path, &mut source_files, 0)?;
-
-        // Sort files for consistent ordering
-        source_files.sort();
-        
-        // Debug: Found source files to scan
-        Ok(source_files)
-    }
-
-    /// Recursively walk directory to find source files
-    fn walk_directory(&self, dir: &Path, files: &mut Vec<PathBuf>, depth: u32) -> Result<()> {
-        if depth > self.config.max_scan_depth {
-            return Ok(());
+        if !project_path.is_dir() {
+            return Ok(Vec::new());
         }
 
-        if !dir.is_dir() {
-            return Ok(());
-        }
-
-        let entries = fs::read_dir(dir)
-            .context(format!("Failed to read directory: {}", dir.display()))?;
-
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
-            
-            // Skip excluded paths
-            if self.should_exclude_path(&path) {
-                continue;
-            }
-
-            if path.is_dir() {
-                self.walk_directory(&path, files, depth + 1)?;
-            } else if self.should_include_file(&path) {
-                // Check file size
-                if let Ok(metadata) = fs::metadata(&path) {
-                    let size_mb = metadata.len() / (1024 * 1024);
-                    if size_mb <= self.config.max_file_size_mb {
-                        files.push(path);
-                    } else {
-                        // Debug: Skipping large file
-                    }
-                }
-            }
-        }
+        // Files sit one level below the deepest directory scanned
+        let source_files = ProjectWalker::new(project_path)
+            .max_depth(self.config.max_scan_depth as usize + 1)
+            .files()
+            .filter(|path| !self.should_exclude_path(path) && self.should_include_file(path))
+            .filter(|path| {
+                fs::metadata(path)
+                    .map(|metadata| metadata.len() / (1024 * 1024) <= self.config.max_file_size_mb)
+                    .unwrap_or(false)
+            })
+            .collect();
 
-        Ok(())
+        Ok(source_files)
     }
 
     /// Check if a path should be excluded from scanning
@@ -459,6 +822,7 @@ impl TodoScanner {
                     let context_lines = self.extract_context_lines(&lines, line_num);
                     let (author, date_added) = self.extract_author_and_date(&description);
                     let tags = self.extract_tags(&description);
+                    let tickets = self.extract_ticket_references(&description);
 
                     todo_items.push(ActualTodoItem {
                         file_path: file_path.to_path_buf(),
@@ -471,6 +835,9 @@ impl TodoScanner {
                         date_added,
                         context_lines,
                         tags,
+                        blame: None,
+                        age_days: None,
+                        tickets,
                     });
 
                     break; // Only match first pattern per line
@@ -522,6 +889,29 @@ impl TodoScanner {
         (author, date)
     }
 
+    /// Set each item's age from its commit or annotation date and escalate old items' priority
+    fn apply_age_escalation(&self, todo_items: &mut [ActualTodoItem], now: DateTime<Utc>) {
+        for item in todo_items {
+            let written = item.blame.as_ref()
+                .and_then(|blame| blame.commit_date.as_deref())
+                .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+                .map(|date| date.with_timezone(&Utc))
+                .or_else(|| item.date_added.as_deref()
+                    .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|date| date.and_utc()));
+            item.age_days = match (&item.blame, written) {
+                (_, Some(written)) => Some((now - written).num_days().max(0) as u32),
+                // Uncommitted lines are as new as they get
+                (Some(blame), None) if blame.commit_id.is_none() => Some(0),
+                _ => None,
+            };
+            if let Some(age_days) = item.age_days {
+                item.priority = item.priority.clone().escalated_for_age(age_days, &self.config.priority_escalation_days);
+            }
+        }
+    }
+
     /// Extract tags from TODO description  
     fn extract_tags(&self, description: &str) -> Vec<String> {
         let tag_regex = Regex::new(r"#([a-zA-Z0-9_-]+)").ok();
@@ -529,22 +919,67 @@ impl TodoScanner {
         if let Some(regex) = tag_regex {
             regex.find_iter(description)
                 .map(|m| m.as_str()[1..].to_string()) // Remove the '#'
+                .filter(|tag| !tag.chars().all(|c| c.is_ascii_digit())) // `#123` is an issue reference
                 .collect()
         } else {
             Vec::new()
         }
     }
 
+    /// Extract Jira keys, GitHub issue references and issue URLs from a TODO description
+    fn extract_ticket_references(&self, description: &str) -> Vec<TicketReference> {
+        let mut tickets: Vec<TicketReference> = Vec::new();
+        let mut push = |tracker: IssueTracker, key: String, url: Option<String>| {
+            if !tickets.iter().any(|ticket| ticket.tracker == tracker && ticket.key == key) {
+                tickets.push(TicketReference { tracker, key, url, status: None });
+            }
+        };
+
+        for caps in TICKET_URL_REGEX.captures_iter(description) {
+            let url = Some(caps[0].to_string());
+            match (caps.get(1), caps.get(2), caps.get(3)) {
+                (Some(repository), Some(number), _) => {
+                    push(IssueTracker::GitHub, format!("{}#{}", repository.as_str(), number.as_str()), url)
+                }
+                (_, _, Some(key)) => push(IssueTracker::Jira, key.as_str().to_string(), url),
+                _ => {}
+            }
+        }
+
+        // URLs are already handled; keep their path segments from matching again below
+        let without_urls = TICKET_URL_REGEX.replace_all(description, " ");
+        for caps in GITHUB_REF_REGEX.captures_iter(&without_urls) {
+            push(IssueTracker::GitHub, format!("{}#{}", &caps[1], &caps[2]), None);
+        }
+        for caps in JIRA_KEY_REGEX.captures_iter(&without_urls) {
+            let project = &caps[1];
+            let is_project = if self.config.ticket_project_keys.is_empty() {
+                !NON_TICKET_PREFIXES.contains(&project)
+            } else {
+                self.config.ticket_project_keys.iter().any(|key| key == project)
+            };
+            if is_project {
+                push(IssueTracker::Jira, caps[0].to_string(), None);
+            }
+        }
+
+        tickets
+    }
+
     /// Generate summary statistics for TODO items
     fn generate_summary(&self, todo_items: &[ActualTodoItem]) -> TodoSummary {
         let mut by_type = HashMap::new();
         let mut by_priority = HashMap::new();
         let mut by_file_type = HashMap::new();
         let mut file_todo_counts = HashMap::new();
+        let mut by_age_bucket = HashMap::new();
+        let mut by_owner = HashMap::new();
 
         for item in todo_items {
             *by_type.entry(item.todo_type.clone()).or_insert(0) += 1;
             *by_priority.entry(item.priority.clone()).or_insert(0) += 1;
+            *by_age_bucket.entry(TodoAgeBucket::from_age_days(item.age_days)).or_insert(0) += 1;
+            *by_owner.entry(item.owner().unwrap_or("unassigned").to_string()).or_insert(0) += 1;
             
             if let Some(file_type) = self.determine_file_type(&item.file_path) {
                 *by_file_type.entry(file_type).or_insert(0) += 1;
@@ -578,16 +1013,92 @@ impl TodoScanner {
             })
             .collect();
 
+        let mut dated: Vec<&ActualTodoItem> = todo_items.iter().filter(|item| item.age_days.is_some()).collect();
+        dated.sort_by_key(|item| std::cmp::Reverse(item.age_days));
+        let oldest_items = dated.into_iter()
+            .take(10)
+            .map(|item| AgedTodo {
+                file_path: item.file_path.clone(),
+                line_number: item.line_number,
+                todo_type: item.todo_type.clone(),
+                description: item.description.clone(),
+                age_days: item.age_days.unwrap_or(0),
+                owner: item.owner().map(str::to_string),
+            })
+            .collect();
+
         TodoSummary {
             total_items: todo_items.len(),
             by_type,
             by_priority,
             by_file_type,
             most_problematic_files,
+            by_age_bucket,
+            by_owner,
+            oldest_items,
+            tickets: TicketSummary::from_items(todo_items),
         }
     }
 }
 
+/// Fill in `blame` for every item from `git blame` of its file's working-tree contents
+#[cfg(feature = "integrations")]
+fn attach_git_blame(project_path: &Path, todo_items: &mut [ActualTodoItem]) -> Result<()> {
+    let repo = git2::Repository::discover(project_path)
+        .context("Failed to open git repository")?;
+    let workdir = repo.workdir()
+        .context("Repository has no working directory")?
+        .canonicalize()?;
+
+    let mut by_file: HashMap<PathBuf, Vec<&mut ActualTodoItem>> = HashMap::new();
+    for item in todo_items.iter_mut() {
+        by_file.entry(item.file_path.clone()).or_default().push(item);
+    }
+
+    for (file_path, items) in by_file {
+        let Ok(relative) = file_path.canonicalize().map(|path| path.strip_prefix(&workdir).map(Path::to_path_buf)) else {
+            continue;
+        };
+        let Ok(relative) = relative else {
+            continue;
+        };
+        // Untracked files have nothing to blame
+        let Ok(committed) = repo.blame_file(&relative, None) else {
+            continue;
+        };
+        let content = fs::read(&file_path)
+            .context(format!("Failed to read file: {}", file_path.display()))?;
+        let blame = committed.blame_buffer(&content)
+            .context(format!("Failed to blame {}", relative.display()))?;
+
+        for item in items {
+            let Some(hunk) = blame.get_line(item.line_number as usize) else {
+                continue;
+            };
+            let commit_id = hunk.final_commit_id();
+            item.blame = Some(if commit_id.is_zero() {
+                TodoBlame { commit_id: None, author: None, author_email: None, commit_date: None }
+            } else {
+                let signature = hunk.final_signature();
+                TodoBlame {
+                    commit_id: Some(commit_id.to_string()),
+                    author: signature.name().map(str::to_string),
+                    author_email: signature.email().map(str::to_string),
+                    commit_date: DateTime::from_timestamp(signature.when().seconds(), 0)
+                        .map(|date| date.to_rfc3339()),
+                }
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "integrations"))]
+fn attach_git_blame(_project_path: &Path, _todo_items: &mut [ActualTodoItem]) -> Result<()> {
+    anyhow::bail!("git blame needs the `integrations` feature")
+}
+
 /// Result from scanning a single file
 #[derive(Debug)]
 struct FileScanResult {
@@ -599,6 +1110,7 @@ struct FileScanResult {
 mod tests {
     use super::*;
     use std::path::PathBuf;
+    use tempfile::TempDir;
 
     #[test]
     fn test_todo_type_from_str() {
@@ -626,6 +1138,161 @@ mod tests {
         assert!(!scanner.file_type_regexes.is_empty());
     }
 
+    #[test]
+    fn test_age_escalation_and_age_summary() {
+        let scanner = TodoScanner::new().unwrap();
+        let item = |line_number: u32, todo_type: TodoType, author: Option<&str>, date_added: Option<&str>, blame: Option<TodoBlame>| ActualTodoItem {
+            file_path: PathBuf::from("src/lib.rs"),
+            line_number,
+            column_number: None,
+            priority: TodoPriority::from_todo_type_and_context(&todo_type, ""),
+            todo_type,
+            description: "clean up".to_string(),
+            author: author.map(str::to_string),
+            date_added: date_added.map(str::to_string),
+            context_lines: Vec::new(),
+            tags: Vec::new(),
+            blame,
+            age_days: None,
+            tickets: Vec::new(),
+        };
+        let committed_by = |author: &str, date: &str| Some(TodoBlame {
+            commit_id: Some("3f2a9c1".to_string()),
+            author: Some(author.to_string()),
+            author_email: None,
+            commit_date: Some(date.to_string()),
+        });
+        let mut items = vec![
+            item(1, TodoType::Fixme, None, None, committed_by("dana", "2019-03-01T12:00:00+00:00")),
+            item(2, TodoType::Note, Some("lee"), Some("2023-09-01"), None),
+            item(3, TodoType::Todo, None, None, committed_by("dana", "2024-05-20T08:30:00+00:00")),
+            item(4, TodoType::Todo, None, None, Some(TodoBlame { commit_id: None, author: None, author_email: None, commit_date: None })),
+            item(5, TodoType::Todo, None, None, None),
+        ];
+
+        let now = DateTime::parse_from_rfc3339("2024-06-01T00:00:00+00:00").unwrap().with_timezone(&Utc);
+        scanner.apply_age_escalation(&mut items, now);
+
+        assert_eq!(items.iter().map(|item| item.age_days).collect::<Vec<_>>(), vec![Some(1918), Some(274), Some(11), Some(0), None]);
+        assert_eq!(items[0].priority, TodoPriority::Critical);
+        assert_eq!(items[1].priority, TodoPriority::Low);
+        assert_eq!(items[2].priority, TodoPriority::Medium);
+
+        let summary = scanner.generate_summary(&items);
+        assert_eq!(summary.by_age_bucket[&TodoAgeBucket::FiveYearsOrOlder], 1);
+        assert_eq!(summary.by_age_bucket[&TodoAgeBucket::UnderOneYear], 1);
+        assert_eq!(summary.by_age_bucket[&TodoAgeBucket::UnderOneMonth], 2);
+        assert_eq!(summary.by_age_bucket[&TodoAgeBucket::Unknown], 1);
+        assert_eq!(summary.by_owner["dana"], 2);
+        assert_eq!(summary.by_owner["lee"], 1);
+        assert_eq!(summary.by_owner["unassigned"], 2);
+        assert_eq!(summary.oldest_items[0].line_number, 1);
+        assert_eq!(summary.oldest_items[0].owner.as_deref(), Some("dana"));
+    }
+
+    #[test]
+    fn test_ticket_references_resolved_from_issue_export() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("billing.py"), r#"
+# TODO(PAY-12): retry declined cards
+# FIXME #34 rounding is off, see https://github.com/acme/ledger/issues/7
+# TODO: support UTF-8 invoice names
+# HACK: see https://acme.atlassian.net/browse/PAY-99 for the real fix
+"#).unwrap();
+        fs::write(temp_dir.path().join("issues.json"), r#"[
+  {"key": "PAY-12", "summary": "Retry declined cards", "status": {"name": "Done", "id": "3"}},
+  {"key": "PAY-40", "fields": {"summary": "Refunds", "status": {"name": "In Progress"}}},
+  {"number": 34, "title": "Rounding", "state": "open"}
+]"#).unwrap();
+
+        let scanner = TodoScanner::with_config(TodoScanConfig {
+            issue_export_path: Some(PathBuf::from("issues.json")),
+            ..TodoScanConfig::default()
+        }).unwrap();
+        let mut result = scanner.scan_project(temp_dir.path()).unwrap();
+        result.todo_items.sort_by_key(|item| item.line_number);
+
+        let tickets: Vec<Vec<(IssueTracker, &str, Option<TicketState>)>> = result.todo_items.iter()
+            .map(|item| item.tickets.iter()
+                .map(|ticket| (ticket.tracker, ticket.key.as_str(), ticket.status.as_ref().map(|status| status.state)))
+                .collect())
+            .collect();
+        assert_eq!(tickets, vec![
+            vec![(IssueTracker::Jira, "PAY-12", Some(TicketState::Closed))],
+            vec![
+                (IssueTracker::GitHub, "acme/ledger#7", Some(TicketState::NotFound)),
+                (IssueTracker::GitHub, "#34", Some(TicketState::Open)),
+            ],
+            vec![],
+            vec![(IssueTracker::Jira, "PAY-99", Some(TicketState::NotFound))],
+        ]);
+        assert!(result.todo_items[1].tags.is_empty());
+
+        let summary = &result.summary.tickets;
+        assert_eq!((summary.with_ticket, summary.without_ticket), (3, 1));
+        assert_eq!(summary.closed_ticket_items.len(), 1);
+        assert_eq!(summary.closed_ticket_items[0].line_number, 2);
+        assert_eq!(summary.unticketed_items[0].line_number, 4);
+        assert_eq!(summary.by_state[&TicketState::NotFound], 2);
+    }
+
+    #[test]
+    fn test_config_loaded_from_status_analysis_section() {
+        let shipped = TodoScanConfig::from_yaml_file(Path::new(ANALYZER_CONFIG_FILE)).unwrap();
+        assert!(!shipped.git_blame);
+        assert_eq!(shipped.priority_escalation_days, vec![365, 1095]);
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("analyzer_config.yaml");
+        fs::write(&config_path, r#"
+status_analysis:
+  scan_todo_comments: true
+  comment_patterns: ["TODO", "FIXME"]
+  git_blame: true
+  priority_escalation_days: [30]
+  ticket_project_keys: ["PAY"]
+  issue_export_path: "exports/issues.json"
+"#).unwrap();
+
+        let config = TodoScanConfig::from_yaml_file(&config_path).unwrap();
+        assert!(config.git_blame);
+        assert_eq!(config.priority_escalation_days, vec![30]);
+        assert_eq!(config.comment_patterns, vec!["TODO", "FIXME"]);
+        assert_eq!(config.ticket_project_keys, vec!["PAY"]);
+        assert_eq!(config.issue_export_path, Some(PathBuf::from("exports/issues.json")));
+        assert_eq!(config.context_lines_before, TodoScanConfig::default().context_lines_before);
+    }
+
+    #[cfg(feature = "integrations")]
+    #[test]
+    fn test_git_blame_attached_to_committed_todos() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp_dir.path()).unwrap();
+        fs::write(temp_dir.path().join("jobs.py"), "# TODO: retry failed jobs\ndef run():\n    pass\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("jobs.py")).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::new("Ada Lovelace", "ada@example.com", &git2::Time::new(1_577_836_800, 0)).unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "Add jobs", &tree, &[]).unwrap();
+
+        // The second TODO only exists in the working tree
+        fs::write(temp_dir.path().join("jobs.py"), "# TODO: retry failed jobs\ndef run():\n    pass\n# FIXME: log failures\n").unwrap();
+
+        let scanner = TodoScanner::with_config(TodoScanConfig { git_blame: true, ..TodoScanConfig::default() }).unwrap();
+        let mut result = scanner.scan_project(temp_dir.path()).unwrap();
+        result.todo_items.sort_by_key(|item| item.line_number);
+
+        let committed = result.todo_items[0].blame.as_ref().unwrap();
+        assert_eq!(committed.author.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(committed.author_email.as_deref(), Some("ada@example.com"));
+        assert_eq!(committed.commit_date.as_deref(), Some("2020-01-01T00:00:00+00:00"));
+        assert!(committed.commit_id.is_some());
+
+        let uncommitted = result.todo_items[1].blame.as_ref().unwrap();
+        assert!(uncommitted.commit_id.is_none() && uncommitted.author.is_none());
+    }
+
     #[test]
     fn test_file_type_detection() {
         let scanner = TodoScanner::new().unwrap();
@@ -60,6 +60,18 @@ pub enum LanguageEcosystem {
 }
 
 impl Framework {
+    /// Framework whose display name is `name`, as analyzers report it in `DetectedFramework::name`
+    pub fn from_display_name(name: &str) -> Option<Framework> {
+        [
+            Framework::React, Framework::NextJS, Framework::NestJS, Framework::Express, Framework::Vue,
+            Framework::Angular, Framework::Flask, Framework::FastAPI, Framework::Django,
+            Framework::SpringBoot, Framework::Quarkus, Framework::Danet, Framework::Axum,
+            Framework::Warp, Framework::Actix, Framework::Gin, Framework::Fiber,
+        ]
+        .into_iter()
+        .find(|framework| framework.to_string() == name)
+    }
+
     pub fn language(&self) -> Language {
         match self {
             Framework::React | Framework::NextJS | Framework::NestJS | 
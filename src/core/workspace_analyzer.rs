@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    AnalysisMetadata, BusinessContext, CodebaseAnalysis, CodebaseAnalyzer, ComponentRelationship,
+    DetectedFramework, FrameworkAnalysis, ImplementationAnalysis, IntegrationPoints, InternalDependency,
+    ProductRequirementDocument, ProjectType, StatusIntelligence,
+};
+use crate::core::framework_detector::{EnhancedDetectedFramework, FrameworkDetector};
+use crate::core::workspace_detector::{WorkspaceDependency, WorkspaceLayout, WorkspacePackage, WorkspaceTool};
+use crate::core::project_walker::ProjectWalker;
+
+/// Per-package results of a workspace analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageAnalysis {
+    pub package: WorkspacePackage,
+    pub frameworks: Vec<EnhancedDetectedFramework>,
+    /// `None` when no analyzer handles the package's language
+    pub analysis: Option<CodebaseAnalysis>,
+}
+
+/// Workspace section of a workspace-level `CodebaseAnalysis`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceBreakdown {
+    pub tools: Vec<WorkspaceTool>,
+    pub packages: Vec<PackageAnalysis>,
+    pub dependency_edges: Vec<WorkspaceDependency>,
+}
+
+/// Runs framework detection, domain inference and status analysis per workspace package and
+/// combines the results into one workspace-level `CodebaseAnalysis`, keeping the per-package
+/// breakdown and the inter-package dependency edges
+pub struct WorkspaceAnalyzer {
+    analyzers: Vec<Box<dyn CodebaseAnalyzer>>,
+}
+
+impl WorkspaceAnalyzer {
+    pub fn new(analyzers: Vec<Box<dyn CodebaseAnalyzer>>) -> Self {
+        Self { analyzers }
+    }
+
+    pub fn analyze(&self, layout: &WorkspaceLayout) -> Result<CodebaseAnalysis> {
+        let packages = layout.packages.iter()
+            .map(|package| self.analyze_package(layout, package))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(self.combine(layout, packages))
+    }
+
+    fn analyze_package(&self, layout: &WorkspaceLayout, package: &WorkspacePackage) -> Result<PackageAnalysis> {
+        let package_dir = layout.package_dir(package);
+
+        let frameworks = FrameworkDetector::detect_package(layout, package)
+            .map(|detection| detection.detected_frameworks)
+            .unwrap_or_default();
+
+        let analysis = match self.select_analyzer(&package_dir) {
+            Some(analyzer) => {
+                let mut analysis = analyzer.analyze(&package_dir.to_string_lossy())?;
+                analysis.project_name = package.name.clone();
+                Some(analysis)
+            }
+            None => None,
+        };
+
+        Ok(PackageAnalysis { package: package.clone(), frameworks, analysis })
+    }
+
+    /// The analyzer that claims the package, otherwise the one whose extensions cover the most files
+    fn select_analyzer(&self, package_dir: &Path) -> Option<&dyn CodebaseAnalyzer> {
+        let path = package_dir.to_string_lossy();
+        if let Some(analyzer) = self.analyzers.iter().find(|analyzer| analyzer.can_analyze(&path)) {
+            return Some(analyzer.as_ref());
+        }
+
+        let mut extension_counts: HashMap<String, usize> = HashMap::new();
+        for path in ProjectWalker::new(package_dir).files() {
+            if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+                *extension_counts.entry(extension.to_string()).or_default() += 1;
+            }
+        }
+
+        self.analyzers.iter()
+            .map(|analyzer| {
+                let files: usize = analyzer.supported_extensions().iter()
+                    .map(|extension| extension_counts.get(*extension).copied().unwrap_or(0))
+                    .sum();
+                (analyzer, files)
+            })
+            .filter(|(_, files)| *files > 0)
+            .max_by_key(|(_, files)| *files)
+            .map(|(analyzer, _)| analyzer.as_ref())
+    }
+
+    fn combine(&self, layout: &WorkspaceLayout, packages: Vec<PackageAnalysis>) -> CodebaseAnalysis {
+        let project_name = layout.root.canonicalize().unwrap_or_else(|_| layout.root.clone())
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "workspace".to_string());
+
+        let analyses: Vec<(&WorkspacePackage, CodebaseAnalysis)> = packages.iter()
+            .filter_map(|p| p.analysis.clone().map(|analysis| (&p.package, scope_ids(analysis, &p.package.name))))
+            .collect();
+
+        // Package weight in blended scores is its share of the workspace's code
+        let total_lines: u32 = analyses.iter().map(|(_, a)| a.analysis_metadata.lines_of_code).sum();
+        let weight = |analysis: &CodebaseAnalysis| -> f32 {
+            if total_lines == 0 {
+                1.0 / analyses.len().max(1) as f32
+            } else {
+                analysis.analysis_metadata.lines_of_code as f32 / total_lines as f32
+            }
+        };
+
+        let mut warnings: Vec<String> = packages.iter()
+            .filter(|p| p.analysis.is_none())
+            .map(|p| format!("No analyzer for package '{}' ({})", p.package.name, p.package.path.display()))
+            .collect();
+
+        let mut combined = CodebaseAnalysis {
+            project_name: project_name.clone(),
+            project_type: dominant_project_type(&analyses),
+            components: Vec::new(),
+            user_stories: Vec::new(),
+            prd: ProductRequirementDocument {
+                title: format!("{} Workspace", project_name),
+                overview: workspace_overview(layout, &analyses),
+                objectives: Vec::new(),
+                target_users: Vec::new(),
+                features: Vec::new(),
+                technical_requirements: Vec::new(),
+                business_context: String::new(),
+                feature_flags: Vec::new(),
+            },
+            tasks: Vec::new(),
+            analysis_metadata: AnalysisMetadata {
+                analyzed_at: chrono::Utc::now().to_rfc3339(),
+                analyzer_version: "0.1.0".to_string(),
+                files_analyzed: 0,
+                lines_of_code: total_lines,
+                confidence_score: 0.0,
+                warnings: Vec::new(),
+            },
+            framework_analysis: FrameworkAnalysis {
+                detected_frameworks: Vec::new(),
+                confidence_scores: HashMap::new(),
+                architecture_pattern: format!("Monorepo ({})", layout.tools.iter().map(|t| format!("{:?}", t)).collect::<Vec<_>>().join(", ")),
+            },
+            business_context: BusinessContext {
+                inferred_product_type: "Unknown".to_string(),
+                confidence: 0.0,
+                evidence: Vec::new(),
+                primary_user_personas: Vec::new(),
+                user_journeys_discovered: Vec::new(),
+                business_domain: "Unknown".to_string(),
+            },
+            implementation_analysis: ImplementationAnalysis {
+                api_endpoints: Vec::new(),
+                database_entities: Vec::new(),
+                component_relationships: Vec::new(),
+                data_flow: Vec::new(),
+            },
+            status_intelligence: StatusIntelligence {
+                completed_features: Vec::new(),
+                in_progress_features: Vec::new(),
+                todo_features: Vec::new(),
+                technical_debt: Vec::new(),
+                overall_completion_percentage: 0.0,
+            },
+            integration_points: IntegrationPoints {
+                external_services: Vec::new(),
+                internal_dependencies: Vec::new(),
+                configuration_files: Vec::new(),
+                environment_variables: Vec::new(),
+            },
+            workspace: None,
+        };
+
+        let mut domains: Vec<String> = Vec::new();
+        let mut strongest_context: Option<&BusinessContext> = None;
+
+        for (package, analysis) in &analyses {
+            let share = weight(analysis);
+
+            combined.components.extend(analysis.components.iter().cloned());
+            combined.user_stories.extend(analysis.user_stories.iter().cloned());
+            combined.tasks.extend(analysis.tasks.iter().cloned());
+
+            let prd = &mut combined.prd;
+            prd.features.extend(analysis.prd.features.iter().cloned());
+            push_all_unique(&mut prd.objectives, &analysis.prd.objectives);
+            push_all_unique(&mut prd.target_users, &analysis.prd.target_users);
+            push_all_unique(&mut prd.technical_requirements, &analysis.prd.technical_requirements);
+            prd.feature_flags.extend(analysis.prd.feature_flags.iter().cloned());
+
+            let metadata = &mut combined.analysis_metadata;
+            metadata.files_analyzed += analysis.analysis_metadata.files_analyzed;
+            metadata.confidence_score += analysis.analysis_metadata.confidence_score * share;
+            warnings.extend(analysis.analysis_metadata.warnings.iter().map(|w| format!("{}: {}", package.name, w)));
+
+            merge_frameworks(&mut combined.framework_analysis, &analysis.framework_analysis);
+
+            let context = &analysis.business_context;
+            combined.business_context.confidence += context.confidence * share;
+            combined.business_context.evidence.push(format!(
+                "{}: {} ({:.0}% confidence)", package.name, context.inferred_product_type, context.confidence * 100.0
+            ));
+            push_all_unique(&mut combined.business_context.primary_user_personas, &context.primary_user_personas);
+            push_all_unique(&mut combined.business_context.user_journeys_discovered, &context.user_journeys_discovered);
+            push_all_unique(&mut domains, std::slice::from_ref(&context.business_domain));
+            if strongest_context.is_none_or(|strongest| context.confidence > strongest.confidence) {
+                strongest_context = Some(context);
+            }
+
+            let implementation = &mut combined.implementation_analysis;
+            implementation.api_endpoints.extend(analysis.implementation_analysis.api_endpoints.iter().cloned());
+            implementation.database_entities.extend(analysis.implementation_analysis.database_entities.iter().cloned());
+            implementation.component_relationships.extend(analysis.implementation_analysis.component_relationships.iter().cloned());
+            implementation.data_flow.extend(analysis.implementation_analysis.data_flow.iter().cloned());
+
+            let status = &mut combined.status_intelligence;
+            status.completed_features.extend(analysis.status_intelligence.completed_features.iter().cloned());
+            status.in_progress_features.extend(analysis.status_intelligence.in_progress_features.iter().cloned());
+            status.todo_features.extend(analysis.status_intelligence.todo_features.iter().cloned());
+            status.technical_debt.extend(analysis.status_intelligence.technical_debt.iter().cloned());
+            status.overall_completion_percentage += analysis.status_intelligence.overall_completion_percentage * share;
+
+            let integrations = &mut combined.integration_points;
+            for service in &analysis.integration_points.external_services {
+                if !integrations.external_services.iter().any(|s| s.name == service.name) {
+                    integrations.external_services.push(service.clone());
+                }
+            }
+            integrations.internal_dependencies.extend(analysis.integration_points.internal_dependencies.iter().cloned());
+            integrations.configuration_files.extend(analysis.integration_points.configuration_files.iter().cloned());
+            push_all_unique(&mut integrations.environment_variables, &analysis.integration_points.environment_variables);
+
+            combined.prd.business_context.push_str(&format!("{}: {}\n", package.name, analysis.prd.business_context));
+        }
+
+        if let Some(strongest) = strongest_context {
+            combined.business_context.inferred_product_type = strongest.inferred_product_type.clone();
+        }
+        if !domains.is_empty() {
+            combined.business_context.business_domain = domains.join(", ");
+        }
+
+        // Inter-package edges appear wherever the single-project result models dependencies
+        for edge in &layout.dependency_edges {
+            combined.implementation_analysis.component_relationships.push(ComponentRelationship {
+                source: edge.from.clone(),
+                target: edge.to.clone(),
+                relationship_type: "workspace_dependency".to_string(),
+                description: format!("{} depends on {} ({:?})", edge.from, edge.to, edge.kind),
+            });
+        }
+        for package in &layout.packages {
+            let dependents: Vec<String> = layout.dependents_of(&package.name).iter().map(|edge| edge.from.clone()).collect();
+            if !dependents.is_empty() {
+                combined.integration_points.internal_dependencies.push(InternalDependency {
+                    name: package.name.clone(),
+                    dependency_type: "workspace_package".to_string(),
+                    usage_locations: dependents,
+                });
+            }
+        }
+
+        combined.analysis_metadata.warnings = warnings;
+        combined.workspace = Some(WorkspaceBreakdown {
+            tools: layout.tools.clone(),
+            packages,
+            dependency_edges: layout.dependency_edges.clone(),
+        });
+        combined
+    }
+}
+
+/// Prefix story and task IDs with the package name so IDs from different packages cannot collide
+fn scope_ids(mut analysis: CodebaseAnalysis, package: &str) -> CodebaseAnalysis {
+    let scoped = |id: &str| format!("{}:{}", package, id);
+
+    for story in &mut analysis.user_stories {
+        story.id = scoped(&story.id);
+    }
+    for task in &mut analysis.tasks {
+        task.id = scoped(&task.id);
+        task.dependencies = task.dependencies.iter().map(|id| scoped(id)).collect();
+    }
+    for feature in &mut analysis.prd.features {
+        feature.related_stories = feature.related_stories.iter().map(|id| scoped(id)).collect();
+    }
+
+    analysis
+}
+
+/// The project type most packages share, ignoring packages the analyzers could not classify
+fn dominant_project_type(analyses: &[(&WorkspacePackage, CodebaseAnalysis)]) -> ProjectType {
+    let mut counts: Vec<(ProjectType, usize)> = Vec::new();
+    for (_, analysis) in analyses {
+        if analysis.project_type == ProjectType::Unknown {
+            continue;
+        }
+        match counts.iter_mut().find(|(project_type, _)| *project_type == analysis.project_type) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((analysis.project_type.clone(), 1)),
+        }
+    }
+
+    counts.into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(project_type, _)| project_type)
+        .unwrap_or(ProjectType::Unknown)
+}
+
+fn workspace_overview(layout: &WorkspaceLayout, analyses: &[(&WorkspacePackage, CodebaseAnalysis)]) -> String {
+    let mut overview = format!("Monorepo with {} packages:", layout.packages.len());
+    for package in &layout.packages {
+        let project_type = analyses.iter()
+            .find(|(p, _)| p.name == package.name)
+            .map(|(_, analysis)| format!("{:?}", analysis.project_type))
+            .unwrap_or_else(|| "not analyzed".to_string());
+        overview.push_str(&format!("\n- {} ({}): {}", package.name, package.path.display(), project_type));
+    }
+    overview
+}
+
+/// One entry per framework name, keeping the highest confidence seen in any package
+fn merge_frameworks(combined: &mut FrameworkAnalysis, package: &FrameworkAnalysis) {
+    for framework in &package.detected_frameworks {
+        match combined.detected_frameworks.iter_mut().find(|f: &&mut DetectedFramework| f.name == framework.name) {
+            Some(existing) if existing.confidence < framework.confidence => *existing = framework.clone(),
+            Some(_) => {}
+            None => combined.detected_frameworks.push(framework.clone()),
+        }
+    }
+    for (name, score) in &package.confidence_scores {
+        let entry = combined.confidence_scores.entry(name.clone()).or_insert(0.0);
+        *entry = entry.max(*score);
+    }
+}
+
+fn push_all_unique(target: &mut Vec<String>, values: &[String]) {
+    for value in values {
+        if !target.contains(value) {
+            target.push(value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::TypeScriptAnalyzer;
+    use crate::core::AnalyzerConfig;
+    use crate::core::workspace_detector::WorkspaceDetector;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_workspace_analysis_keeps_package_breakdown() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |file: &str, content: &str| {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("package.json", r#"{"name": "shop", "workspaces": ["packages/*"]}"#);
+        write("packages/web/package.json", r#"{"name": "web", "dependencies": {"react": "^18.2.0", "ui": "*"}}"#);
+        write("packages/web/src/App.tsx", "import React from 'react';\nexport default function App() { return <div />; }\n");
+        write("packages/ui/package.json", r#"{"name": "ui", "dependencies": {"react": "^18.2.0"}}"#);
+        write("packages/ui/src/Button.tsx", "import React from 'react';\nexport function Button() { return <button />; }\n");
+
+        let layout = WorkspaceDetector::new(root).detect().unwrap();
+        let analyzer = WorkspaceAnalyzer::new(vec![Box::new(TypeScriptAnalyzer::new(AnalyzerConfig::default()))]);
+        let analysis = analyzer.analyze(&layout).unwrap();
+
+        let workspace = analysis.workspace.as_ref().unwrap();
+        assert_eq!(workspace.packages.len(), 2);
+        assert!(workspace.packages.iter().all(|p| p.analysis.is_some()));
+        assert_eq!(workspace.dependency_edges.len(), 1);
+        assert_eq!(analysis.project_type, ProjectType::React);
+        assert_eq!(analysis.analysis_metadata.files_analyzed, 2);
+        assert!(analysis.implementation_analysis.component_relationships.iter()
+            .any(|r| r.relationship_type == "workspace_dependency" && r.source == "web" && r.target == "ui"));
+        assert!(analysis.user_stories.iter().all(|story| story.id.starts_with("web:") || story.id.starts_with("ui:")));
+    }
+}
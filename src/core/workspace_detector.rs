@@ -0,0 +1,813 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::core::framework_rules::parse_manifest;
+use crate::core::project_walker::{ProjectWalker, BUILD_OUTPUT_DIRS, SKIPPED_DIRS};
+
+/// Directories that are not Python namespace packages even without an `__init__.py`
+const NON_NAMESPACE_DIRS: [&str; 8] = ["tests", "test", "docs", "scripts", "examples", "benchmarks", "tools", "migrations"];
+
+/// How far below the root nested Python distributions are searched for
+const PYTHON_SEARCH_DEPTH: usize = 3;
+
+/// Members found by one tool-specific detector
+type DetectedMembers = (WorkspaceTool, Vec<WorkspacePackage>);
+
+/// Tool that declares the workspace members
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WorkspaceTool {
+    NpmWorkspaces,
+    YarnWorkspaces,
+    PnpmWorkspaces,
+    Nx,
+    Turborepo,
+    Cargo,
+    Maven,
+    Gradle,
+    /// Several Python distributions (`pyproject.toml` / `setup.py`) in one repository, or `[tool.uv.workspace]`
+    PythonProjects,
+    /// PEP 420 namespace packages: portions of one namespace without an `__init__.py`
+    PythonNamespace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WorkspaceDependencyKind {
+    Runtime,
+    Development,
+    /// Declared to the build tool without a manifest dependency (Nx `implicitDependencies`)
+    Implicit,
+    /// Found through source imports (Python namespace portions)
+    Import,
+}
+
+/// One package/module/crate of a workspace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspacePackage {
+    /// Name other members use to depend on it: npm/Cargo/Python package name, Maven artifactId,
+    /// Gradle project path (`:services:api`) or dotted namespace portion (`acme.billing`)
+    pub name: String,
+    /// Directory relative to the workspace root; `.` for a root package
+    pub path: PathBuf,
+    pub tool: WorkspaceTool,
+    /// Manifest file name inside `path`, when the member has one
+    pub manifest: Option<String>,
+}
+
+/// `from` depends on `to`; both are package names
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WorkspaceDependency {
+    pub from: String,
+    pub to: String,
+    pub kind: WorkspaceDependencyKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceLayout {
+    pub root: PathBuf,
+    pub tools: Vec<WorkspaceTool>,
+    pub packages: Vec<WorkspacePackage>,
+    pub dependency_edges: Vec<WorkspaceDependency>,
+}
+
+impl WorkspaceLayout {
+    /// A layout only counts as a monorepo when it splits the tree into more than one package
+    pub fn is_monorepo(&self) -> bool {
+        self.packages.len() > 1
+    }
+
+    pub fn package(&self, name: &str) -> Option<&WorkspacePackage> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+
+    pub fn package_dir(&self, package: &WorkspacePackage) -> PathBuf {
+        self.root.join(&package.path)
+    }
+
+    /// Packages `name` depends on
+    pub fn dependencies_of(&self, name: &str) -> Vec<&WorkspaceDependency> {
+        self.dependency_edges.iter().filter(|edge| edge.from == name).collect()
+    }
+
+    /// Packages that depend on `name`
+    pub fn dependents_of(&self, name: &str) -> Vec<&WorkspaceDependency> {
+        self.dependency_edges.iter().filter(|edge| edge.to == name).collect()
+    }
+}
+
+/// Detects monorepo/workspace layouts: npm, Yarn and pnpm workspaces, Nx and Turborepo,
+/// Cargo workspaces, Maven multi-module builds, Gradle `settings.gradle` includes and
+/// Python multi-project or namespace-package repositories
+pub struct WorkspaceDetector {
+    root: PathBuf,
+}
+
+impl WorkspaceDetector {
+    pub fn new(root: &Path) -> Self {
+        Self { root: root.to_path_buf() }
+    }
+
+    /// The workspace layout of the root, or `None` when the root is a single project
+    pub fn detect(&self) -> Option<WorkspaceLayout> {
+        let mut tools = Vec::new();
+        let mut packages: Vec<WorkspacePackage> = Vec::new();
+
+        let detectors: [fn(&Self) -> Option<DetectedMembers>; 6] = [
+            Self::detect_js_workspaces,
+            Self::detect_nx_projects,
+            Self::detect_cargo_workspace,
+            Self::detect_maven_modules,
+            Self::detect_gradle_projects,
+            Self::detect_python_projects,
+        ];
+        for detector in detectors {
+            if let Some((tool, members)) = detector(self) {
+                tools.push(tool);
+                for member in members {
+                    // The same directory can be declared twice (npm workspace that is also an Nx project)
+                    if !packages.iter().any(|existing| existing.path == member.path && existing.name == member.name) {
+                        packages.push(member);
+                    }
+                }
+            }
+        }
+
+        // Turborepo orchestrates the package manager's workspaces rather than declaring members
+        if self.root.join("turbo.json").exists() && !packages.is_empty() {
+            tools.push(WorkspaceTool::Turborepo);
+        }
+
+        if packages.is_empty() {
+            return None;
+        }
+
+        packages.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.name.cmp(&b.name)));
+        let dependency_edges = self.dependency_edges(&packages);
+
+        Some(WorkspaceLayout {
+            root: self.root.clone(),
+            tools,
+            packages,
+            dependency_edges,
+        })
+    }
+
+    /// `workspaces` in package.json (array or `{ "packages": [...] }`), or pnpm-workspace.yaml
+    fn detect_js_workspaces(&self) -> Option<DetectedMembers> {
+        let (tool, patterns) = if let Some(patterns) = self.pnpm_workspace_patterns() {
+            (WorkspaceTool::PnpmWorkspaces, patterns)
+        } else {
+            let package_json = read_json(&self.root.join("package.json"))?;
+            let workspaces = package_json.get("workspaces")?;
+            let patterns = workspaces.as_array()
+                .or_else(|| workspaces.get("packages").and_then(|p| p.as_array()))?
+                .iter()
+                .filter_map(|p| p.as_str().map(|p| p.to_string()))
+                .collect();
+            let uses_yarn = self.root.join("yarn.lock").exists() ||
+                package_json.get("packageManager").and_then(|p| p.as_str()).is_some_and(|p| p.starts_with("yarn"));
+            (if uses_yarn { WorkspaceTool::YarnWorkspaces } else { WorkspaceTool::NpmWorkspaces }, patterns)
+        };
+
+        let members = self.expand_patterns(&patterns)
+            .into_iter()
+            .filter_map(|dir| {
+                let package_json = read_json(&self.root.join(&dir).join("package.json"))?;
+                Some(WorkspacePackage {
+                    name: json_name(&package_json).unwrap_or_else(|| dir_name(&dir)),
+                    path: dir,
+                    tool,
+                    manifest: Some("package.json".to_string()),
+                })
+            })
+            .collect();
+
+        Some((tool, members))
+    }
+
+    fn pnpm_workspace_patterns(&self) -> Option<Vec<String>> {
+        let content = fs::read_to_string(self.root.join("pnpm-workspace.yaml")).ok()?;
+        let workspace: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
+        Some(workspace.get("packages")?
+            .as_sequence()?
+            .iter()
+            .filter_map(|p| p.as_str().map(|p| p.to_string()))
+            .collect())
+    }
+
+    /// Nx projects are directories with a `project.json`
+    fn detect_nx_projects(&self) -> Option<DetectedMembers> {
+        if !self.root.join("nx.json").exists() {
+            return None;
+        }
+
+        let members = self.walk_dirs(usize::MAX)
+            .into_iter()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .filter_map(|dir| {
+                let project = read_json(&self.root.join(&dir).join("project.json"))?;
+                // An Nx project backed by a package.json keeps its npm name so dependency edges line up
+                let name = read_json(&self.root.join(&dir).join("package.json"))
+                    .and_then(|package_json| json_name(&package_json))
+                    .or_else(|| json_name(&project))
+                    .unwrap_or_else(|| dir_name(&dir));
+                Some(WorkspacePackage { name, path: dir, tool: WorkspaceTool::Nx, manifest: Some("project.json".to_string()) })
+            })
+            .collect();
+
+        Some((WorkspaceTool::Nx, members))
+    }
+
+    /// `[workspace] members` / `exclude` in the root Cargo.toml; a root `[package]` is a member too
+    fn detect_cargo_workspace(&self) -> Option<DetectedMembers> {
+        let manifest = read_toml(&self.root.join("Cargo.toml"))?;
+        let workspace = manifest.get("workspace")?;
+        let string_list = |key: &str| -> Vec<String> {
+            workspace.get(key).and_then(|v| v.as_array()).into_iter().flatten()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        };
+
+        let excluded: HashSet<PathBuf> = self.expand_patterns(&string_list("exclude")).into_iter().collect();
+        let mut dirs: Vec<PathBuf> = self.expand_patterns(&string_list("members"))
+            .into_iter()
+            .filter(|dir| !excluded.contains(dir))
+            .collect();
+        if manifest.get("package").is_some() {
+            dirs.insert(0, PathBuf::from("."));
+        }
+
+        let members = dirs.into_iter()
+            .filter_map(|dir| {
+                let member = read_toml(&self.root.join(&dir).join("Cargo.toml"))?;
+                let name = member.get("package")?.get("name")?.as_str()?.to_string();
+                Some(WorkspacePackage { name, path: dir, tool: WorkspaceTool::Cargo, manifest: Some("Cargo.toml".to_string()) })
+            })
+            .collect();
+
+        Some((WorkspaceTool::Cargo, members))
+    }
+
+    /// `<modules>` of the root pom.xml, following nested aggregator poms down to the leaf modules
+    fn detect_maven_modules(&self) -> Option<DetectedMembers> {
+        let root_pom = fs::read_to_string(self.root.join("pom.xml")).ok()?;
+        let root_modules = pom_modules(&root_pom);
+        if root_modules.is_empty() {
+            return None;
+        }
+
+        let mut members = Vec::new();
+        let mut pending: Vec<PathBuf> = root_modules.into_iter().map(PathBuf::from).collect();
+        let mut visited = HashSet::new();
+
+        while let Some(dir) = pending.pop() {
+            let dir = normalize(&dir);
+            if !visited.insert(dir.clone()) {
+                continue;
+            }
+            let Ok(pom) = fs::read_to_string(self.root.join(&dir).join("pom.xml")) else {
+                continue;
+            };
+
+            let nested = pom_modules(&pom);
+            if nested.is_empty() {
+                members.push(WorkspacePackage {
+                    name: pom_artifact_id(&pom).unwrap_or_else(|| dir_name(&dir)),
+                    path: dir,
+                    tool: WorkspaceTool::Maven,
+                    manifest: Some("pom.xml".to_string()),
+                });
+            } else {
+                pending.extend(nested.into_iter().map(|module| dir.join(module)));
+            }
+        }
+
+        Some((WorkspaceTool::Maven, members))
+    }
+
+    /// `include` statements of settings.gradle(.kts); `:services:api` lives in `services/api`
+    fn detect_gradle_projects(&self) -> Option<DetectedMembers> {
+        let settings = fs::read_to_string(self.root.join("settings.gradle"))
+            .or_else(|_| fs::read_to_string(self.root.join("settings.gradle.kts")))
+            .ok()?;
+
+        let include = Regex::new(r"(?m)^\s*include\b\s*\(?([^\n]*)").unwrap();
+        let quoted = Regex::new(r#"["']([^"']+)["']"#).unwrap();
+        let project_dir = Regex::new(r#"project\(\s*["']([^"']+)["']\s*\)\.projectDir\s*=\s*(?:new\s+File\s*\(|file\s*\()\s*(?:settingsDir\s*,\s*|rootDir\s*,\s*)?["']([^"']+)["']"#).unwrap();
+
+        let overrides: HashMap<String, PathBuf> = project_dir.captures_iter(&settings)
+            .map(|c| (gradle_path(&c[1]), PathBuf::from(&c[2])))
+            .collect();
+
+        let members = include.captures_iter(&settings)
+            .flat_map(|c| quoted.captures_iter(&c[1]).map(|q| gradle_path(&q[1])).collect::<Vec<_>>())
+            .map(|project_path| {
+                let dir = overrides.get(&project_path).cloned()
+                    .unwrap_or_else(|| project_path.trim_start_matches(':').split(':').collect::<PathBuf>());
+                let manifest = ["build.gradle", "build.gradle.kts"].into_iter()
+                    .find(|file| self.root.join(&dir).join(file).exists())
+                    .map(|file| file.to_string());
+                WorkspacePackage { name: project_path, path: dir, tool: WorkspaceTool::Gradle, manifest }
+            })
+            .collect::<Vec<_>>();
+
+        (!members.is_empty()).then_some((WorkspaceTool::Gradle, members))
+    }
+
+    /// `[tool.uv.workspace] members`, otherwise nested distributions, otherwise namespace portions
+    fn detect_python_projects(&self) -> Option<DetectedMembers> {
+        let root_pyproject = read_toml(&self.root.join("pyproject.toml"));
+
+        let distribution_dirs: Vec<PathBuf> = match root_pyproject.as_ref()
+            .and_then(|p| p.get("tool")?.get("uv")?.get("workspace")?.get("members")?.as_array().cloned())
+        {
+            Some(patterns) => {
+                let patterns: Vec<String> = patterns.iter().filter_map(|p| p.as_str().map(|s| s.to_string())).collect();
+                self.expand_patterns(&patterns)
+            }
+            None => self.walk_dirs(PYTHON_SEARCH_DEPTH)
+                .into_iter()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .filter(|dir| python_manifest(&self.root.join(dir)).is_some())
+                .collect(),
+        };
+
+        let distributions: Vec<WorkspacePackage> = distribution_dirs.into_iter()
+            .filter_map(|dir| {
+                let manifest = python_manifest(&self.root.join(&dir))?;
+                Some(WorkspacePackage {
+                    name: python_distribution_name(&self.root.join(&dir)).unwrap_or_else(|| dir_name(&dir)),
+                    path: dir,
+                    tool: WorkspaceTool::PythonProjects,
+                    manifest: Some(manifest.to_string()),
+                })
+            })
+            .collect();
+
+        // Distributions that install into a shared namespace (`src/acme/billing` with no `src/acme/__init__.py`)
+        // are still reported as distributions, tagged as namespace packages
+        if distributions.len() > 1 {
+            let shares_namespace = distributions.iter().any(|d| !namespace_portions(&self.root.join(&d.path)).is_empty());
+            let tool = if shares_namespace { WorkspaceTool::PythonNamespace } else { WorkspaceTool::PythonProjects };
+            let members = distributions.into_iter().map(|d| WorkspacePackage { tool, ..d }).collect();
+            return Some((tool, members));
+        }
+
+        // A single project whose code is split into namespace portions
+        let portions: Vec<WorkspacePackage> = namespace_portions(&self.root).into_iter()
+            .map(|(name, dir)| WorkspacePackage { name, path: dir, tool: WorkspaceTool::PythonNamespace, manifest: None })
+            .collect();
+        (portions.len() > 1).then_some((WorkspaceTool::PythonNamespace, portions))
+    }
+
+    /// Expand workspace globs (`packages/*`, `apps/**`, `libs/ui`) to member directories that exist;
+    /// `!pattern` entries remove matches
+    fn expand_patterns(&self, patterns: &[String]) -> Vec<PathBuf> {
+        let mut included: Vec<PathBuf> = Vec::new();
+        let mut excluded: HashSet<PathBuf> = HashSet::new();
+
+        for pattern in patterns {
+            let (negated, pattern) = match pattern.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, pattern.as_str()),
+            };
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            let matches = self.match_dirs(pattern);
+            if negated {
+                excluded.extend(matches);
+            } else {
+                for dir in matches {
+                    if !included.contains(&dir) {
+                        included.push(dir);
+                    }
+                }
+            }
+        }
+
+        included.retain(|dir| !excluded.contains(dir));
+        included
+    }
+
+    fn match_dirs(&self, pattern: &str) -> Vec<PathBuf> {
+        if pattern.is_empty() || pattern == "." {
+            return vec![PathBuf::from(".")];
+        }
+        if !pattern.contains(['*', '?']) {
+            let dir = normalize(Path::new(pattern));
+            return if self.root.join(&dir).is_dir() { vec![dir] } else { Vec::new() };
+        }
+
+        let segments: Vec<&str> = pattern.split('/').collect();
+        let max_depth = if segments.contains(&"**") { usize::MAX } else { segments.len() };
+        self.walk_dirs(max_depth)
+            .into_iter()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .filter(|dir| {
+                let components: Vec<String> = dir.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect();
+                segments_match(&segments, &components)
+            })
+            .collect()
+    }
+
+    /// Directories under the root (relative, root itself as an empty path), skipping ignored and hidden ones
+    fn walk_dirs(&self, max_depth: usize) -> Vec<PathBuf> {
+        ProjectWalker::new(&self.root)
+            .max_depth(max_depth)
+            .include_generated(true)
+            .entries()
+            .filter(|entry| entry.is_dir)
+            .filter_map(|entry| entry.path.strip_prefix(&self.root).ok().map(Path::to_path_buf))
+            .filter(|dir| !dir.components().any(|c| c.as_os_str().to_string_lossy().starts_with('.')))
+            .collect()
+    }
+
+    fn dependency_edges(&self, packages: &[WorkspacePackage]) -> Vec<WorkspaceDependency> {
+        let by_name: HashMap<String, &WorkspacePackage> = packages.iter()
+            .map(|package| (normalize_package_name(&package.name), package))
+            .collect();
+        let mut edges: BTreeMap<(String, String), WorkspaceDependencyKind> = BTreeMap::new();
+
+        for package in packages {
+            let dir = self.root.join(&package.path);
+            for (dependency, kind) in self.declared_dependencies(package, &dir) {
+                let Some(target) = by_name.get(&normalize_package_name(&dependency)) else {
+                    continue;
+                };
+                if target.name == package.name {
+                    continue;
+                }
+                // Runtime wins over development when both declare the same edge
+                edges.entry((package.name.clone(), target.name.clone()))
+                    .and_modify(|existing| if kind == WorkspaceDependencyKind::Runtime { *existing = kind })
+                    .or_insert(kind);
+            }
+        }
+
+        edges.into_iter().map(|((from, to), kind)| WorkspaceDependency { from, to, kind }).collect()
+    }
+
+    /// Dependency names a member declares, in the naming scheme of its tool
+    fn declared_dependencies(&self, package: &WorkspacePackage, dir: &Path) -> Vec<(String, WorkspaceDependencyKind)> {
+        match package.tool {
+            WorkspaceTool::NpmWorkspaces | WorkspaceTool::YarnWorkspaces | WorkspaceTool::PnpmWorkspaces | WorkspaceTool::Turborepo => {
+                npm_dependencies(dir)
+            }
+            WorkspaceTool::Nx => {
+                let mut dependencies = npm_dependencies(dir);
+                if let Some(project) = read_json(&dir.join("project.json")) {
+                    dependencies.extend(project.get("implicitDependencies").and_then(|d| d.as_array()).into_iter().flatten()
+                        .filter_map(|d| d.as_str())
+                        .filter(|d| !d.starts_with('!'))
+                        .map(|d| (d.to_string(), WorkspaceDependencyKind::Implicit)));
+                }
+                dependencies
+            }
+            WorkspaceTool::Cargo => cargo_dependencies(dir),
+            WorkspaceTool::Maven => fs::read_to_string(dir.join("pom.xml"))
+                .map(|pom| maven_dependencies(&pom))
+                .unwrap_or_default(),
+            WorkspaceTool::Gradle => package.manifest.as_ref()
+                .and_then(|manifest| fs::read_to_string(dir.join(manifest)).ok())
+                .map(|build| gradle_dependencies(&build))
+                .unwrap_or_default(),
+            WorkspaceTool::PythonProjects | WorkspaceTool::PythonNamespace => match &package.manifest {
+                Some(manifest) => fs::read_to_string(dir.join(manifest))
+                    .map(|content| parse_manifest(manifest, &content))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, _)| (name, WorkspaceDependencyKind::Runtime))
+                    .collect(),
+                None => python_imports(dir),
+            },
+        }
+    }
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn read_toml(path: &Path) -> Option<toml::Value> {
+    fs::read_to_string(path).ok()?.parse().ok()
+}
+
+fn json_name(json: &serde_json::Value) -> Option<String> {
+    json.get("name").and_then(|n| n.as_str()).map(|n| n.to_string())
+}
+
+fn dir_name(dir: &Path) -> String {
+    dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| ".".to_string())
+}
+
+/// Drop `.` components and resolve `..` so equal directories compare equal
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    if normalized.as_os_str().is_empty() { PathBuf::from(".") } else { normalized }
+}
+
+/// Python treats `-`, `_` and `.` as equivalent and names as case-insensitive (PEP 503)
+fn normalize_package_name(name: &str) -> String {
+    name.to_lowercase().replace(['_', '.'], "-")
+}
+
+/// `*`, `?` and `**` glob segments against path components
+fn segments_match(segments: &[&str], components: &[String]) -> bool {
+    match segments.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => (0..=components.len()).any(|skip| segments_match(rest, &components[skip..])),
+        Some((segment, rest)) => components.split_first()
+            .is_some_and(|(component, remaining)| wildcard_matches(segment, component) && segments_match(rest, remaining)),
+    }
+}
+
+fn wildcard_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn npm_dependencies(dir: &Path) -> Vec<(String, WorkspaceDependencyKind)> {
+    let Some(package_json) = read_json(&dir.join("package.json")) else {
+        return Vec::new();
+    };
+
+    [
+        ("dependencies", WorkspaceDependencyKind::Runtime),
+        ("peerDependencies", WorkspaceDependencyKind::Runtime),
+        ("optionalDependencies", WorkspaceDependencyKind::Runtime),
+        ("devDependencies", WorkspaceDependencyKind::Development),
+    ].into_iter()
+        .filter_map(|(section, kind)| Some((package_json.get(section)?.as_object()?, kind)))
+        .flat_map(|(dependencies, kind)| dependencies.keys().map(move |name| (name.clone(), kind)))
+        .collect()
+}
+
+fn cargo_dependencies(dir: &Path) -> Vec<(String, WorkspaceDependencyKind)> {
+    let Some(manifest) = read_toml(&dir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+
+    [
+        ("dependencies", WorkspaceDependencyKind::Runtime),
+        ("build-dependencies", WorkspaceDependencyKind::Development),
+        ("dev-dependencies", WorkspaceDependencyKind::Development),
+    ].into_iter()
+        .filter_map(|(section, kind)| Some((manifest.get(section)?.as_table()?, kind)))
+        .flat_map(|(dependencies, kind)| dependencies.iter().map(move |(name, spec)| {
+            // `alias = { package = "real-name", path = "../x" }`
+            let name = spec.get("package").and_then(|p| p.as_str()).unwrap_or(name);
+            (name.to_string(), kind)
+        }))
+        .collect()
+}
+
+fn pom_modules(pom: &str) -> Vec<String> {
+    let module = Regex::new(r"<module>\s*([^<\s]+)\s*</module>").unwrap();
+    module.captures_iter(pom).map(|c| c[1].to_string()).collect()
+}
+
+/// The project's own artifactId, ignoring the one inside `<parent>`
+fn pom_artifact_id(pom: &str) -> Option<String> {
+    let parent = Regex::new(r"(?s)<parent>.*?</parent>").unwrap();
+    let without_parent = parent.replace(pom, "");
+    let artifact = Regex::new(r"<artifactId>\s*([^<\s]+)\s*</artifactId>").unwrap();
+    artifact.captures(&without_parent).map(|c| c[1].to_string())
+}
+
+fn maven_dependencies(pom: &str) -> Vec<(String, WorkspaceDependencyKind)> {
+    let dependency = Regex::new(r"(?s)<dependency>(.*?)</dependency>").unwrap();
+    let artifact = Regex::new(r"<artifactId>\s*([^<\s]+)\s*</artifactId>").unwrap();
+    let scope = Regex::new(r"<scope>\s*([^<\s]+)\s*</scope>").unwrap();
+
+    dependency.captures_iter(pom)
+        .filter_map(|c| {
+            let body = c.get(1)?.as_str();
+            let name = artifact.captures(body)?[1].to_string();
+            let kind = match scope.captures(body).map(|s| s[1].to_string()).as_deref() {
+                Some("test") | Some("provided") => WorkspaceDependencyKind::Development,
+                _ => WorkspaceDependencyKind::Runtime,
+            };
+            Some((name, kind))
+        })
+        .collect()
+}
+
+/// `implementation project(':core')`, `testImplementation(project(path: ":fixtures"))`
+fn gradle_dependencies(build: &str) -> Vec<(String, WorkspaceDependencyKind)> {
+    let project = Regex::new(r#"(\w+)\s*\(?\s*project\s*\(\s*(?:path\s*[:=]\s*)?["']([^"']+)["']"#).unwrap();
+    project.captures_iter(build)
+        .map(|c| {
+            let kind = if c[1].starts_with("test") { WorkspaceDependencyKind::Development } else { WorkspaceDependencyKind::Runtime };
+            (gradle_path(&c[2]), kind)
+        })
+        .collect()
+}
+
+/// Gradle project paths are always absolute: `core` and `:core` name the same project
+fn gradle_path(path: &str) -> String {
+    if path.starts_with(':') { path.to_string() } else { format!(":{}", path) }
+}
+
+fn python_manifest(dir: &Path) -> Option<&'static str> {
+    ["pyproject.toml", "setup.py"].into_iter().find(|file| dir.join(file).exists())
+}
+
+fn python_distribution_name(dir: &Path) -> Option<String> {
+    if let Some(pyproject) = read_toml(&dir.join("pyproject.toml")) {
+        let name = pyproject.get("project").and_then(|p| p.get("name"))
+            .or_else(|| pyproject.get("tool")?.get("poetry")?.get("name"));
+        if let Some(name) = name.and_then(|n| n.as_str()) {
+            return Some(name.to_string());
+        }
+    }
+
+    let setup = fs::read_to_string(dir.join("setup.py")).ok()?;
+    let name = Regex::new(r#"name\s*=\s*["']([^"']+)["']"#).unwrap();
+    name.captures(&setup).map(|c| c[1].to_string())
+}
+
+/// PEP 420 portions below `dir` (directly or under `src/`): `acme/billing` with an `__init__.py`
+/// inside a namespace directory `acme` that has none. Returns dotted names with their directories
+fn namespace_portions(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut portions = Vec::new();
+
+    for base in [PathBuf::new(), PathBuf::from("src")] {
+        let Ok(entries) = fs::read_dir(dir.join(&base)) else {
+            continue;
+        };
+        let mut namespaces: Vec<PathBuf> = entries.filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|path| path.is_dir())
+            .filter(|path| path.file_name().and_then(|n| n.to_str()).is_some_and(|name| {
+                !name.starts_with('.') && !SKIPPED_DIRS.contains(&name) && !BUILD_OUTPUT_DIRS.contains(&name) && !NON_NAMESPACE_DIRS.contains(&name) && name != "src"
+            }))
+            .filter(|path| !path.join("__init__.py").exists())
+            .collect();
+        namespaces.sort();
+
+        for namespace in namespaces {
+            // A namespace directory holds only packages, never modules of its own
+            let Ok(children) = fs::read_dir(&namespace) else {
+                continue;
+            };
+            let mut children: Vec<PathBuf> = children.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+            children.sort();
+            if children.iter().any(|child| child.extension().is_some_and(|ext| ext == "py")) {
+                continue;
+            }
+
+            let namespace_name = dir_name(&namespace);
+            for child in children.iter().filter(|child| child.join("__init__.py").exists()) {
+                let relative = child.strip_prefix(dir).unwrap_or(child).to_path_buf();
+                portions.push((format!("{}.{}", namespace_name, dir_name(child)), relative));
+            }
+        }
+    }
+
+    portions
+}
+
+/// `import acme.billing` / `from acme.billing.models import X` in a namespace portion's sources
+fn python_imports(dir: &Path) -> Vec<(String, WorkspaceDependencyKind)> {
+    let import = Regex::new(r"(?m)^\s*(?:from|import)\s+([A-Za-z_][\w]*\.[A-Za-z_][\w]*)").unwrap();
+    let mut imports = HashSet::new();
+
+    let sources = ProjectWalker::new(dir).files()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "py"));
+    for path in sources {
+        if let Ok(content) = fs::read_to_string(&path) {
+            imports.extend(import.captures_iter(&content).map(|c| c[1].to_string()));
+        }
+    }
+
+    let mut imports: Vec<String> = imports.into_iter().collect();
+    imports.sort();
+    imports.into_iter().map(|name| (name, WorkspaceDependencyKind::Import)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, file: &str, content: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_npm_workspaces_with_turbo_and_edges() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, "package.json", r#"{"name": "shop", "private": true, "workspaces": ["apps/*", "packages/*", "!packages/legacy"]}"#);
+        write(root, "turbo.json", "{}");
+        write(root, "apps/web/package.json", r#"{"name": "@shop/web", "dependencies": {"@shop/ui": "*", "react": "^18.2.0"}}"#);
+        write(root, "packages/ui/package.json", r#"{"name": "@shop/ui", "devDependencies": {"@shop/config": "workspace:*"}}"#);
+        write(root, "packages/config/package.json", r#"{"name": "@shop/config"}"#);
+        write(root, "packages/legacy/package.json", r#"{"name": "@shop/legacy"}"#);
+
+        let layout = WorkspaceDetector::new(root).detect().unwrap();
+
+        assert!(layout.is_monorepo());
+        assert_eq!(layout.tools, vec![WorkspaceTool::NpmWorkspaces, WorkspaceTool::Turborepo]);
+        let names: Vec<&str> = layout.packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["@shop/web", "@shop/config", "@shop/ui"]);
+        assert_eq!(layout.dependency_edges, vec![
+            WorkspaceDependency { from: "@shop/ui".to_string(), to: "@shop/config".to_string(), kind: WorkspaceDependencyKind::Development },
+            WorkspaceDependency { from: "@shop/web".to_string(), to: "@shop/ui".to_string(), kind: WorkspaceDependencyKind::Runtime },
+        ]);
+    }
+
+    #[test]
+    fn test_cargo_maven_and_gradle_members() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, "Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/scratch\"]\n");
+        write(root, "crates/core/Cargo.toml", "[package]\nname = \"acme-core\"\n");
+        write(root, "crates/api/Cargo.toml", "[package]\nname = \"acme-api\"\n[dependencies]\nacme-core = { path = \"../core\" }\n");
+        write(root, "crates/scratch/Cargo.toml", "[package]\nname = \"scratch\"\n");
+
+        write(root, "pom.xml", "<project><modules><module>services</module></modules></project>");
+        write(root, "services/pom.xml", "<project><modules><module>billing</module></modules></project>");
+        write(root, "services/billing/pom.xml", "<project><parent><artifactId>services</artifactId></parent><artifactId>billing</artifactId></project>");
+
+        write(root, "settings.gradle", "rootProject.name = 'acme'\ninclude ':app', ':lib:shared'\n");
+        write(root, "app/build.gradle", "dependencies {\n    implementation project(':lib:shared')\n}\n");
+        write(root, "lib/shared/build.gradle", "");
+
+        let layout = WorkspaceDetector::new(root).detect().unwrap();
+
+        assert_eq!(layout.tools, vec![WorkspaceTool::Cargo, WorkspaceTool::Maven, WorkspaceTool::Gradle]);
+        assert!(layout.package("scratch").is_none());
+        assert_eq!(layout.package("billing").unwrap().path, PathBuf::from("services/billing"));
+        assert_eq!(layout.package(":lib:shared").unwrap().path, PathBuf::from("lib/shared"));
+        assert_eq!(layout.dependencies_of("acme-api")[0].to, "acme-core");
+        assert_eq!(layout.dependents_of(":lib:shared")[0].from, ":app");
+    }
+
+    #[test]
+    fn test_pnpm_nx_and_python_namespace() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, "pnpm-workspace.yaml", "packages:\n  - 'libs/**'\n");
+        write(root, "nx.json", "{}");
+        write(root, "libs/data/access/package.json", r#"{"name": "data-access"}"#);
+        write(root, "apps/admin/project.json", r#"{"name": "admin", "implicitDependencies": ["data-access"]}"#);
+
+        let layout = WorkspaceDetector::new(root).detect().unwrap();
+        assert_eq!(layout.tools, vec![WorkspaceTool::PnpmWorkspaces, WorkspaceTool::Nx]);
+        assert_eq!(layout.dependencies_of("admin")[0].kind, WorkspaceDependencyKind::Implicit);
+
+        let python_dir = TempDir::new().unwrap();
+        let root = python_dir.path();
+        write(root, "pyproject.toml", "[project]\nname = \"acme\"\n");
+        write(root, "src/acme/billing/__init__.py", "from acme.shipping import rates\n");
+        write(root, "src/acme/shipping/__init__.py", "");
+        write(root, "tests/unit/__init__.py", "");
+
+        let layout = WorkspaceDetector::new(root).detect().unwrap();
+        assert_eq!(layout.tools, vec![WorkspaceTool::PythonNamespace]);
+        let names: Vec<&str> = layout.packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["acme.billing", "acme.shipping"]);
+        assert_eq!(layout.dependencies_of("acme.billing")[0].kind, WorkspaceDependencyKind::Import);
+    }
+
+    #[test]
+    fn test_single_project_is_not_a_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        write(temp_dir.path(), "package.json", r#"{"name": "app", "dependencies": {"react": "^18.2.0"}}"#);
+        write(temp_dir.path(), "src/App.tsx", "export const App = () => null;");
+
+        assert!(WorkspaceDetector::new(temp_dir.path()).detect().is_none());
+    }
+}
@@ -13,6 +13,7 @@ pub mod claude_spec_context;
 pub mod refined_ccmp_import;
 pub mod refined_claude_spec_context;
 pub mod comprehensive_analysis;
+pub mod sarif;
 
 pub use markdown::MarkdownGenerator;
 pub use prd::PRDGenerator;
@@ -25,6 +26,7 @@ pub use claude_spec_context::ClaudeSpecContextGenerator;
 pub use refined_ccmp_import::RefinedCCMPImportGenerator;
 pub use refined_claude_spec_context::RefinedClaudeSpecContextGenerator;
 pub use comprehensive_analysis::ComprehensiveAnalysisGenerator;
+pub use sarif::SarifGenerator;
 
 pub trait DocumentGenerator {
     fn generate(&self, analysis: &CodebaseAnalysis, intelligent_analysis: Option<&IntelligentAnalysis>) -> Result<String>;
@@ -42,6 +44,7 @@ pub enum DocumentType {
     YamlAnalysis,
     CCPMImport,
     ClaudeSpecContext,
+    Sarif,
 }
 
 pub struct DocumentGeneratorFactory;
@@ -57,6 +60,7 @@ impl DocumentGeneratorFactory {
             DocumentType::YamlAnalysis => Box::new(YamlAnalysisGenerator::new()),
             DocumentType::CCPMImport => Box::new(CCPMImportGenerator::new()),
             DocumentType::ClaudeSpecContext => Box::new(ClaudeSpecContextGenerator::new()),
+            DocumentType::Sarif => Box::new(SarifGenerator::new()),
         }
     }
 
@@ -109,6 +113,7 @@ impl DocumentGeneratorFactory {
             DocumentType::YamlAnalysis => "analysis-output",
             DocumentType::CCPMImport => "ccpm-import",
             DocumentType::ClaudeSpecContext => "claude-spec-context",
+            DocumentType::Sarif => "findings",
         };
         
         let clean_project_name = project_name.to_lowercase()
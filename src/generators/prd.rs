@@ -204,7 +204,29 @@ impl DocumentGenerator for PRDGenerator {
                 content.push_str("\n");
             }
         }
-        
+
+        // Features that only exist behind a flag, and flags left over from finished rollouts
+        let flagged_features: Vec<_> = analysis.prd.feature_flags.iter()
+            .filter(|flag| !flag.flag_only_components.is_empty())
+            .collect();
+        if !flagged_features.is_empty() {
+            content.push_str(&format!("### 5.{} Features Behind Flags\n\n", analysis.prd.features.len() + 1));
+            content.push_str("These features are implemented but only reachable while their flag is on:\n\n");
+            for flag in flagged_features {
+                content.push_str(&format!("- **{}** ({}): {}\n", flag.name, flag.provider, flag.flag_only_components.join(", ")));
+            }
+            content.push('\n');
+        }
+        let stale_flags: Vec<_> = analysis.prd.feature_flags.iter().filter(|flag| flag.likely_stale).collect();
+        if !stale_flags.is_empty() {
+            content.push_str("**Likely Stale Flags**: ");
+            content.push_str(&stale_flags.iter()
+                .map(|flag| format!("{} ({})", flag.name, flag.locations.join(", ")))
+                .collect::<Vec<_>>()
+                .join("; "));
+            content.push_str("\n\n");
+        }
+
         // Technical Requirements
         content.push_str("## 6. Technical Requirements\n\n");
         content.push_str("### 6.1 Core Requirements\n\n");
@@ -0,0 +1,693 @@
+use super::{DocumentGenerator, DocumentType};
+use crate::core::ast_analyzer::{CodeSegment, SegmentMetadata, SegmentType};
+use crate::core::conflict_resolution_engine::{Conflict, ConflictSeverity, ConflictType};
+use crate::core::findings_baseline::{parse_file_location, rule_slug};
+use crate::core::status_inference_engine::{DualCategoryStatusResult, MissingImplementation, PartialImplementation, PlaceholderIndicator};
+use crate::core::todo_scanner::{ActualTodoItem, TodoPriority, TodoScanResult};
+use crate::core::CodebaseAnalysis;
+use crate::intelligence::IntelligentAnalysis;
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const SARIF_VERSION: &str = "2.1.0";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const TOOL_NAME: &str = "codebase-analyzer";
+/// Base id that relative artifact URIs resolve against
+const SOURCE_ROOT_ID: &str = "%SRCROOT%";
+
+/// SARIF 2.1.0 log of TODOs, documentation conflicts, status findings and placeholders
+///
+/// `CodebaseAnalysis` does not carry these findings, so the generator is built with them through
+/// the `with_*` methods before `generate` renders it. Code segments narrow each location to the
+/// enclosing function or class.
+#[derive(Default)]
+pub struct SarifGenerator {
+    source_root: Option<PathBuf>,
+    todos: Vec<ActualTodoItem>,
+    conflicts: Vec<Conflict>,
+    missing_implementations: Vec<MissingImplementation>,
+    partial_implementations: Vec<PartialImplementation>,
+    placeholders: Vec<PlaceholderIndicator>,
+    segments: Vec<(SegmentMetadata, Option<NamedSymbol>)>,
+}
+
+#[derive(Clone)]
+struct NamedSymbol {
+    name: String,
+    kind: &'static str,
+}
+
+impl SarifGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Project root; paths below it are written relative to `%SRCROOT%`
+    pub fn with_source_root(mut self, root: impl AsRef<Path>) -> Self {
+        self.source_root = Some(root.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn with_todos(mut self, scan: &TodoScanResult) -> Self {
+        self.todos.extend(scan.todo_items.iter().cloned());
+        self
+    }
+
+    pub fn with_conflicts(mut self, conflicts: &[Conflict]) -> Self {
+        self.conflicts.extend(conflicts.iter().cloned());
+        self
+    }
+
+    /// Missing and partial implementations and placeholder indicators (TODOs come from `with_todos`)
+    pub fn with_status(mut self, status: &DualCategoryStatusResult) -> Self {
+        self.missing_implementations.extend(status.inferred_status.missing_implementations.iter().cloned());
+        self.partial_implementations.extend(status.inferred_status.partial_implementations.iter().cloned());
+        self.placeholders.extend(status.actual_todo_status.placeholder_indicators.iter().cloned());
+        self
+    }
+
+    pub fn with_segments(mut self, segments: &[CodeSegment]) -> Self {
+        self.segments.extend(segments.iter().map(|segment| (segment.metadata.clone(), segment_symbol(&segment.segment_type))));
+        self
+    }
+
+    /// Serialize every finding as one SARIF run
+    pub fn render(&self, tool_version: &str) -> Result<String> {
+        let locator = Locator::new(self);
+        let mut rules = RuleSet::default();
+        let mut results = Vec::new();
+
+        for todo in &self.todos {
+            let type_name = todo.todo_type.as_str();
+            let rule_index = rules.index(
+                format!("todo/{}", rule_slug(type_name)),
+                format!("{} comment", type_name),
+                "note",
+            );
+            let mut properties = Map::new();
+            properties.insert("priority".to_string(), format!("{:?}", todo.priority).into());
+            if let Some(owner) = todo.owner() {
+                properties.insert("owner".to_string(), owner.into());
+            }
+            if let Some(age_days) = todo.age_days {
+                properties.insert("ageDays".to_string(), age_days.into());
+            }
+            if !todo.tickets.is_empty() {
+                properties.insert("tickets".to_string(), todo.tickets.iter().map(|ticket| ticket.key.clone()).collect::<Vec<_>>().into());
+            }
+            results.push(rules.result(
+                rule_index,
+                priority_level(&todo.priority),
+                format!("{}: {}", type_name, todo.description),
+                vec![locator.location(&todo.file_path, Some(todo.line_number as usize), todo.column_number, None)],
+                Vec::new(),
+                properties,
+            ));
+        }
+
+        for conflict in &self.conflicts {
+            let rule_index = rules.index(
+                format!("conflict/{}", rule_slug(&format!("{:?}", conflict.conflict_type))),
+                conflict_description(&conflict.conflict_type).to_string(),
+                "warning",
+            );
+            let evidence_location = conflict.implementation_evidence.as_ref().and_then(|evidence| {
+                let file = evidence.source_files.first()?;
+                Some(locator.location(file, evidence.line_numbers.first().copied(), None, None))
+            });
+            let claim_location = conflict.documentation_claim.as_ref()
+                .map(|claim| locator.location(&claim.source_file, Some(claim.line_number), None, None));
+            let (locations, related_locations) = match (evidence_location, claim_location) {
+                (Some(evidence), Some(claim)) => (vec![evidence], vec![claim.related(0, "Documentation claim")]),
+                (Some(evidence), None) => (vec![evidence], Vec::new()),
+                (None, Some(claim)) => (vec![claim], Vec::new()),
+                (None, None) => (Vec::new(), Vec::new()),
+            };
+            let mut properties = Map::new();
+            properties.insert("severity".to_string(), format!("{:?}", conflict.severity).into());
+            properties.insert("confidence".to_string(), conflict.confidence.into());
+            properties.insert("recommendedAction".to_string(), conflict.recommended_action.clone().into());
+            results.push(rules.result(
+                rule_index,
+                conflict_level(&conflict.severity),
+                conflict.description.clone(),
+                locations,
+                related_locations,
+                properties,
+            ));
+        }
+
+        for missing in &self.missing_implementations {
+            let rule_index = rules.index(
+                format!("missing-implementation/{}", rule_slug(&missing.implementation_type)),
+                format!("Missing {}", missing.implementation_type),
+                "warning",
+            );
+            let location = match parse_file_location(&missing.expected_location) {
+                Some((dir, None)) if self.is_directory(&dir) => SarifLocation::logical(dir.trim_end_matches('/'), "module"),
+                Some((file, line)) => locator.location(Path::new(&file), line, None, None),
+                None => SarifLocation::logical(&missing.expected_location, "resource"),
+            };
+            let mut properties = Map::new();
+            properties.insert("confidence".to_string(), missing.confidence.into());
+            if let Some(suggestion) = &missing.suggested_implementation {
+                properties.insert("suggestedImplementation".to_string(), suggestion.clone().into());
+            }
+            results.push(rules.result(rule_index, "warning", missing.description.clone(), vec![location], Vec::new(), properties));
+        }
+
+        for partial in &self.partial_implementations {
+            let rule_index = rules.index(
+                format!("partial-implementation/{}", rule_slug(&partial.category)),
+                format!("Partial {} implementation", partial.category),
+                "note",
+            );
+            let location = match partial.evidence.iter().find_map(|evidence| find_file_reference(evidence)) {
+                Some((file, line)) => locator.location(Path::new(&file), line, None, None),
+                None => SarifLocation::logical(&partial.implementation_name, "resource"),
+            };
+            let level = if partial.completeness_percentage < 50.0 { "warning" } else { "note" };
+            let mut properties = Map::new();
+            properties.insert("completenessPercentage".to_string(), partial.completeness_percentage.into());
+            properties.insert("missingParts".to_string(), partial.missing_parts.clone().into());
+            results.push(rules.result(
+                rule_index,
+                level,
+                format!("{} is missing {}", partial.implementation_name, partial.missing_parts.join(", ")),
+                vec![location],
+                Vec::new(),
+                properties,
+            ));
+        }
+
+        for placeholder in &self.placeholders {
+            let rule_index = rules.index(
+                format!("placeholder/{}", rule_slug(&placeholder.indicator_type)),
+                format!("Placeholder implementation ({})", placeholder.indicator_type),
+                "warning",
+            );
+            let symbol = placeholder.function_name.as_deref().or(placeholder.class_name.as_deref());
+            results.push(rules.result(
+                rule_index,
+                "warning",
+                placeholder.description.clone(),
+                vec![locator.location(Path::new(&placeholder.file_path), Some(placeholder.line_number as usize), None, symbol)],
+                Vec::new(),
+                Map::new(),
+            ));
+        }
+
+        let original_uri_base_ids = self.source_root.as_ref().map(|root| {
+            let uri = format!("{}/", file_uri(&absolute(root)).trim_end_matches('/'));
+            HashMap::from([(SOURCE_ROOT_ID.to_string(), ArtifactLocation { uri, uri_base_id: None })])
+        });
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: TOOL_NAME,
+                        version: tool_version.to_string(),
+                        rules: rules.rules,
+                    },
+                },
+                original_uri_base_ids,
+                results,
+            }],
+        };
+        Ok(serde_json::to_string_pretty(&log)?)
+    }
+
+    /// Whether an expected location names a directory: an existing one, or a path with a trailing
+    /// slash or without an extension that is not an existing file (e.g. `alembic/versions`)
+    fn is_directory(&self, location: &str) -> bool {
+        let path = match &self.source_root {
+            Some(root) => root.join(location),
+            None => PathBuf::from(location),
+        };
+        location.ends_with('/') || path.is_dir() || (!path.is_file() && path.extension().is_none())
+    }
+
+    fn artifact_location(&self, file: &Path) -> ArtifactLocation {
+        if let Some(root) = &self.source_root {
+            let relative = file.strip_prefix(root).map(Path::to_path_buf).ok()
+                .or_else(|| absolute(file).strip_prefix(absolute(root)).map(Path::to_path_buf).ok())
+                .or_else(|| file.is_relative().then(|| file.to_path_buf()));
+            if let Some(relative) = relative {
+                return ArtifactLocation { uri: uri_path(&relative), uri_base_id: Some(SOURCE_ROOT_ID.to_string()) };
+            }
+        }
+        let uri = if file.is_absolute() { file_uri(file) } else { uri_path(file) };
+        ArtifactLocation { uri, uri_base_id: None }
+    }
+}
+
+impl DocumentGenerator for SarifGenerator {
+    fn generate(&self, analysis: &CodebaseAnalysis, _intelligent_analysis: Option<&IntelligentAnalysis>) -> Result<String> {
+        self.render(&analysis.analysis_metadata.analyzer_version)
+    }
+
+    fn get_file_extension(&self) -> &str {
+        "sarif"
+    }
+
+    fn get_document_type(&self) -> DocumentType {
+        DocumentType::Sarif
+    }
+}
+
+/// Resolves finding locations against the segments, grouped by artifact URI once per render
+struct Locator<'a> {
+    generator: &'a SarifGenerator,
+    segments: HashMap<String, Vec<&'a (SegmentMetadata, Option<NamedSymbol>)>>,
+}
+
+impl<'a> Locator<'a> {
+    fn new(generator: &'a SarifGenerator) -> Self {
+        let mut segments: HashMap<String, Vec<_>> = HashMap::new();
+        for segment in &generator.segments {
+            segments.entry(generator.artifact_location(&segment.0.file_path).uri).or_default().push(segment);
+        }
+        Self { generator, segments }
+    }
+
+    /// Physical location of a finding, narrowed by the innermost segment around it
+    fn location(&self, file: &Path, line: Option<usize>, column: Option<u32>, symbol: Option<&str>) -> SarifLocation {
+        let artifact = self.generator.artifact_location(file);
+        let segment = self.enclosing_segment(&artifact.uri, line, symbol);
+
+        let (region, context_region) = match (line, segment) {
+            (Some(line), Some((metadata, _))) => (Some(Region::line(line, column)), Some(Region::segment(metadata))),
+            (Some(line), None) => (Some(Region::line(line, column)), None),
+            (None, Some((metadata, _))) => (Some(Region::segment(metadata)), None),
+            (None, None) => (None, None),
+        };
+        let logical_locations = segment
+            .and_then(|(_, symbol)| symbol.as_ref())
+            .map(|symbol| vec![LogicalLocation { name: symbol.name.clone(), kind: symbol.kind }])
+            .unwrap_or_default();
+
+        SarifLocation {
+            id: None,
+            physical_location: Some(PhysicalLocation { artifact_location: artifact, region, context_region }),
+            logical_locations,
+            message: None,
+        }
+    }
+
+    /// Smallest segment of the file containing `line`, else the segment named `symbol`
+    fn enclosing_segment(&self, uri: &str, line: Option<usize>, symbol: Option<&str>) -> Option<&'a (SegmentMetadata, Option<NamedSymbol>)> {
+        let mut in_file = self.segments.get(uri)?.iter().copied();
+        match line {
+            Some(line) => in_file
+                .filter(|(metadata, _)| metadata.line_start <= line && line <= metadata.line_end)
+                .min_by_key(|(metadata, _)| metadata.line_end.saturating_sub(metadata.line_start)),
+            None => in_file.find(|(_, named)| {
+                named.as_ref().is_some_and(|named| Some(named.name.as_str()) == symbol)
+            }),
+        }
+    }
+}
+
+/// Registers each rule once and hands out its index in `tool.driver.rules`
+#[derive(Default)]
+struct RuleSet {
+    rules: Vec<Rule>,
+    indices: HashMap<String, usize>,
+}
+
+impl RuleSet {
+    fn index(&mut self, id: String, description: String, default_level: &'static str) -> usize {
+        if let Some(index) = self.indices.get(&id) {
+            return *index;
+        }
+        let index = self.rules.len();
+        self.indices.insert(id.clone(), index);
+        self.rules.push(Rule {
+            id,
+            short_description: Message { text: description },
+            default_configuration: RuleConfiguration { level: default_level },
+        });
+        index
+    }
+
+    fn result(
+        &self,
+        rule_index: usize,
+        level: &'static str,
+        message: String,
+        locations: Vec<SarifLocation>,
+        related_locations: Vec<SarifLocation>,
+        properties: Map<String, Value>,
+    ) -> SarifResult {
+        SarifResult {
+            rule_id: self.rules[rule_index].id.clone(),
+            rule_index,
+            level,
+            message: Message { text: message },
+            locations,
+            related_locations,
+            properties,
+        }
+    }
+}
+
+/// SARIF levels for `TodoPriority`: only critical TODOs fail a build
+fn priority_level(priority: &TodoPriority) -> &'static str {
+    match priority {
+        TodoPriority::Critical => "error",
+        TodoPriority::High => "warning",
+        TodoPriority::Medium | TodoPriority::Low => "note",
+    }
+}
+
+/// SARIF levels for `ConflictSeverity`, on the same scale as `priority_level`
+fn conflict_level(severity: &ConflictSeverity) -> &'static str {
+    match severity {
+        ConflictSeverity::Critical => "error",
+        ConflictSeverity::High => "warning",
+        ConflictSeverity::Medium | ConflictSeverity::Low => "note",
+    }
+}
+
+fn conflict_description(conflict_type: &ConflictType) -> &'static str {
+    match conflict_type {
+        ConflictType::ClaimedButNotImplemented => "Documented feature is not implemented",
+        ConflictType::ImplementedButNotClaimed => "Implemented feature is not documented",
+        ConflictType::ImplementationMismatch => "Implementation level differs from the documentation",
+        ConflictType::TechnologyMismatch => "Technology stack differs from the documentation",
+        ConflictType::SecurityMismatch => "Security claims do not match the implementation",
+        ConflictType::PerformanceMismatch => "Performance claims do not match the implementation",
+    }
+}
+
+fn segment_symbol(segment_type: &SegmentType) -> Option<NamedSymbol> {
+    let (name, kind) = match segment_type {
+        SegmentType::Function(function) => (function.name.clone(), "function"),
+        SegmentType::Class(class) => (class.name.clone(), "type"),
+        SegmentType::Interface(interface) => (interface.name.clone(), "type"),
+        SegmentType::Route(route) if !route.handler.is_empty() => (route.handler.clone(), "function"),
+        SegmentType::Database(database) => (database.model_name.clone(), "type"),
+        SegmentType::Route(_) | SegmentType::Configuration(_) => return None,
+    };
+    Some(NamedSymbol { name, kind })
+}
+
+/// First word of free-form evidence that names a source file
+fn find_file_reference(text: &str) -> Option<(String, Option<usize>)> {
+    text.split_whitespace()
+        .map(|word| word.trim_end_matches([':', ',', ';', ')']))
+        .filter(|word| {
+            word.contains('/') && word.rsplit('/').next().is_some_and(|name| name.contains('.'))
+        })
+        .find_map(parse_file_location)
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn uri_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/").replace(' ', "%20")
+}
+
+fn file_uri(path: &Path) -> String {
+    let path = uri_path(path);
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_uri_base_ids: Option<HashMap<String, ArtifactLocation>>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+struct Driver {
+    name: &'static str,
+    version: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    short_description: Message,
+    default_configuration: RuleConfiguration,
+}
+
+#[derive(Serialize)]
+struct RuleConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    properties: Map<String, Value>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    physical_location: Option<PhysicalLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    logical_locations: Vec<LogicalLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+impl SarifLocation {
+    fn logical(name: &str, kind: &'static str) -> Self {
+        Self {
+            id: None,
+            physical_location: None,
+            logical_locations: vec![LogicalLocation { name: name.to_string(), kind }],
+            message: None,
+        }
+    }
+
+    /// The same location as a `relatedLocations` entry
+    fn related(mut self, id: usize, message: &str) -> Self {
+        self.id = Some(id);
+        self.message = Some(Message { text: message.to_string() });
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context_region: Option<Region>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_length: Option<usize>,
+}
+
+impl Region {
+    fn line(line: usize, column: Option<u32>) -> Self {
+        Self { start_line: line.max(1), start_column: column.filter(|column| *column > 0), end_line: None, byte_offset: None, byte_length: None }
+    }
+
+    fn segment(metadata: &SegmentMetadata) -> Self {
+        Self {
+            start_line: metadata.line_start.max(1),
+            start_column: None,
+            end_line: Some(metadata.line_end.max(metadata.line_start).max(1)),
+            byte_offset: Some(metadata.byte_start),
+            byte_length: Some(metadata.byte_end.saturating_sub(metadata.byte_start)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LogicalLocation {
+    name: String,
+    kind: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ast_analyzer::FunctionSegment;
+    use crate::core::documentation_claims_extractor::{ClaimPriority, ClaimType, DocumentationClaim};
+    use crate::core::conflict_resolution_engine::ResolutionStrategy;
+    use crate::analyzers::TypeScriptAnalyzer;
+    use crate::core::todo_scanner::TodoScanner;
+    use crate::core::{AnalyzerConfig, CodebaseAnalyzer};
+    use crate::generators::DocumentGeneratorFactory;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sarif_is_a_document_type() {
+        let generator = DocumentGeneratorFactory::create_generator(DocumentType::Sarif);
+        assert_eq!(generator.get_file_extension(), "sarif");
+        assert!(matches!(generator.get_document_type(), DocumentType::Sarif));
+    }
+
+    #[test]
+    fn test_sarif_maps_findings_to_rules_levels_and_segment_locations() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = "export function createOrder() {\n  // FIXME: validate totals\n  return 1;\n}\n";
+        std::fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+        std::fs::write(temp_dir.path().join("src/orders.ts"), source).unwrap();
+
+        let todos = TodoScanner::new().unwrap().scan_project(temp_dir.path()).unwrap();
+        let segment = CodeSegment {
+            segment_type: SegmentType::Function(FunctionSegment {
+                name: "createOrder".to_string(),
+                parameters: Vec::new(),
+                return_type: None,
+                is_async: false,
+                decorators: Vec::new(),
+            }),
+            content: source.to_string(),
+            metadata: SegmentMetadata {
+                line_start: 1,
+                line_end: 4,
+                file_path: temp_dir.path().join("src/orders.ts"),
+                byte_start: 0,
+                byte_end: source.len(),
+            },
+            framework_context: None,
+            business_hints: Vec::new(),
+        };
+        let conflict = Conflict {
+            conflict_type: ConflictType::ClaimedButNotImplemented,
+            severity: ConflictSeverity::Critical,
+            description: "README claims refunds".to_string(),
+            documentation_claim: Some(DocumentationClaim {
+                claim_type: ClaimType::Feature,
+                description: "Supports refunds".to_string(),
+                source_file: temp_dir.path().join("README.md"),
+                line_number: 3,
+                confidence: 0.9,
+                priority: ClaimPriority::High,
+                keywords: Vec::new(),
+                context: String::new(),
+                evidence: Vec::new(),
+            }),
+            implementation_evidence: None,
+            confidence: 0.9,
+            resolution_strategy: ResolutionStrategy::FlagAsInconsistent,
+            recommended_action: "Implement refunds".to_string(),
+        };
+
+        let mut generator = SarifGenerator::new()
+            .with_source_root(temp_dir.path())
+            .with_todos(&todos)
+            .with_conflicts(&[conflict])
+            .with_segments(&[segment]);
+        for location in ["src/orders.ts:3", "GET /orders", "src", "alembic/versions"] {
+            generator.missing_implementations.push(MissingImplementation {
+                implementation_type: "Error Handling".to_string(),
+                expected_location: location.to_string(),
+                description: format!("No error handling at {}", location),
+                confidence: 0.8,
+                evidence: Vec::new(),
+                suggested_implementation: None,
+            });
+        }
+
+        let analysis = TypeScriptAnalyzer::new(AnalyzerConfig::default())
+            .analyze(&temp_dir.path().to_string_lossy())
+            .unwrap();
+        let log: Value = serde_json::from_str(&generator.generate(&analysis, None).unwrap()).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["version"], analysis.analysis_metadata.analyzer_version.as_str());
+        let rule_ids: Vec<&str> = run["tool"]["driver"]["rules"].as_array().unwrap().iter()
+            .map(|rule| rule["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(rule_ids, vec!["todo/fixme", "conflict/claimed-but-not-implemented", "missing-implementation/error-handling"]);
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 6);
+
+        let todo = &results[0]["locations"][0];
+        assert_eq!(todo["physicalLocation"]["artifactLocation"]["uri"], "src/orders.ts");
+        assert_eq!(todo["physicalLocation"]["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(todo["physicalLocation"]["region"]["startLine"], 2);
+        assert_eq!(todo["physicalLocation"]["contextRegion"]["endLine"], 4);
+        assert_eq!(todo["physicalLocation"]["contextRegion"]["byteLength"], source.len());
+        assert_eq!(todo["logicalLocations"][0]["name"], "createOrder");
+
+        assert_eq!(results[1]["level"], "error");
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "README.md");
+
+        assert_eq!(results[2]["locations"][0]["physicalLocation"]["region"]["startLine"], 3);
+        assert_eq!(results[3]["ruleIndex"], 2);
+        assert_eq!(results[3]["locations"][0]["logicalLocations"][0]["name"], "GET /orders");
+        for (result, directory) in results[4..].iter().zip(["src", "alembic/versions"]) {
+            assert!(result["locations"][0]["physicalLocation"].is_null());
+            assert_eq!(result["locations"][0]["logicalLocations"][0]["name"], directory);
+            assert_eq!(result["locations"][0]["logicalLocations"][0]["kind"], "module");
+        }
+    }
+}
@@ -43,6 +43,7 @@ struct CreateIssueResponse {
     html_url: String,
 }
 
+#[derive(Clone)]
 pub struct GitHubClient {
     client: Client,
     token: String,
@@ -81,6 +82,31 @@ impl GitHubClient {
         }
     }
 
+    /// Fetch a single issue (open or closed) from `owner/repo`, or from this repository when
+    /// `repository` is `None`; `None` when no such issue exists
+    pub async fn fetch_issue(&self, repository: Option<&str>, number: u32) -> Result<Option<GitHubIssue>> {
+        let repository = repository
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}/{}", self.repo_owner, self.repo_name));
+        let url = format!("https://api.github.com/repos/{}/issues/{}", repository, number);
+
+        let response = self.client
+            .get(&url)
+            .header("Authorization", format!("token {}", self.token))
+            .header("User-Agent", "codebase-analyzer")
+            .send()
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch GitHub issue {}#{}: {}", repository, number, response.status());
+        }
+
+        Ok(Some(response.json().await?))
+    }
+
     pub async fn create_issue_from_story(&self, story: &UserStory) -> Result<CreatedIssue> {
         let url = format!("https://api.github.com/repos/{}/{}/issues", self.repo_owner, self.repo_name);
         
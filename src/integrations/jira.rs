@@ -97,6 +97,26 @@ struct JiraIssueFields {
     updated: String,
 }
 
+impl From<JiraIssueResponse> for JiraIssue {
+    fn from(issue: JiraIssueResponse) -> Self {
+        Self {
+            id: issue.id,
+            key: issue.key,
+            summary: issue.fields.summary,
+            description: issue.fields.description,
+            status: issue.fields.status,
+            priority: issue.fields.priority.unwrap_or(JiraPriority {
+                name: "Medium".to_string(),
+                id: "3".to_string(),
+            }),
+            assignee: issue.fields.assignee,
+            created: issue.fields.created,
+            updated: issue.fields.updated,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct JiraClient {
     client: Client,
     base_url: String,
@@ -128,22 +148,7 @@ impl JiraClient {
 
         if response.status().is_success() {
             let search_result: SearchResponse = response.json().await?;
-            let issues = search_result.issues.into_iter().map(|issue| {
-                JiraIssue {
-                    id: issue.id,
-                    key: issue.key,
-                    summary: issue.fields.summary,
-                    description: issue.fields.description,
-                    status: issue.fields.status,
-                    priority: issue.fields.priority.unwrap_or(JiraPriority {
-                        name: "Medium".to_string(),
-                        id: "3".to_string(),
-                    }),
-                    assignee: issue.fields.assignee,
-                    created: issue.fields.created,
-                    updated: issue.fields.updated,
-                }
-            }).collect();
+            let issues = search_result.issues.into_iter().map(JiraIssue::from).collect();
             
             Ok(issues)
         } else {
@@ -151,6 +156,28 @@ impl JiraClient {
         }
     }
 
+    /// Fetch a single issue by key; `None` when no such issue exists
+    pub async fn fetch_issue(&self, key: &str) -> Result<Option<JiraIssue>> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, key);
+
+        let response = self.client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Content-Type", "application/json")
+            .send()
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch Jira issue {}: {}", key, response.status());
+        }
+
+        let issue: JiraIssueResponse = response.json().await?;
+        Ok(Some(issue.into()))
+    }
+
     pub async fn create_ticket_from_task(&self, task: &Task, project_key: &str) -> Result<CreatedIssue> {
         let url = format!("{}/rest/api/3/issue", self.base_url);
         
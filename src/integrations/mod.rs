@@ -3,8 +3,12 @@ pub mod github;
 pub mod jira;
 
 use crate::core::{CodebaseAnalysis, Task, UserStory, Priority};
+use crate::core::todo_scanner::{IssueStatusIndex, IssueTracker, TicketState, TicketStatus, TicketSummary, TodoScanResult};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::task::JoinSet;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegrationConfig {
@@ -100,6 +104,105 @@ impl IntegrationEngine {
 
         Ok(summary)
     }
+
+    /// Look up every unresolved ticket referenced from the scanned TODOs in Jira and GitHub,
+    /// a few at a time, then refresh the scan's ticket summary
+    pub async fn resolve_todo_tickets(&self, scan: &mut TodoScanResult, closed_statuses: &[String]) -> Result<()> {
+        let jira = match (&self.config.jira_url, &self.config.jira_token) {
+            (Some(url), Some(token)) => Some(jira::JiraClient::new(url.clone(), token.clone())),
+            _ => None,
+        };
+        let github = self.config.github_token.clone().map(github::GitHubClient::new);
+        let closed_statuses: Arc<[String]> = closed_statuses.into();
+
+        let unresolved: HashSet<(IssueTracker, String)> = scan.todo_items.iter()
+            .flat_map(|item| &item.tickets)
+            .filter(|ticket| ticket.status.is_none())
+            .map(|ticket| (ticket.tracker, ticket.key.clone()))
+            .collect();
+
+        let mut index = IssueStatusIndex::new();
+        let mut pending = unresolved.into_iter();
+        let mut lookups = JoinSet::new();
+        loop {
+            while lookups.len() < TICKET_LOOKUP_CONCURRENCY
+                && let Some((tracker, key)) = pending.next()
+            {
+                spawn_ticket_lookup(&mut lookups, tracker, key, jira.as_ref(), github.as_ref(), &closed_statuses);
+            }
+            let Some(joined) = lookups.join_next().await else {
+                break;
+            };
+
+            match joined {
+                Ok((tracker, key, Ok(status))) => index.insert(tracker, key, status.unwrap_or(TicketStatus {
+                    state: TicketState::NotFound,
+                    title: None,
+                })),
+                Ok((_, key, Err(e))) => println!("Warning: could not resolve ticket {}: {}", key, e),
+                Err(e) => println!("Warning: ticket lookup failed: {}", e),
+            }
+        }
+
+        index.resolve(&mut scan.todo_items);
+        scan.summary.tickets = TicketSummary::from_items(&scan.todo_items);
+        Ok(())
+    }
+}
+
+/// Ticket lookups in flight at once
+const TICKET_LOOKUP_CONCURRENCY: usize = 8;
+
+type TicketLookup = (IssueTracker, String, Result<Option<TicketStatus>>);
+
+/// Start looking up one ticket; references no configured client can resolve are left alone
+fn spawn_ticket_lookup(
+    lookups: &mut JoinSet<TicketLookup>,
+    tracker: IssueTracker,
+    key: String,
+    jira: Option<&jira::JiraClient>,
+    github: Option<&github::GitHubClient>,
+    closed_statuses: &Arc<[String]>,
+) {
+    match (tracker, jira, github) {
+        (IssueTracker::Jira, Some(client), _) => {
+            let client = client.clone();
+            let closed_statuses = Arc::clone(closed_statuses);
+            lookups.spawn(async move {
+                let status = client.fetch_issue(&key).await.map(|issue| {
+                    issue.map(|issue| TicketStatus {
+                        state: if closed_statuses.iter().any(|closed| closed.eq_ignore_ascii_case(&issue.status.name)) {
+                            TicketState::Closed
+                        } else {
+                            TicketState::Open
+                        },
+                        title: Some(issue.summary),
+                    })
+                });
+                (tracker, key, status)
+            });
+        }
+        (IssueTracker::GitHub, _, Some(client)) => {
+            let Some((repository, number)) = key.split_once('#') else {
+                return;
+            };
+            let Ok(number) = number.parse::<u32>() else {
+                return;
+            };
+            let repository = (!repository.is_empty()).then(|| repository.to_string());
+            let client = client.clone();
+            lookups.spawn(async move {
+                let status = client.fetch_issue(repository.as_deref(), number).await.map(|issue| {
+                    issue.map(|issue| TicketStatus {
+                        state: if issue.state == "closed" { TicketState::Closed } else { TicketState::Open },
+                        title: Some(issue.title),
+                    })
+                });
+                (tracker, key, status)
+            });
+        }
+        _ => {}
+    }
 }
 
 impl Default for IntegrationConfig {
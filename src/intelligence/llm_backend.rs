@@ -0,0 +1,598 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Wire protocol spoken by the LLM server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProvider {
+    /// Ollama `/api/generate`
+    #[default]
+    Ollama,
+    /// `/v1/chat/completions` as served by llama.cpp server, vLLM, LM Studio and LocalAI
+    OpenAiCompatible,
+    /// Canned responses from a fixture file, no network
+    Fixture,
+}
+
+impl LlmProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LlmProvider::Ollama => "ollama",
+            LlmProvider::OpenAiCompatible => "openai",
+            LlmProvider::Fixture => "fixture",
+        }
+    }
+
+    /// Where the server listens out of the box
+    pub fn default_base_url(&self) -> &'static str {
+        match self {
+            LlmProvider::Ollama => "http://localhost:11434",
+            LlmProvider::OpenAiCompatible => "http://localhost:8080",
+            LlmProvider::Fixture => "",
+        }
+    }
+}
+
+impl fmt::Display for LlmProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LlmProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "ollama" => Ok(LlmProvider::Ollama),
+            "openai" | "openai-compatible" | "openai_compatible" | "llama.cpp" | "llamacpp" | "vllm"
+            | "lmstudio" | "localai" => Ok(LlmProvider::OpenAiCompatible),
+            "fixture" | "fixtures" => Ok(LlmProvider::Fixture),
+            other => bail!("Unknown LLM provider '{}' (expected ollama, openai or fixture)", other),
+        }
+    }
+}
+
+/// A single completion request, independent of the wire protocol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LlmRequest {
+    pub prompt: String,
+    pub temperature: f32,
+    pub max_tokens: usize,
+    pub top_p: f32,
+    pub repeat_penalty: Option<f32>,
+    /// JSON schema the response must satisfy, enforced by the server where it supports that
+    pub response_schema: Option<serde_json::Value>,
+}
+
+impl LlmRequest {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            temperature: 0.1,
+            max_tokens: 4096,
+            top_p: 0.9,
+            repeat_penalty: None,
+            response_schema: None,
+        }
+    }
+
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    pub fn with_top_p(mut self, top_p: f32) -> Self {
+        self.top_p = top_p;
+        self
+    }
+
+    pub fn with_repeat_penalty(mut self, repeat_penalty: f32) -> Self {
+        self.repeat_penalty = Some(repeat_penalty);
+        self
+    }
+
+    pub fn with_response_schema(mut self, schema: Option<serde_json::Value>) -> Self {
+        self.response_schema = schema;
+        self
+    }
+}
+
+/// A text-completion server the analyzer can talk to
+#[async_trait]
+pub trait LlmBackend: Send + Sync + fmt::Debug {
+    fn provider(&self) -> LlmProvider;
+
+    /// Model name sent with every request
+    fn model(&self) -> &str;
+
+    /// Fails when the server cannot be reached
+    async fn health_check(&self) -> Result<()>;
+
+    async fn list_models(&self) -> Result<Vec<String>>;
+
+    /// The raw completion text
+    async fn generate(&self, request: &LlmRequest) -> Result<String>;
+
+    /// Tokens `text` occupies according to the server's tokenizer, `None` when it cannot say
+    async fn count_tokens(&self, _text: &str) -> Result<Option<usize>> {
+        Ok(None)
+    }
+}
+
+fn http_client(timeout_seconds: u64) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(timeout_seconds))
+        .build()
+        .context("Failed to create HTTP client")
+}
+
+/// A non-success HTTP status from the LLM server, kept typed so callers can tell overload from misuse
+#[derive(Debug, Clone)]
+pub struct LlmStatusError {
+    pub status: u16,
+    pub message: String,
+}
+
+impl LlmStatusError {
+    async fn from_response(action: &str, response: reqwest::Response) -> Self {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        Self {
+            status: status.as_u16(),
+            message: format!("{} (status {}: {})", action, status, text),
+        }
+    }
+
+    /// Rate limiting, request timeouts and server-side failures are worth retrying
+    pub fn is_transient(&self) -> bool {
+        matches!(self.status, 408 | 429 | 500 | 502 | 503 | 504)
+    }
+}
+
+impl fmt::Display for LlmStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LlmStatusError {}
+
+#[derive(Debug, Serialize)]
+struct OllamaGenerateRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+    stream: bool,
+    /// A JSON schema here switches Ollama to schema-constrained decoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'a serde_json::Value>,
+    options: OllamaGenerateOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaGenerateOptions {
+    temperature: f32,
+    num_predict: usize,
+    top_p: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repeat_penalty: Option<f32>,
+    /// Ollama truncates prompts to its own small default context unless told otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_ctx: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaGenerateResponse {
+    response: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTags {
+    models: Vec<OllamaTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTag {
+    name: String,
+}
+
+/// Ollama's native `/api/generate` protocol
+#[derive(Debug, Clone)]
+pub struct OllamaBackend {
+    client: reqwest::Client,
+    base_url: String,
+    model: String,
+    context_window: Option<usize>,
+}
+
+impl OllamaBackend {
+    pub fn new(base_url: &str, model: &str, timeout_seconds: u64) -> Result<Self> {
+        Ok(Self {
+            client: http_client(timeout_seconds)?,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model: model.to_string(),
+            context_window: None,
+        })
+    }
+
+    /// Sent as `num_ctx` so the server allocates the context the batches were sized for
+    pub fn with_context_window(mut self, context_window: usize) -> Self {
+        self.context_window = Some(context_window);
+        self
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OllamaBackend {
+    fn provider(&self) -> LlmProvider {
+        LlmProvider::Ollama
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.list_models().await.map(|_| ())
+    }
+
+    async fn list_models(&self) -> Result<Vec<String>> {
+        let response = self.client
+            .get(format!("{}/api/tags", self.base_url))
+            .send()
+            .await
+            .with_context(|| format!("Failed to connect to Ollama at {}", self.base_url))?;
+        if !response.status().is_success() {
+            return Err(LlmStatusError::from_response("Failed to list Ollama models", response).await.into());
+        }
+        let tags: OllamaTags = response.json().await.context("Failed to parse Ollama model list")?;
+        Ok(tags.models.into_iter().map(|model| model.name).collect())
+    }
+
+    async fn generate(&self, request: &LlmRequest) -> Result<String> {
+        let body = OllamaGenerateRequest {
+            model: &self.model,
+            prompt: &request.prompt,
+            stream: false,
+            format: request.response_schema.as_ref(),
+            options: OllamaGenerateOptions {
+                temperature: request.temperature,
+                num_predict: request.max_tokens,
+                top_p: request.top_p,
+                repeat_penalty: request.repeat_penalty,
+                num_ctx: self.context_window,
+            },
+        };
+        let response = self.client
+            .post(format!("{}/api/generate", self.base_url))
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send request to Ollama at {}", self.base_url))?;
+        if !response.status().is_success() {
+            return Err(LlmStatusError::from_response("Ollama generation failed", response).await.into());
+        }
+        let parsed: OllamaGenerateResponse = response.json().await.context("Failed to parse Ollama response")?;
+        Ok(parsed.response)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ChatCompletionRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    temperature: f32,
+    max_tokens: usize,
+    top_p: f32,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat<'a>>,
+}
+
+/// OpenAI structured outputs; llama.cpp turns the schema into a sampling grammar
+#[derive(Debug, Serialize)]
+struct ResponseFormat<'a> {
+    #[serde(rename = "type")]
+    kind: &'a str,
+    json_schema: JsonSchemaFormat<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSchemaFormat<'a> {
+    name: &'a str,
+    schema: &'a serde_json::Value,
+    strict: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatChoiceMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoiceMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// llama.cpp reads `content`, vLLM reads `model` and `prompt`
+#[derive(Debug, Serialize)]
+struct TokenizeRequest<'a> {
+    model: &'a str,
+    content: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenizeResponse {
+    tokens: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelList {
+    data: Vec<ModelListEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelListEntry {
+    id: String,
+}
+
+/// The OpenAI `/v1/chat/completions` protocol (llama.cpp server, vLLM, LM Studio, LocalAI)
+#[derive(Clone)]
+pub struct OpenAiCompatibleBackend {
+    client: reqwest::Client,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+impl fmt::Debug for OpenAiCompatibleBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenAiCompatibleBackend")
+            .field("base_url", &self.base_url)
+            .field("model", &self.model)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl OpenAiCompatibleBackend {
+    /// `base_url` may be given with or without the trailing `/v1`
+    pub fn new(base_url: &str, model: &str, api_key: Option<String>, timeout_seconds: u64) -> Result<Self> {
+        let base_url = base_url.trim_end_matches('/');
+        Ok(Self {
+            client: http_client(timeout_seconds)?,
+            base_url: base_url.strip_suffix("/v1").unwrap_or(base_url).to_string(),
+            model: model.to_string(),
+            api_key,
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}/v1/{}", self.base_url, path)
+    }
+
+    fn authorize(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.api_key {
+            Some(key) => builder.bearer_auth(key),
+            None => builder,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OpenAiCompatibleBackend {
+    fn provider(&self) -> LlmProvider {
+        LlmProvider::OpenAiCompatible
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.list_models().await.map(|_| ())
+    }
+
+    async fn list_models(&self) -> Result<Vec<String>> {
+        let response = self.authorize(self.client.get(self.endpoint("models")))
+            .send()
+            .await
+            .with_context(|| format!("Failed to connect to LLM server at {}", self.base_url))?;
+        if !response.status().is_success() {
+            return Err(LlmStatusError::from_response("Failed to list models", response).await.into());
+        }
+        let models: ModelList = response.json().await.context("Failed to parse model list")?;
+        Ok(models.data.into_iter().map(|model| model.id).collect())
+    }
+
+    async fn generate(&self, request: &LlmRequest) -> Result<String> {
+        let body = ChatCompletionRequest {
+            model: &self.model,
+            messages: vec![ChatMessage { role: "user", content: &request.prompt }],
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+            top_p: request.top_p,
+            stream: false,
+            response_format: request.response_schema.as_ref().map(|schema| ResponseFormat {
+                kind: "json_schema",
+                json_schema: JsonSchemaFormat { name: "analysis", schema, strict: true },
+            }),
+        };
+        let response = self.authorize(self.client.post(self.endpoint("chat/completions")))
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send request to LLM server at {}", self.base_url))?;
+        if !response.status().is_success() {
+            return Err(LlmStatusError::from_response("Chat completion failed", response).await.into());
+        }
+        let parsed: ChatCompletionResponse = response.json().await.context("Failed to parse chat completion response")?;
+        parsed.choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .context("Chat completion response contained no message content")
+    }
+
+    /// `/tokenize` sits beside `/v1` on llama.cpp server and vLLM; other servers answer 404
+    async fn count_tokens(&self, text: &str) -> Result<Option<usize>> {
+        let body = TokenizeRequest { model: &self.model, content: text, prompt: text };
+        let response = self.authorize(self.client.post(format!("{}/tokenize", self.base_url)))
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send tokenize request to LLM server at {}", self.base_url))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(LlmStatusError::from_response("Tokenize request failed", response).await.into());
+        }
+        let parsed: TokenizeResponse = response.json().await.context("Failed to parse tokenize response")?;
+        Ok(Some(parsed.tokens.len()))
+    }
+}
+
+/// One canned response, served when the prompt contains `prompt_contains`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmFixture {
+    pub prompt_contains: String,
+    pub response: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct FixtureFile {
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    fixtures: Vec<LlmFixture>,
+    #[serde(default)]
+    default_response: Option<String>,
+}
+
+/// Serves recorded responses without a server, for tests and offline demos
+///
+/// Fixtures are tried in order and the first whose `prompt_contains` occurs in the prompt wins.
+/// Without a match the default response is served, and without one of those the request fails.
+#[derive(Debug, Clone)]
+pub struct FixtureBackend {
+    model: String,
+    fixtures: Vec<LlmFixture>,
+    default_response: Option<String>,
+}
+
+impl FixtureBackend {
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            fixtures: Vec::new(),
+            default_response: None,
+        }
+    }
+
+    /// Reads `{"model": ..., "fixtures": [{"prompt_contains": ..., "response": ...}], "default_response": ...}`,
+    /// falling back to `default_model` when the file names no model
+    pub fn from_file(path: &Path, default_model: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read LLM fixtures from {}", path.display()))?;
+        let file: FixtureFile = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse LLM fixtures in {}", path.display()))?;
+        Ok(Self {
+            model: file.model.unwrap_or_else(|| default_model.to_string()),
+            fixtures: file.fixtures,
+            default_response: file.default_response,
+        })
+    }
+
+    pub fn with_fixture(mut self, prompt_contains: &str, response: &str) -> Self {
+        self.fixtures.push(LlmFixture {
+            prompt_contains: prompt_contains.to_string(),
+            response: response.to_string(),
+        });
+        self
+    }
+
+    pub fn with_default_response(mut self, response: &str) -> Self {
+        self.default_response = Some(response.to_string());
+        self
+    }
+}
+
+#[async_trait]
+impl LlmBackend for FixtureBackend {
+    fn provider(&self) -> LlmProvider {
+        LlmProvider::Fixture
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_models(&self) -> Result<Vec<String>> {
+        Ok(vec![self.model.clone()])
+    }
+
+    async fn generate(&self, request: &LlmRequest) -> Result<String> {
+        self.fixtures
+            .iter()
+            .find(|fixture| request.prompt.contains(&fixture.prompt_contains))
+            .map(|fixture| fixture.response.clone())
+            .or_else(|| self.default_response.clone())
+            .with_context(|| {
+                let preview: String = request.prompt.chars().take(80).collect();
+                format!("No LLM fixture matches prompt starting with {:?}", preview)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fixture_backend_serves_first_match_then_default() {
+        let backend = FixtureBackend::new("fixture-model")
+            .with_fixture("invoice", "billing")
+            .with_fixture("login", "authentication");
+
+        assert_eq!(backend.generate(&LlmRequest::new("classify: login and invoice")).await.unwrap(), "billing");
+        assert!(backend.generate(&LlmRequest::new("classify: search")).await.is_err());
+
+        let backend = backend.with_default_response("unknown");
+        assert_eq!(backend.generate(&LlmRequest::new("classify: search")).await.unwrap(), "unknown");
+        assert_eq!(backend.list_models().await.unwrap(), vec!["fixture-model".to_string()]);
+        assert_eq!("llama.cpp".parse::<LlmProvider>().unwrap(), LlmProvider::OpenAiCompatible);
+        assert_eq!(
+            OpenAiCompatibleBackend::new("http://localhost:8080/v1/", "m", None, 5).unwrap().endpoint("models"),
+            "http://localhost:8080/v1/models"
+        );
+    }
+}
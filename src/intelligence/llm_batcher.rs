@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::core::context_types::{CrossReferenceMap, SegmentId};
+
+/// Limits every batch of segments must respect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchBudget {
+    /// Tokens left for segments once the prompt template, response and safety margin are reserved
+    pub segment_tokens: usize,
+    /// Answers degrade when a small model is asked about too many segments at once
+    pub max_segments: usize,
+}
+
+/// Segment indices per batch, in the order batches should be sent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchPlan {
+    pub batches: Vec<Vec<usize>>,
+    /// Segments that exceed the budget on their own and were batched alone
+    pub oversized: Vec<usize>,
+}
+
+/// Which segments belong together, tracked as disjoint sets
+#[derive(Debug, Clone)]
+pub struct SegmentRelations {
+    parent: Vec<usize>,
+}
+
+impl SegmentRelations {
+    pub fn new(len: usize) -> Self {
+        Self { parent: (0..len).collect() }
+    }
+
+    pub fn relate(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            // The lower index stays the root so groups are ordered by their first segment
+            self.parent[root_a.max(root_b)] = root_a.min(root_b);
+        }
+    }
+
+    /// Relates segments that come from the same file
+    pub fn relate_same_file<'a>(&mut self, paths: impl IntoIterator<Item = &'a Path>) {
+        let mut first_in_file: HashMap<&Path, usize> = HashMap::new();
+        for (index, path) in paths.into_iter().enumerate() {
+            let first = *first_in_file.entry(path).or_insert(index);
+            self.relate(first, index);
+        }
+    }
+
+    /// Relates segments linked by a functional dependency, data flow or architectural relationship
+    pub fn relate_cross_referenced(&mut self, ids: &[SegmentId], cross_references: &CrossReferenceMap) {
+        let positions: HashMap<&SegmentId, usize> = ids.iter().enumerate().map(|(index, id)| (id, index)).collect();
+        for (index, id) in ids.iter().enumerate() {
+            for related in cross_references.get_related_segments(id) {
+                if let Some(&other) = positions.get(&related) {
+                    self.relate(index, other);
+                }
+            }
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = index;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Related segments as index groups, each in ascending order
+    fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_root = HashMap::new();
+        for index in 0..self.parent.len() {
+            let root = self.find(index);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+        groups
+    }
+}
+
+/// Packs segments into as few batches as the budget allows, keeping related segments together
+///
+/// Groups of related segments are placed first-fit in decreasing size order. A group too large for one
+/// batch is split in source order, so its parts still land in as few batches as possible.
+pub fn pack_segments(segment_tokens: &[usize], relations: &mut SegmentRelations, budget: BatchBudget) -> BatchPlan {
+    let max_segments = budget.max_segments.max(1);
+    let mut plan = BatchPlan::default();
+    let mut items: Vec<(usize, Vec<usize>)> = Vec::new();
+
+    for group in relations.groups() {
+        let mut chunk = Vec::new();
+        let mut chunk_tokens = 0;
+        for index in group {
+            let tokens = segment_tokens[index];
+            if tokens > budget.segment_tokens {
+                plan.oversized.push(index);
+                items.push((tokens, vec![index]));
+                continue;
+            }
+            if !chunk.is_empty() && (chunk_tokens + tokens > budget.segment_tokens || chunk.len() == max_segments) {
+                items.push((chunk_tokens, std::mem::take(&mut chunk)));
+                chunk_tokens = 0;
+            }
+            chunk.push(index);
+            chunk_tokens += tokens;
+        }
+        if !chunk.is_empty() {
+            items.push((chunk_tokens, chunk));
+        }
+    }
+
+    // Stable sort keeps equally sized items in source order
+    items.sort_by_key(|item| std::cmp::Reverse(item.0));
+    let mut bins: Vec<(usize, Vec<usize>)> = Vec::new();
+    for (tokens, indices) in items {
+        let fits = |bin: &&mut (usize, Vec<usize>)| {
+            bin.0 + tokens <= budget.segment_tokens && bin.1.len() + indices.len() <= max_segments
+        };
+        match bins.iter_mut().find(fits) {
+            Some(bin) => {
+                bin.0 += tokens;
+                bin.1.extend(indices);
+            }
+            None => bins.push((tokens, indices)),
+        }
+    }
+
+    plan.batches = bins
+        .into_iter()
+        .map(|(_, mut indices)| {
+            indices.sort_unstable();
+            indices
+        })
+        .collect();
+    plan.batches.sort_by_key(|indices| indices[0]);
+    plan.oversized.sort_unstable();
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_related_segments_share_a_batch_within_budget() {
+        let paths: Vec<PathBuf> = ["billing.rs", "auth.rs", "billing.rs", "report.rs", "auth.rs", "huge.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let ids: Vec<SegmentId> = (0..paths.len()).map(|index| format!("segment-{}", index)).collect();
+        let mut cross_references = CrossReferenceMap::new();
+        cross_references.add_data_flow(ids[3].clone(), ids[0].clone());
+
+        let mut relations = SegmentRelations::new(paths.len());
+        relations.relate_same_file(paths.iter().map(PathBuf::as_path));
+        relations.relate_cross_referenced(&ids, &cross_references);
+
+        let budget = BatchBudget { segment_tokens: 100, max_segments: 3 };
+        let plan = pack_segments(&[40, 30, 30, 20, 30, 500], &mut relations, budget);
+
+        // billing.rs and the report that reads it together, auth.rs apart, the oversized segment alone
+        assert_eq!(plan.batches, vec![vec![0, 2, 3], vec![1, 4], vec![5]]);
+        assert_eq!(plan.oversized, vec![5]);
+        for batch in &plan.batches[..2] {
+            assert!(batch.iter().map(|&index| [40, 30, 30, 20, 30, 500][index]).sum::<usize>() <= 100);
+        }
+    }
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
 use anyhow::{Result, Context};
 use serde::{Deserialize, Serialize};
+use crate::intelligence::llm_backend::{LlmBackend, LlmProvider};
+use crate::intelligence::llm_cassette::CassetteMode;
+use crate::intelligence::llm_client::ModelConfig;
 use crate::intelligence::ollama_manager::OllamaManager;
 use crate::core::business_purpose_extractor::{BusinessContext, BusinessPurpose, UserPersona, Feature};
 
@@ -16,25 +20,79 @@ pub struct LLMBusinessAnalysis {
 }
 
 pub struct LLMBusinessAnalyzer {
-    ollama_manager: OllamaManager,
-    model_name: String,
+    backend: Arc<dyn LlmBackend>,
+    config: ModelConfig,
 }
 
 impl LLMBusinessAnalyzer {
+    /// Interactive Ollama setup, or a plain connection for other backends and replayed cassettes
     pub async fn new(project_path: &str) -> Result<Self> {
+        let config = ModelConfig::from_env()?;
+        if !Self::selects_ollama_model(&config) {
+            return Self::connect(config).await;
+        }
+
         let mut ollama_manager = OllamaManager::new()?;
         let model_name = ollama_manager.initialize_with_user_interaction(project_path).await?;
 
-        Ok(Self {
-            ollama_manager,
-            model_name,
-        })
+        Self::from_ollama(&ollama_manager, model_name, config)
     }
 
     pub async fn new_non_interactive(project_path: &str) -> Result<Self> {
-        // Initializing LLM Business Analyzer (non-interactive)
-        // Project path validated
-        
+        let config = ModelConfig::from_env()?;
+        let analyzer = if Self::selects_ollama_model(&config) {
+            Self::select_ollama_model(config).await?
+        } else {
+            Self::connect(config).await?
+        };
+
+        // Validate project access (MCP permissions implied by --enable-llm flag)
+        if !std::path::Path::new(project_path).exists() {
+            anyhow::bail!("Project path does not exist: {}", project_path);
+        }
+
+        Ok(analyzer)
+    }
+
+    /// Model selection needs a live Ollama, which a replayed cassette stands in for
+    fn selects_ollama_model(config: &ModelConfig) -> bool {
+        let replaying = config.cassette.as_ref().is_some_and(|cassette| cassette.mode == CassetteMode::Replay);
+        config.provider == LlmProvider::Ollama && !replaying
+    }
+
+    /// Uses an already constructed backend, without a connectivity check
+    pub fn with_backend(backend: Arc<dyn LlmBackend>, config: ModelConfig) -> Self {
+        Self { backend, config }
+    }
+
+    async fn connect(config: ModelConfig) -> Result<Self> {
+        let backend = config.create_backend()?;
+        backend.health_check()
+            .await
+            .with_context(|| format!("{} LLM server at {} is not reachable", config.provider, config.base_url))?;
+        println!("Using model: {}", backend.model());
+
+        Ok(Self::with_backend(backend, config))
+    }
+
+    /// Settings from `ollama_config.json`, keeping the environment's cassette
+    fn from_ollama(ollama_manager: &OllamaManager, model_name: String, env_config: ModelConfig) -> Result<Self> {
+        let ollama_config = ollama_manager.get_config();
+        let config = ModelConfig {
+            provider: LlmProvider::Ollama,
+            model_name,
+            base_url: ollama_config.ollama_url.clone(),
+            context_window: ollama_config.model_settings.context_window,
+            temperature: ollama_config.model_settings.temperature,
+            max_tokens: ollama_config.model_settings.max_tokens,
+            timeout_seconds: ollama_config.model_settings.timeout_seconds,
+            ..env_config
+        };
+
+        Ok(Self::with_backend(config.create_backend()?, config))
+    }
+
+    async fn select_ollama_model(env_config: ModelConfig) -> Result<Self> {
         let ollama_manager = OllamaManager::new()?;
         // OllamaManager created successfully
         
@@ -82,20 +140,8 @@ impl LLMBusinessAnalyzer {
         };
 
         println!("Using model: {}", model_name);
-        
-        // Validate project access (MCP permissions implied by --enable-llm flag)
-        // Validating project path
-        if !std::path::Path::new(project_path).exists() {
-            // Project path does not exist
-            anyhow::bail!("Project path does not exist: {}", project_path);
-        }
-        // Project path exists
 
-        // LLM Business Analyzer initialized successfully
-        Ok(Self {
-            ollama_manager,
-            model_name,
-        })
+        Self::from_ollama(&ollama_manager, model_name, env_config)
     }
 
     pub async fn analyze_business_context(
@@ -149,14 +195,17 @@ impl LLMBusinessAnalyzer {
             project_path, api_endpoints, dependencies, file_names, readme_content
         );
 
-        let response = self.ollama_manager
-            .generate_response(&self.model_name, &prompt)
+        let response = self.generate(&prompt)
             .await
             .with_context(|| "Failed to generate LLM business analysis")?;
 
         self.parse_llm_business_response(&response)
     }
 
+    async fn generate(&self, prompt: &str) -> Result<String> {
+        self.backend.generate(&self.config.request(prompt)).await
+    }
+
     fn build_business_analysis_prompt(
         &self,
         project_path: &str,
@@ -288,7 +337,7 @@ impl LLMBusinessAnalyzer {
             analysis.target_users
         );
 
-        let response = self.ollama_manager.generate_response(&self.model_name, &prompt).await?;
+        let response = self.generate(&prompt).await?;
         
         Ok(response
             .lines()
@@ -324,7 +373,7 @@ impl LLMBusinessAnalyzer {
             analysis.target_users
         );
 
-        let response = self.ollama_manager.generate_response(&self.model_name, &prompt).await?;
+        let response = self.generate(&prompt).await?;
         
         // Try to parse JSON response
         if let Some(start) = response.find('[') {
@@ -379,7 +428,7 @@ impl LLMBusinessAnalyzer {
             analysis.value_proposition
         );
 
-        let response = self.ollama_manager.generate_response(&self.model_name, &prompt).await?;
+        let response = self.generate(&prompt).await?;
         
         Ok(response
             .lines()
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::intelligence::llm_backend::{LlmBackend, LlmProvider, LlmRequest};
+
+/// Whether a cassette captures live traffic or stands in for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteMode {
+    /// Forward to the live backend and store every exchange
+    Record,
+    /// Serve stored exchanges only, never touching the network
+    #[default]
+    Replay,
+}
+
+impl fmt::Display for CassetteMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CassetteMode::Record => "record",
+            CassetteMode::Replay => "replay",
+        })
+    }
+}
+
+impl FromStr for CassetteMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            other => bail!("Unknown cassette mode '{}' (expected record or replay)", other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CassetteConfig {
+    pub dir: PathBuf,
+    pub mode: CassetteMode,
+}
+
+/// A replayed request that was never recorded
+#[derive(Debug, Clone)]
+pub struct CassetteMiss {
+    pub key: String,
+    pub model: String,
+    pub prompt_preview: String,
+}
+
+impl fmt::Display for CassetteMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "No cassette recording for model '{}' and prompt {:?} (key {}); record the cassette again",
+            self.model, self.prompt_preview, self.key
+        )
+    }
+}
+
+impl std::error::Error for CassetteMiss {}
+
+/// One recorded exchange, stored as `<key>.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CassetteEntry {
+    pub model: String,
+    pub prompt_sha256: String,
+    pub temperature: f32,
+    pub max_tokens: usize,
+    pub top_p: f32,
+    pub repeat_penalty: Option<f32>,
+    pub prompt: String,
+    pub response: String,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A recorded server token count, stored as `tokens-<key>.json` so replayed runs batch identically
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenCountEntry {
+    pub model: String,
+    pub text_sha256: String,
+    pub tokens: Option<usize>,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Fields that identify an exchange; the file name is their hash
+#[derive(Serialize)]
+struct CassetteKey<'a> {
+    model: &'a str,
+    prompt_sha256: &'a str,
+    temperature: f32,
+    max_tokens: usize,
+    top_p: f32,
+    repeat_penalty: Option<f32>,
+    /// Skipped when absent so unconstrained recordings keep their keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_schema: Option<&'a serde_json::Value>,
+}
+
+/// Records LLM exchanges to a directory and replays them without a server
+///
+/// Exchanges are keyed by model, prompt hash, sampling options and response schema, so a changed
+/// prompt or option is a miss rather than a stale answer. Replay fails with `CassetteMiss` on a miss.
+#[derive(Debug, Clone)]
+pub struct CassetteBackend {
+    dir: PathBuf,
+    provider: LlmProvider,
+    model: String,
+    /// Present only when recording
+    live: Option<Arc<dyn LlmBackend>>,
+}
+
+impl CassetteBackend {
+    pub fn record(dir: &Path, live: Arc<dyn LlmBackend>) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            provider: live.provider(),
+            model: live.model().to_string(),
+            live: Some(live),
+        }
+    }
+
+    pub fn replay(dir: &Path, provider: LlmProvider, model: &str) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            provider,
+            model: model.to_string(),
+            live: None,
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        if self.live.is_some() { CassetteMode::Record } else { CassetteMode::Replay }
+    }
+
+    fn key(&self, request: &LlmRequest) -> (String, String) {
+        let prompt_sha256 = format!("{:x}", Sha256::digest(request.prompt.as_bytes()));
+        let key = CassetteKey {
+            model: &self.model,
+            prompt_sha256: &prompt_sha256,
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+            top_p: request.top_p,
+            repeat_penalty: request.repeat_penalty,
+            response_schema: request.response_schema.as_ref(),
+        };
+        let encoded = serde_json::to_string(&key).unwrap_or_default();
+        (format!("{:x}", Sha256::digest(encoded.as_bytes())), prompt_sha256)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    fn token_count_key(&self, text: &str) -> (String, String) {
+        let text_sha256 = format!("{:x}", Sha256::digest(text.as_bytes()));
+        let key = format!("{:x}", Sha256::digest(format!("{}\n{}", self.model, text_sha256).as_bytes()));
+        (key, text_sha256)
+    }
+
+    fn write_entry<T: Serialize>(&self, path: &Path, entry: &T) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create cassette directory {}", self.dir.display()))?;
+        std::fs::write(path, serde_json::to_string_pretty(entry)?)
+            .with_context(|| format!("Failed to write cassette entry {}", path.display()))
+    }
+}
+
+#[async_trait]
+impl LlmBackend for CassetteBackend {
+    fn provider(&self) -> LlmProvider {
+        self.provider
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        match &self.live {
+            Some(live) => live.health_check().await,
+            None if self.dir.is_dir() => Ok(()),
+            None => bail!("Cassette directory {} does not exist", self.dir.display()),
+        }
+    }
+
+    async fn list_models(&self) -> Result<Vec<String>> {
+        match &self.live {
+            Some(live) => live.list_models().await,
+            None => Ok(vec![self.model.clone()]),
+        }
+    }
+
+    async fn generate(&self, request: &LlmRequest) -> Result<String> {
+        let (key, prompt_sha256) = self.key(request);
+        let path = self.entry_path(&key);
+
+        let Some(live) = &self.live else {
+            if !path.exists() {
+                return Err(anyhow::Error::new(CassetteMiss {
+                    key,
+                    model: self.model.clone(),
+                    prompt_preview: request.prompt.chars().take(80).collect(),
+                }));
+            }
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read cassette entry {}", path.display()))?;
+            let entry: CassetteEntry = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse cassette entry {}", path.display()))?;
+            return Ok(entry.response);
+        };
+
+        let response = live.generate(request).await?;
+        let entry = CassetteEntry {
+            model: self.model.clone(),
+            prompt_sha256,
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+            top_p: request.top_p,
+            repeat_penalty: request.repeat_penalty,
+            prompt: request.prompt.clone(),
+            response: response.clone(),
+            recorded_at: chrono::Utc::now(),
+        };
+        self.write_entry(&path, &entry)?;
+
+        Ok(response)
+    }
+
+    async fn count_tokens(&self, text: &str) -> Result<Option<usize>> {
+        let (key, text_sha256) = self.token_count_key(text);
+        let path = self.dir.join(format!("tokens-{}.json", key));
+
+        let Some(live) = &self.live else {
+            if !path.exists() {
+                return Err(anyhow::Error::new(CassetteMiss {
+                    key,
+                    model: self.model.clone(),
+                    prompt_preview: text.chars().take(80).collect(),
+                }));
+            }
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read cassette entry {}", path.display()))?;
+            let entry: TokenCountEntry = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse cassette entry {}", path.display()))?;
+            return Ok(entry.tokens);
+        };
+
+        let tokens = live.count_tokens(text).await?;
+        let entry = TokenCountEntry {
+            model: self.model.clone(),
+            text_sha256,
+            tokens,
+            recorded_at: chrono::Utc::now(),
+        };
+        self.write_entry(&path, &entry)?;
+
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ast_analyzer::{CodeSegment, FunctionSegment, SegmentMetadata, SegmentType};
+    use crate::intelligence::llm_backend::FixtureBackend;
+    use crate::intelligence::llm_client::{AnalysisType, LocalLLMManager, ModelConfig};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_recorded_analysis_replays_without_backend_and_misses_fail() {
+        let temp_dir = TempDir::new().unwrap();
+        let segment = CodeSegment {
+            segment_type: SegmentType::Function(FunctionSegment {
+                name: "chargeCard".to_string(),
+                parameters: vec!["amount".to_string()],
+                return_type: None,
+                is_async: true,
+                decorators: Vec::new(),
+            }),
+            content: "async function chargeCard(amount) { return stripe.charge(amount); }".to_string(),
+            metadata: SegmentMetadata {
+                line_start: 1,
+                line_end: 1,
+                file_path: PathBuf::from("src/billing.ts"),
+                byte_start: 0,
+                byte_end: 68,
+            },
+            framework_context: None,
+            business_hints: Vec::new(),
+        };
+        let live = FixtureBackend::new("fixture-model").with_default_response(
+            r#"{"segments": [{"primary_domain": "Payments", "confidence": 0.9, "evidence": ["stripe.charge"]}]}"#,
+        );
+
+        let recorder = CassetteBackend::record(temp_dir.path(), Arc::new(live));
+        let recorded = LocalLLMManager::with_backend(Arc::new(recorder), ModelConfig::default())
+            .analyze_code_segments(std::slice::from_ref(&segment), AnalysisType::BusinessDomain)
+            .await
+            .unwrap();
+
+        let player = CassetteBackend::replay(temp_dir.path(), LlmProvider::Fixture, "fixture-model");
+        let replayed = LocalLLMManager::with_backend(Arc::new(player.clone()), ModelConfig::default())
+            .analyze_code_segments(std::slice::from_ref(&segment), AnalysisType::BusinessDomain)
+            .await
+            .unwrap();
+        assert_eq!(replayed.segments.len(), 1);
+        assert_eq!(replayed.segments[0].primary_domain, recorded.segments[0].primary_domain);
+
+        let miss = player.generate(&LlmRequest::new("never recorded")).await.unwrap_err();
+        assert!(miss.downcast_ref::<CassetteMiss>().is_some());
+        let changed_options = player.generate(&LlmRequest::new("Hello").with_max_tokens(5)).await;
+        assert!(changed_options.is_err());
+    }
+}
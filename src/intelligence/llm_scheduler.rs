@@ -0,0 +1,436 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Once};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::intelligence::llm_backend::{LlmBackend, LlmRequest, LlmStatusError};
+
+/// How often in-flight requests look for a cancellation
+const CANCELLATION_POLL: Duration = Duration::from_millis(100);
+
+type JobFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + 'a>>;
+
+/// Set by the Ctrl-C listener while a run is in progress
+static CTRL_C_PRESSED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchedulerConfig {
+    /// Requests in flight at once; local servers usually serve one or two slots
+    pub max_concurrency: usize,
+    /// Retries after the first attempt, for transient errors and timeouts only
+    pub max_retries: usize,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Limit for a single attempt
+    pub request_timeout: Duration,
+    /// Limit for the whole run; batches not finished by then are reported as incomplete
+    pub run_budget: Option<Duration>,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 2,
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(2),
+            max_backoff: Duration::from_secs(60),
+            request_timeout: Duration::from_secs(180),
+            run_budget: None,
+        }
+    }
+}
+
+/// Why a run stopped before every job had its chance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Cancelled,
+    BudgetExhausted,
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StopReason::Cancelled => "cancelled",
+            StopReason::BudgetExhausted => "run time budget exhausted",
+        })
+    }
+}
+
+/// A job that was cut short by cancellation or the run budget rather than by its own failure
+#[derive(Debug, Clone, Copy)]
+pub struct RunInterrupted(pub StopReason);
+
+impl fmt::Display for RunInterrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LLM run stopped: {}", self.0)
+    }
+}
+
+impl std::error::Error for RunInterrupted {}
+
+/// Cancels a run from another task; Ctrl-C cancels every run in progress
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst) || CTRL_C_PRESSED.load(Ordering::SeqCst)
+    }
+
+    async fn cancelled(&self) {
+        while !self.is_cancelled() {
+            tokio::time::sleep(CANCELLATION_POLL).await;
+        }
+    }
+}
+
+/// Handed to each job so its requests share the run's retries, deadline and cancellation
+#[derive(Debug, Clone)]
+pub struct RunContext {
+    backend: Arc<dyn LlmBackend>,
+    config: SchedulerConfig,
+    deadline: Option<Instant>,
+    cancellation: CancellationToken,
+}
+
+impl RunContext {
+    /// Sends `request`, backing off exponentially between attempts that failed transiently or timed out
+    pub async fn generate(&self, request: &LlmRequest) -> Result<String> {
+        let mut backoff = self.config.initial_backoff;
+        let mut attempt = 0;
+        loop {
+            self.check_stop()?;
+            let timeout = match self.remaining() {
+                Some(remaining) => remaining.min(self.config.request_timeout),
+                None => self.config.request_timeout,
+            };
+
+            let outcome = tokio::select! {
+                outcome = tokio::time::timeout(timeout, self.backend.generate(request)) => outcome,
+                _ = self.cancellation.cancelled() => return Err(RunInterrupted(StopReason::Cancelled).into()),
+            };
+            let error = match outcome {
+                Ok(Ok(response)) => return Ok(response),
+                Ok(Err(error)) if !is_transient(&error) => return Err(error),
+                Ok(Err(error)) => error,
+                Err(_) => {
+                    self.check_stop()?;
+                    anyhow::anyhow!("LLM request timed out after {}s", timeout.as_secs())
+                }
+            };
+
+            if attempt == self.config.max_retries {
+                return Err(error.context(format!("LLM request failed after {} attempts", attempt + 1)));
+            }
+            attempt += 1;
+            if self.remaining().is_some_and(|remaining| remaining <= backoff) {
+                return Err(error.context(RunInterrupted(StopReason::BudgetExhausted)));
+            }
+            println!(
+                "    Transient LLM error, retrying in {}s ({}/{}): {}",
+                backoff.as_secs_f32(),
+                attempt,
+                self.config.max_retries,
+                error
+            );
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = self.cancellation.cancelled() => return Err(RunInterrupted(StopReason::Cancelled).into()),
+            }
+            backoff = (backoff * 2).min(self.config.max_backoff);
+        }
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    fn check_stop(&self) -> Result<()> {
+        if self.cancellation.is_cancelled() {
+            return Err(RunInterrupted(StopReason::Cancelled).into());
+        }
+        if self.remaining() == Some(Duration::ZERO) {
+            return Err(RunInterrupted(StopReason::BudgetExhausted).into());
+        }
+        Ok(())
+    }
+}
+
+/// Timeouts, dropped connections and overload statuses; everything else fails the same way on retry
+pub fn is_transient(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        if let Some(status) = cause.downcast_ref::<LlmStatusError>() {
+            return status.is_transient();
+        }
+        if let Some(http) = cause.downcast_ref::<reqwest::Error>() {
+            return http.is_timeout() || http.is_connect() || http.is_request();
+        }
+        cause.is::<tokio::time::error::Elapsed>()
+    })
+}
+
+/// A job that produced no result
+#[derive(Debug)]
+pub struct JobFailure {
+    pub index: usize,
+    pub error: anyhow::Error,
+}
+
+/// What a run produced, including whatever finished before a failure, cancellation or the budget ran out
+#[derive(Debug)]
+pub struct RunReport<T> {
+    /// Results in job order
+    pub completed: Vec<(usize, T)>,
+    pub failed: Vec<JobFailure>,
+    /// Jobs never started because the run stopped first
+    pub skipped: Vec<usize>,
+    pub stop_reason: Option<StopReason>,
+}
+
+impl<T> RunReport<T> {
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Runs LLM jobs with bounded concurrency, retries, a run time budget and Ctrl-C cancellation
+#[derive(Debug, Clone, Default)]
+pub struct LlmScheduler {
+    config: SchedulerConfig,
+    cancellation: CancellationToken,
+}
+
+impl LlmScheduler {
+    pub fn new(config: SchedulerConfig) -> Self {
+        Self {
+            config,
+            cancellation: CancellationToken::default(),
+        }
+    }
+
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Runs `job(index, context)` for every index below `job_count`, reporting progress as jobs finish
+    ///
+    /// Jobs run concurrently on the calling task, so they may borrow from the caller.
+    pub async fn run<'a, T, F, Fut>(&self, backend: Arc<dyn LlmBackend>, job_count: usize, label: &str, job: F) -> RunReport<T>
+    where
+        F: Fn(usize, RunContext) -> Fut,
+        Fut: Future<Output = Result<T>> + 'a,
+    {
+        let _ctrl_c = CtrlCGuard::install();
+        let start = Instant::now();
+        let context = RunContext {
+            backend,
+            config: self.config.clone(),
+            deadline: self.config.run_budget.map(|budget| start + budget),
+            cancellation: self.cancellation.clone(),
+        };
+
+        let mut report = RunReport { completed: Vec::new(), failed: Vec::new(), skipped: Vec::new(), stop_reason: None };
+        let mut in_flight: Vec<(usize, JobFuture<'a, T>)> = Vec::new();
+        let mut next = 0;
+        while next < job_count || !in_flight.is_empty() {
+            if report.stop_reason.is_none() {
+                report.stop_reason = match context.check_stop() {
+                    Err(error) => error.downcast_ref::<RunInterrupted>().map(|interrupted| interrupted.0),
+                    Ok(()) => None,
+                };
+            }
+            while report.stop_reason.is_none() && next < job_count && in_flight.len() < self.config.max_concurrency.max(1) {
+                in_flight.push((next, Box::pin(job(next, context.clone()))));
+                next += 1;
+            }
+            if in_flight.is_empty() {
+                break;
+            }
+
+            let (index, result) = std::future::poll_fn(|cx| {
+                for position in 0..in_flight.len() {
+                    if let Poll::Ready(result) = in_flight[position].1.as_mut().poll(cx) {
+                        return Poll::Ready((in_flight.swap_remove(position).0, result));
+                    }
+                }
+                Poll::Pending
+            })
+            .await;
+
+            match result {
+                Ok(value) => report.completed.push((index, value)),
+                Err(error) => {
+                    if let Some(interrupted) = error.downcast_ref::<RunInterrupted>() {
+                        report.stop_reason.get_or_insert(interrupted.0);
+                    }
+                    println!("    {} {} failed: {:#}", label, index + 1, error);
+                    report.failed.push(JobFailure { index, error });
+                }
+            }
+            println!(
+                "  Progress: {}/{} done, {} failed, {:.1}s elapsed",
+                report.completed.len() + report.failed.len(),
+                job_count,
+                report.failed.len(),
+                start.elapsed().as_secs_f32()
+            );
+        }
+
+        report.skipped = (next..job_count).collect();
+        if let Some(reason) = report.stop_reason {
+            println!("  ⚠️  Run stopped ({}): {} not started, finished results are kept", reason, report.skipped.len());
+        }
+        report.completed.sort_by_key(|(index, _)| *index);
+        report.failed.sort_by_key(|failure| failure.index);
+        report
+    }
+}
+
+/// Turns Ctrl-C into a cancellation while any run is in progress; a second Ctrl-C exits at once
+struct CtrlCGuard;
+
+/// Runs in progress; Ctrl-C with none in progress exits as it would without the listener
+static ACTIVE_RUNS: Mutex<usize> = Mutex::new(0);
+
+/// Starts the process-wide Ctrl-C listener on first use
+static CTRL_C_LISTENER: Once = Once::new();
+
+impl CtrlCGuard {
+    fn install() -> Self {
+        CTRL_C_LISTENER.call_once(spawn_ctrl_c_listener);
+        let mut active = ACTIVE_RUNS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *active == 0 {
+            CTRL_C_PRESSED.store(false, Ordering::SeqCst);
+        }
+        *active += 1;
+        Self
+    }
+}
+
+impl Drop for CtrlCGuard {
+    fn drop(&mut self) {
+        let mut active = ACTIVE_RUNS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *active -= 1;
+        if *active == 0 && CTRL_C_PRESSED.swap(false, Ordering::SeqCst) {
+            println!("  Run cancelled by Ctrl-C");
+        }
+    }
+}
+
+/// Listens for Ctrl-C on its own thread and runtime, so it outlives the runtime of the run that
+/// started it; tokio keeps its handler installed once listening starts
+fn spawn_ctrl_c_listener() {
+    let spawned = std::thread::Builder::new()
+        .name("llm-ctrl-c".to_string())
+        .spawn(|| {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(error) => {
+                    println!("Warning: Ctrl-C will not cancel LLM runs: {}", error);
+                    return;
+                }
+            };
+            runtime.block_on(async {
+                while tokio::signal::ctrl_c().await.is_ok() {
+                    let active = ACTIVE_RUNS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    if *active == 0 || CTRL_C_PRESSED.swap(true, Ordering::SeqCst) {
+                        std::process::exit(130);
+                    }
+                }
+            });
+        });
+    if let Err(error) = spawned {
+        println!("Warning: Ctrl-C will not cancel LLM runs: {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intelligence::llm_backend::LlmProvider;
+    use async_trait::async_trait;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Fails with 503 until `failures` calls have been made, then echoes the prompt; `permanent` prompts
+    /// get a 400 and `slow` prompts take longer than any test budget
+    #[derive(Debug, Default)]
+    struct FlakyBackend {
+        failures: usize,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LlmBackend for FlakyBackend {
+        fn provider(&self) -> LlmProvider {
+            LlmProvider::Fixture
+        }
+
+        fn model(&self) -> &str {
+            "flaky"
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn list_models(&self) -> Result<Vec<String>> {
+            Ok(vec!["flaky".to_string()])
+        }
+
+        async fn generate(&self, request: &LlmRequest) -> Result<String> {
+            if request.prompt.contains("slow") {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            if request.prompt.contains("permanent") {
+                return Err(LlmStatusError { status: 400, message: "bad request".to_string() }.into());
+            }
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(LlmStatusError { status: 503, message: "overloaded".to_string() }.into());
+            }
+            Ok(request.prompt.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_transient_errors_and_keeps_partial_results() {
+        let backend = Arc::new(FlakyBackend { failures: 2, ..Default::default() });
+        let scheduler = LlmScheduler::new(SchedulerConfig {
+            initial_backoff: Duration::from_millis(1),
+            run_budget: Some(Duration::from_millis(500)),
+            ..Default::default()
+        });
+        let prompts = ["first", "permanent", "slow", "slow", "never started"];
+
+        let report = scheduler
+            .run(backend.clone(), prompts.len(), "Batch", |index, context| async move {
+                context.generate(&LlmRequest::new(prompts[index])).await
+            })
+            .await;
+
+        // The 503s were retried away, the 400 was not, and both slow batches ran into the budget together
+        let completed: Vec<_> = report.completed.iter().map(|(index, response)| (*index, response.as_str())).collect();
+        assert_eq!(completed, vec![(0, "first")]);
+        assert_eq!(report.failed.iter().map(|failure| failure.index).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(!is_transient(&report.failed[0].error));
+        assert_eq!(report.stop_reason, Some(StopReason::BudgetExhausted));
+        assert_eq!(report.skipped, vec![4]);
+        assert!(!report.is_complete());
+
+        let cancelled = LlmScheduler::default();
+        cancelled.cancellation_token().cancel();
+        let report = cancelled
+            .run(backend, 2, "Batch", |_, context| async move { context.generate(&LlmRequest::new("x")).await })
+            .await;
+        assert_eq!((report.completed.len(), report.skipped.len()), (0, 2));
+        assert_eq!(report.stop_reason, Some(StopReason::Cancelled));
+    }
+}
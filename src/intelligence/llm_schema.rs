@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::intelligence::llm_client::RequirementCategory;
+
+/// Segment ids the prompts hand out, `segment_0` for the first segment of a batch
+const SEGMENT_ID_PREFIX: &str = "segment_";
+
+/// The JSON a business domain prompt must answer with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessDomainResponse {
+    pub project_analysis: ProjectSummary,
+    pub functional_requirements: RequirementCategory,
+    pub non_functional_requirements: RequirementCategory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub primary_business_domain: String,
+    pub project_type: String,
+}
+
+/// JSON schema for `BusinessDomainResponse`, for Ollama's `format` field or grammar-constrained decoding
+pub fn business_domain_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "project_analysis": {
+                "type": "object",
+                "properties": {
+                    "primary_business_domain": { "type": "string" },
+                    "project_type": { "type": "string" }
+                },
+                "required": ["primary_business_domain", "project_type"],
+                "additionalProperties": false
+            },
+            "functional_requirements": requirement_category_schema(),
+            "non_functional_requirements": requirement_category_schema()
+        },
+        "required": ["project_analysis", "functional_requirements", "non_functional_requirements"],
+        "additionalProperties": false
+    })
+}
+
+/// Inlined rather than shared through `$ref`, which not every grammar converter resolves
+fn requirement_category_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "description": { "type": "string" },
+            "domains": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": {
+                        "description": { "type": "string" },
+                        "segment_ids": {
+                            "type": "array",
+                            "items": { "type": "string", "pattern": "^segment_[0-9]+$" }
+                        },
+                        "confidence": { "type": "number", "minimum": 0, "maximum": 1 },
+                        "evidence": { "type": "array", "items": { "type": "string" } }
+                    },
+                    "required": ["description", "segment_ids", "confidence", "evidence"],
+                    "additionalProperties": false
+                }
+            }
+        },
+        "required": ["description", "domains"],
+        "additionalProperties": false
+    })
+}
+
+/// Parses a business domain response for a batch of `batch_len` segments, listing every problem found
+pub fn validate_business_domain(response: &str, batch_len: usize) -> Result<BusinessDomainResponse, Vec<String>> {
+    let parsed: BusinessDomainResponse = serde_json::from_str(strip_code_fence(response))
+        .map_err(|e| vec![format!("response is not valid JSON for the schema: {}", e)])?;
+
+    let mut errors = Vec::new();
+    for (group, category) in [
+        ("functional_requirements", &parsed.functional_requirements),
+        ("non_functional_requirements", &parsed.non_functional_requirements),
+    ] {
+        for (domain, analysis) in &category.domains {
+            if !(0.0..=1.0).contains(&analysis.confidence) {
+                errors.push(format!("{}.{}: confidence {} is outside 0..1", group, domain, analysis.confidence));
+            }
+            for segment_id in &analysis.segment_ids {
+                let index = segment_id.strip_prefix(SEGMENT_ID_PREFIX).and_then(|index| index.parse::<usize>().ok());
+                if index.is_none_or(|index| index >= batch_len) {
+                    errors.push(format!(
+                        "{}.{}: unknown segment id '{}' (expected segment_0 to segment_{})",
+                        group,
+                        domain,
+                        segment_id,
+                        batch_len.saturating_sub(1)
+                    ));
+                }
+            }
+        }
+    }
+    if parsed.project_analysis.primary_business_domain.trim().is_empty() {
+        errors.push("project_analysis.primary_business_domain is empty".to_string());
+    }
+
+    if errors.is_empty() { Ok(parsed) } else { Err(errors) }
+}
+
+/// Continues the original prompt with the rejected answer and asks for a corrected one
+pub fn repair_prompt(prompt: &str, previous_response: &str, errors: &[String]) -> String {
+    format!(
+        "{} {}\n\nUser: That response does not match the required JSON schema. Validation errors:\n- {}\n\nRespond with ONLY the corrected JSON.\n\nAssistant:",
+        prompt,
+        previous_response.trim(),
+        errors.join("\n- ")
+    )
+}
+
+/// Models often wrap JSON in a markdown fence even when told not to
+fn strip_code_fence(response: &str) -> &str {
+    let trimmed = response.trim();
+    let Some(inner) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    let inner = inner.strip_prefix("json").unwrap_or(inner);
+    inner.strip_suffix("```").unwrap_or(inner).trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_business_domain_validation_reports_each_problem() {
+        let valid = r#"```json
+{
+  "project_analysis": {"primary_business_domain": "Billing", "project_type": "API Service"},
+  "functional_requirements": {"description": "core", "domains": {
+    "Payments": {"description": "charges cards", "segment_ids": ["segment_0"], "confidence": 0.9, "evidence": ["stripe"]}
+  }},
+  "non_functional_requirements": {"description": "support", "domains": {}}
+}
+```"#;
+        let parsed = validate_business_domain(valid, 1).unwrap();
+        assert_eq!(parsed.functional_requirements.domains["Payments"].segment_ids, vec!["segment_0"]);
+
+        let invalid = valid.replace("\"segment_0\"", "\"segment_4\"").replace("0.9", "1.5");
+        let errors = validate_business_domain(&invalid, 2).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|error| error.contains("segment_4")));
+
+        let missing_field = validate_business_domain(r#"{"segments": []}"#, 1).unwrap_err();
+        assert!(missing_field[0].contains("project_analysis"));
+        assert!(repair_prompt("Assistant:", "{}", &missing_field).contains("missing field"));
+    }
+}
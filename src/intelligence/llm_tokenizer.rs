@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::intelligence::llm_backend::LlmBackend;
+
+/// Splits text into words before merging, after the GPT-4 / Llama 3 pattern. The original's
+/// `\s+(?!\S)` needs lookahead, so trailing whitespace runs split slightly differently.
+const PRETOKENIZE_PATTERN: &str =
+    r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+";
+
+/// SentencePiece's stand-in for a space
+const METASPACE: char = '▁';
+
+/// Counts the tokens a prompt will occupy in the model's context window
+#[async_trait]
+pub trait TokenCounter: Send + Sync + fmt::Debug {
+    async fn count(&self, text: &str) -> Result<usize>;
+
+    /// Shown in progress output, e.g. `bpe (tokenizer.json)`
+    fn describe(&self) -> String;
+}
+
+/// Where token counts come from
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TokenizerSource {
+    /// Character-based estimate, no tokenizer needed
+    #[default]
+    Heuristic,
+    /// A `tokenizer.json` or tiktoken rank file matching the model
+    File(PathBuf),
+    /// The server's own tokenize endpoint
+    Server,
+}
+
+impl FromStr for TokenizerSource {
+    type Err = anyhow::Error;
+
+    /// `heuristic`, `server`, or a path to a tokenizer file
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim() {
+            "" => bail!("Empty tokenizer setting (expected heuristic, server or a tokenizer file path)"),
+            "heuristic" => Ok(TokenizerSource::Heuristic),
+            "server" => Ok(TokenizerSource::Server),
+            path => Ok(TokenizerSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+/// Rounds up from a characters-per-token ratio, erring towards more tokens
+#[derive(Debug, Clone)]
+pub struct HeuristicTokenCounter {
+    chars_per_token: f32,
+}
+
+impl HeuristicTokenCounter {
+    pub fn new(chars_per_token: f32) -> Self {
+        Self { chars_per_token: chars_per_token.max(1.0) }
+    }
+}
+
+impl Default for HeuristicTokenCounter {
+    /// Source code averages 3 to 4 characters per token; the low end keeps batches safe
+    fn default() -> Self {
+        Self::new(3.0)
+    }
+}
+
+#[async_trait]
+impl TokenCounter for HeuristicTokenCounter {
+    async fn count(&self, text: &str) -> Result<usize> {
+        Ok((text.chars().count() as f32 / self.chars_per_token).ceil() as usize)
+    }
+
+    fn describe(&self) -> String {
+        format!("heuristic ({} chars per token)", self.chars_per_token)
+    }
+}
+
+/// Asks the LLM server to tokenize, so counts match the loaded model exactly
+#[derive(Debug, Clone)]
+pub struct BackendTokenCounter {
+    backend: Arc<dyn LlmBackend>,
+}
+
+impl BackendTokenCounter {
+    pub fn new(backend: Arc<dyn LlmBackend>) -> Self {
+        Self { backend }
+    }
+}
+
+#[async_trait]
+impl TokenCounter for BackendTokenCounter {
+    async fn count(&self, text: &str) -> Result<usize> {
+        self.backend.count_tokens(text).await?.with_context(|| {
+            format!(
+                "The {} server has no tokenize endpoint; point LLM_TOKENIZER at a tokenizer file instead",
+                self.backend.provider()
+            )
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!("{} server tokenizer", self.backend.provider())
+    }
+}
+
+/// Merge priorities of a BPE vocabulary
+#[derive(Debug, Clone)]
+enum MergeRanks {
+    /// `tokenizer.json` merges, ranked by position in the list
+    Pairs(HashMap<(String, String), u32>),
+    /// tiktoken ranks: a pair merges when its concatenation is itself a token
+    Tokens(HashMap<String, u32>),
+}
+
+impl MergeRanks {
+    fn rank(&self, left: &str, right: &str) -> Option<u32> {
+        match self {
+            MergeRanks::Pairs(pairs) => pairs.get(&(left.to_string(), right.to_string())).copied(),
+            MergeRanks::Tokens(tokens) => tokens.get(&format!("{}{}", left, right)).copied(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Normalization {
+    /// GPT-2 style: every byte maps to a printable character, so no input is unknown
+    ByteLevel,
+    /// SentencePiece style: spaces become `▁` and unknown characters fall back to one token per byte
+    Metaspace,
+}
+
+/// Byte-pair encoder loaded from a Hugging Face `tokenizer.json` or a tiktoken `.tiktoken` rank file
+///
+/// Only counts tokens; ids are never materialised.
+#[derive(Debug, Clone)]
+pub struct BpeTokenizer {
+    source: PathBuf,
+    merges: MergeRanks,
+    normalization: Normalization,
+    vocab: HashSet<String>,
+    pretokenizer: Regex,
+}
+
+impl BpeTokenizer {
+    /// Picks the format from the content: JSON for `tokenizer.json`, anything else as tiktoken ranks
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tokenizer file {}", path.display()))?;
+        let (merges, normalization, vocab) = if content.trim_start().starts_with('{') {
+            Self::parse_tokenizer_json(&content)
+        } else {
+            Self::parse_tiktoken(&content)
+        }
+        .with_context(|| format!("Failed to load tokenizer file {}", path.display()))?;
+
+        Ok(Self {
+            source: path.to_path_buf(),
+            merges,
+            normalization,
+            vocab,
+            pretokenizer: Regex::new(PRETOKENIZE_PATTERN).expect("pre-tokenizer pattern is valid"),
+        })
+    }
+
+    fn parse_tokenizer_json(content: &str) -> Result<(MergeRanks, Normalization, HashSet<String>)> {
+        let json: Value = serde_json::from_str(content).context("Invalid tokenizer.json")?;
+        let model = json.get("model").context("tokenizer.json has no model section")?;
+        let model_type = model.get("type").and_then(Value::as_str).unwrap_or("BPE");
+        if model_type != "BPE" {
+            bail!("Unsupported tokenizer model '{}' (only BPE is supported)", model_type);
+        }
+
+        let vocab = model
+            .get("vocab")
+            .and_then(Value::as_object)
+            .context("tokenizer.json has no BPE vocab")?
+            .keys()
+            .cloned()
+            .collect();
+
+        // Older files list merges as "a b", newer ones as ["a", "b"]
+        let mut pairs = HashMap::new();
+        for (rank, merge) in model.get("merges").and_then(Value::as_array).into_iter().flatten().enumerate() {
+            let pair = match merge {
+                Value::String(merge) => merge.split_once(' ').map(|(a, b)| (a.to_string(), b.to_string())),
+                Value::Array(parts) => match (parts.first().and_then(Value::as_str), parts.get(1).and_then(Value::as_str)) {
+                    (Some(a), Some(b)) => Some((a.to_string(), b.to_string())),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let (left, right) = pair.with_context(|| format!("Malformed merge at position {}", rank))?;
+            pairs.entry((left, right)).or_insert(rank as u32);
+        }
+
+        let pipeline = format!("{}{}", json.get("pre_tokenizer").unwrap_or(&Value::Null), json.get("decoder").unwrap_or(&Value::Null));
+        let normalization = if pipeline.contains("ByteLevel") { Normalization::ByteLevel } else { Normalization::Metaspace };
+        Ok((MergeRanks::Pairs(pairs), normalization, vocab))
+    }
+
+    /// Lines of `<base64 token> <rank>`
+    fn parse_tiktoken(content: &str) -> Result<(MergeRanks, Normalization, HashSet<String>)> {
+        let byte_chars = byte_to_char_table();
+        let mut tokens = HashMap::new();
+        for (line_number, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let parse = || -> Option<(String, u32)> {
+                let (encoded, rank) = line.trim().split_once(' ')?;
+                let bytes = decode_base64(encoded)?;
+                Some((bytes.iter().map(|&byte| byte_chars[byte as usize]).collect(), rank.parse().ok()?))
+            };
+            let (token, rank) = parse().with_context(|| format!("Malformed tiktoken rank on line {}", line_number + 1))?;
+            tokens.insert(token, rank);
+        }
+        if tokens.is_empty() {
+            bail!("Tokenizer file contains no ranks");
+        }
+        let vocab = tokens.keys().cloned().collect();
+        Ok((MergeRanks::Tokens(tokens), Normalization::ByteLevel, vocab))
+    }
+
+    pub fn count_tokens(&self, text: &str) -> usize {
+        match self.normalization {
+            Normalization::ByteLevel => {
+                let byte_chars = byte_to_char_table();
+                self.pretokenizer
+                    .find_iter(text)
+                    .map(|word| {
+                        let mapped: String = word.as_str().bytes().map(|byte| byte_chars[byte as usize]).collect();
+                        self.count_word(&mapped)
+                    })
+                    .sum()
+            }
+            Normalization::Metaspace => {
+                let normalized = format!("{}{}", METASPACE, text.replace(' ', &METASPACE.to_string()));
+                // Each word keeps its leading `▁`, as SentencePiece vocabularies expect
+                let starts: Vec<usize> = normalized.match_indices(METASPACE).map(|(index, _)| index).collect();
+                starts
+                    .iter()
+                    .zip(starts.iter().skip(1).copied().chain(std::iter::once(normalized.len())))
+                    .map(|(&start, end)| self.count_word(&normalized[start..end]))
+                    .sum()
+            }
+        }
+    }
+
+    /// Applies the lowest-ranked merge until none applies
+    fn count_word(&self, word: &str) -> usize {
+        if self.vocab.contains(word) {
+            return 1;
+        }
+        let mut symbols: Vec<String> = word.chars().map(String::from).collect();
+        loop {
+            let best = symbols
+                .windows(2)
+                .enumerate()
+                .filter_map(|(index, pair)| self.merges.rank(&pair[0], &pair[1]).map(|rank| (rank, index)))
+                .min();
+            let Some((_, index)) = best else { break };
+            let right = symbols.remove(index + 1);
+            symbols[index].push_str(&right);
+        }
+        symbols
+            .iter()
+            .map(|symbol| match self.normalization {
+                Normalization::Metaspace if !self.vocab.contains(symbol) => symbol.len(),
+                _ => 1,
+            })
+            .sum()
+    }
+}
+
+#[async_trait]
+impl TokenCounter for BpeTokenizer {
+    async fn count(&self, text: &str) -> Result<usize> {
+        Ok(self.count_tokens(text))
+    }
+
+    fn describe(&self) -> String {
+        format!("bpe ({})", self.source.display())
+    }
+}
+
+/// GPT-2's reversible byte to printable character mapping, shared by byte-level vocabularies
+fn byte_to_char_table() -> [char; 256] {
+    let mut table = ['\0'; 256];
+    let mut next_unprintable = 256u32;
+    for byte in 0..=255u8 {
+        let printable = matches!(byte, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF);
+        table[byte as usize] = if printable {
+            byte as char
+        } else {
+            let mapped = char::from_u32(next_unprintable).expect("mapped byte is a valid char");
+            next_unprintable += 1;
+            mapped
+        };
+    }
+    table
+}
+
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let value = |c: u8| -> Option<u32> {
+        Some(match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        } as u32)
+    };
+    let digits = encoded.trim_end_matches('=').as_bytes();
+    let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        let mut buffer = 0u32;
+        for (position, &digit) in chunk.iter().enumerate() {
+            buffer |= value(digit)? << (18 - 6 * position);
+        }
+        let decoded = buffer.to_be_bytes();
+        bytes.extend_from_slice(&decoded[1..chunk.len()]);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_bpe_tokenizer_applies_merges_from_either_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let tokenizer_json = temp_dir.path().join("tokenizer.json");
+        std::fs::write(
+            &tokenizer_json,
+            r#"{
+              "pre_tokenizer": {"type": "ByteLevel"},
+              "model": {
+                "type": "BPE",
+                "vocab": {"f": 0, "n": 1, "Ġ": 2, "fn": 3, "Ġfn": 4, "x": 5},
+                "merges": ["f n", ["Ġ", "fn"]]
+              }
+            }"#,
+        )
+        .unwrap();
+        let tokenizer = BpeTokenizer::from_file(&tokenizer_json).unwrap();
+        // Whole words in the vocab are one token; " fnx" merges to "Ġfn" and leaves "x"
+        assert_eq!(tokenizer.count(" fn fn").await.unwrap(), 2);
+        assert_eq!(tokenizer.count("fn fn fnx").await.unwrap(), 4);
+        assert_eq!(tokenizer.count("nf").await.unwrap(), 2);
+
+        // tiktoken: "f" "n" "fn" as base64 with ranks
+        let tiktoken = temp_dir.path().join("cl100k.tiktoken");
+        std::fs::write(&tiktoken, "Zg== 0\nbg== 1\nZm4= 2\n").unwrap();
+        let tokenizer = BpeTokenizer::from_file(&tiktoken).unwrap();
+        assert_eq!(tokenizer.count("fnfn").await.unwrap(), 2);
+
+        assert_eq!(HeuristicTokenCounter::default().count("abcdefg").await.unwrap(), 3);
+        assert_eq!("server".parse::<TokenizerSource>().unwrap(), TokenizerSource::Server);
+    }
+}
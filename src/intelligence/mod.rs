@@ -3,11 +3,20 @@ use crate::core::{
     ImplementationStatus, ComponentType
 };
 
+pub mod llm_backend;
+pub mod llm_batcher;
+pub mod llm_cassette;
 pub mod llm_client;
+pub mod llm_schema;
+pub mod llm_scheduler;
+pub mod llm_tokenizer;
 pub mod ollama_manager;
 pub mod llm_business_analyzer;
 
+pub use llm_backend::*;
+pub use llm_cassette::*;
 pub use llm_client::*;
+pub use llm_tokenizer::*;
 pub use ollama_manager::*;
 pub use llm_business_analyzer::*;
 
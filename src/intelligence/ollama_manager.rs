@@ -3,6 +3,7 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 use anyhow::{Result, Context, bail};
 use std::io::{self, Write};
+use crate::intelligence::llm_backend::{LlmBackend, LlmRequest, OllamaBackend};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OllamaConfig {
@@ -433,75 +434,16 @@ impl OllamaManager {
     }
 
     pub async fn generate_response(&self, model: &str, prompt: &str) -> Result<String> {
-        // Generating LLM response
-        // Using model for generation
-        // Processing prompt
-        // Connecting to Ollama API
-        
-        let request_payload = serde_json::json!({
-            "model": model,
-            "prompt": prompt,
-            "stream": false,
-            "options": {
-                "temperature": self.config.model_settings.temperature,
-                "num_predict": self.config.model_settings.max_tokens,
-                "top_p": self.config.model_settings.top_p,
-                "repeat_penalty": self.config.model_settings.repeat_penalty
-            }
-        });
-        
-        // Preparing request payload
-        
-        // Sending request to Ollama
-        let response = match self.client
-            .post(&format!("{}/api/generate", self.config.ollama_url))
-            .json(&request_payload)
-            .send()
-            .await {
-                Ok(resp) => {
-                    // Received response from Ollama
-                    // Response received successfully
-                    resp
-                }
-                Err(e) => {
-                    // Failed to send request to Ollama
-                    return Err(e.into());
-                }
-            };
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await?;
-            // Ollama returned error
-            bail!("LLM generation failed (status {}): {}", status, error_text);
-        }
-
-        // Parsing response JSON
-        let response_text = response.text().await?;
-        // Processing response
-        
-        let response_json: serde_json::Value = match serde_json::from_str(&response_text) {
-            Ok(json) => json,
-            Err(e) => {
-                // Failed to parse JSON
-                bail!("Failed to parse JSON response from Ollama: {}", e);
-            }
-        };
-        
-        // Skip logging full JSON to avoid token arrays in output
-        // Successfully parsed response JSON
-        
-        match response_json["response"].as_str() {
-            Some(content) => {
-                // Extracted response content
-                // Content extracted successfully
-                Ok(content.to_string())
-            }
-            None => {
-                // No 'response' field found in JSON
-                bail!("Invalid response format from LLM - missing 'response' field")
-            }
-        }
+        let settings = &self.config.model_settings;
+        let backend = OllamaBackend::new(&self.config.ollama_url, model, settings.timeout_seconds)?
+            .with_context_window(settings.context_window);
+        let request = LlmRequest::new(prompt)
+            .with_temperature(settings.temperature)
+            .with_max_tokens(settings.max_tokens)
+            .with_top_p(settings.top_p)
+            .with_repeat_penalty(settings.repeat_penalty);
+
+        backend.generate(&request).await
     }
 
     pub fn get_config(&self) -> &OllamaConfig {